@@ -204,10 +204,9 @@ where
         // Note: See DomainJson implementation for explanation of block_on() usage.
         // This is the standard Actix-web 4.x extractor pattern.
         ready(match futures::executor::block_on(json_fut) {
-            Ok(web::Json(dto)) => dto.validate().map(|_| ValidatedJson(dto)).map_err(|e| {
-                use domainstack_envelope::IntoEnvelopeError;
-                ErrorResponse(e.into_envelope_error())
-            }),
+            Ok(web::Json(dto)) => domainstack_http::validate_dto(dto)
+                .map(ValidatedJson)
+                .map_err(ErrorResponse),
             Err(e) => Err(ErrorResponse(error_envelope::Error::bad_request(format!(
                 "Invalid JSON: {}",
                 e
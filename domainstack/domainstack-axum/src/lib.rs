@@ -189,10 +189,9 @@ where
             )))
         })?;
 
-        dto.validate().map(|_| ValidatedJson(dto)).map_err(|e| {
-            use domainstack_envelope::IntoEnvelopeError;
-            ErrorResponse(e.into_envelope_error())
-        })
+        domainstack_http::validate_dto(dto)
+            .map(ValidatedJson)
+            .map_err(ErrorResponse)
     }
 }
 
@@ -424,6 +423,54 @@ mod tests {
         response.assert_status_bad_request();
     }
 
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_validated_json_emits_tracing() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = Router::new().route("/", post(accept_validated_dto));
+        let server = axum_test::TestServer::new(app).unwrap();
+        server
+            .post("/")
+            .json(&serde_json::json!({"name": "A", "age": 30}))
+            .await
+            .assert_status_bad_request();
+
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("ValidatedUserDto"), "{}", output);
+        assert!(output.contains("path=name"), "{}", output);
+        assert!(output.contains("code=\"min_length\""), "{}", output);
+        assert_eq!(
+            output.matches("validation violation").count(),
+            1,
+            "{}",
+            output
+        );
+    }
+
     #[tokio::test]
     async fn test_error_response_into_response() {
         let err = ErrorResponse(error_envelope::Error::bad_request("Test error"));
@@ -1,3 +1,158 @@
+use crate::parser::{CrossFieldRule, ValidationRule};
+use domainstack::rules::iso;
+
 pub mod json_schema;
 pub mod openapi;
 pub mod zod;
+
+/// Pattern for `mac_address`: colon or hyphen separated octets, or `aabb.ccdd.eeff`.
+pub(crate) const MAC_ADDRESS_PATTERN: &str = r"^(?:[0-9A-Fa-f]{2}([:-])(?:[0-9A-Fa-f]{2}\1){4}[0-9A-Fa-f]{2}|[0-9A-Fa-f]{4}\.[0-9A-Fa-f]{4}\.[0-9A-Fa-f]{4})$";
+
+/// Pattern for `port_range`: `"443"` or `"8000-8999"`.
+pub(crate) const PORT_RANGE_PATTERN: &str = r"^[1-9][0-9]{0,4}(?:-[1-9][0-9]{0,4})?$";
+
+// Syntactic shape of the financial identifiers; checksums are only enforced server-side.
+pub(crate) const LUHN_PATTERN: &str = r"^[0-9]{2,}$";
+pub(crate) const CREDIT_CARD_PATTERN: &str = r"^[0-9](?:[ -]?[0-9]){11,18}$";
+pub(crate) const IBAN_PATTERN: &str = r"^[A-Za-z]{2}[0-9]{2}(?: ?[A-Za-z0-9]){11,30}$";
+pub(crate) const BIC_PATTERN: &str = r"^[A-Z]{6}[A-Z0-9]{2}(?:[A-Z0-9]{3})?$";
+pub(crate) const ISIN_PATTERN: &str = r"^[A-Z]{2}[A-Z0-9]{9}[0-9]$";
+pub(crate) const ABA_ROUTING_PATTERN: &str = r"^[0-9]{9}$";
+
+/// Pattern for `bcp47_locale`: RFC 5646 tag shape; subtag registries are only checked server-side.
+pub(crate) const BCP47_LOCALE_PATTERN: &str = r"^(?:[A-Za-z]{2,3}(?:-[A-Za-z]{3}){0,3}(?:-[A-Za-z]{4})?(?:-(?:[A-Za-z]{2}|[0-9]{3}))?(?:-(?:[A-Za-z0-9]{5,8}|[0-9][A-Za-z0-9]{3}))*(?:-[0-9A-WYZa-wyz](?:-[A-Za-z0-9]{2,8})+)*(?:-[Xx](?:-[A-Za-z0-9]{1,8})+)?|[Xx](?:-[A-Za-z0-9]{1,8})+)$";
+
+// Identifier and encoding shapes
+pub(crate) const ULID_PATTERN: &str = r"^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$";
+pub(crate) const BASE64_PATTERN: &str =
+    r"^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$";
+pub(crate) const BASE64URL_PATTERN: &str =
+    r"^(?:[A-Za-z0-9_-]{4})*(?:[A-Za-z0-9_-]{2}(?:==)?|[A-Za-z0-9_-]{3}=?)?$";
+pub(crate) const JWT_PATTERN: &str = r"^[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*$";
+
+// Input safety rules; device names, the 255-byte limit and percent-encoded
+// segments are only checked server-side.
+pub(crate) const SAFE_FILENAME_PATTERN: &str =
+    r#"^(?!-)[^/\\<>:"|?*\u0000-\u001F\u007F-\u009F]*[^/\\<>:"|?*\u0000-\u001F\u007F-\u009F. ]$"#;
+pub(crate) const NO_PATH_TRAVERSAL_PATTERN: &str =
+    r"^(?![/\\]|[A-Za-z]:)(?![\s\S]*(?:^|[/\\])\.\.(?:[/\\]|$))";
+pub(crate) const NO_NULL_BYTES_PATTERN: &str = r"^[^\u0000]*$";
+pub(crate) const NO_HTML_TAGS_PATTERN: &str = r"^(?![\s\S]*<[A-Za-z/!?])";
+pub(crate) const NO_TEMPLATE_SYNTAX_PATTERN: &str = r"^(?![\s\S]*(?:\{\{|\{%|\{#|\$\{|#\{|<%))";
+
+/// Pattern for a safety rule, if `rule` is one.
+pub(crate) fn safety_pattern(rule: &ValidationRule) -> Option<&'static str> {
+    match rule {
+        ValidationRule::SafeFilename => Some(SAFE_FILENAME_PATTERN),
+        ValidationRule::NoPathTraversal => Some(NO_PATH_TRAVERSAL_PATTERN),
+        ValidationRule::NoNullBytes => Some(NO_NULL_BYTES_PATTERN),
+        ValidationRule::NoHtmlTags => Some(NO_HTML_TAGS_PATTERN),
+        ValidationRule::NoTemplateSyntax => Some(NO_TEMPLATE_SYNTAX_PATTERN),
+        _ => None,
+    }
+}
+
+/// UUID pattern pinning the version nibble and the RFC 9562 variant.
+pub(crate) fn uuid_pattern(version: u8) -> String {
+    format!(
+        "^[0-9A-Fa-f]{{8}}-[0-9A-Fa-f]{{4}}-{}[0-9A-Fa-f]{{3}}-[89ABab][0-9A-Fa-f]{{3}}-[0-9A-Fa-f]{{12}}$",
+        version
+    )
+}
+
+/// Hex digits, with an exact count when `len` is set.
+pub(crate) fn hex_pattern(len: Option<usize>) -> String {
+    match len {
+        Some(len) => format!("^[0-9A-Fa-f]{{{}}}$", len),
+        None => "^[0-9A-Fa-f]+$".to_string(),
+    }
+}
+
+/// Allowed codes for the ISO code-set rules, taken from the tables the
+/// runtime rules check against.
+pub(crate) fn code_set(rule: &ValidationRule) -> Option<&'static [&'static str]> {
+    match rule {
+        ValidationRule::Iso3166Alpha2 => Some(iso::ISO3166_ALPHA2),
+        ValidationRule::Iso3166Alpha3 => Some(iso::ISO3166_ALPHA3),
+        ValidationRule::Iso4217Currency => Some(iso::ISO4217_CURRENCIES),
+        ValidationRule::Iso639Language => Some(iso::ISO639_LANGUAGES),
+        _ => None,
+    }
+}
+
+/// JSON Schema `format` for an address inside `cidr`.
+pub(crate) fn ip_format(cidr: &str) -> &'static str {
+    if cidr.contains(':') {
+        "ipv6"
+    } else {
+        "ipv4"
+    }
+}
+
+/// Object-level keywords for the cross-field rules of a type.
+///
+/// With `json` set, `required_if` uses the JSON Schema `dependentRequired` and
+/// `if` / `then` keywords; OpenAPI 3.0 gets the equivalent `anyOf` / `not`
+/// implication. `fields_equal` and `field_lt` compare values and have no schema
+/// keyword.
+pub(crate) fn apply_cross_field_rules(
+    schema: &mut serde_json::Value,
+    rules: &[CrossFieldRule],
+    json: bool,
+) {
+    use serde_json::{json, Value};
+
+    let required = |fields: &[&String]| json!({ "required": fields });
+    let mut all_of = Vec::new();
+
+    for rule in rules {
+        let constraint = match rule {
+            CrossFieldRule::RequiredIf {
+                field,
+                depends_on,
+                value,
+            } => {
+                let condition = match value {
+                    None if json => {
+                        let dependent = &mut schema["dependentRequired"][depends_on.as_str()];
+                        if !dependent.is_array() {
+                            *dependent = json!([]);
+                        }
+                        if let Some(fields) = dependent.as_array_mut() {
+                            fields.push(Value::String(field.clone()));
+                        }
+                        continue;
+                    }
+                    None => required(&[depends_on]),
+                    Some(value) => json!({
+                        "properties": { depends_on.as_str(): { "enum": [value] } },
+                        "required": [depends_on],
+                    }),
+                };
+                if json {
+                    json!({ "if": condition, "then": required(&[field]) })
+                } else {
+                    json!({ "anyOf": [{ "not": condition }, required(&[field])] })
+                }
+            }
+            CrossFieldRule::RequiredUnless { field, depends_on } => {
+                json!({ "anyOf": [required(&[field]), required(&[depends_on])] })
+            }
+            CrossFieldRule::MutuallyExclusive(a, b) => json!({ "not": required(&[a, b]) }),
+            CrossFieldRule::AtLeastOneOf(fields) => {
+                let each: Vec<Value> = fields.iter().map(|f| required(&[f])).collect();
+                json!({ "anyOf": each })
+            }
+            CrossFieldRule::ExactlyOneOf(fields) => {
+                let each: Vec<Value> = fields.iter().map(|f| required(&[f])).collect();
+                json!({ "oneOf": each })
+            }
+            CrossFieldRule::FieldsEqual(..) | CrossFieldRule::FieldLt(..) => continue,
+        };
+        all_of.push(constraint);
+    }
+
+    if !all_of.is_empty() {
+        schema["allOf"] = Value::Array(all_of);
+    }
+}
@@ -1,3 +1,9 @@
+use super::{
+    apply_cross_field_rules, code_set, hex_pattern, ip_format, safety_pattern, uuid_pattern,
+    ABA_ROUTING_PATTERN, BASE64URL_PATTERN, BASE64_PATTERN, BCP47_LOCALE_PATTERN, BIC_PATTERN,
+    CREDIT_CARD_PATTERN, IBAN_PATTERN, ISIN_PATTERN, JWT_PATTERN, LUHN_PATTERN,
+    MAC_ADDRESS_PATTERN, PORT_RANGE_PATTERN, ULID_PATTERN,
+};
 use crate::parser::{FieldType, ParsedField, ParsedType, ValidationRule};
 use anyhow::Result;
 use serde_json::{json, Map, Value};
@@ -68,6 +74,8 @@ fn generate_type_schema(parsed_type: &ParsedType, version: OpenApiVersion) -> Re
         schema["required"] = Value::Array(required);
     }
 
+    apply_cross_field_rules(&mut schema, &parsed_type.cross_field_rules, false);
+
     Ok(schema)
 }
 
@@ -191,6 +199,103 @@ fn apply_validation_rule(schema: &mut Value, rule: &ValidationRule, _field_type:
             schema["pattern"] = json!(r"^\S*$");
         }
 
+        // Network identifier validations
+        ValidationRule::Ipv4 => {
+            schema["format"] = json!("ipv4");
+        }
+        ValidationRule::Ipv6 => {
+            schema["format"] = json!("ipv6");
+        }
+        ValidationRule::Cidr => {
+            schema["format"] = json!("cidr");
+        }
+        ValidationRule::IpInCidr(cidr) => {
+            schema["format"] = json!(ip_format(cidr));
+        }
+        ValidationRule::Hostname | ValidationRule::Fqdn => {
+            schema["format"] = json!("idn-hostname");
+        }
+        ValidationRule::PortRange => {
+            schema["pattern"] = json!(PORT_RANGE_PATTERN);
+        }
+        ValidationRule::MacAddress => {
+            schema["pattern"] = json!(MAC_ADDRESS_PATTERN);
+        }
+
+        // Financial identifier validations
+        ValidationRule::Luhn => {
+            schema["pattern"] = json!(LUHN_PATTERN);
+        }
+        ValidationRule::CreditCard => {
+            schema["pattern"] = json!(CREDIT_CARD_PATTERN);
+        }
+        ValidationRule::Iban => {
+            schema["pattern"] = json!(IBAN_PATTERN);
+        }
+        ValidationRule::Bic => {
+            schema["pattern"] = json!(BIC_PATTERN);
+        }
+        ValidationRule::Isin => {
+            schema["pattern"] = json!(ISIN_PATTERN);
+        }
+        ValidationRule::AbaRouting => {
+            schema["pattern"] = json!(ABA_ROUTING_PATTERN);
+        }
+
+        // ISO code-set validations
+        ValidationRule::Iso3166Alpha2
+        | ValidationRule::Iso3166Alpha3
+        | ValidationRule::Iso4217Currency
+        | ValidationRule::Iso639Language => {
+            schema["enum"] = json!(code_set(rule));
+        }
+        ValidationRule::Bcp47Locale => {
+            schema["pattern"] = json!(BCP47_LOCALE_PATTERN);
+        }
+
+        // Identifier and encoding validations
+        ValidationRule::Uuid(version) => {
+            schema["format"] = json!("uuid");
+            if let Some(version) = version {
+                schema["pattern"] = json!(uuid_pattern(*version));
+            }
+        }
+        ValidationRule::Ulid => {
+            schema["pattern"] = json!(ULID_PATTERN);
+        }
+        ValidationRule::Base64 => {
+            schema["pattern"] = json!(BASE64_PATTERN);
+        }
+        ValidationRule::Base64Url => {
+            schema["pattern"] = json!(BASE64URL_PATTERN);
+        }
+        ValidationRule::Hex(len) => {
+            schema["pattern"] = json!(hex_pattern(*len));
+        }
+        ValidationRule::JwtStructure => {
+            schema["pattern"] = json!(JWT_PATTERN);
+        }
+
+        // Input safety validations
+        ValidationRule::SafeFilename
+        | ValidationRule::NoPathTraversal
+        | ValidationRule::NoNullBytes
+        | ValidationRule::NoHtmlTags
+        | ValidationRule::NoTemplateSyntax => {
+            schema["pattern"] = json!(safety_pattern(rule));
+        }
+
+        // Password policy: character classes, entropy and forbidden words are server-side only
+        ValidationRule::Password {
+            min_length,
+            max_length,
+            ..
+        } => {
+            schema["format"] = json!("password");
+            schema["minLength"] = json!(min_length);
+            schema["maxLength"] = json!(max_length);
+        }
+
         // Numeric validations
         ValidationRule::Range { min, max } => {
             if let Ok(min_num) = min.parse::<f64>() {
@@ -265,6 +370,7 @@ fn regex_escape(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::CrossFieldRule;
 
     #[test]
     fn test_generate_basic_type() {
@@ -285,6 +391,7 @@ mod tests {
                     }],
                 },
             ],
+            cross_field_rules: vec![],
         }];
 
         let output = generate(&types, OpenApiVersion::V3_0).unwrap();
@@ -307,6 +414,7 @@ mod tests {
                 ty: FieldType::Option(Box::new(FieldType::String)),
                 validation_rules: vec![],
             }],
+            cross_field_rules: vec![],
         }];
 
         let output = generate(&types, OpenApiVersion::V3_1).unwrap();
@@ -328,6 +436,7 @@ mod tests {
                 ty: FieldType::Option(Box::new(FieldType::String)),
                 validation_rules: vec![],
             }],
+            cross_field_rules: vec![],
         }];
 
         let output = generate(&types, OpenApiVersion::V3_0).unwrap();
@@ -349,6 +458,7 @@ mod tests {
                 ty: FieldType::Custom("Customer".to_string()),
                 validation_rules: vec![],
             }],
+            cross_field_rules: vec![],
         }];
 
         let output = generate(&types, OpenApiVersion::V3_0).unwrap();
@@ -429,6 +539,132 @@ mod tests {
         assert_eq!(schema["format"], "email");
     }
 
+    #[test]
+    fn test_network_validations() {
+        let cases = [
+            (ValidationRule::Ipv4, "format", "ipv4"),
+            (ValidationRule::Ipv6, "format", "ipv6"),
+            (ValidationRule::Cidr, "format", "cidr"),
+            (
+                ValidationRule::IpInCidr("10.0.0.0/8".to_string()),
+                "format",
+                "ipv4",
+            ),
+            (ValidationRule::Hostname, "format", "idn-hostname"),
+            (ValidationRule::Fqdn, "format", "idn-hostname"),
+            (ValidationRule::PortRange, "pattern", PORT_RANGE_PATTERN),
+            (ValidationRule::MacAddress, "pattern", MAC_ADDRESS_PATTERN),
+        ];
+
+        for (rule, key, expected) in cases {
+            let mut schema = json!({ "type": "string" });
+            apply_validation_rule(&mut schema, &rule, &FieldType::String);
+            assert_eq!(schema[key], expected, "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_finance_validations() {
+        let cases = [
+            (ValidationRule::Luhn, LUHN_PATTERN),
+            (ValidationRule::CreditCard, CREDIT_CARD_PATTERN),
+            (ValidationRule::Iban, IBAN_PATTERN),
+            (ValidationRule::Bic, BIC_PATTERN),
+            (ValidationRule::Isin, ISIN_PATTERN),
+            (ValidationRule::AbaRouting, ABA_ROUTING_PATTERN),
+        ];
+
+        for (rule, expected) in cases {
+            let mut schema = json!({ "type": "string" });
+            apply_validation_rule(&mut schema, &rule, &FieldType::String);
+            assert_eq!(schema["pattern"], expected, "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_identifier_validations() {
+        let mut schema = json!({ "type": "string" });
+        apply_validation_rule(&mut schema, &ValidationRule::Uuid(None), &FieldType::String);
+        assert_eq!(schema["format"], "uuid");
+        assert!(schema.get("pattern").is_none());
+
+        let mut schema = json!({ "type": "string" });
+        apply_validation_rule(
+            &mut schema,
+            &ValidationRule::Uuid(Some(7)),
+            &FieldType::String,
+        );
+        assert_eq!(schema["format"], "uuid");
+        assert!(schema["pattern"]
+            .as_str()
+            .unwrap()
+            .contains("-7[0-9A-Fa-f]{3}-"));
+
+        let cases = [
+            (ValidationRule::Ulid, ULID_PATTERN.to_string()),
+            (ValidationRule::Base64, BASE64_PATTERN.to_string()),
+            (ValidationRule::Base64Url, BASE64URL_PATTERN.to_string()),
+            (
+                ValidationRule::Hex(Some(40)),
+                "^[0-9A-Fa-f]{40}$".to_string(),
+            ),
+            (ValidationRule::JwtStructure, JWT_PATTERN.to_string()),
+        ];
+        for (rule, expected) in cases {
+            let mut schema = json!({ "type": "string" });
+            apply_validation_rule(&mut schema, &rule, &FieldType::String);
+            assert_eq!(schema["pattern"], expected, "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_password_validation() {
+        let mut schema = json!({ "type": "string" });
+        let rule = ValidationRule::Password {
+            min_length: 12,
+            max_length: 64,
+            lowercase: true,
+            uppercase: true,
+            digit: false,
+            symbol: false,
+        };
+        apply_validation_rule(&mut schema, &rule, &FieldType::String);
+        assert_eq!(schema["format"], "password");
+        assert_eq!(schema["minLength"], 12);
+        assert_eq!(schema["maxLength"], 64);
+        assert!(schema.get("pattern").is_none());
+    }
+
+    #[test]
+    fn test_iso_validations() {
+        let mut schema = json!({ "type": "string" });
+        apply_validation_rule(
+            &mut schema,
+            &ValidationRule::Iso4217Currency,
+            &FieldType::String,
+        );
+        let codes = schema["enum"].as_array().unwrap();
+        assert!(codes.contains(&json!("EUR")));
+        assert!(!codes.contains(&json!("eur")));
+
+        let mut schema = json!({ "type": "string" });
+        apply_validation_rule(
+            &mut schema,
+            &ValidationRule::Iso3166Alpha2,
+            &FieldType::String,
+        );
+        assert_eq!(schema["enum"].as_array().unwrap().len(), 249);
+
+        let mut schema = json!({ "type": "string" });
+        apply_validation_rule(
+            &mut schema,
+            &ValidationRule::Bcp47Locale,
+            &FieldType::String,
+        );
+        assert_eq!(schema["pattern"], BCP47_LOCALE_PATTERN);
+        assert!(schema.get("enum").is_none());
+    }
+
     #[test]
     fn test_url_validation() {
         let mut schema = json!({ "type": "string" });
@@ -682,6 +918,7 @@ mod tests {
                     ty: FieldType::String,
                     validation_rules: vec![],
                 }],
+                cross_field_rules: vec![],
             },
             ParsedType {
                 name: "Order".to_string(),
@@ -690,6 +927,7 @@ mod tests {
                     ty: FieldType::F64,
                     validation_rules: vec![],
                 }],
+                cross_field_rules: vec![],
             },
         ];
 
@@ -729,6 +967,7 @@ mod tests {
                     validation_rules: vec![],
                 },
             ],
+            cross_field_rules: vec![],
         };
 
         let schema = generate_type_schema(&parsed_type, OpenApiVersion::V3_0).unwrap();
@@ -754,6 +993,7 @@ mod tests {
                     validation_rules: vec![],
                 },
             ],
+            cross_field_rules: vec![],
         };
 
         let schema = generate_type_schema(&parsed_type, OpenApiVersion::V3_0).unwrap();
@@ -767,6 +1007,7 @@ mod tests {
         let parsed_type = ParsedType {
             name: "Strict".to_string(),
             fields: vec![],
+            cross_field_rules: vec![],
         };
 
         let schema = generate_type_schema(&parsed_type, OpenApiVersion::V3_0).unwrap();
@@ -784,6 +1025,7 @@ mod tests {
         let types = vec![ParsedType {
             name: "Test".to_string(),
             fields: vec![],
+            cross_field_rules: vec![],
         }];
 
         let output = generate(&types, OpenApiVersion::V3_1).unwrap();
@@ -909,4 +1151,37 @@ mod tests {
             assert_eq!(schema["type"], "integer");
         }
     }
+
+    #[test]
+    fn test_cross_field_constraints() {
+        let parsed_type = ParsedType {
+            name: "Customer".to_string(),
+            fields: vec![],
+            cross_field_rules: vec![
+                CrossFieldRule::RequiredIf {
+                    field: "tax_id".to_string(),
+                    depends_on: "company".to_string(),
+                    value: None,
+                },
+                CrossFieldRule::RequiredUnless {
+                    field: "email".to_string(),
+                    depends_on: "phone".to_string(),
+                },
+                CrossFieldRule::AtLeastOneOf(vec!["email".to_string(), "phone".to_string()]),
+                CrossFieldRule::FieldsEqual("a".to_string(), "b".to_string()),
+            ],
+        };
+
+        let schema = generate_type_schema(&parsed_type, OpenApiVersion::V3_0).unwrap();
+
+        assert!(schema.get("dependentRequired").is_none());
+        assert_eq!(
+            schema["allOf"],
+            json!([
+                { "anyOf": [{ "not": { "required": ["company"] } }, { "required": ["tax_id"] }] },
+                { "anyOf": [{ "required": ["email"] }, { "required": ["phone"] }] },
+                { "anyOf": [{ "required": ["email"] }, { "required": ["phone"] }] }
+            ])
+        );
+    }
 }
@@ -1,4 +1,9 @@
-use crate::parser::{FieldType, ParsedField, ParsedType, ValidationRule};
+use super::{
+    code_set, hex_pattern, ip_format, safety_pattern, uuid_pattern, ABA_ROUTING_PATTERN,
+    BASE64URL_PATTERN, BASE64_PATTERN, BCP47_LOCALE_PATTERN, BIC_PATTERN, CREDIT_CARD_PATTERN,
+    IBAN_PATTERN, ISIN_PATTERN, JWT_PATTERN, LUHN_PATTERN, MAC_ADDRESS_PATTERN, PORT_RANGE_PATTERN,
+};
+use crate::parser::{CrossFieldRule, FieldType, ParsedField, ParsedType, ValidationRule};
 use anyhow::Result;
 
 /// Generate Zod schemas from parsed types
@@ -42,7 +47,9 @@ fn generate_type_schema(output: &mut String, parsed_type: &ParsedType) -> Result
         output.push_str(",\n");
     }
 
-    output.push_str("});\n\n");
+    output.push_str("})");
+    generate_cross_field_refinement(output, parsed_type);
+    output.push_str(";\n\n");
 
     // Generate TypeScript type
     output.push_str(&format!(
@@ -53,6 +60,122 @@ fn generate_type_schema(output: &mut String, parsed_type: &ParsedType) -> Result
     Ok(())
 }
 
+/// Append a `.superRefine(...)` that mirrors the struct's cross-field rules
+fn generate_cross_field_refinement(output: &mut String, parsed_type: &ParsedType) {
+    if parsed_type.cross_field_rules.is_empty() {
+        return;
+    }
+
+    let present = |name: &str| presence_check(parsed_type, name);
+    let issue = |path: Option<&str>, message: String| {
+        let path = path
+            .map(|p| format!("path: [\"{}\"], ", p))
+            .unwrap_or_default();
+        format!(
+            "ctx.addIssue({{ code: z.ZodIssueCode.custom, {}message: \"{}\" }});",
+            path,
+            escape_string(&message)
+        )
+    };
+
+    output.push_str(".superRefine((value, ctx) => {\n");
+    for rule in &parsed_type.cross_field_rules {
+        let (condition, body) = match rule {
+            CrossFieldRule::RequiredIf {
+                field,
+                depends_on,
+                value,
+            } => {
+                // Same message as `rules::required_if_equals`, which names the value
+                let (when, state) = match value {
+                    Some(value) => (
+                        format!("value.{} === {}", depends_on, value),
+                        value
+                            .as_str()
+                            .map_or_else(|| value.to_string(), str::to_string),
+                    ),
+                    None => (present(depends_on), "set".to_string()),
+                };
+                (
+                    format!("{} && !({})", when, present(field)),
+                    issue(
+                        Some(field),
+                        format!("Required when {} is {}", depends_on, state),
+                    ),
+                )
+            }
+            CrossFieldRule::RequiredUnless { field, depends_on } => (
+                format!("!({}) && !({})", present(depends_on), present(field)),
+                issue(
+                    Some(field),
+                    format!("Required unless {} is set", depends_on),
+                ),
+            ),
+            CrossFieldRule::MutuallyExclusive(a, b) => (
+                format!("{} && {}", present(a), present(b)),
+                issue(Some(b), format!("Cannot be set together with {}", a)),
+            ),
+            CrossFieldRule::AtLeastOneOf(fields) => {
+                let any: Vec<String> = fields.iter().map(|f| present(f)).collect();
+                (
+                    format!("!({})", any.join(" || ")),
+                    issue(
+                        None,
+                        format!("At least one of {} is required", fields.join(", ")),
+                    ),
+                )
+            }
+            CrossFieldRule::ExactlyOneOf(fields) => {
+                let flags: Vec<String> = fields
+                    .iter()
+                    .map(|f| format!("{{ field: \"{}\", present: {} }}", f, present(f)))
+                    .collect();
+                output.push_str(&format!(
+                    "  {{\n    const set = [{}].filter((f) => f.present).map((f) => f.field);\n",
+                    flags.join(", ")
+                ));
+                output.push_str(&format!(
+                    "    if (set.length === 0) {{\n      {}\n    }}\n",
+                    issue(
+                        None,
+                        format!("Exactly one of {} is required", fields.join(", "))
+                    )
+                ));
+                output.push_str(
+                    "    for (const field of set.slice(1)) {\n      \
+                     ctx.addIssue({ code: z.ZodIssueCode.custom, path: [field], \
+                     message: `Cannot be set together with ${set[0]}` });\n    }\n  }\n",
+                );
+                continue;
+            }
+            CrossFieldRule::FieldsEqual(a, b) => (
+                format!("value.{} !== value.{}", a, b),
+                issue(Some(b), format!("Must match {}", a)),
+            ),
+            CrossFieldRule::FieldLt(a, b) => (
+                format!("!(value.{} < value.{})", a, b),
+                issue(Some(a), format!("Must be less than {}", b)),
+            ),
+        };
+        output.push_str(&format!("  if ({}) {{\n    {}\n  }}\n", condition, body));
+    }
+    output.push_str("})");
+}
+
+/// JavaScript mirror of `Presence::is_present` for a field of `parsed_type`
+fn presence_check(parsed_type: &ParsedType, name: &str) -> String {
+    let ty = parsed_type
+        .fields
+        .iter()
+        .find(|field| field.name == name)
+        .map(|field| &field.ty);
+    match ty {
+        Some(FieldType::Option(_)) => format!("value.{} !== undefined", name),
+        Some(FieldType::String) | Some(FieldType::Vec(_)) => format!("value.{}.length > 0", name),
+        _ => "true".to_string(),
+    }
+}
+
 fn generate_field_schema(output: &mut String, field: &ParsedField) -> Result<()> {
     // Check if this is an optional field
     let is_optional = matches!(field.ty, FieldType::Option(_));
@@ -69,11 +192,19 @@ fn generate_field_schema(output: &mut String, field: &ParsedField) -> Result<()>
         generate_base_type(&field.ty)
     };
 
-    output.push_str(&base_schema);
-
-    // Add validation rules (before .optional())
-    for rule in &field.validation_rules {
-        generate_validation_rule(output, rule, &field.ty)?;
+    // ISO code sets become a z.enum, which has no string refinements; the
+    // code list already pins down every accepted value
+    let codes = field.validation_rules.iter().find_map(code_set);
+    match codes {
+        Some(codes) if base_schema == "z.string()" => output.push_str(&generate_enum(codes)),
+        _ => {
+            output.push_str(&base_schema);
+
+            // Add validation rules (before .optional())
+            for rule in &field.validation_rules {
+                generate_validation_rule(output, rule, &field.ty)?;
+            }
+        }
     }
 
     // Add .optional() at the end if this is an Option type
@@ -114,6 +245,11 @@ fn generate_base_type(field_type: &FieldType) -> String {
     }
 }
 
+fn generate_enum(codes: &[&str]) -> String {
+    let values: Vec<String> = codes.iter().map(|code| format!("\"{}\"", code)).collect();
+    format!("z.enum([{}])", values.join(", "))
+}
+
 fn generate_validation_rule(
     output: &mut String,
     rule: &ValidationRule,
@@ -148,6 +284,101 @@ fn generate_validation_rule(
         }
         ValidationRule::NoWhitespace => output.push_str(".regex(/^\\S*$/)"),
 
+        // Network identifier validations
+        ValidationRule::Ipv4 => output.push_str(".ip({ version: \"v4\" })"),
+        ValidationRule::Ipv6 => output.push_str(".ip({ version: \"v6\" })"),
+        ValidationRule::Cidr => output.push_str(".cidr()"),
+        ValidationRule::IpInCidr(cidr) => {
+            let version = if ip_format(cidr) == "ipv6" {
+                "v6"
+            } else {
+                "v4"
+            };
+            output.push_str(&format!(
+                ".ip({{ version: \"{}\" }}) /* Must be in {} */",
+                version, cidr
+            ));
+        }
+        ValidationRule::Hostname => {
+            output.push_str(&format!(".regex(/{}/u)", HOSTNAME_PATTERN));
+        }
+        ValidationRule::Fqdn => {
+            output.push_str(&format!(".regex(/{}/u)", FQDN_PATTERN));
+        }
+        ValidationRule::PortRange => {
+            output.push_str(&format!(".regex(/{}/)", PORT_RANGE_PATTERN));
+        }
+        ValidationRule::MacAddress => {
+            output.push_str(&format!(".regex(/{}/)", MAC_ADDRESS_PATTERN));
+        }
+
+        // Financial identifier validations
+        ValidationRule::Luhn => output.push_str(&format!(".regex(/{}/)", LUHN_PATTERN)),
+        ValidationRule::CreditCard => {
+            output.push_str(&format!(".regex(/{}/)", CREDIT_CARD_PATTERN));
+        }
+        ValidationRule::Iban => output.push_str(&format!(".regex(/{}/)", IBAN_PATTERN)),
+        ValidationRule::Bic => output.push_str(&format!(".regex(/{}/)", BIC_PATTERN)),
+        ValidationRule::Isin => output.push_str(&format!(".regex(/{}/)", ISIN_PATTERN)),
+        ValidationRule::AbaRouting => {
+            output.push_str(&format!(".regex(/{}/)", ABA_ROUTING_PATTERN));
+        }
+
+        // ISO code-set validations (code sets are emitted as z.enum by generate_field_schema)
+        ValidationRule::Iso3166Alpha2
+        | ValidationRule::Iso3166Alpha3
+        | ValidationRule::Iso4217Currency
+        | ValidationRule::Iso639Language => {
+            output.push_str(" /* Must be one of the ISO code set values */");
+        }
+        ValidationRule::Bcp47Locale => {
+            output.push_str(&format!(".regex(/{}/)", BCP47_LOCALE_PATTERN));
+        }
+
+        // Identifier and encoding validations
+        ValidationRule::Uuid(None) => output.push_str(".uuid()"),
+        ValidationRule::Uuid(Some(version)) => {
+            output.push_str(&format!(".uuid().regex(/{}/)", uuid_pattern(*version)));
+        }
+        ValidationRule::Ulid => output.push_str(".ulid()"),
+        ValidationRule::Base64 => output.push_str(&format!(".regex(/{}/)", BASE64_PATTERN)),
+        ValidationRule::Base64Url => {
+            output.push_str(&format!(".regex(/{}/)", BASE64URL_PATTERN));
+        }
+        ValidationRule::Hex(len) => output.push_str(&format!(".regex(/{}/)", hex_pattern(*len))),
+        ValidationRule::JwtStructure => output.push_str(&format!(".regex(/{}/)", JWT_PATTERN)),
+
+        // Input safety validations
+        ValidationRule::SafeFilename
+        | ValidationRule::NoPathTraversal
+        | ValidationRule::NoNullBytes
+        | ValidationRule::NoHtmlTags
+        | ValidationRule::NoTemplateSyntax => {
+            let pattern = safety_pattern(rule).unwrap_or_default();
+            output.push_str(&format!(".regex(/{}/)", pattern));
+        }
+
+        // Password policy: one check per character class, so each shows up as its own issue
+        ValidationRule::Password {
+            min_length,
+            max_length,
+            lowercase,
+            uppercase,
+            digit,
+            symbol,
+        } => {
+            output.push_str(&format!(".min({}).max({})", min_length, max_length));
+            let classes = [
+                (*lowercase, r"\p{Ll}", "Must contain a lowercase letter"),
+                (*uppercase, r"\p{Lu}", "Must contain an uppercase letter"),
+                (*digit, "[0-9]", "Must contain a digit"),
+                (*symbol, r"[^\p{L}\p{N}]", "Must contain a symbol"),
+            ];
+            for (_, pattern, message) in classes.iter().filter(|(required, ..)| *required) {
+                output.push_str(&format!(".regex(/{}/u, \"{}\")", pattern, message));
+            }
+        }
+
         // Numeric validations
         ValidationRule::Range { min, max } => {
             output.push_str(&format!(".min({}).max({})", min, max));
@@ -173,6 +404,12 @@ fn generate_validation_rule(
     Ok(())
 }
 
+/// Unicode-aware host name: labels of letters, digits and inner hyphens.
+const HOSTNAME_PATTERN: &str = r"^(?=.{1,253}$)[\p{L}\p{N}](?:[\p{L}\p{N}-]{0,61}[\p{L}\p{N}])?(?:\.[\p{L}\p{N}](?:[\p{L}\p{N}-]{0,61}[\p{L}\p{N}])?)*$";
+
+/// Host name with at least two labels and an optional trailing dot.
+const FQDN_PATTERN: &str = r"^(?=.{1,254}$)(?:[\p{L}\p{N}](?:[\p{L}\p{N}-]{0,61}[\p{L}\p{N}])?\.)+[\p{L}\p{N}][\p{L}\p{N}-]{0,61}[\p{L}\p{N}]\.?$";
+
 fn escape_string(s: &str) -> String {
     s.replace('\\', "\\\\")
         .replace('"', "\\\"")
@@ -237,6 +474,140 @@ mod tests {
         assert_eq!(result, "UserSchema /* Custom type */");
     }
 
+    #[test]
+    fn test_network_validation_rules() {
+        let mut output = String::new();
+
+        generate_validation_rule(&mut output, &ValidationRule::Ipv4, &FieldType::String).unwrap();
+        assert_eq!(output, ".ip({ version: \"v4\" })");
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::Ipv6, &FieldType::String).unwrap();
+        assert_eq!(output, ".ip({ version: \"v6\" })");
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::Cidr, &FieldType::String).unwrap();
+        assert_eq!(output, ".cidr()");
+
+        output.clear();
+        generate_validation_rule(
+            &mut output,
+            &ValidationRule::IpInCidr("fd00::/8".to_string()),
+            &FieldType::String,
+        )
+        .unwrap();
+        assert_eq!(output, ".ip({ version: \"v6\" }) /* Must be in fd00::/8 */");
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::Hostname, &FieldType::String)
+            .unwrap();
+        assert!(output.starts_with(".regex(/^"));
+        assert!(output.ends_with("$/u)"));
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::PortRange, &FieldType::String)
+            .unwrap();
+        assert_eq!(output, ".regex(/^[1-9][0-9]{0,4}(?:-[1-9][0-9]{0,4})?$/)");
+    }
+
+    #[test]
+    fn test_finance_validation_rules() {
+        let mut output = String::new();
+
+        generate_validation_rule(&mut output, &ValidationRule::Iban, &FieldType::String).unwrap();
+        assert_eq!(
+            output,
+            ".regex(/^[A-Za-z]{2}[0-9]{2}(?: ?[A-Za-z0-9]){11,30}$/)"
+        );
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::AbaRouting, &FieldType::String)
+            .unwrap();
+        assert_eq!(output, ".regex(/^[0-9]{9}$/)");
+    }
+
+    #[test]
+    fn test_identifier_validation_rules() {
+        let mut output = String::new();
+
+        generate_validation_rule(&mut output, &ValidationRule::Uuid(None), &FieldType::String)
+            .unwrap();
+        assert_eq!(output, ".uuid()");
+
+        output.clear();
+        generate_validation_rule(
+            &mut output,
+            &ValidationRule::Uuid(Some(4)),
+            &FieldType::String,
+        )
+        .unwrap();
+        assert!(output.starts_with(".uuid().regex(/^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-4"));
+
+        output.clear();
+        generate_validation_rule(&mut output, &ValidationRule::Ulid, &FieldType::String).unwrap();
+        assert_eq!(output, ".ulid()");
+
+        output.clear();
+        generate_validation_rule(
+            &mut output,
+            &ValidationRule::Hex(Some(64)),
+            &FieldType::String,
+        )
+        .unwrap();
+        assert_eq!(output, ".regex(/^[0-9A-Fa-f]{64}$/)");
+
+        output.clear();
+        generate_validation_rule(
+            &mut output,
+            &ValidationRule::JwtStructure,
+            &FieldType::String,
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            ".regex(/^[A-Za-z0-9_-]+\\.[A-Za-z0-9_-]+\\.[A-Za-z0-9_-]*$/)"
+        );
+    }
+
+    #[test]
+    fn test_security_validation_rules() {
+        let mut output = String::new();
+        generate_validation_rule(&mut output, &ValidationRule::NoHtmlTags, &FieldType::String)
+            .unwrap();
+        assert_eq!(output, ".regex(/^(?![\\s\\S]*<[A-Za-z/!?])/)");
+
+        for rule in [
+            ValidationRule::SafeFilename,
+            ValidationRule::NoPathTraversal,
+            ValidationRule::NoNullBytes,
+            ValidationRule::NoTemplateSyntax,
+        ] {
+            output.clear();
+            generate_validation_rule(&mut output, &rule, &FieldType::String).unwrap();
+            assert!(output.starts_with(".regex(/^"), "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_password_validation_rule() {
+        let mut output = String::new();
+        let rule = ValidationRule::Password {
+            min_length: 12,
+            max_length: 128,
+            lowercase: false,
+            uppercase: true,
+            digit: true,
+            symbol: false,
+        };
+        generate_validation_rule(&mut output, &rule, &FieldType::String).unwrap();
+        assert_eq!(
+            output,
+            ".min(12).max(128)\
+             .regex(/\\p{Lu}/u, \"Must contain an uppercase letter\")\
+             .regex(/[0-9]/u, \"Must contain a digit\")"
+        );
+    }
+
     #[test]
     fn test_string_validation_rules() {
         let mut output = String::new();
@@ -460,6 +831,30 @@ mod tests {
         assert_eq!(output, "z.string().url().optional()");
     }
 
+    #[test]
+    fn test_field_schema_iso_code_sets() {
+        let mut output = String::new();
+        let field = ParsedField {
+            name: "currency".to_string(),
+            ty: FieldType::Option(Box::new(FieldType::String)),
+            validation_rules: vec![ValidationRule::Iso4217Currency, ValidationRule::MaxLen(3)],
+        };
+
+        generate_field_schema(&mut output, &field).unwrap();
+        assert!(output.starts_with("z.enum([\"AED\", \"AFN\", "));
+        assert!(output.contains("\"EUR\""));
+        assert!(output.ends_with("\"ZWL\"]).optional()"));
+
+        output.clear();
+        let field = ParsedField {
+            name: "locale".to_string(),
+            ty: FieldType::String,
+            validation_rules: vec![ValidationRule::Bcp47Locale],
+        };
+        generate_field_schema(&mut output, &field).unwrap();
+        assert!(output.starts_with("z.string().regex(/^(?:[A-Za-z]{2,3}"));
+    }
+
     #[test]
     fn test_field_schema_multiple_rules() {
         let mut output = String::new();
@@ -497,6 +892,7 @@ mod tests {
                     }],
                 },
             ],
+            cross_field_rules: vec![],
         }];
 
         let result = generate(&types).unwrap();
@@ -591,6 +987,7 @@ mod tests {
                     ty: FieldType::U32,
                     validation_rules: vec![],
                 }],
+                cross_field_rules: vec![],
             },
             ParsedType {
                 name: "Order".to_string(),
@@ -599,6 +996,7 @@ mod tests {
                     ty: FieldType::F64,
                     validation_rules: vec![],
                 }],
+                cross_field_rules: vec![],
             },
         ];
 
@@ -713,6 +1111,7 @@ mod tests {
         let types = vec![ParsedType {
             name: "Empty".to_string(),
             fields: vec![],
+            cross_field_rules: vec![],
         }];
         let result = generate(&types).unwrap();
         assert!(result.contains("export const EmptySchema = z.object({"));
@@ -730,4 +1129,74 @@ mod tests {
         generate_field_schema(&mut output, &field).unwrap();
         assert_eq!(output, "z.string()");
     }
+
+    #[test]
+    fn test_cross_field_refinement() {
+        let field = |name: &str, ty: FieldType| ParsedField {
+            name: name.to_string(),
+            ty,
+            validation_rules: vec![],
+        };
+        let optional = || FieldType::Option(Box::new(FieldType::String));
+        let types = vec![ParsedType {
+            name: "Checkout".to_string(),
+            fields: vec![
+                field("country", FieldType::String),
+                field("state", optional()),
+                field("card", optional()),
+                field("iban", optional()),
+                field("password", FieldType::String),
+                field("password_confirmation", FieldType::String),
+                field("seats", FieldType::U32),
+                field("reason", optional()),
+            ],
+            cross_field_rules: vec![
+                CrossFieldRule::RequiredIf {
+                    field: "state".to_string(),
+                    depends_on: "country".to_string(),
+                    value: Some("US".into()),
+                },
+                CrossFieldRule::RequiredIf {
+                    field: "reason".to_string(),
+                    depends_on: "seats".to_string(),
+                    value: Some(0.into()),
+                },
+                CrossFieldRule::RequiredIf {
+                    field: "reason".to_string(),
+                    depends_on: "state".to_string(),
+                    value: None,
+                },
+                CrossFieldRule::ExactlyOneOf(vec!["card".to_string(), "iban".to_string()]),
+                CrossFieldRule::FieldsEqual(
+                    "password".to_string(),
+                    "password_confirmation".to_string(),
+                ),
+            ],
+        }];
+
+        let result = generate(&types).unwrap();
+
+        assert!(result.contains("}).superRefine((value, ctx) => {"));
+        assert!(result.contains(
+            "if (value.country === \"US\" && !(value.state !== undefined)) {\n    \
+             ctx.addIssue({ code: z.ZodIssueCode.custom, path: [\"state\"], \
+             message: \"Required when country is US\" });"
+        ));
+        assert!(result.contains(
+            "if (value.seats === 0 && !(value.reason !== undefined)) {\n    \
+             ctx.addIssue({ code: z.ZodIssueCode.custom, path: [\"reason\"], \
+             message: \"Required when seats is 0\" });"
+        ));
+        assert!(result.contains("message: \"Required when state is set\""));
+        assert!(result.contains(
+            "const set = [{ field: \"card\", present: value.card !== undefined }, \
+             { field: \"iban\", present: value.iban !== undefined }]"
+        ));
+        assert!(result.contains("message: \"Exactly one of card, iban is required\""));
+        assert!(result.contains("if (value.password !== value.password_confirmation) {"));
+        assert!(
+            result.contains("path: [\"password_confirmation\"], message: \"Must match password\"")
+        );
+        assert!(result.contains("}\n});"));
+    }
 }
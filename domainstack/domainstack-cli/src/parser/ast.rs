@@ -1,4 +1,4 @@
-use super::ValidationRule;
+use super::{CrossFieldRule, ValidationRule};
 use anyhow::Result;
 use quote::ToTokens;
 use syn::{File, Item, ItemStruct, Type};
@@ -8,6 +8,7 @@ use syn::{File, Item, ItemStruct, Type};
 pub struct ParsedType {
     pub name: String,
     pub fields: Vec<ParsedField>,
+    pub cross_field_rules: Vec<CrossFieldRule>,
 }
 
 #[derive(Debug, Clone)]
@@ -98,6 +99,7 @@ fn parse_struct(struct_item: &ItemStruct) -> Result<Option<ParsedType>> {
     Ok(Some(ParsedType {
         name: struct_item.ident.to_string(),
         fields,
+        cross_field_rules: super::validation::parse_cross_field_rules(&struct_item.attrs)?,
     }))
 }
 
@@ -6,7 +6,7 @@ use std::path::Path;
 use walkdir::WalkDir;
 
 pub use ast::{FieldType, ParsedField, ParsedType};
-pub use validation::ValidationRule;
+pub use validation::{CrossFieldRule, ValidationRule};
 
 /// Parse all Rust files in a directory and extract types with validation rules
 pub fn parse_directory(path: &Path) -> Result<Vec<ParsedType>> {
@@ -9,7 +9,10 @@ pub enum ValidationRule {
     Url,
     MinLen(usize),
     MaxLen(usize),
-    Length { min: usize, max: usize },
+    Length {
+        min: usize,
+        max: usize,
+    },
     NonEmpty,
     NonBlank,
     Alphanumeric,
@@ -22,8 +25,62 @@ pub enum ValidationRule {
     MatchesRegex(String),
     NoWhitespace,
 
+    // Network identifier rules
+    Ipv4,
+    Ipv6,
+    Cidr,
+    IpInCidr(String),
+    Hostname,
+    Fqdn,
+    PortRange,
+    MacAddress,
+
+    // Financial identifier rules
+    Luhn,
+    CreditCard,
+    Iban,
+    Bic,
+    Isin,
+    AbaRouting,
+
+    // ISO code-set rules
+    Iso3166Alpha2,
+    Iso3166Alpha3,
+    Iso4217Currency,
+    Iso639Language,
+    Bcp47Locale,
+
+    // Identifier and encoding rules
+    Uuid(Option<u8>),
+    Ulid,
+    Base64,
+    Base64Url,
+    Hex(Option<usize>),
+    JwtStructure,
+
+    // Input safety rules; device names, the 255-byte limit and percent-encoded
+    // segments are server-side only
+    SafeFilename,
+    NoPathTraversal,
+    NoNullBytes,
+    NoHtmlTags,
+    NoTemplateSyntax,
+
+    // Password policy; entropy, repeat and forbidden-substring checks are server-side only
+    Password {
+        min_length: usize,
+        max_length: usize,
+        lowercase: bool,
+        uppercase: bool,
+        digit: bool,
+        symbol: bool,
+    },
+
     // Numeric rules
-    Range { min: String, max: String },
+    Range {
+        min: String,
+        max: String,
+    },
     Min(String),
     Max(String),
     Positive,
@@ -36,6 +93,54 @@ pub enum ValidationRule {
     Custom(String),
 }
 
+/// Parse `password` or `password(...)`, keeping the options a client schema can express
+fn parse_password(meta: &syn::meta::ParseNestedMeta) -> syn::Result<ValidationRule> {
+    let defaults = domainstack::rules::PasswordPolicy::default();
+    let mut min_length = defaults.min_length;
+    let mut max_length = defaults.max_length;
+    let (mut lowercase, mut uppercase, mut digit, mut symbol) = (false, false, false, false);
+
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|option| {
+            let name = option
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            match name.as_str() {
+                "min_length" => {
+                    let value: syn::LitInt = option.value()?.parse()?;
+                    min_length = value.base10_parse()?;
+                }
+                "max_length" => {
+                    let value: syn::LitInt = option.value()?.parse()?;
+                    max_length = value.base10_parse()?;
+                }
+                "lowercase" => lowercase = true,
+                "uppercase" => uppercase = true,
+                "digit" => digit = true,
+                "symbol" => symbol = true,
+                _ => {
+                    // min_entropy, max_repeats, forbid, forbid_fields
+                    if option.input.peek(syn::Token![=]) {
+                        option.value()?.parse::<syn::Expr>()?;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(ValidationRule::Password {
+        min_length,
+        max_length,
+        lowercase,
+        uppercase,
+        digit,
+        symbol,
+    })
+}
+
 /// Parse validation rules from field attributes
 pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<ValidationRule>> {
     let mut rules = Vec::new();
@@ -43,6 +148,10 @@ pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<Validation
     for attr in attrs {
         if let Some(ident) = attr.path().get_ident() {
             if ident == "validate" {
+                // `groups = [...]` applies to every rule in the same attribute
+                let start = rules.len();
+                let mut groups: Vec<String> = Vec::new();
+
                 // Parse the validation attribute
                 attr.parse_nested_meta(|meta| {
                     let path = &meta.path;
@@ -51,6 +160,10 @@ pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<Validation
                         let rule_name = ident.to_string();
 
                         match rule_name.as_str() {
+                            "groups" => {
+                                let value: syn::Expr = meta.value()?.parse()?;
+                                groups = parse_groups(&value);
+                            }
                             "email" => rules.push(ValidationRule::Email),
                             "url" => rules.push(ValidationRule::Url),
                             "non_empty" => rules.push(ValidationRule::NonEmpty),
@@ -64,6 +177,63 @@ pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<Validation
                             "negative" => rules.push(ValidationRule::Negative),
                             "non_zero" => rules.push(ValidationRule::NonZero),
                             "finite" => rules.push(ValidationRule::Finite),
+                            "ipv4" => rules.push(ValidationRule::Ipv4),
+                            "ipv6" => rules.push(ValidationRule::Ipv6),
+                            "cidr" => rules.push(ValidationRule::Cidr),
+                            "hostname" => rules.push(ValidationRule::Hostname),
+                            "fqdn" => rules.push(ValidationRule::Fqdn),
+                            "port_range" => rules.push(ValidationRule::PortRange),
+                            "mac_address" => rules.push(ValidationRule::MacAddress),
+                            "luhn" => rules.push(ValidationRule::Luhn),
+                            "credit_card" => rules.push(ValidationRule::CreditCard),
+                            "iban" => rules.push(ValidationRule::Iban),
+                            "bic" => rules.push(ValidationRule::Bic),
+                            "isin" => rules.push(ValidationRule::Isin),
+                            "aba_routing" => rules.push(ValidationRule::AbaRouting),
+                            "iso3166_alpha2" => rules.push(ValidationRule::Iso3166Alpha2),
+                            "iso3166_alpha3" => rules.push(ValidationRule::Iso3166Alpha3),
+                            "iso4217_currency" => rules.push(ValidationRule::Iso4217Currency),
+                            "iso639_language" => rules.push(ValidationRule::Iso639Language),
+                            "bcp47_locale" => rules.push(ValidationRule::Bcp47Locale),
+                            "ulid" => rules.push(ValidationRule::Ulid),
+                            "base64" => rules.push(ValidationRule::Base64),
+                            "base64url" => rules.push(ValidationRule::Base64Url),
+                            "jwt_structure" => rules.push(ValidationRule::JwtStructure),
+                            "safe_filename" => rules.push(ValidationRule::SafeFilename),
+                            "no_path_traversal" => rules.push(ValidationRule::NoPathTraversal),
+                            "no_null_bytes" => rules.push(ValidationRule::NoNullBytes),
+                            "no_html_tags" => rules.push(ValidationRule::NoHtmlTags),
+                            "no_template_syntax" => rules.push(ValidationRule::NoTemplateSyntax),
+                            "max_nesting" => {
+                                // JSON depth has no schema keyword; checked server-side
+                                meta.value()?.parse::<syn::Expr>()?;
+                            }
+                            "uuid" => {
+                                let mut version = None;
+                                if meta.input.peek(syn::Token![=]) {
+                                    meta.input.parse::<syn::Token![=]>()?;
+                                    let value: syn::LitInt = meta.input.parse()?;
+                                    version = Some(value.base10_parse()?);
+                                }
+                                rules.push(ValidationRule::Uuid(version));
+                            }
+                            "hex" => {
+                                let mut len = None;
+                                if meta.input.peek(syn::Token![=]) {
+                                    meta.input.parse::<syn::Token![=]>()?;
+                                    let value: syn::LitInt = meta.input.parse()?;
+                                    len = Some(value.base10_parse()?);
+                                }
+                                rules.push(ValidationRule::Hex(len));
+                            }
+                            "password" => rules.push(parse_password(&meta)?),
+                            "ip_in_cidr" => {
+                                if meta.input.peek(syn::Token![=]) {
+                                    meta.input.parse::<syn::Token![=]>()?;
+                                    let value: syn::LitStr = meta.input.parse()?;
+                                    rules.push(ValidationRule::IpInCidr(value.value()));
+                                }
+                            }
                             "min_len" => {
                                 if meta.input.peek(syn::Token![=]) {
                                     meta.input.parse::<syn::Token![=]>()?;
@@ -213,6 +383,11 @@ pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<Validation
 
                     Ok(())
                 })?;
+
+                // Generated schemas describe the default group
+                if !groups.is_empty() && !groups.iter().any(|g| g == "default") {
+                    rules.truncate(start);
+                }
             }
         }
     }
@@ -220,6 +395,167 @@ pub fn parse_validation_attributes(attrs: &[Attribute]) -> Result<Vec<Validation
     Ok(rules)
 }
 
+/// A struct-level rule relating two or more fields
+#[derive(Debug, Clone)]
+pub enum CrossFieldRule {
+    RequiredIf {
+        field: String,
+        depends_on: String,
+        // Literal `value = ...`; `None` means "when depends_on is set"
+        value: Option<serde_json::Value>,
+    },
+    RequiredUnless {
+        field: String,
+        depends_on: String,
+    },
+    MutuallyExclusive(String, String),
+    AtLeastOneOf(Vec<String>),
+    ExactlyOneOf(Vec<String>),
+    FieldsEqual(String, String),
+    FieldLt(String, String),
+}
+
+/// Parse cross-field rules from struct attributes
+///
+/// Rules guarded by `when` or outside the default group depend on runtime state
+/// and are left out, as are `required_if` conditions that aren't literals.
+pub fn parse_cross_field_rules(attrs: &[Attribute]) -> Result<Vec<CrossFieldRule>> {
+    let mut rules = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        let mut parsed = Vec::new();
+        let mut runtime = false;
+
+        attr.parse_nested_meta(|meta| {
+            let name = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+
+            match name.as_str() {
+                "required_if" | "required_unless" => {
+                    let (mut field, mut depends_on) = (String::new(), String::new());
+                    let mut value = None;
+                    let mut literal = true;
+                    meta.parse_nested_meta(|option| {
+                        if option.path.is_ident("value") {
+                            match option.value()?.parse::<syn::Expr>()? {
+                                syn::Expr::Lit(expr) => {
+                                    value = lit_to_json(&expr.lit);
+                                    literal = value.is_some();
+                                }
+                                _ => literal = false,
+                            }
+                        } else {
+                            let lit: syn::LitStr = option.value()?.parse()?;
+                            if option.path.is_ident("field") {
+                                field = lit.value();
+                            } else if option.path.is_ident("depends_on") {
+                                depends_on = lit.value();
+                            }
+                        }
+                        Ok(())
+                    })?;
+                    if name == "required_unless" {
+                        parsed.push(CrossFieldRule::RequiredUnless { field, depends_on });
+                    } else if literal {
+                        parsed.push(CrossFieldRule::RequiredIf {
+                            field,
+                            depends_on,
+                            value,
+                        });
+                    }
+                }
+                "mutually_exclusive" | "at_least_one_of" | "exactly_one_of" | "fields_equal"
+                | "field_lt" => {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let fields: Vec<String> = content
+                        .parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?
+                        .iter()
+                        .map(syn::LitStr::value)
+                        .collect();
+                    let pair = || (fields[0].clone(), fields[1].clone());
+                    match name.as_str() {
+                        _ if fields.len() < 2 => {}
+                        "mutually_exclusive" => {
+                            let (a, b) = pair();
+                            parsed.push(CrossFieldRule::MutuallyExclusive(a, b));
+                        }
+                        "fields_equal" => {
+                            let (a, b) = pair();
+                            parsed.push(CrossFieldRule::FieldsEqual(a, b));
+                        }
+                        "field_lt" => {
+                            let (a, b) = pair();
+                            parsed.push(CrossFieldRule::FieldLt(a, b));
+                        }
+                        "at_least_one_of" => parsed.push(CrossFieldRule::AtLeastOneOf(fields)),
+                        _ => parsed.push(CrossFieldRule::ExactlyOneOf(fields)),
+                    }
+                }
+                "when" => {
+                    runtime = true;
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+                "groups" => {
+                    let value: syn::Expr = meta.value()?.parse()?;
+                    let groups = parse_groups(&value);
+                    runtime |= !groups.is_empty() && !groups.iter().any(|g| g == "default");
+                }
+                _ => {
+                    // check, code, message, range_fields(...)
+                    if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?;
+                    } else if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        content.parse::<proc_macro2::TokenStream>()?;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+
+        if !runtime {
+            rules.append(&mut parsed);
+        }
+    }
+
+    Ok(rules)
+}
+
+fn lit_to_json(lit: &syn::Lit) -> Option<serde_json::Value> {
+    match lit {
+        syn::Lit::Str(s) => Some(s.value().into()),
+        syn::Lit::Bool(b) => Some(b.value.into()),
+        syn::Lit::Int(i) => i.base10_parse::<i64>().ok().map(Into::into),
+        syn::Lit::Float(f) => f
+            .base10_parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number),
+        _ => None,
+    }
+}
+
+/// Group names from `groups = ["create", "update"]` or `groups = "create"`
+fn parse_groups(value: &syn::Expr) -> Vec<String> {
+    let lit_str = |expr: &syn::Expr| match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(s),
+            ..
+        }) => Some(s.value()),
+        _ => None,
+    };
+
+    match value {
+        syn::Expr::Array(array) => array.elems.iter().filter_map(lit_str).collect(),
+        other => lit_str(other).into_iter().collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +597,114 @@ mod tests {
         matches!(rules[0], ValidationRule::Url);
     }
 
+    #[test]
+    fn test_network_validations() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(ipv4, ipv6, cidr, hostname, fqdn, port_range, mac_address)]
+            #[validate(ip_in_cidr = "10.0.0.0/8")]
+            addr: String
+        });
+        assert_eq!(rules.len(), 8);
+        assert!(matches!(rules[0], ValidationRule::Ipv4));
+        assert!(matches!(rules[1], ValidationRule::Ipv6));
+        assert!(matches!(rules[2], ValidationRule::Cidr));
+        assert!(matches!(rules[3], ValidationRule::Hostname));
+        assert!(matches!(rules[4], ValidationRule::Fqdn));
+        assert!(matches!(rules[5], ValidationRule::PortRange));
+        assert!(matches!(rules[6], ValidationRule::MacAddress));
+        assert!(matches!(&rules[7], ValidationRule::IpInCidr(c) if c == "10.0.0.0/8"));
+    }
+
+    #[test]
+    fn test_finance_validations() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(luhn, credit_card, iban, bic, isin, aba_routing)]
+            account: String
+        });
+        assert_eq!(rules.len(), 6);
+        assert!(matches!(rules[0], ValidationRule::Luhn));
+        assert!(matches!(rules[1], ValidationRule::CreditCard));
+        assert!(matches!(rules[2], ValidationRule::Iban));
+        assert!(matches!(rules[3], ValidationRule::Bic));
+        assert!(matches!(rules[4], ValidationRule::Isin));
+        assert!(matches!(rules[5], ValidationRule::AbaRouting));
+    }
+
+    #[test]
+    fn test_iso_validations() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(iso3166_alpha2, iso3166_alpha3, iso4217_currency, iso639_language, bcp47_locale)]
+            code: String
+        });
+        assert_eq!(rules.len(), 5);
+        assert!(matches!(rules[0], ValidationRule::Iso3166Alpha2));
+        assert!(matches!(rules[1], ValidationRule::Iso3166Alpha3));
+        assert!(matches!(rules[2], ValidationRule::Iso4217Currency));
+        assert!(matches!(rules[3], ValidationRule::Iso639Language));
+        assert!(matches!(rules[4], ValidationRule::Bcp47Locale));
+    }
+
+    #[test]
+    fn test_identifier_validations() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(uuid, uuid = 7, ulid, base64, base64url, hex, hex = 64, jwt_structure)]
+            id: String
+        });
+        assert_eq!(rules.len(), 8);
+        assert!(matches!(rules[0], ValidationRule::Uuid(None)));
+        assert!(matches!(rules[1], ValidationRule::Uuid(Some(7))));
+        assert!(matches!(rules[2], ValidationRule::Ulid));
+        assert!(matches!(rules[3], ValidationRule::Base64));
+        assert!(matches!(rules[4], ValidationRule::Base64Url));
+        assert!(matches!(rules[5], ValidationRule::Hex(None)));
+        assert!(matches!(rules[6], ValidationRule::Hex(Some(64))));
+        assert!(matches!(rules[7], ValidationRule::JwtStructure));
+    }
+
+    #[test]
+    fn test_security_validations() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(safe_filename, no_path_traversal, no_null_bytes)]
+            #[validate(no_html_tags, no_template_syntax, max_nesting = 32)]
+            name: String
+        });
+        assert_eq!(rules.len(), 5);
+        assert!(matches!(rules[0], ValidationRule::SafeFilename));
+        assert!(matches!(rules[1], ValidationRule::NoPathTraversal));
+        assert!(matches!(rules[2], ValidationRule::NoNullBytes));
+        assert!(matches!(rules[3], ValidationRule::NoHtmlTags));
+        assert!(matches!(rules[4], ValidationRule::NoTemplateSyntax));
+    }
+
+    #[test]
+    fn test_password_validation() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(password, password(min_length = 12, uppercase, digit, min_entropy = 60, forbid_fields = ["email"]))]
+            password: String
+        });
+        assert_eq!(rules.len(), 2);
+        assert!(matches!(
+            rules[0],
+            ValidationRule::Password {
+                min_length: 8,
+                max_length: 128,
+                uppercase: false,
+                ..
+            }
+        ));
+        assert!(matches!(
+            rules[1],
+            ValidationRule::Password {
+                min_length: 12,
+                max_length: 128,
+                lowercase: false,
+                uppercase: true,
+                digit: true,
+                symbol: false,
+            }
+        ));
+    }
+
     #[test]
     fn test_non_empty_validation() {
         let rules = parse_field_attributes(quote! {
@@ -970,4 +1414,54 @@ mod tests {
         });
         assert_eq!(rules.len(), 3);
     }
+
+    #[test]
+    fn test_grouped_rules_only_in_default_group() {
+        let rules = parse_field_attributes(quote! {
+            #[validate(max_len = 100)]
+            #[validate(max_len = 10000, groups = ["admin"])]
+            #[validate(min_len = 3, groups = ["default", "create"])]
+            bio: String
+        });
+        assert_eq!(rules.len(), 2);
+        assert!(matches!(rules[0], ValidationRule::MaxLen(100)));
+        assert!(matches!(rules[1], ValidationRule::MinLen(3)));
+    }
+
+    #[test]
+    fn test_cross_field_rules() {
+        let item_struct: syn::ItemStruct = syn::parse2(quote! {
+            #[validate(required_if(field = "state", depends_on = "country", value = "US"))]
+            #[validate(required_if(field = "tax_id", depends_on = "company"))]
+            #[validate(required_if(field = "vat", depends_on = "kind", value = Kind::Business))]
+            #[validate(mutually_exclusive("card", "iban"), message = "Pick one")]
+            #[validate(exactly_one_of("card", "iban", "paypal"))]
+            #[validate(fields_equal("password", "password_confirmation"))]
+            #[validate(at_least_one_of("email", "phone"), groups = ["admin"])]
+            #[validate(field_lt("min", "max"), when = "self.strict")]
+            #[validate(check = "self.a <= self.b", code = "order", message = "Out of order")]
+            struct Test {
+                a: u8,
+            }
+        })
+        .expect("Failed to parse struct");
+        let rules = parse_cross_field_rules(&item_struct.attrs).unwrap();
+
+        // Non-literal conditions, other groups and `when` are runtime-only
+        assert_eq!(rules.len(), 5);
+        assert!(matches!(
+            &rules[0],
+            CrossFieldRule::RequiredIf { field, depends_on, value: Some(value) }
+                if field == "state" && depends_on == "country" && value == "US"
+        ));
+        assert!(matches!(
+            &rules[1],
+            CrossFieldRule::RequiredIf { value: None, .. }
+        ));
+        assert!(
+            matches!(&rules[2], CrossFieldRule::MutuallyExclusive(a, b) if a == "card" && b == "iban")
+        );
+        assert!(matches!(&rules[3], CrossFieldRule::ExactlyOneOf(fields) if fields.len() == 3));
+        assert!(matches!(&rules[4], CrossFieldRule::FieldsEqual(..)));
+    }
 }
@@ -0,0 +1,312 @@
+//! Struct-level cross-field rules, shared by `Validate`, `ToSchema` and `ToJsonSchema`.
+//!
+//! ```text
+//! #[validate(required_if(field = "state", depends_on = "country", value = "US"))]
+//! #[validate(required_unless(field = "email", depends_on = "phone"))]
+//! #[validate(mutually_exclusive("card", "iban"))]
+//! #[validate(at_least_one_of("email", "phone"))]
+//! #[validate(exactly_one_of("card", "iban", "paypal"))]
+//! #[validate(fields_equal("password", "password_confirmation"))]
+//! #[validate(field_lt("min_price", "max_price"))]
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitStr};
+
+#[derive(Debug, Clone)]
+pub(crate) enum CrossFieldRule {
+    RequiredIf {
+        field: Ident,
+        depends_on: Ident,
+        value: Option<syn::Expr>,
+    },
+    RequiredUnless {
+        field: Ident,
+        depends_on: Ident,
+    },
+    MutuallyExclusive(Ident, Ident),
+    AtLeastOneOf(Vec<Ident>),
+    ExactlyOneOf(Vec<Ident>),
+    FieldsEqual(Ident, Ident),
+    FieldLt(Ident, Ident),
+}
+
+impl CrossFieldRule {
+    /// Whether `path` names a cross-field rule.
+    pub(crate) fn is_rule(path: &syn::Path) -> bool {
+        [
+            "required_if",
+            "required_unless",
+            "mutually_exclusive",
+            "at_least_one_of",
+            "exactly_one_of",
+            "fields_equal",
+            "field_lt",
+        ]
+        .iter()
+        .any(|name| path.is_ident(name))
+    }
+
+    pub(crate) fn parse(list: &syn::MetaList) -> syn::Result<Self> {
+        let name = list
+            .path
+            .get_ident()
+            .map(Ident::to_string)
+            .unwrap_or_default();
+        match name.as_str() {
+            "required_if" | "required_unless" => parse_required(list, name == "required_if"),
+            _ => {
+                let fields = list
+                    .parse_args_with(Punctuated::<LitStr, syn::Token![,]>::parse_terminated)?
+                    .iter()
+                    .map(LitStr::parse)
+                    .collect::<syn::Result<Vec<Ident>>>()?;
+                let pair = || match &fields[..] {
+                    [a, b] => Ok((a.clone(), b.clone())),
+                    _ => Err(syn::Error::new_spanned(
+                        list,
+                        format!("{} takes two field names", name),
+                    )),
+                };
+                match name.as_str() {
+                    "mutually_exclusive" => pair().map(|(a, b)| Self::MutuallyExclusive(a, b)),
+                    "fields_equal" => pair().map(|(a, b)| Self::FieldsEqual(a, b)),
+                    "field_lt" => pair().map(|(a, b)| Self::FieldLt(a, b)),
+                    _ if fields.len() < 2 => Err(syn::Error::new_spanned(
+                        list,
+                        format!("{} takes at least two field names", name),
+                    )),
+                    "at_least_one_of" => Ok(Self::AtLeastOneOf(fields)),
+                    _ => Ok(Self::ExactlyOneOf(fields)),
+                }
+            }
+        }
+    }
+
+    /// Validation code; `overrides` rewrites the violations in `e` before they are kept.
+    pub(crate) fn check(&self, overrides: Option<TokenStream>) -> TokenStream {
+        let (rule, value) = match self {
+            Self::RequiredIf {
+                field,
+                depends_on,
+                value,
+            } => {
+                let condition = match value {
+                    // `Option` fields compare their inner value
+                    Some(value) => quote! {
+                        {
+                            #[allow(unused_imports)]
+                            use domainstack::__private::{ViaOptionValue as _, ViaValue as _};
+                            (&&domainstack::__private::DependsOn(&self.#depends_on))
+                                .equals_value(&#value)
+                        }
+                    },
+                    None => present(depends_on),
+                };
+                let (field_present, field, depends_on) =
+                    (present(field), field.to_string(), depends_on.to_string());
+                let rule = match value {
+                    Some(value) => {
+                        let value = value_text(value);
+                        quote! { domainstack::rules::required_if_equals(#field, #depends_on, #value) }
+                    }
+                    None => quote! { domainstack::rules::required_if(#field, #depends_on) },
+                };
+                (rule, quote! { &(#condition, #field_present) })
+            }
+            Self::RequiredUnless { field, depends_on } => {
+                let present_other = present(depends_on);
+                let present_field = present(field);
+                let (field, depends_on) = (field.to_string(), depends_on.to_string());
+                (
+                    quote! { domainstack::rules::required_unless(#field, #depends_on) },
+                    quote! { &(#present_other, #present_field) },
+                )
+            }
+            Self::MutuallyExclusive(a, b) => {
+                let (a_present, b_present) = (present(a), present(b));
+                let (a, b) = (a.to_string(), b.to_string());
+                (
+                    quote! { domainstack::rules::mutually_exclusive(#a, #b) },
+                    quote! { &(#a_present, #b_present) },
+                )
+            }
+            Self::AtLeastOneOf(fields) | Self::ExactlyOneOf(fields) => {
+                let rule_fn = match self {
+                    Self::AtLeastOneOf(_) => quote! { at_least_one_of },
+                    _ => quote! { exactly_one_of },
+                };
+                let flags = fields.iter().map(present);
+                let names = fields.iter().map(Ident::to_string);
+                (
+                    quote! { domainstack::rules::#rule_fn(&[#(#names),*]) },
+                    quote! { &[#(#flags),*][..] },
+                )
+            }
+            Self::FieldsEqual(a, b) | Self::FieldLt(a, b) => {
+                let rule_fn = match self {
+                    Self::FieldsEqual(..) => quote! { fields_equal },
+                    _ => quote! { field_lt },
+                };
+                let value = quote! { &(self.#a.clone(), self.#b.clone()) };
+                let (a, b) = (a.to_string(), b.to_string());
+                (quote! { domainstack::rules::#rule_fn(#a, #b) }, value)
+            }
+        };
+
+        quote! {
+            {
+                let rule = #rule;
+                if let Err(e) = domainstack::validate(domainstack::Path::root(), #value, &rule) {
+                    #overrides
+                    err.extend(e);
+                }
+            }
+        }
+    }
+
+    /// Schema builder calls for the object schema, or `None` if the rule can't be expressed.
+    ///
+    /// `schema` is `Schema` or `JsonSchema`; `json` enables the JSON Schema only
+    /// keywords (`dependentRequired`, `if` / `then`), where OpenAPI 3.0 gets the
+    /// equivalent `anyOf` / `not` implication.
+    pub(crate) fn schema(&self, schema: &TokenStream, json: bool) -> Option<TokenStream> {
+        let required = |fields: &[&Ident]| {
+            let names = fields.iter().map(|f| f.to_string());
+            quote! { #schema::new().required(&[#(#names),*]) }
+        };
+
+        match self {
+            Self::RequiredIf {
+                field,
+                depends_on,
+                value,
+            } => {
+                let then = required(&[field]);
+                let condition = match value {
+                    None if json => {
+                        let (depends_on, field) = (depends_on.to_string(), field.to_string());
+                        return Some(quote! { .dependent_required(#depends_on, &[#field]) });
+                    }
+                    None => required(&[depends_on]),
+                    // Only literals are known to serialize to the same JSON value
+                    Some(syn::Expr::Lit(lit)) => {
+                        let name = depends_on.to_string();
+                        quote! {
+                            #schema::new()
+                                .property(#name, #schema::new().enum_values(&[#lit]))
+                                .required(&[#name])
+                        }
+                    }
+                    Some(_) => return None,
+                };
+                if json {
+                    Some(quote! { .constraint(#schema::if_then(#condition, #then)) })
+                } else {
+                    Some(quote! {
+                        .constraint(#schema::any_of(vec![#schema::negation(#condition), #then]))
+                    })
+                }
+            }
+            Self::RequiredUnless { field, depends_on } => {
+                let (field, other) = (required(&[field]), required(&[depends_on]));
+                Some(quote! { .constraint(#schema::any_of(vec![#field, #other])) })
+            }
+            Self::MutuallyExclusive(a, b) => {
+                let both = required(&[a, b]);
+                Some(quote! { .constraint(#schema::negation(#both)) })
+            }
+            Self::AtLeastOneOf(fields) => {
+                let each = fields.iter().map(|f| required(&[f]));
+                Some(quote! { .constraint(#schema::any_of(vec![#(#each),*])) })
+            }
+            Self::ExactlyOneOf(fields) => {
+                let each = fields.iter().map(|f| required(&[f]));
+                Some(quote! { .constraint(#schema::one_of(vec![#(#each),*])) })
+            }
+            // Comparisons between values have no schema keyword
+            Self::FieldsEqual(..) | Self::FieldLt(..) => None,
+        }
+    }
+}
+
+/// `required_if(field = "...", depends_on = "...", value = <expr>)` / `required_unless(...)`
+fn parse_required(list: &syn::MetaList, allow_value: bool) -> syn::Result<CrossFieldRule> {
+    let (mut field, mut depends_on, mut value) = (None, None, None);
+    list.parse_nested_meta(|option| {
+        if option.path.is_ident("field") || option.path.is_ident("depends_on") {
+            let lit: LitStr = option.value()?.parse()?;
+            let ident: Ident = lit.parse()?;
+            if option.path.is_ident("field") {
+                field = Some(ident);
+            } else {
+                depends_on = Some(ident);
+            }
+        } else if allow_value && option.path.is_ident("value") {
+            value = Some(option.value()?.parse()?);
+        } else if allow_value {
+            return Err(
+                option.error("unsupported option; expected `field`, `depends_on` or `value`")
+            );
+        } else {
+            return Err(option.error("unsupported option; expected `field` or `depends_on`"));
+        }
+        Ok(())
+    })?;
+
+    let (Some(field), Some(depends_on)) = (field, depends_on) else {
+        return Err(syn::Error::new_spanned(
+            list,
+            "expected `field = \"...\"` and `depends_on = \"...\"`",
+        ));
+    };
+    Ok(if allow_value {
+        CrossFieldRule::RequiredIf {
+            field,
+            depends_on,
+            value,
+        }
+    } else {
+        CrossFieldRule::RequiredUnless { field, depends_on }
+    })
+}
+
+fn present(field: &Ident) -> TokenStream {
+    quote! { domainstack::rules::Presence::is_present(&self.#field) }
+}
+
+/// How a `required_if` value reads in messages: string and char literals without
+/// quotes, anything else as written (`Country::US`).
+fn value_text(value: &syn::Expr) -> String {
+    match value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => lit.value(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Char(lit),
+            ..
+        }) => lit.value().to_string(),
+        _ => quote!(#value).to_string().replace(' ', ""),
+    }
+}
+
+/// Schema builder calls for the cross-field rules of `input`.
+///
+/// Rules with `when` or `groups` depend on runtime state and are left out.
+pub(crate) fn schema_constraints(
+    input: &syn::DeriveInput,
+    schema: &TokenStream,
+    json: bool,
+) -> syn::Result<TokenStream> {
+    Ok(crate::parse_struct_attributes(input)?
+        .iter()
+        .filter(|sv| sv.when.is_none() && sv.groups.is_empty())
+        .filter_map(|sv| match &sv.check {
+            crate::StructCheck::CrossField(rule) => rule.schema(schema, json),
+            _ => None,
+        })
+        .collect())
+}
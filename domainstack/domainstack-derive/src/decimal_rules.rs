@@ -0,0 +1,254 @@
+//! `decimal(precision = 10, scale = 2, min = "0", max = "99999.99", multiple_of = "0.05")`
+//! on `rust_decimal::Decimal` fields.
+//!
+//! Bounds are decimal literals, written as strings or numbers, and are parsed here so
+//! that `Validate` builds exact `Decimal`s while the schema derives emit `minimum`,
+//! `maximum` and `multipleOf`. `scale = 2` alone maps to `multipleOf: 0.01`, and
+//! `precision` bounds `minimum` / `maximum` when no explicit bound is tighter.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Most fractional digits a `rust_decimal::Decimal` can hold.
+const MAX_SCALE: u32 = 28;
+
+/// Most significant digits a `rust_decimal::Decimal` can hold.
+const MAX_PRECISION: u32 = 29;
+
+#[derive(Debug, Clone, Default)]
+pub struct DecimalRule {
+    precision: Option<u32>,
+    scale: Option<u32>,
+    min: Option<DecimalLit>,
+    max: Option<DecimalLit>,
+    multiple_of: Option<DecimalLit>,
+}
+
+/// A decimal literal, kept as mantissa and scale for `Decimal::from_i128_with_scale`.
+#[derive(Debug, Clone)]
+struct DecimalLit {
+    mantissa: i128,
+    scale: u32,
+    value: f64,
+}
+
+impl DecimalRule {
+    /// Parse `decimal(...)` from `#[validate(...)]`, or `None` if `meta` names another rule.
+    pub fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        if !meta.path.is_ident("decimal") {
+            return Ok(None);
+        }
+
+        let mut rule = DecimalRule::default();
+        meta.parse_nested_meta(|option| {
+            if option.path.is_ident("precision") {
+                let lit: syn::LitInt = option.value()?.parse()?;
+                let precision = lit.base10_parse()?;
+                if !(1..=MAX_PRECISION).contains(&precision) {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "precision must be between 1 and 29",
+                    ));
+                }
+                rule.precision = Some(precision);
+            } else if option.path.is_ident("scale") {
+                let lit: syn::LitInt = option.value()?.parse()?;
+                let scale = lit.base10_parse()?;
+                if scale > MAX_SCALE {
+                    return Err(syn::Error::new_spanned(lit, "scale must be at most 28"));
+                }
+                rule.scale = Some(scale);
+            } else if option.path.is_ident("min") {
+                rule.min = Some(parse_decimal(&option.value()?.parse()?)?);
+            } else if option.path.is_ident("max") {
+                rule.max = Some(parse_decimal(&option.value()?.parse()?)?);
+            } else if option.path.is_ident("multiple_of") {
+                let expr: syn::Expr = option.value()?.parse()?;
+                let step = parse_decimal(&expr)?;
+                if step.mantissa == 0 {
+                    return Err(syn::Error::new_spanned(expr, "multiple_of cannot be zero"));
+                }
+                rule.multiple_of = Some(step);
+            } else {
+                return Err(option.error(
+                    "unsupported option; expected `precision`, `scale`, `min`, `max` or `multiple_of`",
+                ));
+            }
+            Ok(())
+        })?;
+
+        if let (Some(precision), Some(scale)) = (rule.precision, rule.scale) {
+            if scale > precision {
+                return Err(meta.error("scale cannot exceed precision"));
+            }
+        }
+        if let (Some(min), Some(max)) = (&rule.min, &rule.max) {
+            if min.value > max.value {
+                return Err(meta.error("min cannot be greater than max"));
+            }
+        }
+        if rule.precision.is_none()
+            && rule.scale.is_none()
+            && rule.min.is_none()
+            && rule.max.is_none()
+            && rule.multiple_of.is_none()
+        {
+            return Err(meta.error(
+                "decimal requires at least one of `precision`, `scale`, `min`, `max` or `multiple_of`",
+            ));
+        }
+
+        Ok(Some(rule))
+    }
+
+    /// Expression building the combined `Rule<Decimal>`.
+    pub fn constructor(&self) -> TokenStream {
+        let mut rules = Vec::new();
+        if let Some(precision) = self.precision {
+            rules.push(quote! { domainstack::rules::decimal::precision(#precision) });
+        }
+        if let Some(scale) = self.scale {
+            rules.push(quote! { domainstack::rules::decimal::scale(#scale) });
+        }
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => {
+                rules.push(quote! { domainstack::rules::decimal::range(#min, #max) })
+            }
+            (Some(min), None) => rules.push(quote! { domainstack::rules::min(#min) }),
+            (None, Some(max)) => rules.push(quote! { domainstack::rules::max(#max) }),
+            (None, None) => {}
+        }
+        if let Some(step) = &self.multiple_of {
+            rules.push(quote! { domainstack::rules::decimal::multiple_of(#step) });
+        }
+
+        let first = &rules[0];
+        let rest = &rules[1..];
+        quote! { #first #(.and(#rest))* }
+    }
+
+    /// Add `minimum`, `maximum` and `multipleOf` to a `Schema` or `JsonSchema`.
+    pub fn apply_schema(&self, schema: TokenStream) -> TokenStream {
+        // Largest magnitude `NUMERIC(p, s)` can hold: 999.99 for (5, 2)
+        let bound = self.precision.map(|precision| {
+            let scale = self.scale.unwrap_or(0);
+            let nines = |count: u32| "9".repeat(count as usize);
+            let text = if scale == 0 {
+                nines(precision)
+            } else {
+                format!("0{}.{}", nines(precision - scale), nines(scale))
+            };
+            text.parse::<f64>().unwrap()
+        });
+
+        let minimum = match (self.min.as_ref().map(|min| min.value), bound) {
+            (Some(min), Some(bound)) => Some(min.max(-bound)),
+            (min, bound) => min.or(bound.map(|bound| -bound)),
+        };
+        let maximum = match (self.max.as_ref().map(|max| max.value), bound) {
+            (Some(max), Some(bound)) => Some(max.min(bound)),
+            (max, bound) => max.or(bound),
+        };
+        let multiple_of = match (&self.multiple_of, self.scale) {
+            (Some(step), _) => Some(step.value),
+            (None, Some(scale)) => Some(format!("1e-{}", scale).parse::<f64>().unwrap()),
+            (None, None) => None,
+        };
+
+        let mut schema = schema;
+        if let Some(minimum) = minimum {
+            let minimum = f64_tokens(minimum);
+            schema = quote! { #schema.minimum(#minimum) };
+        }
+        if let Some(maximum) = maximum {
+            let maximum = f64_tokens(maximum);
+            schema = quote! { #schema.maximum(#maximum) };
+        }
+        if let Some(multiple_of) = multiple_of {
+            let multiple_of = f64_tokens(multiple_of);
+            schema = quote! { #schema.multiple_of(#multiple_of) };
+        }
+        schema
+    }
+}
+
+impl quote::ToTokens for DecimalLit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let DecimalLit {
+            mantissa, scale, ..
+        } = self;
+        tokens.extend(quote! {
+            domainstack::rules::decimal::Decimal::from_i128_with_scale(#mantissa, #scale)
+        });
+    }
+}
+
+/// `f64` as an unsuffixed literal, with a leading `-` token when negative.
+fn f64_tokens(value: f64) -> TokenStream {
+    let literal = proc_macro2::Literal::f64_unsuffixed(value.abs());
+    if value.is_sign_negative() && value != 0.0 {
+        quote! { -#literal }
+    } else {
+        quote! { #literal }
+    }
+}
+
+/// `"12.50"`, `12.50`, `-3` or `"-0.05"`.
+fn parse_decimal(expr: &syn::Expr) -> syn::Result<DecimalLit> {
+    let error = || syn::Error::new_spanned(expr, "expected a decimal number like \"12.50\"");
+
+    let text = match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => literal_text(lit),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match &**inner {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => {
+                literal_text(lit).map(|t| format!("-{}", t))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(error)?;
+
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let digits_only = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !digits_only(whole) || !digits_only(fraction) {
+        return Err(error());
+    }
+
+    let scale = fraction.len() as u32;
+    if scale > MAX_SCALE {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "decimal literals can have at most 28 decimal places",
+        ));
+    }
+    // `Decimal` mantissas are 96 bits
+    let mantissa: i128 = format!("{}{}", whole, fraction)
+        .parse()
+        .ok()
+        .filter(|mantissa: &i128| *mantissa < 1i128 << 96)
+        .ok_or_else(|| syn::Error::new_spanned(expr, "decimal literal is out of range"))?;
+
+    Ok(DecimalLit {
+        mantissa: if negative { -mantissa } else { mantissa },
+        scale,
+        value: text.parse().map_err(|_| error())?,
+    })
+}
+
+fn literal_text(lit: &syn::Lit) -> Option<String> {
+    match lit {
+        syn::Lit::Str(lit) => Some(lit.value()),
+        syn::Lit::Int(lit) if lit.suffix().is_empty() => Some(lit.base10_digits().to_string()),
+        syn::Lit::Float(lit) if lit.suffix().is_empty() => Some(lit.base10_digits().to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,60 @@
+//! Parsing helpers for validation groups: `#[validate(..., groups = ["create"])]`.
+//!
+//! Rules without groups apply to every group; grouped rules apply only when one
+//! of their groups is validated. `validate()` validates the `"default"` group.
+
+use syn::{Expr, Lit};
+
+/// Group validated by `Validate::validate` and used for `schema()`.
+pub const DEFAULT_GROUP: &str = "default";
+
+/// Parse `groups = ["create", "update"]` (or `groups = "create"`) from nested meta.
+pub fn parse_groups(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<String>> {
+    let value: Expr = meta.value()?.parse()?;
+    parse_groups_value(&value)
+}
+
+/// Parse the value of a `groups = ...` attribute entry.
+pub fn parse_groups_value(expr: &Expr) -> syn::Result<Vec<String>> {
+    let group_name = |expr: &Expr| match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(s), ..
+        }) if !s.value().is_empty() => Ok(s.value()),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "expected a non-empty group name string",
+        )),
+    };
+
+    match expr {
+        Expr::Array(array) => {
+            let groups = array
+                .elems
+                .iter()
+                .map(group_name)
+                .collect::<syn::Result<Vec<_>>>()?;
+            if groups.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "`groups` must name at least one group",
+                ));
+            }
+            Ok(groups)
+        }
+        other => Ok(vec![group_name(other)?]),
+    }
+}
+
+/// Returns `true` if a rule tagged with `groups` runs when validating `group`.
+pub fn applies(groups: &[String], group: &str) -> bool {
+    groups.is_empty() || groups.iter().any(|g| g == group)
+}
+
+/// Add each group in `groups` to `all`, keeping first-seen order.
+pub fn collect(all: &mut Vec<String>, groups: &[String]) {
+    for group in groups {
+        if !all.contains(group) {
+            all.push(group.clone());
+        }
+    }
+}
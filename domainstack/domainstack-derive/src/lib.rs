@@ -8,6 +8,8 @@
 //! - **`#[derive(ToSchema)]`** - OpenAPI 3.0 schema generation from validation rules
 //! - **`#[derive(ToJsonSchema)]`** - JSON Schema (Draft 2020-12) generation from validation rules
 //! - **`#[derive(ValidateOnDeserialize)]`** - Validate automatically during serde deserialization (requires `serde` feature)
+//! - **`#[derive(ValidatePartial)]`** - Generate a validated `<Name>Patch` type for PATCH endpoints
+//! - **`#[derive(ValidateTransition)]`** - Validate state transitions of an enum
 //!
 //! ## `#[derive(Validate)]`
 //!
@@ -85,6 +87,58 @@
 //! assert!(card.validate().is_ok());
 //! ```
 //!
+//! ### Validation Context
+//!
+//! Rules that depend on runtime state can declare a context type. The derive then
+//! implements `ValidateWith<Ctx>` instead of `Validate`, and rule arguments and `check`
+//! expressions can refer to the context as `ctx`:
+//!
+//! ```rust
+//! use domainstack::prelude::*;
+//! use domainstack_derive::Validate;
+//!
+//! struct TenantLimits {
+//!     max_rooms: usize,
+//!     max_guests: u8,
+//! }
+//!
+//! #[derive(Debug, Validate)]
+//! #[validate(context = TenantLimits)]
+//! #[validate(
+//!     check = "self.guests <= ctx.max_guests",
+//!     code = "guest_limit",
+//!     message = "Too many guests for this tenant"
+//! )]
+//! struct Booking {
+//!     #[validate(max_items = ctx.max_rooms)]
+//!     rooms: Vec<String>,
+//!     guests: u8,
+//! }
+//!
+//! let limits = TenantLimits { max_rooms: 2, max_guests: 4 };
+//! let booking = Booking { rooms: vec!["101".into()], guests: 2 };
+//! assert!(booking.validate_with(&limits).is_ok());
+//! ```
+//!
+//! Nested types that also take the context use `#[validate(nested(context))]`;
+//! plain `#[validate(nested)]` keeps calling `Validate::validate`.
+//!
+//! ### Validation Groups
+//!
+//! Tag rules with `groups = [...]` to run them only for specific operations.
+//! Untagged rules run for every group; `validate()` validates the `"default"` group.
+//!
+//! ```rust,ignore
+//! #[derive(Validate)]
+//! struct UserDto {
+//!     #[validate(forbidden, groups = ["create"])]
+//!     #[validate(required, groups = ["update"])]
+//!     id: Option<u64>,
+//! }
+//!
+//! dto.validate_group("update")?;
+//! ```
+//!
 //! ## `#[derive(ToSchema)]`
 //!
 //! Generates a `ToSchema` trait implementation that produces OpenAPI 3.0 schemas from validation rules.
@@ -150,14 +204,76 @@
 //! let user: User = serde_json::from_str(r#"{"email": "alice@example.com", "age": 30}"#)?;
 //! // ↑ If this succeeds, user is guaranteed valid!
 //! ```
+//!
+//! ## `#[derive(ValidatePartial)]`
+//!
+//! Generates a `<Name>Patch` type with `Option`-wrapped fields for PATCH endpoints.
+//! Present fields are validated with the original rules and paths; cross-field
+//! checks run only when all of their inputs are present.
+//!
+//! ```rust,ignore
+//! use domainstack_derive::{Validate, ValidatePartial};
+//!
+//! #[derive(Validate, ValidatePartial)]
+//! #[patch(derive(Default, serde::Deserialize))]
+//! struct User {
+//!     #[validate(email)]
+//!     email: String,
+//!
+//!     #[validate(range(min = 18, max = 120))]
+//!     age: u8,
+//! }
+//!
+//! let patch = UserPatch { age: Some(200), ..Default::default() };
+//! assert!(patch.validate().is_err()); // age: out_of_range; email not checked
+//! patch.apply_to(&mut user);
+//! ```
+//!
+//! ## `#[derive(ValidateTransition)]`
+//!
+//! Declares the allowed state transitions of an enum. Disallowed transitions
+//! produce an `invalid_transition` violation with `from`/`to` meta.
+//!
+//! ```rust
+//! use domainstack::prelude::*;
+//! use domainstack_derive::ValidateTransition;
+//!
+//! #[derive(Debug, Clone, PartialEq, ValidateTransition)]
+//! #[transitions(Pending -> [Confirmed, Cancelled], Confirmed -> Cancelled)]
+//! enum BookingStatus {
+//!     Pending,
+//!     Confirmed,
+//!     Cancelled,
+//! }
+//!
+//! let next = BookingStatus::Pending
+//!     .transition(BookingStatus::Confirmed)
+//!     .unwrap();
+//! assert!(next.transition(BookingStatus::Pending).is_err());
+//! ```
 
+use cross_field::CrossFieldRule;
+use decimal_rules::DecimalRule;
+use money_rules::MoneyRule;
+use password::PasswordRule;
 use proc_macro::TokenStream;
 use quote::quote;
+use str_rules::StrRule;
 use syn::{parse_macro_input, Attribute, Data, DeriveInput, Expr, Field, Fields, Lit, Meta};
+use time_rules::TimeRule;
 
+mod cross_field;
+mod decimal_rules;
+mod groups;
 #[cfg(feature = "schema")]
 mod json_schema;
+mod money_rules;
+mod partial;
+mod password;
 mod schema;
+mod str_rules;
+mod time_rules;
+mod transition;
 
 #[proc_macro_derive(Validate, attributes(validate))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
@@ -190,6 +306,26 @@ pub fn derive_to_json_schema(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(ValidatePartial, attributes(validate, patch, serde))]
+pub fn derive_validate_partial(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match partial::derive_validate_partial_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(ValidateTransition, attributes(transitions))]
+pub fn derive_validate_transition(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match transition::derive_validate_transition_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[cfg(feature = "serde")]
 #[proc_macro_derive(ValidateOnDeserialize, attributes(validate, serde))]
 pub fn derive_validate_on_deserialize(input: TokenStream) -> TokenStream {
@@ -228,6 +364,14 @@ fn generate_validate_on_deserialize_impl(
         }
     };
 
+    if parse_context_type(input)?.is_some() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[derive(ValidateOnDeserialize)] does not support #[validate(context = ...)]; \
+             deserialization has no context to validate against",
+        ));
+    }
+
     // Parse struct-level validation checks (reuse existing function)
     let struct_validations = parse_struct_attributes(input)?;
 
@@ -253,6 +397,22 @@ fn generate_validate_on_deserialize_impl(
     // Generate validation code for struct-level checks (reuse existing function)
     let struct_validation_code = struct_validations.iter().map(generate_struct_validation);
 
+    // Deserialization validates the default group
+    let validate_impl = generate_impl(
+        name,
+        &impl_generics,
+        &ty_generics,
+        where_clause,
+        None,
+        quote! {
+            // Field-level validations
+            #(#field_validation_code)*
+
+            // Struct-level validations (cross-field checks)
+            #(#struct_validation_code)*
+        },
+    );
+
     // Generate intermediate struct name
     let intermediate_name = syn::Ident::new(&format!("{}Intermediate", name), name.span());
 
@@ -309,26 +469,14 @@ fn generate_validate_on_deserialize_impl(
 
                 // Phase 3: Validate using fully qualified syntax
                 <#name #ty_generics as ::domainstack::Validate>::validate(&value)
-                    .map_err(|e| ::serde::de::Error::custom(format!("Validation failed: {}", e)))?;
+                    .map_err(|e| ::serde::de::Error::custom(::core::format_args!("Validation failed: {}", e)))?;
 
                 Ok(value)
             }
         }
 
         // Generate Validate implementation with actual validation logic
-        impl #impl_generics ::domainstack::Validate for #name #ty_generics #where_clause {
-            fn validate(&self) -> Result<(), ::domainstack::ValidationError> {
-                let mut err = ::domainstack::ValidationError::default();
-
-                // Field-level validations
-                #(#field_validation_code)*
-
-                // Struct-level validations (cross-field checks)
-                #(#struct_validation_code)*
-
-                if err.is_empty() { Ok(()) } else { Err(err) }
-            }
-        }
+        #validate_impl
     };
 
     Ok(expanded)
@@ -351,14 +499,15 @@ enum ValidationRule {
         message: Option<String>,
     },
     Nested,
+    NestedWithContext,
     Each(Box<ValidationRule>),
     Custom(String),
 
     // New rich syntax rules
     Email,
     Url,
-    MinLen(usize),
-    MaxLen(usize),
+    MinLen(proc_macro2::TokenStream),
+    MaxLen(proc_macro2::TokenStream),
     Alphanumeric,
     Ascii,
     AlphaOnly,
@@ -370,27 +519,65 @@ enum ValidationRule {
     StartsWith(String),
     EndsWith(String),
     MatchesRegex(String),
+    // Format rules backed by a single `domainstack::rules` constructor
+    Str(StrRule),
+    Password(PasswordRule),
+    // past / future on DateTime<Utc> fields
+    Time(Box<TimeRule>),
+    // decimal(precision = .., scale = .., ...) on Decimal fields
+    Decimal(Box<DecimalRule>),
+    // money(currency = "currency", ...) checked against a sibling currency field
+    Money(Box<MoneyRule>),
     Min(proc_macro2::TokenStream),
     Max(proc_macro2::TokenStream),
     Positive,
     Negative,
     NonZero,
     Finite,
+    // Coordinates in degrees
+    Latitude,
+    Longitude,
     MultipleOf(proc_macro2::TokenStream),
     Equals(proc_macro2::TokenStream),
     NotEquals(proc_macro2::TokenStream),
     OneOf(Vec<String>),
-    MinItems(usize),
-    MaxItems(usize),
+    MinItems(proc_macro2::TokenStream),
+    MaxItems(proc_macro2::TokenStream),
     Unique,
+    UniqueBy(syn::Ident),
+    // Depth limit for serde_json::Value fields
+    MaxNesting(proc_macro2::TokenStream),
+    // Presence rules (Option<T> fields)
+    Required,
+    Forbidden,
+    // Rule that only runs when validating one of `groups`
+    Grouped {
+        groups: Vec<String>,
+        rule: Box<ValidationRule>,
+    },
 }
 
 #[derive(Debug, Clone)]
 struct StructValidation {
-    check: String,
+    check: StructCheck,
     code: Option<String>,
     message: Option<String>,
     when: Option<String>,
+    groups: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum StructCheck {
+    /// `check = "<expr>"`
+    Expr(String),
+    /// `range_fields(start = "...", end = "...", max_span = <expr>)`
+    RangeFields {
+        start: syn::Ident,
+        end: syn::Ident,
+        max_span: Option<syn::Expr>,
+    },
+    /// `required_if(...)`, `at_least_one_of(...)`, ... (see `cross_field`)
+    CrossField(CrossFieldRule),
 }
 
 #[derive(Debug)]
@@ -426,13 +613,15 @@ fn generate_validate_impl(input: &DeriveInput) -> syn::Result<proc_macro2::Token
             ),
             Fields::Unit => {
                 // Unit structs always pass validation
-                Ok(quote! {
-                    impl #impl_generics domainstack::Validate for #name #ty_generics #where_clause {
-                        fn validate(&self) -> Result<(), domainstack::ValidationError> {
-                            Ok(())
-                        }
-                    }
-                })
+                let context = parse_context_type(input)?;
+                Ok(generate_impl(
+                    name,
+                    &impl_generics,
+                    &ty_generics,
+                    where_clause,
+                    context.as_ref(),
+                    quote! {},
+                ))
             }
         },
         Data::Enum(data) => generate_enum_validate(
@@ -484,23 +673,34 @@ fn generate_named_struct_validate(
     // Generate validation code for struct-level checks
     let struct_validation_code = struct_validations.iter().map(generate_struct_validation);
 
-    let expanded = quote! {
-        impl #impl_generics domainstack::Validate for #name #ty_generics #where_clause {
-            fn validate(&self) -> Result<(), domainstack::ValidationError> {
-                let mut err = domainstack::ValidationError::default();
-
-                // Field-level validations
-                #(#field_validation_code)*
-
-                // Struct-level validations (cross-field checks)
-                #(#struct_validation_code)*
-
-                if err.is_empty() { Ok(()) } else { Err(err) }
-            }
-        }
+    let context = parse_context_type(input)?;
+    check_context_rules(
+        input,
+        context.as_ref(),
+        field_validations.iter().flat_map(|fv| &fv.rules),
+    )?;
+    let grouped = uses_groups(
+        field_validations.iter().flat_map(|fv| &fv.rules),
+        &struct_validations,
+    );
+    check_group_context(input, context.as_ref(), grouped)?;
+
+    let body = quote! {
+        // Field-level validations
+        #(#field_validation_code)*
+
+        // Struct-level validations (cross-field checks)
+        #(#struct_validation_code)*
     };
 
-    Ok(expanded)
+    Ok(generate_impl(
+        name,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        context.as_ref(),
+        body,
+    ))
 }
 
 /// Validation info for tuple struct fields (indexed)
@@ -547,23 +747,34 @@ fn generate_tuple_struct_validate(
     // Generate validation code for struct-level checks
     let struct_validation_code = struct_validations.iter().map(generate_struct_validation);
 
-    let expanded = quote! {
-        impl #impl_generics domainstack::Validate for #name #ty_generics #where_clause {
-            fn validate(&self) -> Result<(), domainstack::ValidationError> {
-                let mut err = domainstack::ValidationError::default();
-
-                // Field-level validations
-                #(#field_validation_code)*
-
-                // Struct-level validations (cross-field checks)
-                #(#struct_validation_code)*
-
-                if err.is_empty() { Ok(()) } else { Err(err) }
-            }
-        }
+    let context = parse_context_type(input)?;
+    check_context_rules(
+        input,
+        context.as_ref(),
+        field_validations.iter().flat_map(|fv| &fv.rules),
+    )?;
+    let grouped = uses_groups(
+        field_validations.iter().flat_map(|fv| &fv.rules),
+        &struct_validations,
+    );
+    check_group_context(input, context.as_ref(), grouped)?;
+
+    let body = quote! {
+        // Field-level validations
+        #(#field_validation_code)*
+
+        // Struct-level validations (cross-field checks)
+        #(#struct_validation_code)*
     };
 
-    Ok(expanded)
+    Ok(generate_impl(
+        name,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        context.as_ref(),
+        body,
+    ))
 }
 
 /// Generate Validate impl for enums
@@ -573,9 +784,11 @@ fn generate_enum_validate(
     ty_generics: &syn::TypeGenerics,
     where_clause: Option<&syn::WhereClause>,
     variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
-    _input: &DeriveInput,
+    input: &DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
+    let context = parse_context_type(input)?;
     let mut variant_arms = Vec::new();
+    let mut grouped = false;
 
     for variant in variants {
         let variant_name = &variant.ident;
@@ -595,6 +808,8 @@ fn generate_enum_validate(
                     let field_name = field.ident.as_ref().unwrap();
                     let field_name_str = field_name.to_string();
                     let rules = parse_field_attributes(field)?;
+                    check_context_rules(field, context.as_ref(), &rules)?;
+                    grouped |= uses_groups(&rules, &[]);
 
                     for rule in rules {
                         let validation_code =
@@ -629,6 +844,8 @@ fn generate_enum_validate(
                     let binding = &field_bindings[index];
                     let field_name_str = index.to_string();
                     let rules = parse_field_attributes(field)?;
+                    check_context_rules(field, context.as_ref(), &rules)?;
+                    grouped |= uses_groups(&rules, &[]);
 
                     for rule in rules {
                         let validation_code =
@@ -658,21 +875,171 @@ fn generate_enum_validate(
         }
     }
 
-    let expanded = quote! {
-        impl #impl_generics domainstack::Validate for #name #ty_generics #where_clause {
-            fn validate(&self) -> Result<(), domainstack::ValidationError> {
-                let mut err = domainstack::ValidationError::default();
+    check_group_context(input, context.as_ref(), grouped)?;
+
+    let body = quote! {
+        match self {
+            #(#variant_arms)*
+        }
+    };
+
+    Ok(generate_impl(
+        name,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        context.as_ref(),
+        body,
+    ))
+}
+
+/// Wrap generated validation statements in either a `ValidateGroup` impl or, when the
+/// type declares `#[validate(context = Ctx)]`, a `ValidateWith<Ctx>` impl that binds `ctx`.
+///
+/// The `ValidateGroup` impl binds `group`, and the type's `Validate` impl delegates to
+/// it with the default group. Context impls validate nested fields with the default group.
+///
+/// Every impl reports its outcome through `domainstack::telemetry`, which is a no-op
+/// unless the `tracing` or `metrics` feature is enabled.
+fn generate_impl(
+    name: &syn::Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+    context: Option<&syn::Type>,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match context {
+        Some(ctx_ty) => quote! {
+            impl #impl_generics domainstack::ValidateWith<#ctx_ty> for #name #ty_generics #where_clause {
+                fn validate_with(&self, ctx: &#ctx_ty) -> Result<(), domainstack::ValidationError> {
+                    let __domainstack_scope =
+                        domainstack::telemetry::enter(::core::any::type_name::<Self>());
+                    #[allow(unused_variables)]
+                    let group = domainstack::DEFAULT_GROUP;
+                    let mut err = domainstack::ValidationError::default();
 
-                match self {
-                    #(#variant_arms)*
+                    #body
+
+                    __domainstack_scope.finish(if err.is_empty() { Ok(()) } else { Err(err) })
+                }
+            }
+        },
+        None => quote! {
+            impl #impl_generics domainstack::ValidateGroup for #name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn validate_group(&self, group: &str) -> Result<(), domainstack::ValidationError> {
+                    let __domainstack_scope =
+                        domainstack::telemetry::enter(::core::any::type_name::<Self>());
+                    let mut err = domainstack::ValidationError::default();
+
+                    #body
+
+                    __domainstack_scope.finish(if err.is_empty() { Ok(()) } else { Err(err) })
                 }
+            }
 
-                if err.is_empty() { Ok(()) } else { Err(err) }
+            impl #impl_generics domainstack::Validate for #name #ty_generics #where_clause {
+                fn validate(&self) -> Result<(), domainstack::ValidationError> {
+                    domainstack::ValidateGroup::validate_group(self, domainstack::DEFAULT_GROUP)
+                }
             }
+        },
+    }
+}
+
+/// Returns `true` if any field rule or struct-level check is tagged with `groups`.
+fn uses_groups<'a>(
+    rules: impl IntoIterator<Item = &'a ValidationRule>,
+    struct_validations: &[StructValidation],
+) -> bool {
+    rules
+        .into_iter()
+        .any(|rule| matches!(rule, ValidationRule::Grouped { .. }))
+        || struct_validations.iter().any(|sv| !sv.groups.is_empty())
+}
+
+/// Reject validation groups on types that declare a validation context.
+fn check_group_context(
+    spanned: &dyn quote::ToTokens,
+    context: Option<&syn::Type>,
+    grouped: bool,
+) -> syn::Result<()> {
+    if context.is_some() && grouped {
+        return Err(syn::Error::new_spanned(
+            spanned,
+            "validation `groups` cannot be combined with #[validate(context = ...)]",
+        ));
+    }
+    Ok(())
+}
+
+/// Parse the optional `#[validate(context = Ctx)]` type-level attribute.
+///
+/// Accepts either a bare type (`context = TenantLimits`) or a string
+/// (`context = "TenantLimits"`).
+fn parse_context_type(input: &DeriveInput) -> syn::Result<Option<syn::Type>> {
+    let mut context = None;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
         }
-    };
 
-    Ok(expanded)
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        let nested: syn::punctuated::Punctuated<Meta, syn::Token![,]> =
+            list.parse_args_with(syn::punctuated::Punctuated::parse_terminated)?;
+
+        for meta in nested {
+            if let Meta::NameValue(nv) = meta {
+                if nv.path.is_ident("context") {
+                    if context.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            "Duplicate #[validate(context = ...)] attribute",
+                        ));
+                    }
+                    let ty: syn::Type = match &nv.value {
+                        Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(s), ..
+                        }) => s.parse()?,
+                        other => syn::parse2(quote! { #other })?,
+                    };
+                    context = Some(ty);
+                }
+            }
+        }
+    }
+
+    Ok(context)
+}
+
+/// Reject context-only rules on types that don't declare a validation context.
+fn check_context_rules<'a>(
+    spanned: &dyn quote::ToTokens,
+    context: Option<&syn::Type>,
+    rules: impl IntoIterator<Item = &'a ValidationRule>,
+) -> syn::Result<()> {
+    if context.is_some() {
+        return Ok(());
+    }
+
+    let needs_context = rules.into_iter().any(|rule| match rule {
+        ValidationRule::NestedWithContext => true,
+        ValidationRule::Each(inner) => matches!(**inner, ValidationRule::NestedWithContext),
+        _ => false,
+    });
+
+    if needs_context {
+        return Err(syn::Error::new_spanned(
+            spanned,
+            "`nested(context)` requires a type-level #[validate(context = ...)] attribute",
+        ));
+    }
+
+    Ok(())
 }
 
 fn parse_struct_attributes(input: &DeriveInput) -> syn::Result<Vec<StructValidation>> {
@@ -683,14 +1050,15 @@ fn parse_struct_attributes(input: &DeriveInput) -> syn::Result<Vec<StructValidat
             continue;
         }
 
-        let validation = parse_struct_validate_attribute(attr)?;
-        validations.push(validation);
+        if let Some(validation) = parse_struct_validate_attribute(attr)? {
+            validations.push(validation);
+        }
     }
 
     Ok(validations)
 }
 
-fn parse_struct_validate_attribute(attr: &Attribute) -> syn::Result<StructValidation> {
+fn parse_struct_validate_attribute(attr: &Attribute) -> syn::Result<Option<StructValidation>> {
     let meta = &attr.meta;
 
     match meta {
@@ -702,34 +1070,73 @@ fn parse_struct_validate_attribute(attr: &Attribute) -> syn::Result<StructValida
             let mut code = None;
             let mut message = None;
             let mut when = None;
+            let mut groups = Vec::new();
+            let mut has_context = false;
 
             for meta in nested {
                 match meta {
                     Meta::NameValue(nv) => {
-                        if nv.path.is_ident("check") {
-                            check = Some(parse_string_lit(&nv.value)?);
+                        if nv.path.is_ident("context") {
+                            // Handled by parse_context_type
+                            has_context = true;
+                        } else if nv.path.is_ident("check") {
+                            if check.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    nv,
+                                    "Use only one of 'check', 'range_fields' or a cross-field rule per attribute",
+                                ));
+                            }
+                            check = Some(StructCheck::Expr(parse_string_lit(&nv.value)?));
                         } else if nv.path.is_ident("code") {
                             code = Some(parse_string_lit(&nv.value)?);
                         } else if nv.path.is_ident("message") {
                             message = Some(parse_string_lit(&nv.value)?);
                         } else if nv.path.is_ident("when") {
                             when = Some(parse_string_lit(&nv.value)?);
+                        } else if nv.path.is_ident("groups") {
+                            groups = groups::parse_groups_value(&nv.value)?;
                         }
                     }
+                    Meta::List(list) if list.path.is_ident("range_fields") => {
+                        if check.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                list,
+                                "Use only one of 'check', 'range_fields' or a cross-field rule per attribute",
+                            ));
+                        }
+                        check = Some(parse_range_fields(&list)?);
+                    }
+                    Meta::List(list) if CrossFieldRule::is_rule(&list.path) => {
+                        if check.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                list,
+                                "Use only one of 'check', 'range_fields' or a cross-field rule per attribute",
+                            ));
+                        }
+                        check = Some(StructCheck::CrossField(CrossFieldRule::parse(&list)?));
+                    }
                     _ => return Err(syn::Error::new_spanned(meta, "Expected name = value")),
                 }
             }
 
-            let check = check.ok_or_else(|| {
-                syn::Error::new_spanned(attr, "Struct-level validation requires 'check' parameter")
-            })?;
+            let check = match check {
+                Some(check) => check,
+                None if has_context => return Ok(None),
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "Struct-level validation requires 'check', 'range_fields' or a cross-field rule",
+                    ))
+                }
+            };
 
-            Ok(StructValidation {
+            Ok(Some(StructValidation {
                 check,
                 code,
                 message,
                 when,
-            })
+                groups,
+            }))
         }
         _ => Err(syn::Error::new_spanned(
             attr,
@@ -738,6 +1145,39 @@ fn parse_struct_validate_attribute(attr: &Attribute) -> syn::Result<StructValida
     }
 }
 
+/// `range_fields(start = "check_in", end = "check_out", max_span = <expr>)`
+fn parse_range_fields(list: &syn::MetaList) -> syn::Result<StructCheck> {
+    let (mut start, mut end, mut max_span) = (None, None, None);
+    list.parse_nested_meta(|option| {
+        if option.path.is_ident("start") || option.path.is_ident("end") {
+            let lit: syn::LitStr = option.value()?.parse()?;
+            let field: syn::Ident = lit.parse()?;
+            if option.path.is_ident("start") {
+                start = Some(field);
+            } else {
+                end = Some(field);
+            }
+        } else if option.path.is_ident("max_span") {
+            max_span = Some(option.value()?.parse()?);
+        } else {
+            return Err(option.error("unsupported option; expected `start`, `end` or `max_span`"));
+        }
+        Ok(())
+    })?;
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok(StructCheck::RangeFields {
+            start,
+            end,
+            max_span,
+        }),
+        _ => Err(syn::Error::new_spanned(
+            list,
+            "range_fields requires `start = \"...\"` and `end = \"...\"`",
+        )),
+    }
+}
+
 fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
     let mut rules = Vec::new();
 
@@ -746,8 +1186,28 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
             continue;
         }
 
+        // `groups = [...]` applies to every rule in the same attribute
+        let start = rules.len();
+        let mut groups = Vec::new();
+
         // Parse all rules from this attribute
         attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("groups") {
+                groups = groups::parse_groups(&meta)?;
+                return Ok(());
+            }
+
+            // Presence rules
+            if meta.path.is_ident("required") {
+                rules.push(ValidationRule::Required);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("forbidden") {
+                rules.push(ValidationRule::Forbidden);
+                return Ok(());
+            }
+
             // Email
             if meta.path.is_ident("email") {
                 rules.push(ValidationRule::Email);
@@ -760,23 +1220,47 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                 return Ok(());
             }
 
+            // Format rules: ipv4, hostname, mac_address, ...
+            if let Some(rule) = StrRule::parse(&meta)? {
+                rules.push(ValidationRule::Str(rule));
+                return Ok(());
+            }
+
+            // password or password(min_length = 12, forbid_fields = ["email"], ...)
+            if let Some(rule) = PasswordRule::parse(&meta)? {
+                rules.push(ValidationRule::Password(rule));
+                return Ok(());
+            }
+
+            // past, future, or past(clock = ctx.clock)
+            if let Some(rule) = TimeRule::parse(&meta)? {
+                rules.push(ValidationRule::Time(Box::new(rule)));
+                return Ok(());
+            }
+
+            // decimal(precision = 10, scale = 2, min = "0", ...)
+            if let Some(rule) = DecimalRule::parse(&meta)? {
+                rules.push(ValidationRule::Decimal(Box::new(rule)));
+                return Ok(());
+            }
+
+            // money(currency = "currency") or money(currency = "currency", min = .., max = ..)
+            if let Some(rule) = MoneyRule::parse(&meta)? {
+                rules.push(ValidationRule::Money(Box::new(rule)));
+                return Ok(());
+            }
+
             // min_len
             if meta.path.is_ident("min_len") {
-                let value: syn::Lit = meta.value()?.parse()?;
-                if let syn::Lit::Int(lit_int) = value {
-                    let val = lit_int.base10_parse()?;
-                    rules.push(ValidationRule::MinLen(val));
-                }
+                let value: syn::Expr = meta.value()?.parse()?;
+                rules.push(ValidationRule::MinLen(quote! { #value }));
                 return Ok(());
             }
 
             // max_len
             if meta.path.is_ident("max_len") {
-                let value: syn::Lit = meta.value()?.parse()?;
-                if let syn::Lit::Int(lit_int) = value {
-                    let val = lit_int.base10_parse()?;
-                    rules.push(ValidationRule::MaxLen(val));
-                }
+                let value: syn::Expr = meta.value()?.parse()?;
+                rules.push(ValidationRule::MaxLen(quote! { #value }));
                 return Ok(());
             }
 
@@ -858,9 +1342,9 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                 return Ok(());
             }
 
-            // nested
+            // nested / nested(context)
             if meta.path.is_ident("nested") {
-                rules.push(ValidationRule::Nested);
+                rules.push(parse_nested_rule(&meta)?);
                 return Ok(());
             }
 
@@ -869,7 +1353,7 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                 meta.parse_nested_meta(|nested| {
                     // Handle nested
                     if nested.path.is_ident("nested") {
-                        rules.push(ValidationRule::Each(Box::new(ValidationRule::Nested)));
+                        rules.push(ValidationRule::Each(Box::new(parse_nested_rule(&nested)?)));
                         return Ok(());
                     }
 
@@ -932,6 +1416,10 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                         rules.push(ValidationRule::Each(Box::new(ValidationRule::Url)));
                         return Ok(());
                     }
+                    if let Some(rule) = StrRule::parse(&nested)? {
+                        rules.push(ValidationRule::Each(Box::new(ValidationRule::Str(rule))));
+                        return Ok(());
+                    }
                     if nested.path.is_ident("alphanumeric") {
                         rules.push(ValidationRule::Each(Box::new(ValidationRule::Alphanumeric)));
                         return Ok(());
@@ -961,19 +1449,17 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
 
                     // Handle rules with parameters
                     if nested.path.is_ident("min_len") {
-                        let value: syn::Lit = nested.value()?.parse()?;
-                        if let syn::Lit::Int(lit_int) = value {
-                            let val = lit_int.base10_parse()?;
-                            rules.push(ValidationRule::Each(Box::new(ValidationRule::MinLen(val))));
-                        }
+                        let value: syn::Expr = nested.value()?.parse()?;
+                        rules.push(ValidationRule::Each(Box::new(ValidationRule::MinLen(
+                            quote! { #value },
+                        ))));
                         return Ok(());
                     }
                     if nested.path.is_ident("max_len") {
-                        let value: syn::Lit = nested.value()?.parse()?;
-                        if let syn::Lit::Int(lit_int) = value {
-                            let val = lit_int.base10_parse()?;
-                            rules.push(ValidationRule::Each(Box::new(ValidationRule::MaxLen(val))));
-                        }
+                        let value: syn::Expr = nested.value()?.parse()?;
+                        rules.push(ValidationRule::Each(Box::new(ValidationRule::MaxLen(
+                            quote! { #value },
+                        ))));
                         return Ok(());
                     }
                     if nested.path.is_ident("matches_regex") {
@@ -1102,6 +1588,16 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                 return Ok(());
             }
 
+            if meta.path.is_ident("latitude") {
+                rules.push(ValidationRule::Latitude);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("longitude") {
+                rules.push(ValidationRule::Longitude);
+                return Ok(());
+            }
+
             if meta.path.is_ident("multiple_of") {
                 let value: syn::Expr = meta.value()?.parse()?;
                 rules.push(ValidationRule::MultipleOf(quote! { #value }));
@@ -1123,20 +1619,20 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
 
             // Collection rules
             if meta.path.is_ident("min_items") {
-                let value: syn::Lit = meta.value()?.parse()?;
-                if let syn::Lit::Int(lit_int) = value {
-                    let val = lit_int.base10_parse()?;
-                    rules.push(ValidationRule::MinItems(val));
-                }
+                let value: syn::Expr = meta.value()?.parse()?;
+                rules.push(ValidationRule::MinItems(quote! { #value }));
                 return Ok(());
             }
 
             if meta.path.is_ident("max_items") {
-                let value: syn::Lit = meta.value()?.parse()?;
-                if let syn::Lit::Int(lit_int) = value {
-                    let val = lit_int.base10_parse()?;
-                    rules.push(ValidationRule::MaxItems(val));
-                }
+                let value: syn::Expr = meta.value()?.parse()?;
+                rules.push(ValidationRule::MaxItems(quote! { #value }));
+                return Ok(());
+            }
+
+            if meta.path.is_ident("max_nesting") {
+                let value: syn::Expr = meta.value()?.parse()?;
+                rules.push(ValidationRule::MaxNesting(quote! { #value }));
                 return Ok(());
             }
 
@@ -1145,14 +1641,62 @@ fn parse_field_attributes(field: &Field) -> syn::Result<Vec<ValidationRule>> {
                 return Ok(());
             }
 
+            if meta.path.is_ident("unique_by") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                rules.push(ValidationRule::UniqueBy(lit.parse()?));
+                return Ok(());
+            }
+
             // Unknown rule - silently ignore for forward compatibility
             Ok(())
         })?;
+
+        if !groups.is_empty() {
+            if rules.len() == start {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`groups` must be combined with at least one rule in the same attribute",
+                ));
+            }
+            for rule in &mut rules[start..] {
+                let inner = std::mem::replace(rule, ValidationRule::Unique);
+                *rule = ValidationRule::Grouped {
+                    groups: groups.clone(),
+                    rule: Box::new(inner),
+                };
+            }
+        }
     }
 
     Ok(rules)
 }
 
+/// Parse `nested` or `nested(context)`.
+///
+/// The bare form validates the field with `Validate`; `nested(context)` passes the
+/// enclosing type's validation context down through `ValidateWith`.
+fn parse_nested_rule(meta: &syn::meta::ParseNestedMeta) -> syn::Result<ValidationRule> {
+    if !meta.input.peek(syn::token::Paren) {
+        return Ok(ValidationRule::Nested);
+    }
+
+    let mut with_context = false;
+    meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("context") {
+            with_context = true;
+            Ok(())
+        } else {
+            Err(inner.error("expected `context`"))
+        }
+    })?;
+
+    Ok(if with_context {
+        ValidationRule::NestedWithContext
+    } else {
+        ValidationRule::Nested
+    })
+}
+
 fn parse_string_lit(expr: &Expr) -> syn::Result<String> {
     match expr {
         Expr::Lit(lit_expr) => match &lit_expr.lit {
@@ -1170,106 +1714,163 @@ fn generate_field_validation(fv: &FieldValidation) -> proc_macro2::TokenStream {
     let validations: Vec<_> = fv
         .rules
         .iter()
-        .map(|rule| match rule {
-            // Legacy rules
-            ValidationRule::Length { min, max, .. } => {
-                generate_length_validation(field_name, &field_name_str, min, max)
-            }
-            ValidationRule::Range { min, max, .. } => {
-                generate_range_validation(field_name, &field_name_str, min, max)
-            }
-            ValidationRule::Nested => generate_nested_validation(field_name, &field_name_str),
-            ValidationRule::Each(inner_rule) => {
-                generate_each_validation(field_name, &field_name_str, inner_rule)
-            }
-            ValidationRule::Custom(fn_path) => {
-                generate_custom_validation(field_name, &field_name_str, fn_path)
-            }
+        .map(|rule| generate_field_rule(field_name, &field_name_str, rule))
+        .collect();
 
-            // New rich syntax rules - String rules
-            ValidationRule::Email => {
-                generate_simple_string_rule(field_name, &field_name_str, "email")
-            }
-            ValidationRule::Url => generate_simple_string_rule(field_name, &field_name_str, "url"),
-            ValidationRule::MinLen(min) => generate_min_len(field_name, &field_name_str, *min),
-            ValidationRule::MaxLen(max) => generate_max_len(field_name, &field_name_str, *max),
-            ValidationRule::Alphanumeric => {
-                generate_simple_string_rule(field_name, &field_name_str, "alphanumeric")
-            }
-            ValidationRule::Ascii => {
-                generate_simple_string_rule(field_name, &field_name_str, "ascii")
-            }
-            ValidationRule::AlphaOnly => {
-                generate_simple_string_rule(field_name, &field_name_str, "alpha_only")
-            }
-            ValidationRule::NumericString => {
-                generate_simple_string_rule(field_name, &field_name_str, "numeric_string")
-            }
-            ValidationRule::NonEmpty => {
-                generate_simple_string_rule(field_name, &field_name_str, "non_empty")
-            }
-            ValidationRule::NonBlank => {
-                generate_simple_string_rule(field_name, &field_name_str, "non_blank")
-            }
-            ValidationRule::NoWhitespace => {
-                generate_simple_string_rule(field_name, &field_name_str, "no_whitespace")
-            }
-            ValidationRule::Contains(substr) => {
-                generate_string_param_rule(field_name, &field_name_str, "contains", substr)
-            }
-            ValidationRule::StartsWith(prefix) => {
-                generate_string_param_rule(field_name, &field_name_str, "starts_with", prefix)
-            }
-            ValidationRule::EndsWith(suffix) => {
-                generate_string_param_rule(field_name, &field_name_str, "ends_with", suffix)
-            }
-            ValidationRule::MatchesRegex(pattern) => {
-                generate_matches_regex(field_name, &field_name_str, pattern)
-            }
+    quote! {
+        #(#validations)*
+    }
+}
 
-            // Numeric rules
-            ValidationRule::Min(min) => generate_min_max(field_name, &field_name_str, "min", min),
-            ValidationRule::Max(max) => generate_min_max(field_name, &field_name_str, "max", max),
-            ValidationRule::Positive => {
-                generate_simple_numeric_rule(field_name, &field_name_str, "positive")
-            }
-            ValidationRule::Negative => {
-                generate_simple_numeric_rule(field_name, &field_name_str, "negative")
-            }
-            ValidationRule::NonZero => {
-                generate_simple_numeric_rule(field_name, &field_name_str, "non_zero")
-            }
-            ValidationRule::Finite => {
-                generate_simple_numeric_rule(field_name, &field_name_str, "finite")
-            }
-            ValidationRule::MultipleOf(n) => {
-                generate_min_max(field_name, &field_name_str, "multiple_of", n)
+/// Generate validation code for a single rule on a named field
+fn generate_field_rule(
+    field_name: &syn::Ident,
+    field_name_str: &str,
+    rule: &ValidationRule,
+) -> proc_macro2::TokenStream {
+    match rule {
+        // Legacy rules
+        ValidationRule::Length { min, max, .. } => {
+            generate_length_validation(field_name, field_name_str, min, max)
+        }
+        ValidationRule::Range { min, max, .. } => {
+            generate_range_validation(field_name, field_name_str, min, max)
+        }
+        ValidationRule::Nested => generate_nested_validation(field_name, field_name_str),
+        ValidationRule::NestedWithContext => quote! {
+            if let Err(e) = domainstack::ValidateWith::validate_with(&self.#field_name, ctx) {
+                err.merge_prefixed(#field_name_str, e);
             }
+        },
+        ValidationRule::Each(inner_rule) => {
+            generate_each_validation(field_name, field_name_str, inner_rule)
+        }
+        ValidationRule::Custom(fn_path) => {
+            generate_custom_validation(field_name, field_name_str, fn_path)
+        }
 
-            // Choice rules
-            ValidationRule::Equals(val) => {
-                generate_min_max(field_name, &field_name_str, "equals", val)
-            }
-            ValidationRule::NotEquals(val) => {
-                generate_min_max(field_name, &field_name_str, "not_equals", val)
-            }
-            ValidationRule::OneOf(values) => generate_one_of(field_name, &field_name_str, values),
+        // New rich syntax rules - String rules
+        ValidationRule::Email => generate_simple_string_rule(field_name, field_name_str, "email"),
+        ValidationRule::Url => generate_simple_string_rule(field_name, field_name_str, "url"),
+        ValidationRule::MinLen(min) => generate_min_len(field_name, field_name_str, min),
+        ValidationRule::MaxLen(max) => generate_max_len(field_name, field_name_str, max),
+        ValidationRule::Alphanumeric => {
+            generate_simple_string_rule(field_name, field_name_str, "alphanumeric")
+        }
+        ValidationRule::Ascii => generate_simple_string_rule(field_name, field_name_str, "ascii"),
+        ValidationRule::AlphaOnly => {
+            generate_simple_string_rule(field_name, field_name_str, "alpha_only")
+        }
+        ValidationRule::NumericString => {
+            generate_simple_string_rule(field_name, field_name_str, "numeric_string")
+        }
+        ValidationRule::NonEmpty => {
+            generate_simple_string_rule(field_name, field_name_str, "non_empty")
+        }
+        ValidationRule::NonBlank => {
+            generate_simple_string_rule(field_name, field_name_str, "non_blank")
+        }
+        ValidationRule::NoWhitespace => {
+            generate_simple_string_rule(field_name, field_name_str, "no_whitespace")
+        }
+        ValidationRule::Contains(substr) => {
+            generate_string_param_rule(field_name, field_name_str, "contains", substr)
+        }
+        ValidationRule::StartsWith(prefix) => {
+            generate_string_param_rule(field_name, field_name_str, "starts_with", prefix)
+        }
+        ValidationRule::EndsWith(suffix) => {
+            generate_string_param_rule(field_name, field_name_str, "ends_with", suffix)
+        }
+        ValidationRule::MatchesRegex(pattern) => {
+            generate_matches_regex(field_name, field_name_str, pattern)
+        }
+        ValidationRule::Str(rule) => {
+            generate_str_rule(quote! { self.#field_name.as_str() }, field_name_str, rule)
+        }
+        ValidationRule::Password(rule) => apply_rule_expr(
+            quote! { self.#field_name.as_str() },
+            field_name_str,
+            rule.constructor(|sibling| quote! { &self.#sibling }),
+        ),
+        ValidationRule::Time(rule) => apply_rule_expr(
+            quote! { &self.#field_name },
+            field_name_str,
+            rule.constructor(),
+        ),
+        ValidationRule::Decimal(rule) => apply_rule_expr(
+            quote! { &self.#field_name },
+            field_name_str,
+            rule.constructor(),
+        ),
+        ValidationRule::Money(rule) => apply_rule_expr(
+            quote! { &self.#field_name },
+            field_name_str,
+            rule.constructor(|sibling| quote! { &self.#sibling }),
+        ),
 
-            // Collection rules
-            ValidationRule::MinItems(min) => {
-                generate_collection_rule(field_name, &field_name_str, "min_items", *min)
-            }
-            ValidationRule::MaxItems(max) => {
-                generate_collection_rule(field_name, &field_name_str, "max_items", *max)
-            }
-            ValidationRule::Unique => {
-                generate_simple_collection_rule(field_name, &field_name_str, "unique")
-            }
-        })
-        .collect();
+        // Numeric rules
+        ValidationRule::Min(min) => generate_min_max(field_name, field_name_str, "min", min),
+        ValidationRule::Max(max) => generate_min_max(field_name, field_name_str, "max", max),
+        ValidationRule::Positive => {
+            generate_simple_numeric_rule(field_name, field_name_str, "positive")
+        }
+        ValidationRule::Negative => {
+            generate_simple_numeric_rule(field_name, field_name_str, "negative")
+        }
+        ValidationRule::NonZero => {
+            generate_simple_numeric_rule(field_name, field_name_str, "non_zero")
+        }
+        ValidationRule::Latitude => {
+            generate_simple_numeric_rule(field_name, field_name_str, "latitude")
+        }
+        ValidationRule::Longitude => {
+            generate_simple_numeric_rule(field_name, field_name_str, "longitude")
+        }
+        ValidationRule::Finite => {
+            generate_simple_numeric_rule(field_name, field_name_str, "finite")
+        }
+        ValidationRule::MultipleOf(n) => {
+            generate_min_max(field_name, field_name_str, "multiple_of", n)
+        }
 
-    quote! {
-        #(#validations)*
+        // Choice rules
+        ValidationRule::Equals(val) => generate_min_max(field_name, field_name_str, "equals", val),
+        ValidationRule::NotEquals(val) => {
+            generate_min_max(field_name, field_name_str, "not_equals", val)
+        }
+        ValidationRule::OneOf(values) => generate_one_of(field_name, field_name_str, values),
+
+        // Collection rules
+        ValidationRule::MinItems(min) => {
+            generate_collection_rule(field_name, field_name_str, "min_items", min)
+        }
+        ValidationRule::MaxItems(max) => {
+            generate_collection_rule(field_name, field_name_str, "max_items", max)
+        }
+        ValidationRule::Unique => {
+            generate_simple_collection_rule(field_name, field_name_str, "unique")
+        }
+        ValidationRule::UniqueBy(key) => {
+            generate_unique_by(quote! { &self.#field_name[..] }, field_name_str, key)
+        }
+        ValidationRule::MaxNesting(max) => apply_rule_expr(
+            quote! { &self.#field_name },
+            field_name_str,
+            quote! { domainstack::rules::max_nesting(#max) },
+        ),
+
+        // Presence rules
+        ValidationRule::Required => {
+            generate_simple_option_rule(quote! { &self.#field_name }, field_name_str, "required")
+        }
+        ValidationRule::Forbidden => {
+            generate_simple_option_rule(quote! { &self.#field_name }, field_name_str, "forbidden")
+        }
+        ValidationRule::Grouped { groups, rule } => generate_grouped(
+            groups,
+            generate_field_rule(field_name, field_name_str, rule),
+        ),
     }
 }
 
@@ -1325,8 +1926,16 @@ fn generate_indexed_field_validation(
             },
             _ => quote! {},
         },
-        ValidationRule::Nested => quote! {
-            if let Err(e) = self.#field_index.validate() {
+        ValidationRule::Nested => {
+            let check = nested_group_check(quote! { &self.#field_index });
+            quote! {
+                if let Err(e) = #check {
+                    err.merge_prefixed(#field_name_str, e);
+                }
+            }
+        }
+        ValidationRule::NestedWithContext => quote! {
+            if let Err(e) = domainstack::ValidateWith::validate_with(&self.#field_index, ctx) {
                 err.merge_prefixed(#field_name_str, e);
             }
         },
@@ -1346,6 +1955,29 @@ fn generate_indexed_field_validation(
                 }
             }
         },
+        ValidationRule::Str(rule) => {
+            generate_str_rule(quote! { self.#field_index.as_str() }, field_name_str, rule)
+        }
+        ValidationRule::Password(rule) => apply_rule_expr(
+            quote! { self.#field_index.as_str() },
+            field_name_str,
+            rule.constructor(no_named_fields),
+        ),
+        ValidationRule::Time(rule) => apply_rule_expr(
+            quote! { &self.#field_index },
+            field_name_str,
+            rule.constructor(),
+        ),
+        ValidationRule::Decimal(rule) => apply_rule_expr(
+            quote! { &self.#field_index },
+            field_name_str,
+            rule.constructor(),
+        ),
+        ValidationRule::Money(rule) => apply_rule_expr(
+            quote! { &self.#field_index },
+            field_name_str,
+            rule.constructor(no_named_fields),
+        ),
         ValidationRule::MinLen(min) => quote! {
             {
                 let rule = domainstack::rules::min_len(#min);
@@ -1442,6 +2074,40 @@ fn generate_indexed_field_validation(
                 }
             }
         },
+        ValidationRule::Latitude => quote! {
+            {
+                let rule = domainstack::rules::latitude();
+                if let Err(e) = domainstack::validate(#field_name_str, &self.#field_index, &rule) {
+                    err.extend(e);
+                }
+            }
+        },
+        ValidationRule::Longitude => quote! {
+            {
+                let rule = domainstack::rules::longitude();
+                if let Err(e) = domainstack::validate(#field_name_str, &self.#field_index, &rule) {
+                    err.extend(e);
+                }
+            }
+        },
+        ValidationRule::MaxNesting(max) => apply_rule_expr(
+            quote! { &self.#field_index },
+            field_name_str,
+            quote! { domainstack::rules::max_nesting(#max) },
+        ),
+        ValidationRule::UniqueBy(key) => {
+            generate_unique_by(quote! { &self.#field_index[..] }, field_name_str, key)
+        }
+        ValidationRule::Required => {
+            generate_simple_option_rule(quote! { &self.#field_index }, field_name_str, "required")
+        }
+        ValidationRule::Forbidden => {
+            generate_simple_option_rule(quote! { &self.#field_index }, field_name_str, "forbidden")
+        }
+        ValidationRule::Grouped { groups, rule } => generate_grouped(
+            groups,
+            generate_indexed_field_validation(field_index, field_name_str, rule),
+        ),
         _ => quote! {},
     }
 }
@@ -1482,8 +2148,16 @@ fn generate_enum_field_validation(
             },
             _ => quote! {},
         },
-        ValidationRule::Nested => quote! {
-            if let Err(e) = #field_name.validate() {
+        ValidationRule::Nested => {
+            let check = nested_group_check(quote! { #field_name });
+            quote! {
+                if let Err(e) = #check {
+                    err.merge_prefixed(#field_name_str, e);
+                }
+            }
+        }
+        ValidationRule::NestedWithContext => quote! {
+            if let Err(e) = domainstack::ValidateWith::validate_with(#field_name, ctx) {
                 err.merge_prefixed(#field_name_str, e);
             }
         },
@@ -1503,6 +2177,25 @@ fn generate_enum_field_validation(
                 }
             }
         },
+        ValidationRule::Str(rule) => {
+            generate_str_rule(quote! { #field_name.as_str() }, field_name_str, rule)
+        }
+        ValidationRule::Password(rule) => apply_rule_expr(
+            quote! { #field_name.as_str() },
+            field_name_str,
+            rule.constructor(|sibling| quote! { #sibling }),
+        ),
+        ValidationRule::Time(rule) => {
+            apply_rule_expr(quote! { #field_name }, field_name_str, rule.constructor())
+        }
+        ValidationRule::Decimal(rule) => {
+            apply_rule_expr(quote! { #field_name }, field_name_str, rule.constructor())
+        }
+        ValidationRule::Money(rule) => apply_rule_expr(
+            quote! { #field_name },
+            field_name_str,
+            rule.constructor(|sibling| quote! { #sibling }),
+        ),
         ValidationRule::MinLen(min) => quote! {
             {
                 let rule = domainstack::rules::min_len(#min);
@@ -1575,6 +2268,40 @@ fn generate_enum_field_validation(
                 }
             }
         },
+        ValidationRule::Latitude => quote! {
+            {
+                let rule = domainstack::rules::latitude();
+                if let Err(e) = domainstack::validate(#field_name_str, #field_name, &rule) {
+                    err.extend(e);
+                }
+            }
+        },
+        ValidationRule::Longitude => quote! {
+            {
+                let rule = domainstack::rules::longitude();
+                if let Err(e) = domainstack::validate(#field_name_str, #field_name, &rule) {
+                    err.extend(e);
+                }
+            }
+        },
+        ValidationRule::MaxNesting(max) => apply_rule_expr(
+            quote! { #field_name },
+            field_name_str,
+            quote! { domainstack::rules::max_nesting(#max) },
+        ),
+        ValidationRule::UniqueBy(key) => {
+            generate_unique_by(quote! { &#field_name[..] }, field_name_str, key)
+        }
+        ValidationRule::Required => {
+            generate_simple_option_rule(quote! { #field_name }, field_name_str, "required")
+        }
+        ValidationRule::Forbidden => {
+            generate_simple_option_rule(quote! { #field_name }, field_name_str, "forbidden")
+        }
+        ValidationRule::Grouped { groups, rule } => generate_grouped(
+            groups,
+            generate_enum_field_validation(field_name, field_name_str, rule),
+        ),
         _ => quote! {},
     }
 }
@@ -1585,8 +2312,20 @@ fn generate_enum_tuple_field_validation(
     field_name_str: &str,
     rule: &ValidationRule,
 ) -> proc_macro2::TokenStream {
-    // Reuse the same logic as enum struct variant fields
-    generate_enum_field_validation(binding, field_name_str, rule)
+    match rule {
+        ValidationRule::Password(rule) => apply_rule_expr(
+            quote! { #binding.as_str() },
+            field_name_str,
+            rule.constructor(no_named_fields),
+        ),
+        ValidationRule::Money(rule) => apply_rule_expr(
+            quote! { #binding },
+            field_name_str,
+            rule.constructor(no_named_fields),
+        ),
+        // Reuse the same logic as enum struct variant fields
+        _ => generate_enum_field_validation(binding, field_name_str, rule),
+    }
 }
 
 fn generate_length_validation(
@@ -1649,13 +2388,28 @@ fn generate_nested_validation(
     field_name: &syn::Ident,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
+    let check = nested_group_check(quote! { &self.#field_name });
     quote! {
-        if let Err(e) = self.#field_name.validate() {
+        if let Err(e) = #check {
             err.merge_prefixed(#field_name_str, e);
         }
     }
 }
 
+/// Validate a nested value (a reference expression) for the current `group`.
+///
+/// Types implementing `ValidateGroup` receive the group; types that only implement
+/// `Validate` are validated with `validate()`.
+fn nested_group_check(value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use domainstack::__private::{ViaValidate as _, ViaValidateGroup as _};
+            (&&domainstack::__private::NestedGroup(#value)).validate_nested(group)
+        }
+    }
+}
+
 fn generate_each_validation(
     field_name: &syn::Ident,
     field_name_str: &str,
@@ -1663,9 +2417,20 @@ fn generate_each_validation(
 ) -> proc_macro2::TokenStream {
     match inner_rule {
         ValidationRule::Nested => {
+            let check = nested_group_check(quote! { item });
             quote! {
                 for (i, item) in self.#field_name.iter().enumerate() {
-                    if let Err(e) = item.validate() {
+                    if let Err(e) = #check {
+                        let path = domainstack::Path::root().field(#field_name_str).index(i);
+                        err.merge_prefixed(path, e);
+                    }
+                }
+            }
+        }
+        ValidationRule::NestedWithContext => {
+            quote! {
+                for (i, item) in self.#field_name.iter().enumerate() {
+                    if let Err(e) = domainstack::ValidateWith::validate_with(item, ctx) {
                         let path = domainstack::Path::root().field(#field_name_str).index(i);
                         err.merge_prefixed(path, e);
                     }
@@ -1742,6 +2507,20 @@ fn generate_each_validation(
                 }
             }
         }
+        ValidationRule::Str(rule) => {
+            let rule_expr = rule.constructor();
+            quote! {
+                {
+                    let rule = #rule_expr;
+                    for (i, item) in self.#field_name.iter().enumerate() {
+                        let path = domainstack::Path::root().field(#field_name_str).index(i);
+                        if let Err(e) = domainstack::validate(path, item.as_str(), &rule) {
+                            err.extend(e);
+                        }
+                    }
+                }
+            }
+        }
         ValidationRule::Alphanumeric => {
             quote! {
                 {
@@ -1900,10 +2679,52 @@ fn generate_simple_string_rule(
     }
 }
 
+/// Validate `value` (a `&str` expression) against a [`StrRule`]
+fn generate_str_rule(
+    value: proc_macro2::TokenStream,
+    field_name_str: &str,
+    rule: &StrRule,
+) -> proc_macro2::TokenStream {
+    let rule_expr = rule.constructor();
+    quote! {
+        {
+            let rule = #rule_expr;
+            if let Err(e) = domainstack::validate(#field_name_str, #value, &rule) {
+                err.extend(e);
+            }
+        }
+    }
+}
+
+/// Validate `value` against the rule built by `rule_expr` (a password or datetime rule)
+fn apply_rule_expr(
+    value: proc_macro2::TokenStream,
+    field_name_str: &str,
+    rule_expr: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            let rule = #rule_expr;
+            if let Err(e) = domainstack::validate(#field_name_str, #value, &rule) {
+                err.extend(e);
+            }
+        }
+    }
+}
+
+/// Sibling field lookup for tuple fields, where `forbid_fields` or `currency` has nothing to name
+fn no_named_fields(sibling: &syn::Ident) -> proc_macro2::TokenStream {
+    syn::Error::new(
+        sibling.span(),
+        "sibling field references require a struct or enum variant with named fields",
+    )
+    .to_compile_error()
+}
+
 fn generate_min_len(
     field_name: &syn::Ident,
     field_name_str: &str,
-    min: usize,
+    min: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     quote! {
         {
@@ -1918,7 +2739,7 @@ fn generate_min_len(
 fn generate_max_len(
     field_name: &syn::Ident,
     field_name_str: &str,
-    max: usize,
+    max: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     quote! {
         {
@@ -2022,15 +2843,14 @@ fn generate_collection_rule(
     field_name: &syn::Ident,
     field_name_str: &str,
     rule_fn: &str,
-    val: usize,
+    val: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let rule_fn: proc_macro2::TokenStream = format!("domainstack::rules::{}({})", rule_fn, val)
-        .parse()
-        .unwrap();
+    let rule_fn = syn::Ident::new(rule_fn, proc_macro2::Span::call_site());
+    let rule_fn = quote! { domainstack::rules::#rule_fn(#val) };
     quote! {
         {
             let rule = #rule_fn;
-            if let Err(e) = domainstack::validate(#field_name_str, &self.#field_name, &rule) {
+            if let Err(e) = domainstack::validate(#field_name_str, &self.#field_name[..], &rule) {
                 err.extend(e);
             }
         }
@@ -2048,37 +2868,142 @@ fn generate_simple_collection_rule(
     quote! {
         {
             let rule = #rule_fn;
-            if let Err(e) = domainstack::validate(#field_name_str, &self.#field_name, &rule) {
+            if let Err(e) = domainstack::validate(#field_name_str, &self.#field_name[..], &rule) {
                 err.extend(e);
             }
         }
     }
 }
 
-fn generate_struct_validation(sv: &StructValidation) -> proc_macro2::TokenStream {
-    let check_expr: proc_macro2::TokenStream = sv.check.parse().unwrap();
+/// `unique_by = "key"`: the helper fn pins the item type from the field before the
+/// key closure is checked, so `item.key` resolves without a type annotation.
+fn generate_unique_by(
+    items: proc_macro2::TokenStream,
+    field_name_str: &str,
+    key: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let key_str = key.to_string();
+    quote! {
+        {
+            fn __unique_by<T: 'static, K: ::core::cmp::Eq + ::core::hash::Hash>(
+                _items: &[T],
+                key: impl Fn(&T) -> K + Send + Sync + 'static,
+            ) -> domainstack::Rule<[T]> {
+                domainstack::rules::unique_by(#key_str, key)
+            }
+            let items = #items;
+            let rule = __unique_by(items, |item| ::core::clone::Clone::clone(&item.#key));
+            if let Err(e) = domainstack::validate(#field_name_str, items, &rule) {
+                err.extend(e);
+            }
+        }
+    }
+}
+
+/// `required` / `forbidden` on `value`, a reference to the `Option` field
+fn generate_simple_option_rule(
+    value: proc_macro2::TokenStream,
+    field_name_str: &str,
+    rule_fn: &str,
+) -> proc_macro2::TokenStream {
+    let rule_fn = syn::Ident::new(rule_fn, proc_macro2::Span::call_site());
+    quote! {
+        {
+            let rule = domainstack::rules::#rule_fn();
+            if let Err(e) = domainstack::validate(#field_name_str, #value, &rule) {
+                err.extend(e);
+            }
+        }
+    }
+}
+
+/// `valid_range()` (and `max_span`) on `(self.start, self.end)`, reported at the end field.
+fn generate_range_fields(
+    sv: &StructValidation,
+    start: &syn::Ident,
+    end: &syn::Ident,
+    max_span: Option<&syn::Expr>,
+) -> proc_macro2::TokenStream {
+    let end_str = end.to_string();
+    let max_span = max_span.map(|max| quote! { .and(domainstack::rules::max_span(#max)) });
+    let overrides = violation_overrides(sv);
+
+    quote! {
+        {
+            let rule = domainstack::rules::valid_range() #max_span;
+            let range = (self.#start.clone(), self.#end.clone());
+            if let Err(e) = domainstack::validate(#end_str, &range, &rule) {
+                #overrides
+                err.extend(e);
+            }
+        }
+    }
+}
+
+/// Apply a struct validation's `code` / `message` to the violations in `e`
+fn violation_overrides(sv: &StructValidation) -> Option<proc_macro2::TokenStream> {
     let code = sv
         .code
-        .as_deref()
-        .unwrap_or("cross_field_validation_failed");
+        .as_ref()
+        .map(|code| quote! { violation.code = #code; });
     let message = sv
         .message
-        .as_deref()
-        .unwrap_or("Cross-field validation failed");
-
-    let validation_code = quote! {
-        if !(#check_expr) {
-            err.violations.push(domainstack::Violation {
-                path: domainstack::Path::root(),
-                code: #code,
-                message: #message.to_string(),
-                meta: domainstack::Meta::default(),
-            });
+        .as_ref()
+        .map(|message| quote! { violation.message = ::core::convert::Into::into(#message); });
+    (code.is_some() || message.is_some()).then(|| {
+        quote! {
+            let mut e = e;
+            for violation in e.violations.iter_mut() {
+                #code
+                #message
+            }
+        }
+    })
+}
+
+/// Wrap generated validation code so it only runs for the given groups.
+fn generate_grouped(groups: &[String], code: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        if [#(#groups),*].contains(&group) {
+            #code
         }
+    }
+}
+
+fn generate_struct_validation(sv: &StructValidation) -> proc_macro2::TokenStream {
+    let validation_code = match &sv.check {
+        StructCheck::Expr(check) => {
+            let check_expr: proc_macro2::TokenStream = check.parse().unwrap();
+            let code = sv
+                .code
+                .as_deref()
+                .unwrap_or("cross_field_validation_failed");
+            let message = sv
+                .message
+                .as_deref()
+                .unwrap_or("Cross-field validation failed");
+
+            quote! {
+                if !(#check_expr) {
+                    err.violations.push(domainstack::Violation {
+                        path: domainstack::Path::root(),
+                        code: #code,
+                        message: ::core::convert::Into::into(#message),
+                        meta: domainstack::Meta::default(),
+                    });
+                }
+            }
+        }
+        StructCheck::RangeFields {
+            start,
+            end,
+            max_span,
+        } => generate_range_fields(sv, start, end, max_span.as_ref()),
+        StructCheck::CrossField(rule) => rule.check(violation_overrides(sv)),
     };
 
     // Wrap in conditional if 'when' is specified
-    if let Some(when_expr) = &sv.when {
+    let validation_code = if let Some(when_expr) = &sv.when {
         let when_tokens: proc_macro2::TokenStream = when_expr.parse().unwrap();
         quote! {
             if #when_tokens {
@@ -2087,5 +3012,11 @@ fn generate_struct_validation(sv: &StructValidation) -> proc_macro2::TokenStream
         }
     } else {
         validation_code
+    };
+
+    if sv.groups.is_empty() {
+        validation_code
+    } else {
+        generate_grouped(&sv.groups, validation_code)
     }
 }
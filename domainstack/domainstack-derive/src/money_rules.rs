@@ -0,0 +1,72 @@
+//! `#[validate(money(currency = "currency", min = 1.0, max = 500.0))]` on amount fields.
+//!
+//! The currency is a sibling field, so like `password(forbid_fields = ...)` the
+//! constructor takes a closure resolving the field name to an expression for its
+//! value. `min` and `max` are expressions of the amount's type and must come together.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct MoneyRule {
+    currency: syn::Ident,
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+}
+
+impl MoneyRule {
+    /// Parse `money(...)` from `#[validate(...)]`, or `None` if `meta` names another rule.
+    pub fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        if !meta.path.is_ident("money") {
+            return Ok(None);
+        }
+
+        let mut currency = None;
+        let mut min = None;
+        let mut max = None;
+        meta.parse_nested_meta(|option| {
+            if option.path.is_ident("currency") {
+                let lit: syn::LitStr = option.value()?.parse()?;
+                currency = Some(lit.parse()?);
+            } else if option.path.is_ident("min") {
+                min = Some(option.value()?.parse()?);
+            } else if option.path.is_ident("max") {
+                max = Some(option.value()?.parse()?);
+            } else {
+                return Err(
+                    option.error("unsupported money option; expected `currency`, `min` or `max`")
+                );
+            }
+            Ok(())
+        })?;
+
+        let Some(currency) = currency else {
+            return Err(meta.error("money requires `currency = \"field\"`"));
+        };
+        if min.is_some() != max.is_some() {
+            return Err(meta.error("money requires both `min` and `max`, or neither"));
+        }
+
+        Ok(Some(MoneyRule { currency, min, max }))
+    }
+
+    /// The sibling field holding the currency code.
+    pub fn currency(&self) -> &syn::Ident {
+        &self.currency
+    }
+
+    /// Expression building `rules::money` or `rules::money_range`.
+    ///
+    /// `field` maps the currency field to an expression for its value (anything
+    /// implementing `AsRef<str>`).
+    pub fn constructor(&self, field: impl Fn(&syn::Ident) -> TokenStream) -> TokenStream {
+        let currency = field(&self.currency);
+        let currency = quote! { ::core::convert::AsRef::<str>::as_ref(#currency) };
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => {
+                quote! { domainstack::rules::money_range(#currency, #min, #max) }
+            }
+            _ => quote! { domainstack::rules::money(#currency) },
+        }
+    }
+}
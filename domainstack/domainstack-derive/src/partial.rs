@@ -0,0 +1,349 @@
+//! Derive implementation for `ValidatePartial`.
+//!
+//! Generates a PATCH companion type (`UserPatch` for `User`) whose fields are
+//! `Option`-wrapped, plus a `Validate` impl that applies the original field rules
+//! to present fields only. Field rules are generated by the same code paths as
+//! `#[derive(Validate)]`; accesses to `self.field` are then rewritten to the
+//! unwrapped value, so rules, error codes and paths stay identical.
+
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+use crate::{
+    apply_rule_expr, check_context_rules, check_group_context, generate_field_rule,
+    generate_grouped, generate_impl, generate_struct_validation, parse_context_type,
+    parse_field_attributes, parse_struct_attributes, uses_groups, FieldValidation, ValidationRule,
+};
+
+/// Options from the type-level `#[patch(...)]` attribute.
+#[derive(Default)]
+struct PatchOptions {
+    name: Option<Ident>,
+    derives: Vec<syn::Path>,
+}
+
+/// Derive implementation for ValidatePartial
+pub fn derive_validate_partial_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "#[derive(ValidatePartial)] only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "#[derive(ValidatePartial)] only supports structs with named fields",
+            ))
+        }
+    };
+
+    let options = parse_patch_options(&input)?;
+    let patch_name = options
+        .name
+        .unwrap_or_else(|| format_ident!("{}Patch", name));
+    let derives = &options.derives;
+
+    // serde attributes are only valid when the patch derives a serde trait
+    let uses_serde = derives.iter().any(|path| {
+        path.segments
+            .last()
+            .is_some_and(|seg| seg.ident == "Serialize" || seg.ident == "Deserialize")
+    });
+    let serde_attrs = |attrs: &[syn::Attribute]| -> Vec<syn::Attribute> {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.path().is_ident("doc") || (uses_serde && attr.path().is_ident("serde"))
+            })
+            .cloned()
+            .collect()
+    };
+
+    let field_names: HashSet<String> = fields
+        .iter()
+        .map(|f| f.ident.as_ref().unwrap().to_string())
+        .collect();
+
+    // Patch struct fields: same name, visibility and docs, type wrapped in Option
+    let patch_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let attrs = serde_attrs(&field.attrs);
+        quote! {
+            #(#attrs)*
+            #field_vis #ident: ::core::option::Option<#ty>
+        }
+    });
+
+    // Field-level rules run only when the field is present
+    let mut field_validations = Vec::new();
+    let mut field_validation_code = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap().clone();
+        let rules = parse_field_attributes(field)?;
+        if rules.is_empty() {
+            continue;
+        }
+
+        let fv = FieldValidation {
+            field_name: field_name.clone(),
+            field_type: field.ty.clone(),
+            rules,
+        };
+        let binding = format_ident!("__patch_{}", field_name);
+        let bindings = [(field_name.to_string(), binding.clone())];
+        let code: TokenStream = fv
+            .rules
+            .iter()
+            .map(|rule| generate_patch_rule(&field_name, rule))
+            .collect();
+        let code = replace_field_access(code, &bindings);
+        field_validation_code.push(quote! {
+            if let ::core::option::Option::Some(#binding) = &self.#field_name {
+                #code
+            }
+        });
+        field_validations.push(fv);
+    }
+
+    // Cross-field checks run only when every field they reference is present
+    let struct_validations = parse_struct_attributes(&input)?;
+    let struct_validation_code = struct_validations
+        .iter()
+        .map(|sv| {
+            let code = generate_struct_validation(sv);
+            let referenced = referenced_fields(code.clone(), &field_names);
+            if referenced.is_empty() {
+                return code;
+            }
+
+            let bindings: Vec<(String, Ident)> = referenced
+                .iter()
+                .map(|f| (f.clone(), format_ident!("__patch_{}", f)))
+                .collect();
+            let code = replace_field_access(code, &bindings);
+            let fields = referenced.iter().map(|f| format_ident!("{}", f));
+            let patterns = bindings.iter().map(|(_, binding)| binding);
+            quote! {
+                if let (#(::core::option::Option::Some(#patterns),)*) = (#(&self.#fields,)*) {
+                    #code
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let context = parse_context_type(&input)?;
+    check_context_rules(
+        &input,
+        context.as_ref(),
+        field_validations.iter().flat_map(|fv| &fv.rules),
+    )?;
+    let grouped = uses_groups(
+        field_validations.iter().flat_map(|fv| &fv.rules),
+        &struct_validations,
+    );
+    check_group_context(&input, context.as_ref(), grouped)?;
+
+    let validate_impl = generate_impl(
+        &patch_name,
+        &impl_generics,
+        &ty_generics,
+        where_clause,
+        context.as_ref(),
+        quote! {
+            // Field-level validations (present fields only)
+            #(#field_validation_code)*
+
+            // Struct-level validations (only when all inputs are present)
+            #(#struct_validation_code)*
+        },
+    );
+
+    let struct_attrs = serde_attrs(&input.attrs);
+    let derive_attr = if derives.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[derive(#(#derives),*)] }
+    };
+    let all_fields = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let doc = format!(
+        "Partial update for [`{}`]: only present fields are validated and applied.",
+        name
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #derive_attr
+        #(#struct_attrs)*
+        #vis struct #patch_name #generics #where_clause {
+            #(#patch_fields,)*
+        }
+
+        impl #impl_generics #patch_name #ty_generics #where_clause {
+            /// Returns `true` if no field is present.
+            pub fn is_empty(&self) -> bool {
+                true #(&& self.#all_fields.is_none())*
+            }
+
+            /// Overwrites the fields of `target` that are present in this patch.
+            ///
+            /// Validate the merged value afterwards to enforce invariants that
+            /// span patched and unpatched fields.
+            pub fn apply_to(self, target: &mut #name #ty_generics) {
+                #(
+                    if let ::core::option::Option::Some(value) = self.#all_fields {
+                        target.#all_fields = value;
+                    }
+                )*
+            }
+        }
+
+        #validate_impl
+    })
+}
+
+fn parse_patch_options(input: &DeriveInput) -> syn::Result<PatchOptions> {
+    let mut options = PatchOptions::default();
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("patch") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                options.name = Some(lit.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|inner| {
+                    options.derives.push(inner.path);
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("expected `name = \"...\"` or `derive(...)`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// Generate a field rule for the patch type.
+///
+/// Same as `#[derive(Validate)]`, except that the siblings named by a password's
+/// `forbid_fields` are `Option`s here; an absent sibling forbids nothing. A money
+/// amount is only checked when its currency is present too.
+fn generate_patch_rule(field_name: &Ident, rule: &ValidationRule) -> TokenStream {
+    let field_name_str = field_name.to_string();
+    match rule {
+        ValidationRule::Password(password) => apply_rule_expr(
+            quote! { self.#field_name.as_str() },
+            &field_name_str,
+            password.constructor(|sibling| quote! { self.#sibling.as_deref().unwrap_or_default() }),
+        ),
+        ValidationRule::Money(money) => {
+            let currency = money.currency();
+            let check = apply_rule_expr(
+                quote! { &self.#field_name },
+                &field_name_str,
+                money.constructor(|_| quote! { __patch_currency }),
+            );
+            quote! {
+                if let ::core::option::Option::Some(__patch_currency) = &self.#currency {
+                    #check
+                }
+            }
+        }
+        ValidationRule::Grouped { groups, rule } => {
+            generate_grouped(groups, generate_patch_rule(field_name, rule))
+        }
+        _ => generate_field_rule(field_name, &field_name_str, rule),
+    }
+}
+
+/// Collect the fields referenced as `self.field` in generated code, in first-use order.
+fn referenced_fields(tokens: TokenStream, fields: &HashSet<String>) -> Vec<String> {
+    fn walk(tokens: TokenStream, fields: &HashSet<String>, found: &mut Vec<String>) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, tt) in tokens.iter().enumerate() {
+            match tt {
+                TokenTree::Group(group) => walk(group.stream(), fields, found),
+                TokenTree::Ident(ident) if ident == "self" => {
+                    if let Some(field) = field_after_self(&tokens[i + 1..]) {
+                        if fields.contains(&field) && !found.contains(&field) {
+                            found.push(field);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    walk(tokens, fields, &mut found);
+    found
+}
+
+/// Rewrite `self.field` to `(*binding)` for each `(field, binding)` pair.
+fn replace_field_access(tokens: TokenStream, bindings: &[(String, Ident)]) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    replace_field_access(group.stream(), bindings),
+                );
+                new_group.set_span(group.span());
+                out.push(TokenTree::Group(new_group));
+            }
+            TokenTree::Ident(ident) if ident == "self" => {
+                let binding = field_after_self(&tokens[i + 1..]).and_then(|field| {
+                    bindings
+                        .iter()
+                        .find(|(name, _)| *name == field)
+                        .map(|(_, binding)| binding)
+                });
+                if let Some(binding) = binding {
+                    out.extend(quote! { (*#binding) });
+                    // skip `self`, `.` and the field name
+                    i += 3;
+                    continue;
+                }
+                out.push(tokens[i].clone());
+            }
+            other => out.push(other.clone()),
+        }
+        i += 1;
+    }
+
+    out.into_iter().collect()
+}
+
+/// Returns the field name if `rest` starts with `.field`.
+fn field_after_self(rest: &[TokenTree]) -> Option<String> {
+    match rest {
+        [TokenTree::Punct(dot), TokenTree::Ident(field), ..] if dot.as_char() == '.' => {
+            Some(field.to_string())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,170 @@
+//! `#[validate(password(...))]`: builds a `domainstack::rules::PasswordPolicy`.
+//!
+//! Shared by `Validate` and the schema derives. `forbid_fields` names sibling
+//! fields whose values the password must not contain, so the constructor takes
+//! a closure that resolves a field name to an expression for its value.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+#[derive(Debug, Clone, Default)]
+pub struct PasswordRule {
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    lowercase: bool,
+    uppercase: bool,
+    digit: bool,
+    symbol: bool,
+    min_entropy: Option<u32>,
+    max_repeats: Option<usize>,
+    forbid: Vec<String>,
+    forbid_fields: Vec<syn::Ident>,
+}
+
+impl PasswordRule {
+    /// Parse `password` or `password(...)`, or `None` if `meta` names another rule.
+    pub fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        if !meta.path.is_ident("password") {
+            return Ok(None);
+        }
+
+        let mut rule = PasswordRule::default();
+        if !meta.input.peek(syn::token::Paren) {
+            return Ok(Some(rule));
+        }
+
+        meta.parse_nested_meta(|option| {
+            let Some(ident) = option.path.get_ident() else {
+                return Err(option.error("unsupported password option"));
+            };
+            match ident.to_string().as_str() {
+                "min_length" => rule.min_length = Some(parse_int(&option)?),
+                "max_length" => rule.max_length = Some(parse_int(&option)?),
+                "min_entropy" => rule.min_entropy = Some(parse_int(&option)?),
+                "max_repeats" => rule.max_repeats = Some(parse_int(&option)?),
+                "lowercase" => rule.lowercase = true,
+                "uppercase" => rule.uppercase = true,
+                "digit" => rule.digit = true,
+                "symbol" => rule.symbol = true,
+                "forbid" => {
+                    rule.forbid = parse_str_list(&option)?
+                        .iter()
+                        .map(syn::LitStr::value)
+                        .collect()
+                }
+                "forbid_fields" => {
+                    rule.forbid_fields = parse_str_list(&option)?
+                        .iter()
+                        .map(|name| name.parse())
+                        .collect::<syn::Result<_>>()?
+                }
+                _ => {
+                    return Err(option.error(
+                        "unsupported password option; expected min_length, max_length, \
+                         lowercase, uppercase, digit, symbol, min_entropy, max_repeats, \
+                         forbid or forbid_fields",
+                    ))
+                }
+            }
+            Ok(())
+        })?;
+
+        if let (Some(min), Some(max)) = (rule.min_length, rule.max_length) {
+            if min > max {
+                return Err(meta.error("password min_length must not exceed max_length"));
+            }
+        }
+
+        Ok(Some(rule))
+    }
+
+    /// Expression building the `domainstack::rules::password` rule.
+    ///
+    /// `field` maps a `forbid_fields` entry to an expression for that field's
+    /// value (anything implementing `AsRef<str>`).
+    pub fn constructor(&self, field: impl Fn(&syn::Ident) -> TokenStream) -> TokenStream {
+        let mut settings = Vec::new();
+        if let Some(min) = self.min_length {
+            settings.push(quote! { min_length: #min });
+        }
+        if let Some(max) = self.max_length {
+            settings.push(quote! { max_length: #max });
+        }
+        for (enabled, name) in [
+            (self.lowercase, "require_lowercase"),
+            (self.uppercase, "require_uppercase"),
+            (self.digit, "require_digit"),
+            (self.symbol, "require_symbol"),
+        ] {
+            if enabled {
+                let name = format_ident!("{}", name);
+                settings.push(quote! { #name: true });
+            }
+        }
+        if let Some(bits) = self.min_entropy {
+            settings.push(quote! { min_entropy_bits: ::core::option::Option::Some(#bits) });
+        }
+        if let Some(max) = self.max_repeats {
+            settings.push(quote! { max_repeats: ::core::option::Option::Some(#max) });
+        }
+
+        let forbid = &self.forbid;
+        let identities = self.forbid_fields.iter().map(|name| {
+            let value = field(name);
+            quote! { .forbid_identity(#value) }
+        });
+
+        quote! {
+            domainstack::rules::password(
+                domainstack::rules::PasswordPolicy {
+                    #(#settings,)*
+                    ..::core::default::Default::default()
+                }
+                #(.forbid(#forbid))*
+                #(#identities)*
+            )
+        }
+    }
+
+    /// Add `format: password` and the length bounds to a schema builder expression.
+    pub fn apply_schema(&self, schema: TokenStream) -> TokenStream {
+        let mut schema = quote! { #schema.format("password") };
+        if let Some(min) = self.min_length {
+            schema = quote! { #schema.min_length(#min) };
+        }
+        if let Some(max) = self.max_length {
+            schema = quote! { #schema.max_length(#max) };
+        }
+        schema
+    }
+}
+
+fn parse_int<N>(meta: &syn::meta::ParseNestedMeta) -> syn::Result<N>
+where
+    N: std::str::FromStr,
+    N::Err: std::fmt::Display,
+{
+    let lit: syn::LitInt = meta.value()?.parse()?;
+    lit.base10_parse()
+}
+
+fn parse_str_list(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<syn::LitStr>> {
+    let value: syn::Expr = meta.value()?.parse()?;
+    let syn::Expr::Array(array) = value else {
+        return Err(syn::Error::new_spanned(
+            value,
+            "expected a list of strings like [\"a\", \"b\"]",
+        ));
+    };
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => Ok(s.clone()),
+            other => Err(syn::Error::new_spanned(other, "expected a string literal")),
+        })
+        .collect()
+}
@@ -2,6 +2,13 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Attribute, Data, DeriveInput, Fields, Lit, Type};
 
+use crate::decimal_rules::DecimalRule;
+use crate::groups;
+use crate::money_rules::MoneyRule;
+use crate::password::PasswordRule;
+use crate::str_rules::StrRule;
+use crate::time_rules::TimeRule;
+
 /// Derive implementation for ToSchema
 pub fn derive_to_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
     let name = &input.ident;
@@ -48,12 +55,6 @@ pub fn derive_to_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
         })
         .collect::<syn::Result<_>>()?;
 
-    // Generate schema properties
-    let properties = generate_properties(&field_schemas)?;
-
-    // Generate required fields array
-    let required_fields = generate_required_fields(&field_schemas);
-
     // Apply struct-level hints
     let schema_name = name.to_string();
     let description = struct_schema_hints
@@ -65,6 +66,56 @@ pub fn derive_to_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
         quote! { .example(::serde_json::json!(#ex)) }
     });
 
+    // Struct-level cross-field rules as anyOf / oneOf / not constraints
+    let constraints = crate::cross_field::schema_constraints(
+        &input,
+        &quote! { ::domainstack_schema::Schema },
+        false,
+    )?;
+
+    let object_schema = |fields: &[FieldSchema]| -> syn::Result<TokenStream> {
+        // Generate schema properties
+        let properties = generate_properties(fields)?;
+
+        // Generate required fields array
+        let required_fields = generate_required_fields(fields);
+
+        Ok(quote! {
+            ::domainstack_schema::Schema::object()
+                #properties
+                #required_fields
+                #constraints
+                #description
+                #example
+        })
+    };
+
+    // schema() describes the default group
+    let schema = object_schema(&fields_for_group(
+        &field_schemas,
+        Some(groups::DEFAULT_GROUP),
+    ))?;
+
+    // One schema per validation group, e.g. different request bodies for create/update
+    let all_groups = collect_groups(&field_schemas);
+    let group_schema_fn = if all_groups.is_empty() {
+        quote! {}
+    } else {
+        let group_schemas = all_groups
+            .iter()
+            .map(|group| object_schema(&fields_for_group(&field_schemas, Some(group))))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let ungrouped = object_schema(&fields_for_group(&field_schemas, None))?;
+        quote! {
+            fn schema_for_group(group: &str) -> ::domainstack_schema::Schema {
+                match group {
+                    #(#all_groups => #group_schemas,)*
+                    _ => #ungrouped,
+                }
+            }
+        }
+    };
+
     Ok(quote! {
         impl #impl_generics ::domainstack_schema::ToSchema for #name #ty_generics #where_clause {
             fn schema_name() -> &'static str {
@@ -72,12 +123,10 @@ pub fn derive_to_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
             }
 
             fn schema() -> ::domainstack_schema::Schema {
-                ::domainstack_schema::Schema::object()
-                    #properties
-                    #required_fields
-                    #description
-                    #example
+                #schema
             }
+
+            #group_schema_fn
         }
     })
 }
@@ -109,6 +158,9 @@ struct SchemaHints {
 enum ValidationRule {
     Email,
     Url,
+    Str(StrRule),
+    Decimal(Box<DecimalRule>),
+    Password(PasswordRule),
     MinLen(usize),
     MaxLen(usize),
     Length {
@@ -143,6 +195,12 @@ enum ValidationRule {
     Nested,
     EachNested,
     Custom(String),
+    Required,
+    Forbidden,
+    Grouped {
+        groups: Vec<String>,
+        rule: Box<ValidationRule>,
+    },
 }
 
 /// Parse #[validate(...)] attributes from a field
@@ -154,7 +212,27 @@ fn parse_validation_attributes(attrs: &[Attribute]) -> syn::Result<Vec<Validatio
             continue;
         }
 
+        // `groups = [...]` applies to every rule in the same attribute
+        let start = rules.len();
+        let mut rule_groups = Vec::new();
+
         attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("groups") {
+                rule_groups = groups::parse_groups(&meta)?;
+                return Ok(());
+            }
+
+            // Presence rules
+            if meta.path.is_ident("required") {
+                rules.push(ValidationRule::Required);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("forbidden") {
+                rules.push(ValidationRule::Forbidden);
+                return Ok(());
+            }
+
             // Email
             if meta.path.is_ident("email") {
                 rules.push(ValidationRule::Email);
@@ -167,6 +245,34 @@ fn parse_validation_attributes(attrs: &[Attribute]) -> syn::Result<Vec<Validatio
                 return Ok(());
             }
 
+            // Format rules: ipv4, hostname, mac_address, ...
+            if let Some(rule) = StrRule::parse(&meta)? {
+                rules.push(ValidationRule::Str(rule));
+                return Ok(());
+            }
+
+            // password(...): format and length bounds
+            if let Some(rule) = PasswordRule::parse(&meta)? {
+                rules.push(ValidationRule::Password(rule));
+                return Ok(());
+            }
+
+            // Date/time rules have no schema keywords, but their options must be consumed
+            if TimeRule::parse(&meta)?.is_some() {
+                return Ok(());
+            }
+
+            // money(currency = ...) depends on a sibling field; `Money` describes the pair
+            if MoneyRule::parse(&meta)?.is_some() {
+                return Ok(());
+            }
+
+            // decimal(...): minimum, maximum and multipleOf
+            if let Some(rule) = DecimalRule::parse(&meta)? {
+                rules.push(ValidationRule::Decimal(Box::new(rule)));
+                return Ok(());
+            }
+
             // min_len
             if meta.path.is_ident("min_len") {
                 let value: syn::Lit = meta.value()?.parse()?;
@@ -234,6 +340,22 @@ fn parse_validation_attributes(attrs: &[Attribute]) -> syn::Result<Vec<Validatio
                 return Ok(());
             }
 
+            // latitude / longitude: the coordinate bounds in degrees
+            if meta.path.is_ident("latitude") {
+                rules.push(ValidationRule::Range {
+                    min: quote! { -90 },
+                    max: quote! { 90 },
+                });
+                return Ok(());
+            }
+            if meta.path.is_ident("longitude") {
+                rules.push(ValidationRule::Range {
+                    min: quote! { -180 },
+                    max: quote! { 180 },
+                });
+                return Ok(());
+            }
+
             // nested
             if meta.path.is_ident("nested") {
                 rules.push(ValidationRule::Nested);
@@ -306,11 +428,68 @@ fn parse_validation_attributes(attrs: &[Attribute]) -> syn::Result<Vec<Validatio
             // For now, skip other rules - we'll add them incrementally
             Ok(())
         })?;
+
+        if !rule_groups.is_empty() {
+            for rule in &mut rules[start..] {
+                let inner = std::mem::replace(rule, ValidationRule::Unique);
+                *rule = ValidationRule::Grouped {
+                    groups: rule_groups.clone(),
+                    rule: Box::new(inner),
+                };
+            }
+        }
     }
 
     Ok(rules)
 }
 
+/// Collect every group named by a field rule, in first-seen order
+fn collect_groups(fields: &[FieldSchema]) -> Vec<String> {
+    let mut all = Vec::new();
+    for field in fields {
+        for rule in &field.validation_rules {
+            if let ValidationRule::Grouped { groups, .. } = rule {
+                groups::collect(&mut all, groups);
+            }
+        }
+    }
+    all
+}
+
+/// Resolve the fields and rules that apply when validating `group`.
+///
+/// `None` selects only ungrouped rules. Fields with an applicable `forbidden`
+/// rule are left out of the schema entirely.
+fn fields_for_group(fields: &[FieldSchema], group: Option<&str>) -> Vec<FieldSchema> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let validation_rules: Vec<_> = field
+                .validation_rules
+                .iter()
+                .filter_map(|rule| match rule {
+                    ValidationRule::Grouped { groups, rule } => group
+                        .filter(|group| groups::applies(groups, group))
+                        .map(|_| (**rule).clone()),
+                    other => Some(other.clone()),
+                })
+                .collect();
+
+            if validation_rules
+                .iter()
+                .any(|r| matches!(r, ValidationRule::Forbidden))
+            {
+                return None;
+            }
+
+            Some(FieldSchema {
+                validation_rules,
+                ..field.clone()
+            })
+        })
+        .collect()
+}
+
 /// Parse #[schema(...)] attributes from a field
 fn parse_field_schema_attributes(attrs: &[Attribute]) -> syn::Result<SchemaHints> {
     let mut hints = SchemaHints::default();
@@ -414,7 +593,7 @@ fn generate_base_schema_from_type(ty: &Type, rules: &[ValidationRule]) -> syn::R
             "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "usize" | "isize" => {
                 quote! { ::domainstack_schema::Schema::integer() }
             }
-            "f32" | "f64" => quote! { ::domainstack_schema::Schema::number() },
+            "f32" | "f64" | "Decimal" => quote! { ::domainstack_schema::Schema::number() },
             "bool" => quote! { ::domainstack_schema::Schema::boolean() },
             "Vec" => {
                 // Extract inner type from Vec<T>
@@ -450,6 +629,9 @@ fn apply_validation_constraints(base: TokenStream, rules: &[ValidationRule]) ->
             ValidationRule::Url => {
                 quote! { #schema.format("uri") }
             }
+            ValidationRule::Str(rule) => rule.apply_schema(schema),
+            ValidationRule::Decimal(rule) => rule.apply_schema(schema),
+            ValidationRule::Password(rule) => rule.apply_schema(schema),
             ValidationRule::MinLen(min) => {
                 quote! { #schema.min_length(#min) }
             }
@@ -538,7 +720,12 @@ fn apply_schema_hints(base: TokenStream, hints: &SchemaHints) -> TokenStream {
 fn generate_required_fields(fields: &[FieldSchema]) -> TokenStream {
     let required: Vec<_> = fields
         .iter()
-        .filter(|f| !is_option_type(&f.ty))
+        .filter(|f| {
+            !is_option_type(&f.ty)
+                || f.validation_rules
+                    .iter()
+                    .any(|r| matches!(r, ValidationRule::Required))
+        })
         .map(|f| f.name.to_string())
         .collect();
 
@@ -0,0 +1,282 @@
+//! String rules that map one-to-one onto a `domainstack::rules` constructor.
+//!
+//! Shared by `Validate` (which calls the constructor) and the schema derives
+//! (which map each rule to a `format`, `pattern` or `enum`), so adding a rule here
+//! covers all three.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Pattern for `mac_address`: colon or hyphen separated octets, or `aabb.ccdd.eeff`.
+const MAC_ADDRESS_PATTERN: &str = r"^(?:[0-9A-Fa-f]{2}([:-])(?:[0-9A-Fa-f]{2}\1){4}[0-9A-Fa-f]{2}|[0-9A-Fa-f]{4}\.[0-9A-Fa-f]{4}\.[0-9A-Fa-f]{4})$";
+
+/// Pattern for `port_range`: `"443"` or `"8000-8999"`.
+const PORT_RANGE_PATTERN: &str = r"^[1-9][0-9]{0,4}(?:-[1-9][0-9]{0,4})?$";
+
+// Syntactic shape of the financial identifiers; checksums are only enforced server-side.
+const LUHN_PATTERN: &str = r"^[0-9]{2,}$";
+const CREDIT_CARD_PATTERN: &str = r"^[0-9](?:[ -]?[0-9]){11,18}$";
+const IBAN_PATTERN: &str = r"^[A-Za-z]{2}[0-9]{2}(?: ?[A-Za-z0-9]){11,30}$";
+const BIC_PATTERN: &str = r"^[A-Z]{6}[A-Z0-9]{2}(?:[A-Z0-9]{3})?$";
+const ISIN_PATTERN: &str = r"^[A-Z]{2}[A-Z0-9]{9}[0-9]$";
+const ABA_ROUTING_PATTERN: &str = r"^[0-9]{9}$";
+
+// Identifier and encoding shapes
+const ULID_PATTERN: &str = r"^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$";
+const BASE64_PATTERN: &str = r"^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$";
+const BASE64URL_PATTERN: &str =
+    r"^(?:[A-Za-z0-9_-]{4})*(?:[A-Za-z0-9_-]{2}(?:==)?|[A-Za-z0-9_-]{3}=?)?$";
+const JWT_PATTERN: &str = r"^[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*$";
+
+// Input safety rules; what a pattern can't say (device names, the 255-byte limit,
+// percent-encoded segments) is only checked server-side.
+const SAFE_FILENAME_PATTERN: &str =
+    r#"^(?!-)[^/\\<>:"|?*\u0000-\u001F\u007F-\u009F]*[^/\\<>:"|?*\u0000-\u001F\u007F-\u009F. ]$"#;
+const NO_PATH_TRAVERSAL_PATTERN: &str =
+    r"^(?![/\\]|[A-Za-z]:)(?![\s\S]*(?:^|[/\\])\.\.(?:[/\\]|$))";
+const NO_NULL_BYTES_PATTERN: &str = r"^[^\u0000]*$";
+const NO_HTML_TAGS_PATTERN: &str = r"^(?![\s\S]*<[A-Za-z/!?])";
+const NO_TEMPLATE_SYNTAX_PATTERN: &str = r"^(?![\s\S]*(?:\{\{|\{%|\{#|\$\{|#\{|<%))";
+
+/// Pattern for `bcp47_locale`: RFC 5646 tag shape; subtag registries are only checked server-side.
+const BCP47_LOCALE_PATTERN: &str = r"^(?:[A-Za-z]{2,3}(?:-[A-Za-z]{3}){0,3}(?:-[A-Za-z]{4})?(?:-(?:[A-Za-z]{2}|[0-9]{3}))?(?:-(?:[A-Za-z0-9]{5,8}|[0-9][A-Za-z0-9]{3}))*(?:-[0-9A-WYZa-wyz](?:-[A-Za-z0-9]{2,8})+)*(?:-[Xx](?:-[A-Za-z0-9]{1,8})+)?|[Xx](?:-[A-Za-z0-9]{1,8})+)$";
+
+#[derive(Debug, Clone)]
+pub enum StrRule {
+    Ipv4,
+    Ipv6,
+    Cidr,
+    IpInCidr(String),
+    Hostname,
+    Fqdn,
+    PortRange,
+    MacAddress,
+    Luhn,
+    CreditCard,
+    Iban,
+    Bic,
+    Isin,
+    AbaRouting,
+    Iso3166Alpha2,
+    Iso3166Alpha3,
+    Iso4217Currency,
+    Iso639Language,
+    Bcp47Locale,
+    Uuid(Option<u8>),
+    Ulid,
+    Base64,
+    Base64Url,
+    Hex(Option<usize>),
+    JwtStructure,
+    SafeFilename,
+    NoPathTraversal,
+    NoNullBytes,
+    NoHtmlTags,
+    NoTemplateSyntax,
+}
+
+impl StrRule {
+    /// Parse a string rule from `#[validate(...)]`, or `None` if `meta` names another rule.
+    pub fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        let Some(ident) = meta.path.get_ident() else {
+            return Ok(None);
+        };
+
+        let rule = match ident.to_string().as_str() {
+            "ipv4" => StrRule::Ipv4,
+            "ipv6" => StrRule::Ipv6,
+            "cidr" => StrRule::Cidr,
+            "hostname" => StrRule::Hostname,
+            "fqdn" => StrRule::Fqdn,
+            "port_range" => StrRule::PortRange,
+            "mac_address" => StrRule::MacAddress,
+            "luhn" => StrRule::Luhn,
+            "credit_card" => StrRule::CreditCard,
+            "iban" => StrRule::Iban,
+            "bic" => StrRule::Bic,
+            "isin" => StrRule::Isin,
+            "aba_routing" => StrRule::AbaRouting,
+            "iso3166_alpha2" => StrRule::Iso3166Alpha2,
+            "iso3166_alpha3" => StrRule::Iso3166Alpha3,
+            "iso4217_currency" => StrRule::Iso4217Currency,
+            "iso639_language" => StrRule::Iso639Language,
+            "bcp47_locale" => StrRule::Bcp47Locale,
+            "ulid" => StrRule::Ulid,
+            "base64" => StrRule::Base64,
+            "base64url" => StrRule::Base64Url,
+            "jwt_structure" => StrRule::JwtStructure,
+            "safe_filename" => StrRule::SafeFilename,
+            "no_path_traversal" => StrRule::NoPathTraversal,
+            "no_null_bytes" => StrRule::NoNullBytes,
+            "no_html_tags" => StrRule::NoHtmlTags,
+            "no_template_syntax" => StrRule::NoTemplateSyntax,
+            "uuid" => {
+                let version = if meta.input.peek(syn::Token![=]) {
+                    let lit: syn::LitInt = meta.value()?.parse()?;
+                    let version: u8 = lit.base10_parse()?;
+                    if !(1..=8).contains(&version) {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "uuid version must be between 1 and 8",
+                        ));
+                    }
+                    Some(version)
+                } else {
+                    None
+                };
+                StrRule::Uuid(version)
+            }
+            "hex" => {
+                let len = if meta.input.peek(syn::Token![=]) {
+                    let lit: syn::LitInt = meta.value()?.parse()?;
+                    Some(lit.base10_parse()?)
+                } else {
+                    None
+                };
+                StrRule::Hex(len)
+            }
+            "ip_in_cidr" => {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                if !is_cidr(&lit.value()) {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "ip_in_cidr expects a CIDR range like \"10.0.0.0/8\"",
+                    ));
+                }
+                StrRule::IpInCidr(lit.value())
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(rule))
+    }
+
+    /// Expression building the `domainstack::rules` rule.
+    pub fn constructor(&self) -> TokenStream {
+        match self {
+            StrRule::Ipv4 => quote! { domainstack::rules::ipv4() },
+            StrRule::Ipv6 => quote! { domainstack::rules::ipv6() },
+            StrRule::Cidr => quote! { domainstack::rules::cidr() },
+            StrRule::IpInCidr(cidr) => quote! { domainstack::rules::ip_in_cidr(#cidr) },
+            StrRule::Hostname => quote! { domainstack::rules::hostname() },
+            StrRule::Fqdn => quote! { domainstack::rules::fqdn() },
+            StrRule::PortRange => quote! { domainstack::rules::port_range() },
+            StrRule::MacAddress => quote! { domainstack::rules::mac_address() },
+            StrRule::Luhn => quote! { domainstack::rules::luhn() },
+            StrRule::CreditCard => quote! { domainstack::rules::credit_card() },
+            StrRule::Iban => quote! { domainstack::rules::iban() },
+            StrRule::Bic => quote! { domainstack::rules::bic() },
+            StrRule::Isin => quote! { domainstack::rules::isin() },
+            StrRule::AbaRouting => quote! { domainstack::rules::aba_routing() },
+            StrRule::Iso3166Alpha2 => quote! { domainstack::rules::iso3166_alpha2() },
+            StrRule::Iso3166Alpha3 => quote! { domainstack::rules::iso3166_alpha3() },
+            StrRule::Iso4217Currency => quote! { domainstack::rules::iso4217_currency() },
+            StrRule::Iso639Language => quote! { domainstack::rules::iso639_language() },
+            StrRule::Bcp47Locale => quote! { domainstack::rules::bcp47_locale() },
+            StrRule::Uuid(version) => {
+                let version = option_tokens(version.as_ref());
+                quote! { domainstack::rules::uuid(#version) }
+            }
+            StrRule::Ulid => quote! { domainstack::rules::ulid() },
+            StrRule::Base64 => quote! { domainstack::rules::base64() },
+            StrRule::Base64Url => quote! { domainstack::rules::base64url() },
+            StrRule::Hex(len) => {
+                let len = option_tokens(len.as_ref());
+                quote! { domainstack::rules::hex(#len) }
+            }
+            StrRule::JwtStructure => quote! { domainstack::rules::jwt_structure() },
+            StrRule::SafeFilename => quote! { domainstack::rules::safe_filename() },
+            StrRule::NoPathTraversal => quote! { domainstack::rules::no_path_traversal() },
+            StrRule::NoNullBytes => quote! { domainstack::rules::no_null_bytes() },
+            StrRule::NoHtmlTags => quote! { domainstack::rules::no_html_tags() },
+            StrRule::NoTemplateSyntax => quote! { domainstack::rules::no_template_syntax() },
+        }
+    }
+
+    /// Add the matching constraint to a `Schema` or `JsonSchema` builder expression.
+    pub fn apply_schema(&self, schema: TokenStream) -> TokenStream {
+        match self {
+            StrRule::Ipv4 => quote! { #schema.format("ipv4") },
+            StrRule::Ipv6 => quote! { #schema.format("ipv6") },
+            StrRule::Cidr => quote! { #schema.format("cidr") },
+            StrRule::IpInCidr(cidr) => {
+                let format = if cidr.contains(':') { "ipv6" } else { "ipv4" };
+                quote! { #schema.format(#format) }
+            }
+            StrRule::Hostname | StrRule::Fqdn => quote! { #schema.format("idn-hostname") },
+            StrRule::PortRange => quote! { #schema.pattern(#PORT_RANGE_PATTERN) },
+            StrRule::MacAddress => quote! { #schema.pattern(#MAC_ADDRESS_PATTERN) },
+            StrRule::Luhn => quote! { #schema.pattern(#LUHN_PATTERN) },
+            StrRule::CreditCard => quote! { #schema.pattern(#CREDIT_CARD_PATTERN) },
+            StrRule::Iban => quote! { #schema.pattern(#IBAN_PATTERN) },
+            StrRule::Bic => quote! { #schema.pattern(#BIC_PATTERN) },
+            StrRule::Isin => quote! { #schema.pattern(#ISIN_PATTERN) },
+            StrRule::AbaRouting => quote! { #schema.pattern(#ABA_ROUTING_PATTERN) },
+            // Code sets reuse the tables embedded in `domainstack::rules::iso`
+            StrRule::Iso3166Alpha2 => {
+                quote! { #schema.enum_values(domainstack::rules::iso::ISO3166_ALPHA2) }
+            }
+            StrRule::Iso3166Alpha3 => {
+                quote! { #schema.enum_values(domainstack::rules::iso::ISO3166_ALPHA3) }
+            }
+            StrRule::Iso4217Currency => {
+                quote! { #schema.enum_values(domainstack::rules::iso::ISO4217_CURRENCIES) }
+            }
+            StrRule::Iso639Language => {
+                quote! { #schema.enum_values(domainstack::rules::iso::ISO639_LANGUAGES) }
+            }
+            StrRule::Bcp47Locale => quote! { #schema.pattern(#BCP47_LOCALE_PATTERN) },
+            StrRule::Uuid(None) => quote! { #schema.format("uuid") },
+            StrRule::Uuid(Some(version)) => {
+                let pattern = uuid_pattern(*version);
+                quote! { #schema.format("uuid").pattern(#pattern) }
+            }
+            StrRule::Ulid => quote! { #schema.pattern(#ULID_PATTERN) },
+            StrRule::Base64 => quote! { #schema.pattern(#BASE64_PATTERN) },
+            StrRule::Base64Url => quote! { #schema.pattern(#BASE64URL_PATTERN) },
+            StrRule::Hex(None) => quote! { #schema.pattern("^[0-9A-Fa-f]+$") },
+            StrRule::Hex(Some(len)) => {
+                let pattern = format!("^[0-9A-Fa-f]{{{}}}$", len);
+                quote! { #schema.pattern(#pattern) }
+            }
+            StrRule::JwtStructure => quote! { #schema.pattern(#JWT_PATTERN) },
+            StrRule::SafeFilename => quote! { #schema.pattern(#SAFE_FILENAME_PATTERN) },
+            StrRule::NoPathTraversal => quote! { #schema.pattern(#NO_PATH_TRAVERSAL_PATTERN) },
+            StrRule::NoNullBytes => quote! { #schema.pattern(#NO_NULL_BYTES_PATTERN) },
+            StrRule::NoHtmlTags => quote! { #schema.pattern(#NO_HTML_TAGS_PATTERN) },
+            StrRule::NoTemplateSyntax => quote! { #schema.pattern(#NO_TEMPLATE_SYNTAX_PATTERN) },
+        }
+    }
+}
+
+/// `Some(value)` / `None` tokens for optional rule arguments.
+fn option_tokens<T: quote::ToTokens>(value: Option<&T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::core::option::Option::Some(#value) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+/// UUID pattern pinning the version nibble and the RFC 9562 variant.
+fn uuid_pattern(version: u8) -> String {
+    format!(
+        "^[0-9A-Fa-f]{{8}}-[0-9A-Fa-f]{{4}}-{}[0-9A-Fa-f]{{3}}-[89ABab][0-9A-Fa-f]{{3}}-[0-9A-Fa-f]{{12}}$",
+        version
+    )
+}
+
+/// Compile-time check of an `ip_in_cidr` range, so a typo fails the build
+/// instead of panicking on first use.
+fn is_cidr(value: &str) -> bool {
+    let Some((addr, prefix)) = value.split_once('/') else {
+        return false;
+    };
+    let Ok(addr) = addr.parse::<std::net::IpAddr>() else {
+        return false;
+    };
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    !prefix.is_empty()
+        && prefix.bytes().all(|b| b.is_ascii_digit())
+        && !(prefix.len() > 1 && prefix.starts_with('0'))
+        && prefix.parse::<u32>().is_ok_and(|p| p <= max)
+}
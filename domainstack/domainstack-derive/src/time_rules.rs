@@ -0,0 +1,299 @@
+//! Date/time rules on chrono or `time` fields: `past`, `future`, `before`, `after`,
+//! `age_range`, `within`, `date_granularity`, `weekday_in`, `within_business_hours`
+//! and `not_holiday`.
+//!
+//! Without options `past` and `future` read the clock from the `RuleContext` (the
+//! thread's test clock or the system clock). `clock = <expr>` takes the current time
+//! from any `domainstack::Clock` instead, typically the validation context of a
+//! `ValidateWith` type: `#[validate(past(clock = ctx.clock))]`.
+//!
+//! Durations are written as `"1h30m"`, `"15m"` or `"7d"`, times of day as
+//! `"09:00"`, and weekdays as `"Mon"` or `"Monday"`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub enum TimeRule {
+    Past(Option<syn::Expr>),
+    Future(Option<syn::Expr>),
+    Before(syn::Expr),
+    After(syn::Expr),
+    AgeRange { min: syn::Expr, max: syn::Expr },
+    Within(DurationLit),
+    Granularity(DurationLit),
+    WeekdayIn(Vec<syn::Ident>),
+    BusinessHours(Box<BusinessHours>),
+    NotHoliday(syn::Expr),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DurationLit {
+    secs: u64,
+    nanos: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BusinessHours {
+    tz: syn::Expr,
+    days: Option<Vec<syn::Ident>>,
+    opens: Option<(u8, u8)>,
+    closes: Option<(u8, u8)>,
+}
+
+impl TimeRule {
+    /// Parse a date/time rule from `#[validate(...)]`, or `None` if `meta` names
+    /// another rule.
+    pub fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        let Some(ident) = meta.path.get_ident() else {
+            return Ok(None);
+        };
+
+        let rule = match ident.to_string().as_str() {
+            "past" => TimeRule::Past(parse_clock(meta)?),
+            "future" => TimeRule::Future(parse_clock(meta)?),
+            "before" => TimeRule::Before(meta.value()?.parse()?),
+            "after" => TimeRule::After(meta.value()?.parse()?),
+            "age_range" => {
+                let (mut min, mut max) = (None, None);
+                meta.parse_nested_meta(|option| {
+                    if option.path.is_ident("min") {
+                        min = Some(option.value()?.parse()?);
+                        Ok(())
+                    } else if option.path.is_ident("max") {
+                        max = Some(option.value()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(option.error("unsupported option; expected `min` or `max`"))
+                    }
+                })?;
+                match (min, max) {
+                    (Some(min), Some(max)) => TimeRule::AgeRange { min, max },
+                    _ => return Err(meta.error("age_range requires `min` and `max`")),
+                }
+            }
+            "within" => TimeRule::Within(parse_duration(&meta.value()?.parse()?)?),
+            "date_granularity" => {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                let step = parse_duration(&lit)?;
+                if step.secs == 0 && step.nanos == 0 {
+                    return Err(syn::Error::new_spanned(lit, "granularity cannot be zero"));
+                }
+                TimeRule::Granularity(step)
+            }
+            "weekday_in" => TimeRule::WeekdayIn(parse_weekdays(&meta.value()?.parse()?)?),
+            "within_business_hours" => {
+                TimeRule::BusinessHours(Box::new(parse_business_hours(meta)?))
+            }
+            "not_holiday" => TimeRule::NotHoliday(meta.value()?.parse()?),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(rule))
+    }
+
+    /// Expression building the `domainstack::rules` date/time rule.
+    pub fn constructor(&self) -> TokenStream {
+        match self {
+            TimeRule::Past(None) => quote! { domainstack::rules::temporal_past() },
+            TimeRule::Future(None) => quote! { domainstack::rules::temporal_future() },
+            TimeRule::Past(Some(clock)) => {
+                let as_of = stopped_clock(clock);
+                quote! { domainstack::rules::past_as_of(#as_of) }
+            }
+            TimeRule::Future(Some(clock)) => {
+                let as_of = stopped_clock(clock);
+                quote! { domainstack::rules::future_as_of(#as_of) }
+            }
+            TimeRule::Before(limit) => quote! { domainstack::rules::temporal_before(#limit) },
+            TimeRule::After(limit) => quote! { domainstack::rules::temporal_after(#limit) },
+            TimeRule::AgeRange { min, max } => {
+                quote! { domainstack::rules::temporal_age_range(#min, #max) }
+            }
+            TimeRule::Within(max) => quote! { domainstack::rules::within(#max) },
+            TimeRule::Granularity(step) => {
+                quote! { domainstack::rules::date_granularity(#step) }
+            }
+            TimeRule::WeekdayIn(days) => quote! {
+                domainstack::rules::weekday_in([#(domainstack::rules::Weekday::#days),*])
+            },
+            TimeRule::BusinessHours(hours) => {
+                let tz = &hours.tz;
+                let days = hours.days.as_ref().map(|days| {
+                    quote! { hours = hours.days([#(domainstack::rules::Weekday::#days),*]); }
+                });
+                let opens = hours
+                    .opens
+                    .map(|(hour, minute)| quote! { hours.opens = (#hour, #minute); });
+                let closes = hours
+                    .closes
+                    .map(|(hour, minute)| quote! { hours.closes = (#hour, #minute); });
+                quote! {
+                    domainstack::rules::within_business_hours(#tz, {
+                        let mut hours = domainstack::rules::BusinessHours::default();
+                        #days
+                        #opens
+                        #closes
+                        hours
+                    })
+                }
+            }
+            TimeRule::NotHoliday(calendar) => {
+                quote! { domainstack::rules::not_holiday(#calendar) }
+            }
+        }
+    }
+}
+
+impl quote::ToTokens for DurationLit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let DurationLit { secs, nanos } = self;
+        tokens.extend(quote! { ::core::time::Duration::new(#secs, #nanos) });
+    }
+}
+
+/// `clock = <expr>` in `past(...)` / `future(...)`.
+fn parse_clock(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<syn::Expr>> {
+    let mut clock = None;
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|option| {
+            if option.path.is_ident("clock") {
+                clock = Some(option.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(option.error("unsupported option; expected `clock = <expr>`"))
+            }
+        })?;
+    }
+    Ok(clock)
+}
+
+/// The clock's current time as a `FixedClock`, which the `*_as_of` rules accept
+/// for any date/time field type.
+fn stopped_clock(clock: &syn::Expr) -> TokenStream {
+    quote! {
+        domainstack::FixedClock::from_unix_nanos({
+            use domainstack::Clock as _;
+            (#clock).now_unix_nanos()
+        })
+    }
+}
+
+fn parse_business_hours(meta: &syn::meta::ParseNestedMeta) -> syn::Result<BusinessHours> {
+    let (mut tz, mut days, mut opens, mut closes) = (None, None, None, None);
+    meta.parse_nested_meta(|option| {
+        if option.path.is_ident("tz") {
+            tz = Some(option.value()?.parse()?);
+        } else if option.path.is_ident("days") {
+            days = Some(parse_weekdays(&option.value()?.parse()?)?);
+        } else if option.path.is_ident("opens") {
+            opens = Some(parse_time_of_day(&option.value()?.parse()?)?);
+        } else if option.path.is_ident("closes") {
+            closes = Some(parse_time_of_day(&option.value()?.parse()?)?);
+        } else {
+            return Err(
+                option.error("unsupported option; expected `tz`, `days`, `opens` or `closes`")
+            );
+        }
+        Ok(())
+    })?;
+    let tz = tz.ok_or_else(|| meta.error("within_business_hours requires `tz = <zone>`"))?;
+    Ok(BusinessHours {
+        tz,
+        days,
+        opens,
+        closes,
+    })
+}
+
+/// `"1h30m"`, `"15m"`, `"7d"`, `"90s"`, `"250ms"`.
+fn parse_duration(lit: &syn::LitStr) -> syn::Result<DurationLit> {
+    let error = || {
+        syn::Error::new_spanned(
+            lit,
+            "expected a duration like \"30s\", \"15m\", \"1h30m\" or \"7d\"",
+        )
+    };
+    let text = lit.value();
+    let mut rest = text.as_str();
+    let mut nanos: u128 = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(error)?;
+        let count: u128 = rest[..digits].parse().map_err(|_| error())?;
+        rest = &rest[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit: u128 = match &rest[..unit_len] {
+            "d" => 86_400_000_000_000,
+            "h" => 3_600_000_000_000,
+            "m" => 60_000_000_000,
+            "s" => 1_000_000_000,
+            "ms" => 1_000_000,
+            "us" => 1_000,
+            "ns" => 1,
+            _ => return Err(error()),
+        };
+        rest = &rest[unit_len..];
+        nanos = count
+            .checked_mul(unit)
+            .and_then(|part| nanos.checked_add(part))
+            .ok_or_else(error)?;
+    }
+    if text.is_empty() {
+        return Err(error());
+    }
+    Ok(DurationLit {
+        secs: u64::try_from(nanos / 1_000_000_000).map_err(|_| error())?,
+        nanos: (nanos % 1_000_000_000) as u32,
+    })
+}
+
+/// `"09:00"` as `(9, 0)`; `"24:00"` is allowed as a closing time.
+fn parse_time_of_day(lit: &syn::LitStr) -> syn::Result<(u8, u8)> {
+    let text = lit.value();
+    let parsed = text.split_once(':').and_then(|(hour, minute)| {
+        let valid = hour.len() == 2 && minute.len() == 2;
+        let hour: u8 = hour.parse().ok()?;
+        let minute: u8 = minute.parse().ok()?;
+        (valid && minute < 60 && (hour < 24 || (hour, minute) == (24, 0))).then_some((hour, minute))
+    });
+    parsed.ok_or_else(|| syn::Error::new_spanned(lit, "expected a time of day like \"09:00\""))
+}
+
+/// `["Mon", "Friday"]` as `domainstack::rules::Weekday` variant names.
+fn parse_weekdays(list: &syn::ExprArray) -> syn::Result<Vec<syn::Ident>> {
+    list.elems
+        .iter()
+        .map(|elem| {
+            let lit = match elem {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) => lit,
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "expected a weekday name like \"Mon\"",
+                    ))
+                }
+            };
+            let name = match lit.value().to_ascii_lowercase().as_str() {
+                "mon" | "monday" => "Monday",
+                "tue" | "tuesday" => "Tuesday",
+                "wed" | "wednesday" => "Wednesday",
+                "thu" | "thursday" => "Thursday",
+                "fri" | "friday" => "Friday",
+                "sat" | "saturday" => "Saturday",
+                "sun" | "sunday" => "Sunday",
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "expected a weekday name like \"Mon\" or \"Monday\"",
+                    ))
+                }
+            };
+            Ok(syn::Ident::new(name, lit.span()))
+        })
+        .collect()
+}
@@ -0,0 +1,121 @@
+//! Derive implementation for the `ValidateTransition` trait.
+//!
+//! Generates a state-machine check from a declarative transition table on an enum:
+//!
+//! ```text
+//! #[transitions(Pending -> [Confirmed, Cancelled], Confirmed -> Cancelled)]
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Fields, Ident, Token};
+
+/// One `From -> To` or `From -> [To, ...]` entry.
+struct TransitionRule {
+    from: Ident,
+    to: Vec<Ident>,
+}
+
+impl Parse for TransitionRule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let from: Ident = input.parse()?;
+        input.parse::<Token![->]>()?;
+
+        let to = if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect()
+        } else {
+            vec![input.parse()?]
+        };
+
+        Ok(TransitionRule { from, to })
+    }
+}
+
+/// Derive implementation for ValidateTransition
+pub fn derive_validate_transition_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "#[derive(ValidateTransition)] only supports enums",
+            ))
+        }
+    };
+
+    // Parse every #[transitions(...)] attribute; multiple attributes are merged
+    let mut rules = Vec::new();
+    for attr in &input.attrs {
+        if !attr.path().is_ident("transitions") {
+            continue;
+        }
+        let parsed =
+            attr.parse_args_with(Punctuated::<TransitionRule, Token![,]>::parse_terminated)?;
+        rules.extend(parsed);
+    }
+
+    // Pattern matching any payload of a variant, e.g. `Self::Pending { .. }`
+    let pattern_for = |ident: &Ident| -> syn::Result<TokenStream> {
+        let variant = variants.iter().find(|v| v.ident == *ident).ok_or_else(|| {
+            syn::Error::new_spanned(ident, format!("`{}` is not a variant of `{}`", ident, name))
+        })?;
+        Ok(match &variant.fields {
+            Fields::Named(_) => quote! { Self::#ident { .. } },
+            Fields::Unnamed(_) => quote! { Self::#ident(..) },
+            Fields::Unit => quote! { Self::#ident },
+        })
+    };
+
+    let mut allowed_arms = Vec::new();
+    for rule in &rules {
+        let from = pattern_for(&rule.from)?;
+        for to in &rule.to {
+            let to = pattern_for(to)?;
+            allowed_arms.push(quote! { (#from, #to) });
+        }
+    }
+
+    let can_transition_body = if allowed_arms.is_empty() {
+        quote! {
+            let _ = (self, next);
+            false
+        }
+    } else {
+        quote! {
+            matches!((self, next), #(#allowed_arms)|*)
+        }
+    };
+
+    let name_arms = variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let ident_str = ident.to_string();
+            let pattern = pattern_for(ident)?;
+            Ok(quote! { #pattern => #ident_str, })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics domainstack::ValidateTransition for #name #ty_generics #where_clause {
+            fn state_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            fn can_transition_to(&self, next: &Self) -> bool {
+                #can_transition_body
+            }
+        }
+    })
+}
@@ -0,0 +1,128 @@
+use domainstack::prelude::*;
+use domainstack_derive::Validate;
+
+struct TenantLimits {
+    max_rooms: usize,
+    max_adults: u8,
+    allow_pets: bool,
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = TenantLimits)]
+struct Room {
+    #[validate(range(min = 1, max = ctx.max_adults))]
+    adults: u8,
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = TenantLimits)]
+#[validate(
+    check = "!self.pets || ctx.allow_pets",
+    code = "pets_not_allowed",
+    message = "This property does not allow pets"
+)]
+struct Booking {
+    #[validate(length(min = 1, max = 50))]
+    guest_name: String,
+
+    #[validate(min_items = 1, max_items = ctx.max_rooms)]
+    #[validate(each(nested(context)))]
+    rooms: Vec<Room>,
+
+    pets: bool,
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = "TenantLimits")]
+struct Reservation {
+    #[validate(nested(context))]
+    booking: Booking,
+}
+
+fn limits() -> TenantLimits {
+    TenantLimits {
+        max_rooms: 2,
+        max_adults: 3,
+        allow_pets: false,
+    }
+}
+
+fn booking(rooms: Vec<u8>, pets: bool) -> Booking {
+    Booking {
+        guest_name: "Alice".to_string(),
+        rooms: rooms.into_iter().map(|adults| Room { adults }).collect(),
+        pets,
+    }
+}
+
+#[test]
+fn test_valid_within_context_limits() {
+    assert!(booking(vec![2, 3], false).validate_with(&limits()).is_ok());
+}
+
+#[test]
+fn test_rule_argument_reads_context() {
+    let err = booking(vec![1, 1, 1], false)
+        .validate_with(&limits())
+        .unwrap_err();
+
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "too_many_items");
+    assert_eq!(err.violations[0].path.to_string(), "rooms");
+    assert_eq!(err.violations[0].meta.get("max"), Some("2"));
+}
+
+#[test]
+fn test_same_value_passes_with_different_context() {
+    let generous = TenantLimits {
+        max_rooms: 5,
+        max_adults: 3,
+        allow_pets: true,
+    };
+    assert!(booking(vec![1, 1, 1], true)
+        .validate_with(&generous)
+        .is_ok());
+}
+
+#[test]
+fn test_check_expression_reads_context() {
+    let err = booking(vec![2], true).validate_with(&limits()).unwrap_err();
+
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "pets_not_allowed");
+}
+
+#[test]
+fn test_nested_context_propagates_to_collection_items() {
+    let err = booking(vec![2, 4], false)
+        .validate_with(&limits())
+        .unwrap_err();
+
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "out_of_range");
+    assert_eq!(err.violations[0].path.to_string(), "rooms[1].adults");
+}
+
+#[test]
+fn test_nested_context_propagates_to_fields() {
+    let reservation = Reservation {
+        booking: booking(vec![4], true),
+    };
+
+    let err = reservation.validate_with(&limits()).unwrap_err();
+    let paths: Vec<String> = err.violations.iter().map(|v| v.path.to_string()).collect();
+
+    assert_eq!(err.violations.len(), 2);
+    assert!(paths.contains(&"booking.rooms[0].adults".to_string()));
+    assert!(paths.contains(&"booking".to_string()));
+}
+
+#[test]
+fn test_context_free_rules_still_apply() {
+    let mut b = booking(vec![1], false);
+    b.guest_name = String::new();
+
+    let err = b.validate_with(&limits()).unwrap_err();
+    assert_eq!(err.violations[0].code, "min_length");
+    assert_eq!(err.violations[0].path.to_string(), "guest_name");
+}
@@ -0,0 +1,263 @@
+//! Tests for the struct-level cross-field rules (`required_if`, `exactly_one_of`, ...)
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate, ValidatePartial};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Default, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+#[validate(required_if(field = "state", depends_on = "country", value = "US"))]
+#[validate(required_if(field = "tax_id", depends_on = "company"))]
+#[validate(required_unless(field = "email", depends_on = "phone"))]
+#[validate(mutually_exclusive("card", "iban"))]
+struct Customer {
+    country: String,
+    state: Option<String>,
+    company: Option<String>,
+    tax_id: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    card: Option<String>,
+    iban: Option<String>,
+}
+
+#[derive(Debug, Default, Validate, ToSchema, ValidatePartial)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+#[patch(derive(Debug, Default))]
+#[validate(exactly_one_of("card", "iban", "paypal"))]
+#[validate(at_least_one_of("receipt_email", "receipt_phone"))]
+#[validate(
+    fields_equal("password", "password_confirmation"),
+    message = "Passwords do not match"
+)]
+#[validate(field_lt("min_amount", "max_amount"))]
+struct Checkout {
+    card: Option<String>,
+    iban: Option<String>,
+    paypal: Option<String>,
+    receipt_email: Option<String>,
+    receipt_phone: Option<String>,
+    password: String,
+    password_confirmation: String,
+    min_amount: u32,
+    max_amount: u32,
+}
+
+fn customer() -> Customer {
+    Customer {
+        country: "FR".to_string(),
+        email: Some("ada@example.com".to_string()),
+        ..Default::default()
+    }
+}
+
+fn checkout() -> Checkout {
+    Checkout {
+        card: Some("4111111111111111".to_string()),
+        receipt_email: Some("ada@example.com".to_string()),
+        password: "hunter2".to_string(),
+        password_confirmation: "hunter2".to_string(),
+        min_amount: 1,
+        max_amount: 100,
+        ..Default::default()
+    }
+}
+
+fn found(err: &ValidationError) -> Vec<(String, &'static str)> {
+    err.violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect()
+}
+
+#[test]
+fn test_presence_rules() {
+    assert!(customer().validate().is_ok());
+
+    let mut invalid = customer();
+    invalid.country = "US".to_string();
+    invalid.company = Some("Acme".to_string());
+    invalid.email = None;
+    invalid.card = Some("4111111111111111".to_string());
+    invalid.iban = Some("DE89370400440532013000".to_string());
+    let err = invalid.validate().unwrap_err();
+    assert_eq!(
+        found(&err),
+        [
+            ("state".to_string(), "required"),
+            ("tax_id".to_string(), "required"),
+            ("email".to_string(), "required"),
+            ("iban".to_string(), "mutually_exclusive"),
+        ]
+    );
+    assert_eq!(err.violations[0].meta.get("depends_on"), Some("country"));
+    assert_eq!(err.violations[0].meta.get("value"), Some("US"));
+    assert_eq!(err.violations[0].message, "Required when country is US");
+    assert_eq!(err.violations[1].message, "Required when company is set");
+
+    let mut phone_only = customer();
+    phone_only.email = None;
+    phone_only.phone = Some("+14155552671".to_string());
+    assert!(phone_only.validate().is_ok());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Plan {
+    Free,
+    Business,
+}
+
+#[derive(Debug, Validate)]
+#[validate(required_if(field = "state", depends_on = "country", value = "US"))]
+#[validate(required_if(field = "vat_id", depends_on = "plan", value = Plan::Business))]
+#[validate(required_if(field = "reason", depends_on = "seats", value = 0))]
+struct Signup {
+    country: Option<String>,
+    state: Option<String>,
+    plan: Option<Plan>,
+    vat_id: Option<String>,
+    seats: Option<u32>,
+    reason: Option<String>,
+}
+
+#[test]
+fn test_required_if_value_on_option_fields() {
+    let signup = Signup {
+        country: Some("US".to_string()),
+        state: None,
+        plan: Some(Plan::Business),
+        vat_id: None,
+        seats: Some(0),
+        reason: None,
+    };
+    let err = signup.validate().unwrap_err();
+    assert_eq!(
+        found(&err),
+        [
+            ("state".to_string(), "required"),
+            ("vat_id".to_string(), "required"),
+            ("reason".to_string(), "required"),
+        ]
+    );
+    assert_eq!(err.violations[0].message, "Required when country is US");
+    assert_eq!(err.violations[1].meta.get("value"), Some("Plan::Business"));
+    assert_eq!(err.violations[2].message, "Required when seats is 0");
+
+    // `None` and other values never trigger the rule
+    let signup = Signup {
+        country: None,
+        state: None,
+        plan: Some(Plan::Free),
+        vat_id: None,
+        seats: Some(3),
+        reason: None,
+    };
+    assert!(signup.validate().is_ok());
+}
+
+#[test]
+fn test_group_and_comparison_rules() {
+    assert!(checkout().validate().is_ok());
+
+    let mut invalid = checkout();
+    invalid.paypal = Some("ada@example.com".to_string());
+    invalid.receipt_email = None;
+    invalid.password_confirmation = "hunter3".to_string();
+    invalid.min_amount = 100;
+    let err = invalid.validate().unwrap_err();
+    assert_eq!(
+        found(&err),
+        [
+            ("paypal".to_string(), "mutually_exclusive"),
+            (String::new(), "at_least_one_required"),
+            ("password_confirmation".to_string(), "fields_not_equal"),
+            ("min_amount".to_string(), "not_less_than_field"),
+        ]
+    );
+    assert_eq!(err.violations[2].message, "Passwords do not match");
+
+    let mut none = checkout();
+    none.card = None;
+    let err = none.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "exactly_one_required");
+    assert_eq!(
+        err.violations[0].meta.get("fields"),
+        Some("card, iban, paypal")
+    );
+}
+
+#[test]
+fn test_partial_checks_present_fields_only() {
+    assert!(CheckoutPatch::default().validate().is_ok());
+
+    let patch = CheckoutPatch {
+        password: Some("hunter2".to_string()),
+        password_confirmation: Some("hunter3".to_string()),
+        ..Default::default()
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(
+        found(&err),
+        [("password_confirmation".to_string(), "fields_not_equal")]
+    );
+}
+
+#[test]
+fn test_openapi_constraints() {
+    let schema = serde_json::to_value(Customer::schema()).unwrap();
+    let constraints = schema["allOf"].as_array().unwrap();
+    assert_eq!(constraints.len(), 4);
+
+    // state is required unless country isn't "US"
+    assert_eq!(
+        constraints[0]["anyOf"][0]["not"]["properties"]["country"]["enum"],
+        serde_json::json!(["US"])
+    );
+    assert_eq!(
+        constraints[0]["anyOf"][1]["required"],
+        serde_json::json!(["state"])
+    );
+    assert_eq!(
+        constraints[3]["not"]["required"],
+        serde_json::json!(["card", "iban"])
+    );
+
+    let schema = serde_json::to_value(Checkout::schema()).unwrap();
+    let constraints = schema["allOf"].as_array().unwrap();
+    // fields_equal and field_lt have no schema form
+    assert_eq!(constraints.len(), 2);
+    assert_eq!(constraints[0]["oneOf"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        constraints[1]["anyOf"][1]["required"],
+        serde_json::json!(["receipt_phone"])
+    );
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema_constraints() {
+    use domainstack_schema::ToJsonSchema as _;
+
+    let schema = serde_json::to_value(Customer::json_schema()).unwrap();
+    assert_eq!(
+        schema["dependentRequired"],
+        serde_json::json!({"company": ["tax_id"]})
+    );
+    let constraints = schema["allOf"].as_array().unwrap();
+    assert_eq!(
+        constraints[0]["if"]["properties"]["country"]["enum"],
+        serde_json::json!(["US"])
+    );
+    assert_eq!(
+        constraints[0]["then"]["required"],
+        serde_json::json!(["state"])
+    );
+    // No `additionalProperties: false` in the subschemas
+    assert!(constraints[0]["then"].get("additionalProperties").is_none());
+
+    let schema = serde_json::to_value(Checkout::json_schema()).unwrap();
+    assert_eq!(
+        schema["allOf"][0]["oneOf"][2]["required"],
+        serde_json::json!(["paypal"])
+    );
+}
@@ -0,0 +1,268 @@
+//! Tests for the date/time rules and their clocks in the derive macros
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use domainstack::prelude::*;
+use domainstack::rules::Holidays;
+use domainstack::{with_test_clock, Clock, FixedClock};
+use domainstack_derive::{ToSchema, Validate, ValidatePartial};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, day, 12, 0, 0).unwrap()
+}
+
+#[derive(Debug, Validate, ValidatePartial)]
+struct Invoice {
+    #[validate(past)]
+    issued_at: DateTime<Utc>,
+
+    #[validate(future)]
+    due_at: DateTime<Utc>,
+}
+
+#[test]
+fn test_rules_read_the_test_clock() {
+    let invoice = Invoice {
+        issued_at: at(10),
+        due_at: at(20),
+    };
+
+    with_test_clock(FixedClock::from(at(15)), || {
+        assert!(invoice.validate().is_ok());
+    });
+
+    let err = with_test_clock(FixedClock::from(at(25)), || invoice.validate().unwrap_err());
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "due_at");
+    assert_eq!(err.violations[0].code, "not_in_future");
+}
+
+#[test]
+fn test_partial_validates_present_fields() {
+    let patch = InvoicePatch {
+        issued_at: Some(at(20)),
+        due_at: None,
+    };
+    let err = with_test_clock(FixedClock::from(at(15)), || patch.validate().unwrap_err());
+    assert_eq!(err.violations[0].code, "not_in_past");
+}
+
+/// Validation context carrying the business date to validate against
+struct AsOf {
+    clock: FixedClock,
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = AsOf)]
+struct Trade {
+    #[validate(past(clock = ctx.clock))]
+    executed_at: DateTime<Utc>,
+
+    #[validate(future(clock = ctx.clock))]
+    settles_at: DateTime<Utc>,
+}
+
+#[test]
+fn test_clock_from_validation_context() {
+    let trade = Trade {
+        executed_at: at(10),
+        settles_at: at(12),
+    };
+
+    let as_of = |day| AsOf {
+        clock: FixedClock::from(at(day)),
+    };
+    assert!(trade.validate_with(&as_of(11)).is_ok());
+
+    let err = trade.validate_with(&as_of(9)).unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "executed_at");
+    assert_eq!(
+        err.violations[0].meta.get("as_of"),
+        Some("2024-03-09T12:00:00+00:00")
+    );
+
+    // The context clock takes precedence over the thread's test clock
+    let err = with_test_clock(FixedClock::from(at(11)), || {
+        trade.validate_with(&as_of(13)).unwrap_err()
+    });
+    assert_eq!(err.violations[0].path.to_string(), "settles_at");
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = "std::sync::Arc<dyn Clock>")]
+enum Event {
+    Scheduled {
+        #[validate(future(clock = ctx))]
+        starts_at: DateTime<Utc>,
+    },
+    Logged(#[validate(past(clock = ctx))] DateTime<Utc>),
+}
+
+#[test]
+fn test_clock_in_enum_variants() {
+    let clock: std::sync::Arc<dyn Clock> = std::sync::Arc::new(FixedClock::from(at(15)));
+
+    assert!(Event::Scheduled { starts_at: at(16) }
+        .validate_with(&clock)
+        .is_ok());
+    assert!(Event::Logged(at(14)).validate_with(&clock).is_ok());
+
+    let err = Event::Logged(at(15) + Duration::seconds(1))
+        .validate_with(&clock)
+        .unwrap_err();
+    assert_eq!(err.violations[0].code, "not_in_past");
+}
+
+fn berlin() -> FixedOffset {
+    FixedOffset::east_opt(3600).unwrap()
+}
+
+fn bank_holidays() -> Holidays {
+    Holidays::new()
+        .every_year(12, 25, "Christmas Day")
+        .on(2024, 4, 1, "Easter Monday")
+}
+
+fn time_date(year: i32, month: time::Month, day: u8) -> time::Date {
+    time::Date::from_calendar_date(year, month, day).unwrap()
+}
+
+fn opening_day() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+}
+
+#[derive(Debug, Validate)]
+struct Appointment {
+    #[validate(within_business_hours(tz = berlin(), opens = "08:30", closes = "18:00"))]
+    #[validate(date_granularity = "15m", not_holiday = bank_holidays())]
+    starts_at: DateTime<Utc>,
+
+    #[validate(weekday_in = ["Mon", "wednesday", "Fri"], after = opening_day())]
+    follow_up_on: NaiveDate,
+
+    #[validate(within = "30d", future)]
+    reminder_at: NaiveDateTime,
+
+    #[validate(age_range(min = 18, max = 120))]
+    patient_born_on: time::Date,
+}
+
+fn appointment() -> Appointment {
+    Appointment {
+        // Tuesday 09:15 in Berlin
+        starts_at: Utc.with_ymd_and_hms(2024, 3, 26, 8, 15, 0).unwrap(),
+        // Wednesday
+        follow_up_on: NaiveDate::from_ymd_opt(2024, 4, 3).unwrap(),
+        reminder_at: NaiveDate::from_ymd_opt(2024, 3, 25)
+            .unwrap()
+            .and_hms_opt(18, 0, 0)
+            .unwrap(),
+        patient_born_on: time_date(1990, time::Month::May, 17),
+    }
+}
+
+#[test]
+fn test_calendar_rules() {
+    let now = FixedClock::from(Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap());
+    with_test_clock(now, || {
+        assert!(appointment().validate().is_ok());
+
+        // 18:00 in Berlin is closing time, on a quarter hour
+        let mut late = appointment();
+        late.starts_at = Utc.with_ymd_and_hms(2024, 3, 26, 17, 0, 0).unwrap();
+        let err = late.validate().unwrap_err();
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "outside_business_hours");
+        assert_eq!(err.violations[0].meta.get("opens"), Some("08:30"));
+
+        let mut off_slot = appointment();
+        off_slot.starts_at += Duration::minutes(5);
+        let err = off_slot.validate().unwrap_err();
+        assert_eq!(err.violations[0].code, "granularity_mismatch");
+        assert_eq!(err.violations[0].meta.get("granularity"), Some("15m"));
+
+        let mut holiday = appointment();
+        holiday.starts_at = Utc.with_ymd_and_hms(2024, 4, 1, 8, 0, 0).unwrap();
+        let err = holiday.validate().unwrap_err();
+        assert_eq!(err.violations[0].path.to_string(), "starts_at");
+        assert_eq!(err.violations[0].meta.get("holiday"), Some("Easter Monday"));
+
+        let mut thursday = appointment();
+        thursday.follow_up_on = NaiveDate::from_ymd_opt(2024, 4, 4).unwrap();
+        let err = thursday.validate().unwrap_err();
+        assert_eq!(err.violations[0].code, "weekday_not_allowed");
+        assert_eq!(
+            err.violations[0].meta.get("allowed"),
+            Some("Monday,Wednesday,Friday")
+        );
+    });
+}
+
+#[test]
+fn test_clock_relative_rules_on_other_types() {
+    let now = FixedClock::from(Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap());
+    with_test_clock(now, || {
+        let mut far = appointment();
+        far.reminder_at += Duration::days(30);
+        let err = far.validate().unwrap_err();
+        assert_eq!(err.violations[0].path.to_string(), "reminder_at");
+        assert_eq!(err.violations[0].code, "not_within");
+
+        let mut minor = appointment();
+        minor.patient_born_on = time_date(2010, time::Month::January, 1);
+        let err = minor.validate().unwrap_err();
+        assert_eq!(err.violations[0].code, "age_out_of_range");
+        assert_eq!(err.violations[0].meta.get("age"), Some("14"));
+    });
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = AsOf)]
+struct Settlement {
+    #[validate(past(clock = ctx.clock))]
+    value_date: time::Date,
+
+    #[validate(future(clock = ctx.clock), weekday_in = ["Mon", "Tue", "Wed", "Thu", "Fri"])]
+    settles_at: time::OffsetDateTime,
+}
+
+#[test]
+fn test_time_crate_fields() {
+    let settlement = Settlement {
+        value_date: time_date(2024, time::Month::March, 8),
+        settles_at: time::macros::datetime!(2024-03-11 09:00 +01:00),
+    };
+    let as_of = |day| AsOf {
+        clock: FixedClock::from(at(day)),
+    };
+
+    assert!(settlement.validate_with(&as_of(9)).is_ok());
+
+    // The value date is not yet in the past on the day itself
+    let err = settlement.validate_with(&as_of(8)).unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "value_date");
+
+    let weekend = Settlement {
+        settles_at: time::macros::datetime!(2024-03-10 09:00 +01:00),
+        ..settlement
+    };
+    let err = weekend.validate_with(&as_of(9)).unwrap_err();
+    assert_eq!(err.violations[0].meta.get("weekday"), Some("Sunday"));
+}
+
+/// Wire format of `Appointment`: `ToSchema` accepts the date/time rules without
+/// adding keywords for them
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct AppointmentDto {
+    #[validate(within_business_hours(tz = berlin(), days = ["Mon"], opens = "08:30"))]
+    #[validate(past(clock = ctx.clock), within = "1h30m", weekday_in = ["Sat"])]
+    #[validate(min_len = 10)]
+    starts_at: String,
+}
+
+#[test]
+fn test_schema_derive_skips_datetime_rules() {
+    let openapi = serde_json::to_value(AppointmentDto::schema()).unwrap();
+    assert_eq!(openapi["properties"]["starts_at"]["minLength"], 10);
+}
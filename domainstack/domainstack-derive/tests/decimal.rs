@@ -0,0 +1,108 @@
+//! Tests for `decimal(...)` on `rust_decimal::Decimal` fields
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct LineItem {
+    #[validate(decimal(precision = 10, scale = 2, min = "0.01"))]
+    unit_price: Decimal,
+
+    #[validate(decimal(scale = 3, min = 0, max = 1000, multiple_of = 0.5))]
+    weight_kg: Decimal,
+
+    #[validate(decimal(min = -90, max = "90"))]
+    latitude: Decimal,
+}
+
+fn item(unit_price: Decimal, weight_kg: Decimal) -> LineItem {
+    LineItem {
+        unit_price,
+        weight_kg,
+        latitude: Decimal::ZERO,
+    }
+}
+
+#[test]
+fn test_valid_decimals() {
+    let item = item(Decimal::new(1999, 2), Decimal::new(25, 1));
+    assert!(item.validate().is_ok());
+
+    let max_price = Decimal::new(9_999_999_999, 2);
+    assert!(LineItem {
+        latitude: Decimal::new(-8999, 2),
+        ..self::item(max_price, Decimal::ZERO)
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn test_scale_and_precision() {
+    let err = item(Decimal::new(19999, 3), Decimal::ONE)
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "unit_price");
+    assert_eq!(err.violations[0].code, "scale_exceeded");
+
+    let err = item(Decimal::new(10_000_000_001, 2), Decimal::ONE)
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.violations[0].code, "precision_exceeded");
+    assert_eq!(err.violations[0].meta.get("actual"), Some("11"));
+}
+
+#[test]
+fn test_bounds_and_step() {
+    let err = item(Decimal::ZERO, Decimal::new(12, 1))
+        .validate()
+        .unwrap_err();
+    let codes: Vec<_> = err.violations.iter().map(|v| v.code).collect();
+    assert_eq!(codes, ["below_minimum", "not_multiple"]);
+    assert_eq!(err.violations[0].meta.get("min"), Some("0.01"));
+    assert_eq!(err.violations[1].meta.get("divisor"), Some("0.5"));
+
+    let err = LineItem {
+        latitude: Decimal::new(-9001, 2),
+        ..item(Decimal::ONE, Decimal::ONE)
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "latitude");
+    assert_eq!(err.violations[0].code, "out_of_range");
+}
+
+#[test]
+fn test_decimal_schema() {
+    let schema = serde_json::to_value(LineItem::schema()).unwrap();
+    let price = &schema["properties"]["unit_price"];
+    assert_eq!(price["type"], "number");
+    assert_eq!(price["multipleOf"], 0.01);
+    assert_eq!(price["minimum"], 0.01);
+    assert_eq!(price["maximum"], 99999999.99);
+
+    let weight = &schema["properties"]["weight_kg"];
+    assert_eq!(weight["multipleOf"], 0.5);
+    assert_eq!(weight["minimum"], 0.0);
+    assert_eq!(weight["maximum"], 1000.0);
+
+    let latitude = &schema["properties"]["latitude"];
+    assert_eq!(latitude["minimum"], -90.0);
+    assert!(latitude.get("multipleOf").is_none());
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_decimal_json_schema() {
+    use domainstack_schema::ToJsonSchema as _;
+
+    let schema = serde_json::to_value(LineItem::json_schema()).unwrap();
+    let price = &schema["properties"]["unit_price"];
+    assert_eq!(price["type"], "number");
+    assert_eq!(price["multipleOf"], 0.01);
+    assert_eq!(price["maximum"], 99999999.99);
+}
@@ -0,0 +1,109 @@
+//! Tests for financial identifier rules in the derive macros
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct Payout {
+    #[validate(credit_card)]
+    card: String,
+
+    #[validate(iban)]
+    iban: String,
+
+    #[validate(bic)]
+    bic: String,
+
+    #[validate(isin)]
+    security: String,
+
+    #[validate(aba_routing)]
+    routing: String,
+
+    #[validate(luhn)]
+    reference: String,
+
+    #[validate(each(iban))]
+    backup_accounts: Vec<String>,
+}
+
+fn valid_payout() -> Payout {
+    Payout {
+        card: "4111 1111 1111 1111".to_string(),
+        iban: "DE89 3704 0044 0532 0130 00".to_string(),
+        bic: "DEUTDEFF".to_string(),
+        security: "US0378331005".to_string(),
+        routing: "021000021".to_string(),
+        reference: "79927398713".to_string(),
+        backup_accounts: vec!["GB82WEST12345698765432".to_string()],
+    }
+}
+
+#[test]
+fn test_valid_finance_fields() {
+    assert!(valid_payout().validate().is_ok());
+}
+
+#[test]
+fn test_invalid_finance_fields() {
+    let payout = Payout {
+        card: "5555555555554445".to_string(),
+        iban: "DE89 3704 0044 0532 0130 01".to_string(),
+        bic: "DEUT".to_string(),
+        security: "US0378331006".to_string(),
+        routing: "021000022".to_string(),
+        reference: "79927398710".to_string(),
+        backup_accounts: vec!["GB82WEST12345698765432".to_string(), "ZZ00".to_string()],
+    };
+
+    let err = payout.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code, v.meta.get("reason")))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            ("card".to_string(), "invalid_credit_card", Some("checksum")),
+            ("iban".to_string(), "invalid_iban", Some("checksum")),
+            ("bic".to_string(), "invalid_bic", None),
+            ("security".to_string(), "invalid_isin", Some("checksum")),
+            (
+                "routing".to_string(),
+                "invalid_aba_routing",
+                Some("checksum")
+            ),
+            ("reference".to_string(), "invalid_luhn", Some("checksum")),
+            (
+                "backup_accounts[1]".to_string(),
+                "invalid_iban",
+                Some("format")
+            ),
+        ]
+    );
+    assert_eq!(err.violations[0].meta.get("brand"), Some("mastercard"));
+}
+
+#[test]
+fn test_finance_rules_emit_patterns() {
+    #[allow(unused_mut)]
+    let mut outputs = vec![serde_json::to_value(Payout::schema()).unwrap()];
+    #[cfg(feature = "schema")]
+    {
+        use domainstack_schema::ToJsonSchema as _;
+        outputs.push(serde_json::to_value(Payout::json_schema()).unwrap());
+    }
+
+    for json in outputs {
+        let props = &json["properties"];
+        assert_eq!(props["routing"]["pattern"], "^[0-9]{9}$");
+        assert_eq!(props["security"]["pattern"], "^[A-Z]{2}[A-Z0-9]{9}[0-9]$");
+        for field in ["card", "iban", "bic", "reference"] {
+            assert!(props[field]["pattern"].is_string(), "{}", field);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Tests for `latitude` / `longitude` and the geo rules in derived types
+
+use domainstack::prelude::*;
+use domainstack::rules::geo::{self, PolygonPolicy};
+use domainstack::rules::{BoundingBox, GeoPoint};
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Clone, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct Location {
+    #[validate(latitude)]
+    lat: f64,
+
+    #[validate(longitude)]
+    lon: f64,
+}
+
+impl GeoPoint for Location {
+    fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    fn lon(&self) -> f64 {
+        self.lon
+    }
+}
+
+#[derive(Debug, Validate)]
+struct DeliveryZone {
+    #[validate(nested)]
+    #[validate(custom = "in_europe")]
+    depot: Location,
+
+    #[validate(custom = "valid_area")]
+    area: Vec<Vec<[f64; 2]>>,
+}
+
+#[allow(clippy::result_large_err)]
+fn in_europe(depot: &Location) -> Result<(), ValidationError> {
+    let rule = rules::within_bbox(BoundingBox::new(-25.0, 34.0, 45.0, 72.0));
+    validate(Path::root(), depot, &rule)
+}
+
+#[allow(clippy::result_large_err)]
+fn valid_area(area: &Vec<Vec<[f64; 2]>>) -> Result<(), ValidationError> {
+    let rule = geo::polygon(PolygonPolicy {
+        max_vertices: Some(100),
+        rfc7946_winding: true,
+        ..Default::default()
+    });
+    validate(Path::root(), area.as_slice(), &rule)
+}
+
+#[derive(Debug, Validate)]
+struct Latitude(#[validate(latitude)] f64);
+
+#[derive(Debug, Validate)]
+enum Waypoint {
+    Fix {
+        #[validate(latitude)]
+        lat: f64,
+        #[validate(longitude)]
+        lon: f64,
+    },
+}
+
+fn square() -> Vec<Vec<[f64; 2]>> {
+    vec![vec![
+        [0.0, 50.0],
+        [1.0, 50.0],
+        [1.0, 51.0],
+        [0.0, 51.0],
+        [0.0, 50.0],
+    ]]
+}
+
+#[test]
+fn test_coordinates() {
+    let location = Location {
+        lat: 95.0,
+        lon: -181.0,
+    };
+    let err = location.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("lat".to_string(), "invalid_latitude"),
+            ("lon".to_string(), "invalid_longitude"),
+        ]
+    );
+
+    assert!(Latitude(-45.0).validate().is_ok());
+    assert!(Latitude(f64::NAN).validate().is_err());
+
+    let fix = Waypoint::Fix {
+        lat: 10.0,
+        lon: 200.0,
+    };
+    assert_eq!(
+        fix.validate().unwrap_err().violations[0].path.to_string(),
+        "lon"
+    );
+}
+
+#[test]
+fn test_zone() {
+    let zone = DeliveryZone {
+        depot: Location {
+            lat: 50.5,
+            lon: 0.5,
+        },
+        area: square(),
+    };
+    assert!(zone.validate().is_ok());
+
+    let zone = DeliveryZone {
+        depot: Location {
+            lat: 40.7,
+            lon: -74.0,
+        },
+        area: square(),
+    };
+    let err = zone.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "outside_bbox");
+    assert_eq!(err.violations[0].path.to_string(), "depot");
+}
+
+#[test]
+fn test_polygon_paths() {
+    let mut area = square();
+    area[0][2] = [1.0, 95.0];
+    area[0].reverse();
+    let zone = DeliveryZone {
+        depot: Location {
+            lat: 50.5,
+            lon: 0.5,
+        },
+        area,
+    };
+    let err = zone.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "invalid_latitude");
+    assert_eq!(err.violations[0].path.to_string(), "area[0][2][1]");
+
+    // Clockwise exterior ring
+    let mut area = square();
+    area[0].reverse();
+    let zone = DeliveryZone {
+        depot: Location {
+            lat: 50.5,
+            lon: 0.5,
+        },
+        area,
+    };
+    let err = zone.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_winding");
+    assert_eq!(err.violations[0].path.to_string(), "area[0]");
+}
+
+#[test]
+fn test_coordinate_schema() {
+    let schema = serde_json::to_value(Location::schema()).unwrap();
+    assert_eq!(schema["properties"]["lat"]["minimum"], -90.0);
+    assert_eq!(schema["properties"]["lat"]["maximum"], 90.0);
+    assert_eq!(schema["properties"]["lon"]["minimum"], -180.0);
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_coordinate_json_schema() {
+    use domainstack_schema::ToJsonSchema as _;
+
+    let schema = serde_json::to_value(Location::json_schema()).unwrap();
+    assert_eq!(schema["properties"]["lon"]["type"], "number");
+    assert_eq!(schema["properties"]["lon"]["maximum"], 180.0);
+}
@@ -0,0 +1,316 @@
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::{OpenApiBuilder, ToSchema as ToSchemaTrait};
+
+#[derive(Debug, Validate, ToSchema)]
+#[validate(
+    check = "self.role != \"admin\" || self.email.ends_with(\"@example.com\")",
+    code = "admin_domain",
+    message = "Admins must use a company address",
+    groups = ["admin"]
+)]
+struct UserDto {
+    #[validate(forbidden, groups = ["create"])]
+    #[validate(required, groups = ["update"])]
+    id: Option<u64>,
+
+    #[validate(email)]
+    email: String,
+
+    #[validate(max_len = 10, groups = ["default", "create", "update"])]
+    #[validate(max_len = 100, groups = ["admin"])]
+    bio: String,
+
+    role: String,
+}
+
+fn dto(id: Option<u64>, bio_len: usize) -> UserDto {
+    UserDto {
+        id,
+        email: "alice@example.com".to_string(),
+        bio: "x".repeat(bio_len),
+        role: "user".to_string(),
+    }
+}
+
+fn codes(err: &ValidationError) -> Vec<&'static str> {
+    err.violations.iter().map(|v| v.code).collect()
+}
+
+#[test]
+fn test_ungrouped_rules_run_in_every_group() {
+    let mut user = dto(Some(1), 5);
+    user.email = "not-an-email".to_string();
+
+    for group in ["default", "update", "admin", "unknown"] {
+        let err = user.validate_group(group).unwrap_err();
+        assert!(codes(&err).contains(&"invalid_email"), "group {}", group);
+    }
+}
+
+#[test]
+fn test_required_on_update_forbidden_on_create() {
+    let err = dto(Some(7), 5).validate_group("create").unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "id");
+    assert_eq!(err.violations[0].code, "forbidden");
+
+    let err = dto(None, 5).validate_group("update").unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "id");
+    assert_eq!(err.violations[0].code, "required");
+
+    assert!(dto(None, 5).validate_group("create").is_ok());
+    assert!(dto(Some(7), 5).validate_group("update").is_ok());
+}
+
+#[test]
+fn test_validate_uses_default_group() {
+    // id rules are not part of the default group
+    assert!(dto(Some(7), 5).validate().is_ok());
+    assert!(dto(None, 5).validate().is_ok());
+
+    let err = dto(None, 50).validate().unwrap_err();
+    assert_eq!(codes(&err), vec!["max_length"]);
+}
+
+#[test]
+fn test_admin_group_relaxes_limits() {
+    assert!(dto(None, 50).validate_group("update").is_err());
+    assert!(dto(None, 50).validate_group("admin").is_ok());
+    assert!(dto(None, 500).validate_group("admin").is_err());
+}
+
+#[test]
+fn test_grouped_struct_check() {
+    let mut user = dto(None, 5);
+    user.role = "admin".to_string();
+    user.email = "admin@other.org".to_string();
+
+    assert!(user.validate().is_ok());
+    let err = user.validate_group("admin").unwrap_err();
+    assert_eq!(codes(&err), vec!["admin_domain"]);
+}
+
+#[derive(Debug, Validate)]
+struct Rename(#[validate(min_len = 3, groups = ["strict"])] String);
+
+#[test]
+fn test_groups_on_tuple_struct() {
+    let value = Rename("ab".to_string());
+    assert!(value.validate().is_ok());
+    assert!(value.validate_group("strict").is_err());
+}
+
+#[derive(Debug, Validate)]
+struct RecordId(
+    #[validate(forbidden, groups = ["create"])]
+    #[validate(required, groups = ["update"])]
+    Option<u64>,
+);
+
+#[derive(Debug, Validate)]
+enum Command {
+    Rename {
+        #[validate(required)]
+        id: Option<u64>,
+    },
+    Delete(#[validate(required, groups = ["update"])] Option<u64>),
+}
+
+#[test]
+fn test_presence_rules_on_tuple_fields() {
+    let err = RecordId(Some(7)).validate_group("create").unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "0");
+    assert_eq!(err.violations[0].code, "forbidden");
+
+    let err = RecordId(None).validate_group("update").unwrap_err();
+    assert_eq!(err.violations[0].code, "required");
+
+    assert!(RecordId(None).validate_group("create").is_ok());
+    assert!(RecordId(Some(7)).validate_group("update").is_ok());
+}
+
+#[test]
+fn test_presence_rules_on_enum_fields() {
+    let err = Command::Rename { id: None }.validate().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "id");
+    assert_eq!(err.violations[0].code, "required");
+    assert!(Command::Rename { id: Some(1) }.validate().is_ok());
+
+    let err = Command::Delete(None).validate_group("update").unwrap_err();
+    assert_eq!(err.violations[0].code, "required");
+    assert!(Command::Delete(None).validate().is_ok());
+}
+
+#[derive(Debug, Validate)]
+struct Team {
+    #[validate(nested)]
+    owner: UserDto,
+
+    #[validate(each(nested))]
+    members: Vec<UserDto>,
+
+    #[validate(nested)]
+    label: Label,
+}
+
+/// Nested type without any grouped rules
+#[derive(Debug, Validate)]
+struct Label {
+    #[validate(max_len = 5)]
+    text: String,
+}
+
+#[test]
+fn test_nested_fields_receive_group() {
+    let team = Team {
+        owner: dto(Some(1), 5),
+        members: vec![dto(None, 5), dto(Some(2), 5)],
+        label: Label {
+            text: "core".to_string(),
+        },
+    };
+
+    let err = team.validate_group("create").unwrap_err();
+    let paths: Vec<_> = err.violations.iter().map(|v| v.path.to_string()).collect();
+    assert_eq!(paths, vec!["owner.id", "members[1].id"]);
+    assert!(codes(&err).iter().all(|code| *code == "forbidden"));
+
+    let err = team.validate_group("update").unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "members[0].id");
+    assert_eq!(err.violations[0].code, "required");
+
+    assert!(team.validate().is_ok());
+}
+
+#[test]
+fn test_ungrouped_types_implement_validate_group() {
+    let label = Label {
+        text: "too long".to_string(),
+    };
+    assert!(label.validate_group("create").is_err());
+    assert_eq!(
+        label.validate_group("create").unwrap_err(),
+        label.validate().unwrap_err()
+    );
+}
+
+#[test]
+fn test_nested_manual_validate_impl() {
+    struct Slug(String);
+
+    impl Validate for Slug {
+        fn validate(&self) -> Result<(), ValidationError> {
+            if self.0.contains(' ') {
+                return Err(ValidationError::single("", "invalid_slug", "No spaces"));
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    struct Page {
+        #[validate(nested)]
+        slug: Slug,
+
+        #[validate(forbidden, groups = ["create"])]
+        id: Option<u64>,
+    }
+
+    let page = Page {
+        slug: Slug("a b".to_string()),
+        id: None,
+    };
+    let err = page.validate_group("create").unwrap_err();
+    assert_eq!(codes(&err), vec!["invalid_slug"]);
+}
+
+#[test]
+fn test_schema_per_group() {
+    let create = serde_json::to_value(UserDto::schema_for_group("create")).unwrap();
+    assert!(create["properties"]["id"].is_null());
+    assert_eq!(
+        create["properties"]["bio"]["maxLength"].as_f64(),
+        Some(10.0)
+    );
+    assert_eq!(
+        create["required"],
+        serde_json::json!(["email", "bio", "role"])
+    );
+
+    let update = serde_json::to_value(UserDto::schema_for_group("update")).unwrap();
+    assert!(update["properties"]["id"].is_object());
+    assert_eq!(
+        update["required"],
+        serde_json::json!(["id", "email", "bio", "role"])
+    );
+
+    let admin = serde_json::to_value(UserDto::schema_for_group("admin")).unwrap();
+    assert_eq!(
+        admin["properties"]["bio"]["maxLength"].as_f64(),
+        Some(100.0)
+    );
+    assert!(admin["properties"]["id"].is_object());
+    assert_eq!(
+        admin["required"],
+        serde_json::json!(["email", "bio", "role"])
+    );
+}
+
+#[test]
+fn test_default_schema_matches_default_group() {
+    let schema = serde_json::to_value(UserDto::schema()).unwrap();
+    assert_eq!(
+        schema["properties"]["bio"]["maxLength"].as_f64(),
+        Some(10.0)
+    );
+    assert!(schema["properties"]["id"].is_object());
+}
+
+#[test]
+fn test_openapi_register_group() {
+    let spec = OpenApiBuilder::new("Users", "1.0")
+        .register_group::<UserDto>("create", "UserCreate")
+        .register_group::<UserDto>("update", "UserUpdate")
+        .build();
+    let json = serde_json::to_value(&spec).unwrap();
+
+    let schemas = &json["components"]["schemas"];
+    assert!(schemas["UserCreate"]["properties"]["id"].is_null());
+    assert!(schemas["UserUpdate"]["properties"]["id"].is_object());
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema_per_group() {
+    use domainstack_derive::ToJsonSchema;
+    use domainstack_schema::ToJsonSchema as ToJsonSchemaTrait;
+
+    #[derive(ToJsonSchema)]
+    #[allow(dead_code)]
+    struct Item {
+        #[validate(forbidden, groups = ["create"])]
+        id: Option<u64>,
+
+        #[validate(max_len = 20)]
+        #[validate(min_len = 5, groups = ["publish"])]
+        title: String,
+    }
+
+    let create = serde_json::to_value(Item::json_schema_for_group("create")).unwrap();
+    assert!(create["properties"]["id"].is_null());
+
+    let publish = serde_json::to_value(Item::json_schema_for_group("publish")).unwrap();
+    assert_eq!(
+        publish["properties"]["title"]["minLength"].as_f64(),
+        Some(5.0)
+    );
+
+    let default = serde_json::to_value(Item::json_schema()).unwrap();
+    assert!(default["properties"]["title"]["minLength"].is_null());
+    assert_eq!(
+        default["properties"]["title"]["maxLength"].as_f64(),
+        Some(20.0)
+    );
+}
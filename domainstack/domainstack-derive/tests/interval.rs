@@ -0,0 +1,179 @@
+//! Tests for `range_fields` and the interval rules in derived types
+
+use chrono::{Duration, NaiveDate};
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate, ValidatePartial};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 7, d).unwrap()
+}
+
+#[derive(Debug, Clone, Validate, ValidatePartial)]
+#[patch(derive(Debug, Default))]
+#[validate(range_fields(start = "check_in", end = "check_out", max_span = Duration::days(14)))]
+struct Stay {
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+}
+
+#[derive(Debug, Validate)]
+struct Booking {
+    #[validate(min_len = 1)]
+    guest: String,
+
+    #[validate(each(nested))]
+    #[validate(custom = "no_double_booking")]
+    stays: Vec<Stay>,
+}
+
+#[allow(clippy::result_large_err)]
+fn no_double_booking(stays: &Vec<Stay>) -> Result<(), ValidationError> {
+    let rule = rules::non_overlapping(|stay: &Stay| (stay.check_in, stay.check_out));
+    validate("stays", stays.as_slice(), &rule)
+}
+
+#[test]
+fn test_range_fields_ordered() {
+    let stay = Stay {
+        check_in: day(1),
+        check_out: day(4),
+    };
+    assert!(stay.validate().is_ok());
+}
+
+#[test]
+fn test_range_fields_reversed() {
+    let stay = Stay {
+        check_in: day(4),
+        check_out: day(4),
+    };
+    let err = stay.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "check_out");
+    assert_eq!(err.violations[0].code, "invalid_range");
+    assert_eq!(err.violations[0].meta.get("start"), Some("2024-07-04"));
+}
+
+#[test]
+fn test_range_fields_max_span() {
+    let stay = Stay {
+        check_in: day(1),
+        check_out: day(16),
+    };
+    let err = stay.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "span_too_long");
+    assert_eq!(err.violations[0].path.to_string(), "check_out");
+}
+
+#[test]
+fn test_non_overlapping_stays() {
+    let stay = |check_in, check_out| Stay {
+        check_in: day(check_in),
+        check_out: day(check_out),
+    };
+    let booking = Booking {
+        guest: "Ada".to_string(),
+        stays: vec![stay(1, 4), stay(4, 6), stay(10, 12), stay(5, 11)],
+    };
+
+    let err = booking.validate().unwrap_err();
+    let indices: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| {
+            assert_eq!(v.code, "overlapping_intervals");
+            v.meta.get("indices").unwrap()
+        })
+        .collect();
+    assert_eq!(indices, ["[1, 3]", "[2, 3]"]);
+}
+
+#[derive(Debug, Validate)]
+#[validate(
+    range_fields(start = "from", end = "until"),
+    code = "bad_window",
+    message = "Window must end after it starts",
+    when = "self.enabled"
+)]
+struct MaintenanceWindow {
+    enabled: bool,
+    from: u32,
+    until: u32,
+}
+
+#[test]
+fn test_range_fields_overrides_and_when() {
+    let window = MaintenanceWindow {
+        enabled: true,
+        from: 10,
+        until: 2,
+    };
+    let err = window.validate().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "until");
+    assert_eq!(err.violations[0].code, "bad_window");
+    assert_eq!(err.violations[0].message, "Window must end after it starts");
+    assert_eq!(err.violations[0].meta.get("end"), Some("2"));
+
+    let disabled = MaintenanceWindow {
+        enabled: false,
+        from: 10,
+        until: 2,
+    };
+    assert!(disabled.validate().is_ok());
+}
+
+#[derive(Debug, Validate)]
+#[validate(range_fields(start = "first", end = "last", max_span = 14))]
+struct Pages {
+    first: u32,
+    last: u32,
+}
+
+#[test]
+fn test_range_fields_max_span_unsigned_reversed() {
+    let err = Pages { first: 5, last: 1 }.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "invalid_range");
+
+    let err = Pages {
+        first: 0,
+        last: u32::MAX,
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.violations[0].code, "span_too_long");
+}
+
+#[test]
+fn test_range_fields_partial() {
+    // Only checked when both ends are present
+    let patch = StayPatch {
+        check_out: Some(day(1)),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+
+    let patch = StayPatch {
+        check_in: Some(day(3)),
+        check_out: Some(day(1)),
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_range");
+    assert_eq!(err.violations[0].path.to_string(), "check_out");
+}
+
+/// `ToSchema` accepts the struct-level `range_fields` attribute
+#[derive(ToSchema)]
+#[validate(range_fields(start = "min_guests", end = "max_guests"))]
+#[allow(dead_code)]
+struct RoomDto {
+    min_guests: u8,
+    max_guests: u8,
+}
+
+#[test]
+fn test_range_fields_schema() {
+    let schema = serde_json::to_value(RoomDto::schema()).unwrap();
+    assert_eq!(schema["type"], "object");
+}
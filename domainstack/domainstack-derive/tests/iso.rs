@@ -0,0 +1,118 @@
+//! Tests for ISO code-set rules in the derive macros
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct Address {
+    #[validate(iso3166_alpha2)]
+    country: String,
+
+    #[validate(iso3166_alpha3)]
+    country_alpha3: String,
+
+    #[validate(iso4217_currency)]
+    currency: String,
+
+    #[validate(iso639_language)]
+    language: String,
+
+    #[validate(bcp47_locale)]
+    locale: String,
+
+    #[validate(each(iso3166_alpha2))]
+    ships_to: Vec<String>,
+}
+
+fn valid_address() -> Address {
+    Address {
+        country: "CH".to_string(),
+        country_alpha3: "CHE".to_string(),
+        currency: "CHF".to_string(),
+        language: "de".to_string(),
+        locale: "de-CH".to_string(),
+        ships_to: vec!["DE".to_string(), "AT".to_string()],
+    }
+}
+
+#[test]
+fn test_valid_iso_fields() {
+    assert!(valid_address().validate().is_ok());
+}
+
+#[test]
+fn test_invalid_iso_fields() {
+    let address = Address {
+        country: "Switzerland".to_string(),
+        country_alpha3: "CH".to_string(),
+        currency: "chf".to_string(),
+        language: "deu".to_string(),
+        locale: "de_CH".to_string(),
+        ships_to: vec!["DE".to_string(), "EU".to_string()],
+    };
+
+    let err = address.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            ("country".to_string(), "invalid_country_code"),
+            ("country_alpha3".to_string(), "invalid_country_code"),
+            ("currency".to_string(), "invalid_currency_code"),
+            ("language".to_string(), "invalid_language_code"),
+            ("locale".to_string(), "invalid_locale"),
+            ("ships_to[1]".to_string(), "invalid_country_code"),
+        ]
+    );
+    assert_eq!(err.violations[4].meta.get("reason"), Some("syntax"));
+    assert!(err.violations.iter().all(|v| v.message.len() < 60));
+}
+
+#[derive(Debug, Validate)]
+struct Currency(#[validate(iso4217_currency)] String);
+
+#[test]
+fn test_iso_rule_on_tuple_struct() {
+    assert!(Currency("JPY".to_string()).validate().is_ok());
+    let err = Currency("YEN".to_string()).validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_currency_code");
+}
+
+#[test]
+fn test_iso_rules_emit_enums() {
+    #[allow(unused_mut)]
+    let mut outputs = vec![serde_json::to_value(Address::schema()).unwrap()];
+    #[cfg(feature = "schema")]
+    {
+        use domainstack_schema::ToJsonSchema as _;
+        outputs.push(serde_json::to_value(Address::json_schema()).unwrap());
+    }
+
+    for json in outputs {
+        let props = &json["properties"];
+        let country = props["country"]["enum"].as_array().unwrap();
+        assert_eq!(country.len(), 249);
+        assert!(country.contains(&"CH".into()));
+        assert_eq!(
+            props["country_alpha3"]["enum"].as_array().unwrap().len(),
+            249
+        );
+        assert!(props["currency"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&"CHF".into()));
+        assert!(props["language"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&"de".into()));
+        assert!(props["locale"]["pattern"].is_string());
+        assert!(props["locale"].get("enum").is_none());
+    }
+}
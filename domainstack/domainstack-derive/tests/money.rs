@@ -0,0 +1,110 @@
+//! Tests for `#[validate(money(currency = "..."))]`
+
+use domainstack::prelude::*;
+use domainstack::rules::decimal::Decimal;
+use domainstack_derive::{Validate, ValidatePartial};
+
+#[derive(Debug, Validate, ValidatePartial)]
+#[patch(derive(Debug, Default))]
+struct Payment {
+    #[validate(money(currency = "currency"))]
+    amount: f64,
+
+    #[validate(iso4217_currency)]
+    currency: String,
+}
+
+#[derive(Debug, Validate)]
+struct Invoice {
+    #[validate(money(
+        currency = "currency",
+        min = Decimal::new(100, 2),
+        max = Decimal::new(50_000, 2)
+    ))]
+    total: Decimal,
+
+    currency: String,
+}
+
+#[derive(Debug, Validate)]
+enum Charge {
+    Card {
+        #[validate(money(currency = "currency"))]
+        amount: i64,
+        currency: String,
+    },
+}
+
+fn payment(amount: f64, currency: &str) -> Payment {
+    Payment {
+        amount,
+        currency: currency.to_string(),
+    }
+}
+
+#[test]
+fn test_money_minor_units() {
+    assert!(payment(19.99, "USD").validate().is_ok());
+    assert!(payment(1500.0, "JPY").validate().is_ok());
+    assert!(payment(1.125, "KWD").validate().is_ok());
+
+    let err = payment(1500.5, "JPY").validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "amount");
+    assert_eq!(err.violations[0].code, "invalid_minor_units");
+    assert_eq!(err.violations[0].meta.get("currency"), Some("JPY"));
+    assert_eq!(err.violations[0].meta.get("minor_units"), Some("0"));
+    assert_eq!(err.violations[0].meta.get("actual"), Some("1"));
+}
+
+#[test]
+fn test_money_unknown_currency() {
+    let err = payment(10.0, "ABC").validate().unwrap_err();
+    let codes: Vec<_> = err.violations.iter().map(|v| v.code).collect();
+    assert_eq!(codes, ["unknown_currency", "invalid_currency_code"]);
+    assert_eq!(err.violations[0].path.to_string(), "amount");
+}
+
+#[test]
+fn test_money_range_decimal() {
+    let invoice = |total: Decimal| Invoice {
+        total,
+        currency: "EUR".to_string(),
+    };
+    assert!(invoice(Decimal::new(12_550, 2)).validate().is_ok());
+
+    let err = invoice(Decimal::new(50, 2)).validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "out_of_range");
+    assert_eq!(err.violations[0].meta.get("min"), Some("1.00"));
+    assert_eq!(err.violations[0].meta.get("max"), Some("500.00"));
+
+    let err = invoice(Decimal::new(10_005, 3)).validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_minor_units");
+}
+
+#[test]
+fn test_money_enum_variant() {
+    let charge = Charge::Card {
+        amount: 500,
+        currency: "XAU".to_string(),
+    };
+    assert!(charge.validate().is_ok());
+}
+
+#[test]
+fn test_money_partial() {
+    // Without the currency there is nothing to check the amount against
+    let patch = PaymentPatch {
+        amount: Some(0.001),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+
+    let patch = PaymentPatch {
+        amount: Some(0.001),
+        currency: Some("EUR".to_string()),
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_minor_units");
+    assert_eq!(err.violations[0].path.to_string(), "amount");
+}
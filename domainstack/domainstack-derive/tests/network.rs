@@ -0,0 +1,159 @@
+//! Tests for network identifier rules in the derive macros
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct FirewallRule {
+    #[validate(ipv4)]
+    gateway: String,
+
+    #[validate(ipv6)]
+    gateway_v6: String,
+
+    #[validate(cidr)]
+    source: String,
+
+    #[validate(ip_in_cidr = "10.0.0.0/8")]
+    target: String,
+
+    #[validate(hostname)]
+    host: String,
+
+    #[validate(fqdn)]
+    domain: String,
+
+    #[validate(port_range)]
+    ports: String,
+
+    #[validate(mac_address)]
+    mac: String,
+
+    #[validate(each(hostname))]
+    aliases: Vec<String>,
+}
+
+fn valid_rule() -> FirewallRule {
+    FirewallRule {
+        gateway: "192.168.0.1".to_string(),
+        gateway_v6: "fe80::1".to_string(),
+        source: "0.0.0.0/0".to_string(),
+        target: "10.1.2.3".to_string(),
+        host: "db-1".to_string(),
+        domain: "example.com".to_string(),
+        ports: "8000-8999".to_string(),
+        mac: "00:1a:2b:3c:4d:5e".to_string(),
+        aliases: vec!["db".to_string(), "bücher.example".to_string()],
+    }
+}
+
+#[test]
+fn test_valid_network_fields() {
+    assert!(valid_rule().validate().is_ok());
+}
+
+#[test]
+fn test_invalid_network_fields() {
+    let rule = FirewallRule {
+        gateway: "300.0.0.1".to_string(),
+        gateway_v6: "fe80::1::2".to_string(),
+        source: "10.0.0.0".to_string(),
+        target: "192.168.0.1".to_string(),
+        host: "-db".to_string(),
+        domain: "localhost".to_string(),
+        ports: "9000-8000".to_string(),
+        mac: "00:1a:2b".to_string(),
+        aliases: vec!["ok".to_string(), "not ok".to_string()],
+    };
+
+    let err = rule.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            ("gateway".to_string(), "invalid_ipv4"),
+            ("gateway_v6".to_string(), "invalid_ipv6"),
+            ("source".to_string(), "invalid_cidr"),
+            ("target".to_string(), "ip_not_in_cidr"),
+            ("host".to_string(), "invalid_hostname"),
+            ("domain".to_string(), "invalid_fqdn"),
+            ("ports".to_string(), "invalid_port_range"),
+            ("mac".to_string(), "invalid_mac_address"),
+            ("aliases[1]".to_string(), "invalid_hostname"),
+        ]
+    );
+    assert_eq!(err.violations[3].meta.get("cidr"), Some("10.0.0.0/8"));
+}
+
+#[derive(Debug, Validate)]
+struct Host(#[validate(hostname)] String);
+
+#[derive(Debug, Validate)]
+enum Endpoint {
+    Ip {
+        #[validate(ipv4)]
+        addr: String,
+    },
+    Mac(#[validate(mac_address)] String),
+}
+
+#[test]
+fn test_network_rules_on_tuple_struct_and_enum() {
+    assert!(Host("example.com".to_string()).validate().is_ok());
+    let err = Host("exa_mple.com".to_string()).validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_hostname");
+
+    let err = Endpoint::Ip {
+        addr: "::1".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "addr");
+    assert_eq!(err.violations[0].code, "invalid_ipv4");
+
+    let err = Endpoint::Mac("zz:zz:zz:zz:zz:zz".to_string())
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.violations[0].code, "invalid_mac_address");
+}
+
+#[test]
+fn test_network_rules_openapi_formats() {
+    let json = serde_json::to_value(FirewallRule::schema()).unwrap();
+    let props = &json["properties"];
+
+    assert_eq!(props["gateway"]["format"], "ipv4");
+    assert_eq!(props["gateway_v6"]["format"], "ipv6");
+    assert_eq!(props["source"]["format"], "cidr");
+    assert_eq!(props["target"]["format"], "ipv4");
+    assert_eq!(props["host"]["format"], "idn-hostname");
+    assert_eq!(props["domain"]["format"], "idn-hostname");
+    assert!(props["ports"]["pattern"].is_string());
+    assert!(props["mac"]["pattern"].is_string());
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_network_rules_json_schema_formats() {
+    use domainstack_schema::ToJsonSchema as _;
+
+    let json = serde_json::to_value(FirewallRule::json_schema()).unwrap();
+    let props = &json["properties"];
+
+    assert_eq!(props["gateway"]["format"], "ipv4");
+    assert_eq!(props["gateway_v6"]["format"], "ipv6");
+    assert_eq!(props["source"]["format"], "cidr");
+    assert_eq!(props["target"]["format"], "ipv4");
+    assert_eq!(props["host"]["format"], "idn-hostname");
+    assert_eq!(
+        props["ports"]["pattern"],
+        "^[1-9][0-9]{0,4}(?:-[1-9][0-9]{0,4})?$"
+    );
+}
@@ -0,0 +1,212 @@
+use domainstack::prelude::*;
+use domainstack_derive::{Validate, ValidatePartial};
+
+#[derive(Debug, Clone, Validate, ValidatePartial)]
+#[patch(derive(Debug, Default))]
+#[validate(
+    check = "self.password == self.password_confirmation",
+    code = "passwords_mismatch",
+    message = "Passwords must match"
+)]
+#[validate(
+    check = "self.age >= 18 || !self.newsletter",
+    code = "newsletter_requires_adult",
+    message = "Newsletter requires an adult account"
+)]
+struct User {
+    #[validate(email, max_len = 255)]
+    email: String,
+
+    #[validate(length(min = 2, max = 50))]
+    name: String,
+
+    #[validate(range(min = 13, max = 120))]
+    age: u8,
+
+    #[validate(min_len = 8)]
+    password: String,
+
+    password_confirmation: String,
+
+    newsletter: bool,
+
+    #[validate(nested)]
+    address: Address,
+
+    #[validate(max_items = 3)]
+    #[validate(each(length(min = 1, max = 20)))]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Validate)]
+struct Address {
+    #[validate(length(min = 5, max = 10))]
+    zip: String,
+}
+
+fn user() -> User {
+    User {
+        email: "alice@example.com".to_string(),
+        name: "Alice".to_string(),
+        age: 30,
+        password: "correct-horse".to_string(),
+        password_confirmation: "correct-horse".to_string(),
+        newsletter: true,
+        address: Address {
+            zip: "12345".to_string(),
+        },
+        tags: vec!["rust".to_string()],
+    }
+}
+
+#[test]
+fn test_empty_patch_is_valid() {
+    let patch = UserPatch::default();
+    assert!(patch.is_empty());
+    assert!(patch.validate().is_ok());
+}
+
+#[test]
+fn test_present_fields_use_same_rules_and_paths() {
+    let patch = UserPatch {
+        email: Some("not-an-email".to_string()),
+        age: Some(200),
+        ..Default::default()
+    };
+
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 2);
+    assert_eq!(err.violations[0].path.to_string(), "email");
+    assert_eq!(err.violations[0].code, "invalid_email");
+    assert_eq!(err.violations[1].path.to_string(), "age");
+    assert_eq!(err.violations[1].code, "out_of_range");
+}
+
+#[test]
+fn test_absent_fields_are_not_validated() {
+    // An empty name would fail min length, but it isn't part of the patch
+    let patch = UserPatch {
+        age: Some(40),
+        ..Default::default()
+    };
+
+    assert!(!patch.is_empty());
+    assert!(patch.validate().is_ok());
+}
+
+#[test]
+fn test_nested_and_collection_paths() {
+    let patch = UserPatch {
+        address: Some(Address {
+            zip: "1".to_string(),
+        }),
+        tags: Some(vec!["ok".to_string(), String::new()]),
+        ..Default::default()
+    };
+
+    let err = patch.validate().unwrap_err();
+    let paths: Vec<String> = err.violations.iter().map(|v| v.path.to_string()).collect();
+    assert_eq!(paths, vec!["address.zip", "tags[1]"]);
+}
+
+#[test]
+fn test_cross_field_check_requires_all_inputs() {
+    // Only one side of the password check is present: skipped
+    let patch = UserPatch {
+        password: Some("new-password".to_string()),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+
+    // Both sides present: check runs
+    let patch = UserPatch {
+        password: Some("new-password".to_string()),
+        password_confirmation: Some("other-password".to_string()),
+        ..Default::default()
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "passwords_mismatch");
+}
+
+#[test]
+fn test_cross_field_check_with_comparison() {
+    let patch = UserPatch {
+        age: Some(15),
+        newsletter: Some(true),
+        ..Default::default()
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "newsletter_requires_adult");
+
+    let patch = UserPatch {
+        age: Some(15),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+}
+
+#[test]
+fn test_apply_then_validate_merged() {
+    let mut target = user();
+
+    // The patch alone is valid, but the merged result breaks the cross-field check
+    let patch = UserPatch {
+        password: Some("brand-new-password".to_string()),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+
+    patch.apply_to(&mut target);
+    assert_eq!(target.password, "brand-new-password");
+    assert_eq!(target.email, "alice@example.com");
+
+    let err = target.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "passwords_mismatch");
+}
+
+#[derive(Debug, Validate, ValidatePartial)]
+#[allow(dead_code)]
+#[patch(name = "LimitsUpdate", derive(Default))]
+#[validate(context = usize)]
+struct Limits {
+    #[validate(max_items = *ctx)]
+    rooms: Vec<u8>,
+}
+
+#[test]
+fn test_custom_name_and_context() {
+    let update = LimitsUpdate {
+        rooms: Some(vec![1, 2, 3]),
+    };
+
+    assert!(update.validate_with(&3).is_ok());
+    let err = update.validate_with(&2).unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "rooms");
+    assert_eq!(err.violations[0].code, "too_many_items");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_patch_deserializes_with_serde_attributes() {
+    use serde::Deserialize;
+
+    #[derive(Debug, ValidatePartial)]
+    #[patch(derive(Debug, Deserialize))]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct Profile {
+        #[validate(length(min = 1, max = 20))]
+        display_name: String,
+
+        #[validate(url)]
+        avatar_url: String,
+    }
+
+    let patch: ProfilePatch = serde_json::from_str(r#"{"displayName": ""}"#).unwrap();
+    assert!(patch.avatar_url.is_none());
+
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "display_name");
+}
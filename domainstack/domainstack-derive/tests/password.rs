@@ -0,0 +1,167 @@
+//! Tests for the password policy rule in the derive macros
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate, ValidatePartial};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+
+#[derive(Debug, Validate, ValidatePartial, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+#[patch(derive(Default))]
+struct Signup {
+    #[validate(length(min = 3, max = 32))]
+    username: String,
+
+    #[validate(email)]
+    email: String,
+
+    #[validate(password(
+        min_length = 12,
+        max_length = 64,
+        lowercase,
+        uppercase,
+        digit,
+        max_repeats = 2,
+        forbid = ["password"],
+        forbid_fields = ["username", "email"]
+    ))]
+    password: String,
+}
+
+fn signup(password: &str) -> Signup {
+    Signup {
+        username: "jdoe".to_string(),
+        email: "jane.doe@example.com".to_string(),
+        password: password.to_string(),
+    }
+}
+
+fn codes(err: &ValidationError) -> Vec<(String, &'static str)> {
+    err.violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect()
+}
+
+#[test]
+fn test_valid_password() {
+    assert!(signup("Tr0ubadour-Horse").validate().is_ok());
+}
+
+#[test]
+fn test_each_unmet_requirement_is_reported() {
+    let err = signup("passwordddd").validate().unwrap_err();
+    assert_eq!(
+        codes(&err),
+        vec![
+            ("password".to_string(), "password_too_short"),
+            ("password".to_string(), "password_missing_class"),
+            ("password".to_string(), "password_missing_class"),
+            ("password".to_string(), "password_forbidden_substring"),
+            ("password".to_string(), "password_repeated_chars"),
+        ]
+    );
+    assert_eq!(err.violations[1].meta.get("class"), Some("uppercase"));
+    assert_eq!(err.violations[2].meta.get("class"), Some("digit"));
+    assert_eq!(err.violations[4].meta.get("max"), Some("2"));
+}
+
+#[test]
+fn test_password_must_not_contain_sibling_fields() {
+    let err = signup("Hello-JDoe-2024").validate().unwrap_err();
+    assert_eq!(
+        codes(&err),
+        vec![("password".to_string(), "password_forbidden_substring")]
+    );
+
+    // The email's local part is forbidden as well as the full address
+    let err = signup("Jane.Doe-2024!").validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "password_forbidden_substring");
+}
+
+#[test]
+fn test_partial_forbids_only_present_siblings() {
+    let patch = SignupPatch {
+        password: Some("Hello-JDoe-2024".to_string()),
+        ..Default::default()
+    };
+    assert!(patch.validate().is_ok());
+
+    let patch = SignupPatch {
+        username: Some("jdoe".to_string()),
+        password: Some("Hello-JDoe-2024".to_string()),
+        ..Default::default()
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "password_forbidden_substring");
+
+    let patch = SignupPatch {
+        password: Some("short".to_string()),
+        ..Default::default()
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "password_too_short");
+}
+
+#[derive(Debug, Validate)]
+enum Credential {
+    Password {
+        login: String,
+        #[validate(password(min_entropy = 60, forbid_fields = ["login"]))]
+        secret: String,
+    },
+    Pin(#[validate(password(min_length = 4, digit))] String),
+}
+
+#[test]
+fn test_password_in_enum_variants() {
+    let ok = Credential::Password {
+        login: "admin".to_string(),
+        secret: "Quartz-Lantern-88".to_string(),
+    };
+    assert!(ok.validate().is_ok());
+
+    let err = Credential::Password {
+        login: "admin".to_string(),
+        secret: "admin12345".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        codes(&err),
+        vec![
+            ("secret".to_string(), "password_forbidden_substring"),
+            ("secret".to_string(), "password_low_entropy"),
+        ]
+    );
+
+    let err = Credential::Pin("abcd".to_string()).validate().unwrap_err();
+    assert_eq!(err.violations[0].meta.get("class"), Some("digit"));
+}
+
+#[derive(Debug, Validate)]
+struct ApiSecret(#[validate(password)] String);
+
+#[test]
+fn test_default_policy_on_tuple_struct() {
+    assert!(ApiSecret("longenough".to_string()).validate().is_ok());
+    let err = ApiSecret("short".to_string()).validate().unwrap_err();
+    assert_eq!(err.violations[0].meta.get("min"), Some("8"));
+}
+
+#[test]
+fn test_password_schema_output() {
+    #[allow(unused_mut)]
+    let mut outputs = vec![serde_json::to_value(Signup::schema()).unwrap()];
+    #[cfg(feature = "schema")]
+    {
+        use domainstack_schema::ToJsonSchema as _;
+        outputs.push(serde_json::to_value(Signup::json_schema()).unwrap());
+    }
+
+    for json in outputs {
+        let password = &json["properties"]["password"];
+        assert_eq!(password["format"], "password");
+        assert_eq!(password["minLength"], 12);
+        assert_eq!(password["maxLength"], 64);
+    }
+}
@@ -0,0 +1,122 @@
+//! Tests for input safety rules in the derive macros
+
+use domainstack::prelude::*;
+use domainstack_derive::{ToSchema, Validate};
+use domainstack_schema::ToSchema as ToSchemaTrait;
+use serde_json::json;
+
+#[derive(Debug, Validate, ToSchema)]
+#[cfg_attr(feature = "schema", derive(domainstack_derive::ToJsonSchema))]
+struct Upload {
+    #[validate(safe_filename)]
+    file_name: String,
+
+    #[validate(no_path_traversal)]
+    folder: String,
+
+    #[validate(no_null_bytes)]
+    label: String,
+
+    #[validate(no_html_tags)]
+    caption: String,
+
+    #[validate(no_template_syntax)]
+    greeting: String,
+
+    #[validate(each(safe_filename))]
+    attachments: Vec<String>,
+}
+
+fn valid_upload() -> Upload {
+    Upload {
+        file_name: "report-2024.pdf".to_string(),
+        folder: "reports/q3".to_string(),
+        label: "Q3 report".to_string(),
+        caption: "Revenue < forecast".to_string(),
+        greeting: "Hello {name}".to_string(),
+        attachments: vec!["chart.png".to_string()],
+    }
+}
+
+#[test]
+fn test_valid_upload() {
+    assert!(valid_upload().validate().is_ok());
+}
+
+#[test]
+fn test_invalid_upload() {
+    let upload = Upload {
+        file_name: "-rf".to_string(),
+        folder: "reports/../../etc".to_string(),
+        label: "Q3\0report".to_string(),
+        caption: "<img src=x onerror=alert(1)>".to_string(),
+        greeting: "Hello {{ config }}".to_string(),
+        attachments: vec!["chart.png".to_string(), "CON".to_string()],
+    };
+
+    let err = upload.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            ("file_name".to_string(), "unsafe_filename"),
+            ("folder".to_string(), "path_traversal"),
+            ("label".to_string(), "null_byte"),
+            ("caption".to_string(), "html_tag"),
+            ("greeting".to_string(), "template_syntax"),
+            ("attachments[1]".to_string(), "unsafe_filename"),
+        ]
+    );
+    assert_eq!(err.violations[0].meta.get("reason"), Some("leading_dash"));
+    assert_eq!(err.violations[5].meta.get("reason"), Some("reserved_name"));
+}
+
+#[derive(Debug, Validate)]
+struct Webhook {
+    #[validate(max_nesting = 3)]
+    payload: serde_json::Value,
+}
+
+#[derive(Debug, Validate)]
+struct Metadata(#[validate(max_nesting = 1)] serde_json::Value);
+
+#[test]
+fn test_max_nesting() {
+    let webhook = Webhook {
+        payload: json!({"order": {"items": [{"sku": "A1"}]}}),
+    };
+    let err = webhook.validate().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "payload");
+    assert_eq!(err.violations[0].code, "too_deeply_nested");
+    assert_eq!(err.violations[0].meta.get("max"), Some("3"));
+
+    assert!(Metadata(json!({"plan": "pro"})).validate().is_ok());
+    assert!(Metadata(json!({"tags": []})).validate().is_err());
+}
+
+#[test]
+fn test_security_rules_schema_patterns() {
+    let json = serde_json::to_value(Upload::schema()).unwrap();
+    let props = &json["properties"];
+
+    for field in ["file_name", "folder", "label", "caption", "greeting"] {
+        assert!(props[field]["pattern"].is_string(), "{field}");
+    }
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_security_rules_json_schema_patterns() {
+    use domainstack_schema::ToJsonSchema as _;
+
+    let json = serde_json::to_value(Upload::json_schema()).unwrap();
+    assert_eq!(
+        json["properties"]["caption"]["pattern"],
+        r"^(?![\s\S]*<[A-Za-z/!?])"
+    );
+}
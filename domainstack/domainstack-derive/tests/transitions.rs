@@ -0,0 +1,113 @@
+use domainstack::prelude::*;
+use domainstack_derive::ValidateTransition;
+
+#[derive(Debug, Clone, PartialEq, ValidateTransition)]
+#[transitions(Pending -> [Confirmed, Cancelled], Confirmed -> [Completed, Cancelled])]
+enum BookingStatus {
+    Pending,
+    Confirmed,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, ValidateTransition)]
+#[transitions(Draft -> Submitted)]
+#[transitions(Submitted -> Approved, Submitted -> Rejected)]
+enum Review {
+    Draft,
+    Submitted { reviewer: String },
+    Approved(u32),
+    Rejected { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, ValidateTransition)]
+enum Frozen {
+    Only,
+}
+
+#[test]
+fn test_allowed_transitions() {
+    assert!(BookingStatus::Pending.can_transition_to(&BookingStatus::Confirmed));
+    assert!(BookingStatus::Pending.can_transition_to(&BookingStatus::Cancelled));
+    assert!(BookingStatus::Confirmed.can_transition_to(&BookingStatus::Completed));
+    assert!(BookingStatus::Confirmed.can_transition_to(&BookingStatus::Cancelled));
+}
+
+#[test]
+fn test_disallowed_transitions() {
+    assert!(!BookingStatus::Pending.can_transition_to(&BookingStatus::Completed));
+    assert!(!BookingStatus::Cancelled.can_transition_to(&BookingStatus::Pending));
+    assert!(!BookingStatus::Completed.can_transition_to(&BookingStatus::Completed));
+}
+
+#[test]
+fn test_invalid_transition_error() {
+    let err = BookingStatus::Completed
+        .validate_transition(&BookingStatus::Pending)
+        .unwrap_err();
+
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].code, "invalid_transition");
+    assert_eq!(
+        err.violations[0].message,
+        "Cannot transition from Completed to Pending"
+    );
+    assert_eq!(err.violations[0].meta.get("from"), Some("Completed"));
+    assert_eq!(err.violations[0].meta.get("to"), Some("Pending"));
+}
+
+#[test]
+fn test_transition_returns_next_state() {
+    let status = BookingStatus::Pending;
+    let status = status.transition(BookingStatus::Confirmed).unwrap();
+    assert_eq!(status, BookingStatus::Confirmed);
+
+    assert!(status.transition(BookingStatus::Pending).is_err());
+}
+
+#[test]
+fn test_variants_with_data_and_merged_attributes() {
+    let draft = Review::Draft;
+    let submitted = Review::Submitted {
+        reviewer: "alice".to_string(),
+    };
+
+    assert_eq!(submitted.state_name(), "Submitted");
+    assert!(draft.can_transition_to(&submitted));
+    assert!(submitted.can_transition_to(&Review::Approved(5)));
+    assert!(submitted.can_transition_to(&Review::Rejected {
+        reason: "incomplete".to_string(),
+    }));
+    assert!(!Review::Approved(5).can_transition_to(&draft));
+}
+
+#[test]
+fn test_no_transitions_declared() {
+    assert!(!Frozen::Only.can_transition_to(&Frozen::Only));
+    assert_eq!(Frozen::Only.state_name(), "Only");
+}
+
+#[test]
+fn test_transition_error_in_aggregate() {
+    struct Booking {
+        status: BookingStatus,
+    }
+
+    impl Booking {
+        #[allow(clippy::result_large_err)]
+        fn cancel(&mut self) -> Result<(), ValidationError> {
+            self.status = self
+                .status
+                .transition(BookingStatus::Cancelled)
+                .map_err(|e| e.prefixed("status"))?;
+            Ok(())
+        }
+    }
+
+    let mut booking = Booking {
+        status: BookingStatus::Completed,
+    };
+    let err = booking.cancel().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "status");
+    assert_eq!(booking.status, BookingStatus::Completed);
+}
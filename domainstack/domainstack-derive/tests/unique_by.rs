@@ -0,0 +1,120 @@
+//! Tests for `#[validate(unique_by = "...")]`
+
+use domainstack::prelude::*;
+use domainstack_derive::{Validate, ValidatePartial};
+
+#[derive(Debug, Clone)]
+struct Member {
+    email: String,
+    seat: u32,
+}
+
+#[derive(Debug, Validate, ValidatePartial)]
+#[patch(derive(Debug, Default))]
+struct Team {
+    #[validate(min_items = 1)]
+    #[validate(unique_by = "email")]
+    #[validate(unique_by = "seat")]
+    members: Vec<Member>,
+}
+
+fn member(email: &str, seat: u32) -> Member {
+    Member {
+        email: email.to_string(),
+        seat,
+    }
+}
+
+#[test]
+fn test_unique_by_valid() {
+    let team = Team {
+        members: vec![member("ada@example.com", 1), member("grace@example.com", 2)],
+    };
+    assert!(team.validate().is_ok());
+}
+
+#[test]
+fn test_unique_by_paths() {
+    let team = Team {
+        members: vec![
+            member("ada@example.com", 1),
+            member("grace@example.com", 2),
+            member("alan@example.com", 2),
+            member("ada@example.com", 3),
+        ],
+    };
+    let err = team.validate().unwrap_err();
+    let found: Vec<_> = err
+        .violations
+        .iter()
+        .map(|v| (v.path.to_string(), v.code, v.meta.get("duplicate_of")))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("members[3].email".to_string(), "duplicate_item", Some("0")),
+            ("members[2].seat".to_string(), "duplicate_item", Some("1")),
+        ]
+    );
+}
+
+#[test]
+fn test_unique_by_partial() {
+    assert!(TeamPatch::default().validate().is_ok());
+
+    let patch = TeamPatch {
+        members: Some(vec![
+            member("ada@example.com", 1),
+            member("ada@example.com", 2),
+        ]),
+    };
+    let err = patch.validate().unwrap_err();
+    assert_eq!(err.violations.len(), 1);
+    assert_eq!(err.violations[0].path.to_string(), "members[1].email");
+}
+
+#[derive(Debug, Validate)]
+struct Roster(#[validate(unique_by = "email")] Vec<Member>);
+
+#[derive(Debug, Validate)]
+enum Assignment {
+    Team {
+        #[validate(unique_by = "seat")]
+        members: Vec<Member>,
+    },
+    Pair(#[validate(unique_by = "email")] Vec<Member>),
+}
+
+#[test]
+fn test_unique_by_tuple_field() {
+    assert!(Roster(vec![member("ada@example.com", 1)])
+        .validate()
+        .is_ok());
+
+    let roster = Roster(vec![
+        member("ada@example.com", 1),
+        member("ada@example.com", 2),
+    ]);
+    let err = roster.validate().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "0[1].email");
+    assert_eq!(err.violations[0].code, "duplicate_item");
+}
+
+#[test]
+fn test_unique_by_enum_fields() {
+    let team = Assignment::Team {
+        members: vec![member("ada@example.com", 1), member("grace@example.com", 1)],
+    };
+    let err = team.validate().unwrap_err();
+    assert_eq!(err.violations[0].path.to_string(), "members[1].seat");
+
+    let pair = Assignment::Pair(vec![
+        member("ada@example.com", 1),
+        member("ada@example.com", 2),
+    ]);
+    let err = pair.validate().unwrap_err();
+    assert_eq!(err.violations[0].code, "duplicate_item");
+    assert!(Assignment::Pair(vec![member("ada@example.com", 1)])
+        .validate()
+        .is_ok());
+}
@@ -13,6 +13,9 @@
 //! These functions are used internally by framework adapters (`domainstack-axum`, `domainstack-actix`, `domainstack-rocket`)
 //! but can also be used directly in custom extractors or handlers.
 //!
+//! With the `tracing` or `metrics` feature, both helpers report the outcome through
+//! [`domainstack::telemetry`], labelled with the domain or DTO type name.
+//!
 //! ## Example - `into_domain`
 //!
 //! ```rust
@@ -56,7 +59,7 @@
 //! let validated = validate_dto(dto).expect("Valid DTO");
 //! ```
 
-use domainstack::{Validate, ValidationError};
+use domainstack::{telemetry, Validate, ValidationError};
 use domainstack_envelope::IntoEnvelopeError;
 
 #[allow(clippy::result_large_err)]
@@ -64,7 +67,10 @@ pub fn into_domain<T, Dto>(dto: Dto) -> Result<T, error_envelope::Error>
 where
     T: TryFrom<Dto, Error = ValidationError>,
 {
-    T::try_from(dto).map_err(|e| e.into_envelope_error())
+    let scope = telemetry::enter(core::any::type_name::<T>());
+    scope
+        .finish(T::try_from(dto))
+        .map_err(|e| e.into_envelope_error())
 }
 
 #[allow(clippy::result_large_err)]
@@ -72,7 +78,9 @@ pub fn validate_dto<Dto>(dto: Dto) -> Result<Dto, error_envelope::Error>
 where
     Dto: Validate,
 {
-    dto.validate()
+    let scope = telemetry::enter(core::any::type_name::<Dto>());
+    scope
+        .finish(dto.validate())
         .map(|_| dto)
         .map_err(|e| e.into_envelope_error())
 }
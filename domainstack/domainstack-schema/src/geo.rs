@@ -0,0 +1,142 @@
+//! Schemas for coordinates and GeoJSON (RFC 7946) geometry, matching `rules::geo`.
+
+use crate::{JsonSchema, Schema};
+
+/// Schema helpers for coordinates and GeoJSON geometry.
+///
+/// The bounds match `rules::latitude`, `rules::longitude` and the position checks of
+/// `rules::geo::polygon`. Closed rings, winding and self-intersection can't be
+/// expressed in a schema and are left to the rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use domainstack_schema::Geo;
+///
+/// assert_eq!(Geo::latitude().maximum, Some(90.0));
+///
+/// let json = serde_json::to_value(Geo::json_polygon()).unwrap();
+/// assert_eq!(json["properties"]["type"]["enum"][0], "Polygon");
+/// assert_eq!(json["properties"]["coordinates"]["items"]["minItems"], 4);
+/// ```
+pub struct Geo;
+
+impl Geo {
+    /// A latitude in degrees, from -90 to 90.
+    pub fn latitude() -> Schema {
+        Schema::number().minimum(-90).maximum(90)
+    }
+
+    /// A longitude in degrees, from -180 to 180.
+    pub fn longitude() -> Schema {
+        Schema::number().minimum(-180).maximum(180)
+    }
+
+    /// A GeoJSON position: `[longitude, latitude]` with an optional altitude.
+    pub fn position() -> Schema {
+        Schema::array(Schema::number())
+            .min_items(2)
+            .max_items(3)
+            .description("[longitude, latitude] or [longitude, latitude, altitude]")
+    }
+
+    /// A GeoJSON `bbox`: `[west, south, east, north]`.
+    pub fn bbox() -> Schema {
+        Schema::array(Schema::number())
+            .min_items(4)
+            .max_items(4)
+            .description("[west, south, east, north]")
+    }
+
+    /// A GeoJSON `Point` geometry.
+    pub fn point() -> Schema {
+        Schema::object()
+            .property("type", Schema::string().enum_values(&["Point"]))
+            .property("coordinates", Self::position())
+            .required(&["type", "coordinates"])
+    }
+
+    /// A GeoJSON `Polygon` geometry: rings of at least 4 positions.
+    pub fn polygon() -> Schema {
+        let ring = Schema::array(Self::position()).min_items(4);
+        Schema::object()
+            .property("type", Schema::string().enum_values(&["Polygon"]))
+            .property("coordinates", Schema::array(ring).min_items(1))
+            .required(&["type", "coordinates"])
+    }
+
+    /// JSON Schema version of [`Geo::latitude`].
+    pub fn json_latitude() -> JsonSchema {
+        JsonSchema::number().minimum(-90).maximum(90)
+    }
+
+    /// JSON Schema version of [`Geo::longitude`].
+    pub fn json_longitude() -> JsonSchema {
+        JsonSchema::number().minimum(-180).maximum(180)
+    }
+
+    /// JSON Schema version of [`Geo::position`].
+    pub fn json_position() -> JsonSchema {
+        JsonSchema::array(JsonSchema::number())
+            .min_items(2)
+            .max_items(3)
+            .description("[longitude, latitude] or [longitude, latitude, altitude]")
+    }
+
+    /// JSON Schema version of [`Geo::bbox`].
+    pub fn json_bbox() -> JsonSchema {
+        JsonSchema::array(JsonSchema::number())
+            .min_items(4)
+            .max_items(4)
+            .description("[west, south, east, north]")
+    }
+
+    /// JSON Schema version of [`Geo::point`].
+    pub fn json_point() -> JsonSchema {
+        JsonSchema::object()
+            .property("type", JsonSchema::string().enum_values(&["Point"]))
+            .property("coordinates", Self::json_position())
+            .required(&["type", "coordinates"])
+    }
+
+    /// JSON Schema version of [`Geo::polygon`].
+    pub fn json_polygon() -> JsonSchema {
+        let ring = JsonSchema::array(Self::json_position()).min_items(4);
+        JsonSchema::object()
+            .property("type", JsonSchema::string().enum_values(&["Polygon"]))
+            .property("coordinates", JsonSchema::array(ring).min_items(1))
+            .required(&["type", "coordinates"])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        let json = serde_json::to_value(Geo::longitude()).unwrap();
+        assert_eq!(json["minimum"], -180.0);
+        assert_eq!(json["maximum"], 180.0);
+
+        let json = serde_json::to_value(Geo::json_bbox()).unwrap();
+        assert_eq!(json["minItems"], 4);
+        assert_eq!(json["maxItems"], 4);
+    }
+
+    #[test]
+    fn test_geometry() {
+        let json = serde_json::to_value(Geo::polygon()).unwrap();
+        assert_eq!(json["required"], serde_json::json!(["type", "coordinates"]));
+        let coordinates = &json["properties"]["coordinates"];
+        assert_eq!(coordinates["minItems"], 1);
+        assert_eq!(coordinates["items"]["items"]["maxItems"], 3);
+
+        let json = serde_json::to_value(Geo::json_point()).unwrap();
+        assert_eq!(
+            json["properties"]["type"]["enum"],
+            serde_json::json!(["Point"])
+        );
+        assert_eq!(json["properties"]["coordinates"]["minItems"], 2);
+    }
+}
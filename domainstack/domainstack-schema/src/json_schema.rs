@@ -104,6 +104,16 @@ pub struct JsonSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub not: Option<Box<JsonSchema>>,
 
+    // Conditionals
+    #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+    pub if_schema: Option<Box<JsonSchema>>,
+
+    #[serde(rename = "then", skip_serializing_if = "Option::is_none")]
+    pub then_schema: Option<Box<JsonSchema>>,
+
+    #[serde(rename = "dependentRequired", skip_serializing_if = "Option::is_none")]
+    pub dependent_required: Option<HashMap<String, Vec<String>>>,
+
     // Metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<serde_json::Value>,
@@ -178,6 +188,9 @@ impl JsonSchema {
             all_of: None,
             one_of: None,
             not: None,
+            if_schema: None,
+            then_schema: None,
+            dependent_required: None,
             default: None,
             examples: None,
             deprecated: None,
@@ -436,6 +449,31 @@ impl JsonSchema {
             ..Self::new()
         }
     }
+
+    /// Create a conditional schema: values matching `if_schema` must also match `then_schema`
+    pub fn if_then(if_schema: JsonSchema, then_schema: JsonSchema) -> Self {
+        Self {
+            if_schema: Some(Box::new(if_schema)),
+            then_schema: Some(Box::new(then_schema)),
+            ..Self::new()
+        }
+    }
+
+    /// Require `fields` whenever `field` is present (`dependentRequired`)
+    pub fn dependent_required(mut self, field: impl Into<String>, fields: &[&str]) -> Self {
+        self.dependent_required
+            .get_or_insert_with(HashMap::new)
+            .entry(field.into())
+            .or_default()
+            .extend(fields.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// Add a subschema to `allOf`, e.g. a cross-field constraint on an object
+    pub fn constraint(mut self, schema: JsonSchema) -> Self {
+        self.all_of.get_or_insert_with(Vec::new).push(schema);
+        self
+    }
 }
 
 impl Default for JsonSchema {
@@ -478,6 +516,14 @@ pub trait ToJsonSchema {
 
     /// Generate the JSON Schema for this type.
     fn json_schema() -> JsonSchema;
+
+    /// Generate the JSON Schema for a validation group.
+    ///
+    /// Defaults to [`json_schema`](Self::json_schema).
+    fn json_schema_for_group(group: &str) -> JsonSchema {
+        let _ = group;
+        Self::json_schema()
+    }
 }
 
 // Implementations for primitive types
@@ -645,6 +691,13 @@ impl JsonSchemaBuilder {
         self
     }
 
+    /// Register the schema of a validation group under a custom name
+    pub fn register_group<T: ToJsonSchema>(mut self, group: &str, name: impl Into<String>) -> Self {
+        self.defs
+            .insert(name.into(), T::json_schema_for_group(group));
+        self
+    }
+
     /// Add a schema with a custom name
     pub fn add_schema(mut self, name: impl Into<String>, schema: JsonSchema) -> Self {
         self.defs.insert(name.into(), schema);
@@ -750,6 +803,31 @@ mod tests {
         assert_eq!(schema.any_of.as_ref().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_cross_field_keywords() {
+        let schema = JsonSchema::object()
+            .dependent_required("company", &["tax_id"])
+            .dependent_required("company", &["vat_id"])
+            .constraint(JsonSchema::if_then(
+                JsonSchema::new().required(&["shipping"]),
+                JsonSchema::new().required(&["address"]),
+            ));
+
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            json["dependentRequired"]["company"],
+            serde_json::json!(["tax_id", "vat_id"])
+        );
+        assert_eq!(
+            json["allOf"][0]["then"]["required"],
+            serde_json::json!(["address"])
+        );
+        assert_eq!(
+            json["allOf"][0]["if"]["required"],
+            serde_json::json!(["shipping"])
+        );
+    }
+
     #[test]
     fn test_builder() {
         struct User;
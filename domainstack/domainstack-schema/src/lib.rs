@@ -100,7 +100,13 @@
 //! | `length(min, max)` | `minLength`, `maxLength` | `.min_length(3).max_length(50)` |
 //! | `range(min, max)` | `minimum`, `maximum` | `.minimum(0).maximum(100)` |
 //! | `email()` | `format: "email"` | `.format("email")` |
+//! | `ipv4()` / `ipv6()` | `format: "ipv4"` / `"ipv6"` | `.format("ipv4")` |
+//! | `hostname()` / `fqdn()` | `format: "idn-hostname"` | `.format("idn-hostname")` |
+//! | `cidr()` | `format: "cidr"` | `.format("cidr")` |
+//! | `uuid(None)` | `format: "uuid"` | `.format("uuid")` |
+//! | `password(policy)` | `format: "password"`, `minLength`, `maxLength` | `.format("password").min_length(12)` |
 //! | `one_of(...)` | `enum` | `.enum_values(&["a", "b"])` |
+//! | `iso3166_alpha2()` / `iso4217_currency()` | `enum` | `.enum_values(rules::iso::ISO4217_CURRENCIES)` |
 //! | `numeric_string()` | `pattern` | `.pattern("^[0-9]+$")` |
 //! | `min_items(n)` | `minItems` | `.min_items(1)` |
 //! | `max_items(n)` | `maxItems` | `.max_items(10)` |
@@ -229,14 +235,18 @@
 //! - Best practices
 //! - Performance characteristics
 
+mod geo;
 mod json_schema;
+mod money;
 mod openapi;
 mod schema;
 mod traits;
 
+pub use geo::Geo;
 pub use json_schema::{
     AdditionalProperties, JsonSchema, JsonSchemaBuilder, JsonSchemaType, ToJsonSchema,
 };
+pub use money::Money;
 pub use openapi::{OpenApiBuilder, OpenApiSpec};
 pub use schema::{Schema, SchemaType};
 pub use traits::ToSchema;
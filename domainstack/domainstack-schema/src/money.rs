@@ -0,0 +1,113 @@
+//! Schemas for money: amounts stepped by a currency's minor unit and ISO 4217 codes.
+
+use crate::{JsonSchema, Schema, ToJsonSchema, ToSchema};
+use domainstack::rules::iso::ISO4217_CURRENCIES;
+use domainstack::rules::money::minor_units;
+
+/// Schema helpers for money amounts and currency codes.
+///
+/// As a type, `Money` describes the common `{ "amount": 12.5, "currency": "EUR" }`
+/// object, which `#[validate(money(currency = "currency"))]` validates.
+///
+/// # Examples
+///
+/// ```rust
+/// use domainstack_schema::{Money, ToSchema};
+///
+/// // JPY has no minor unit, KWD has three
+/// assert_eq!(Money::amount("JPY").multiple_of, Some(1.0));
+/// assert_eq!(Money::amount("KWD").multiple_of, Some(0.001));
+///
+/// let schema = Money::schema();
+/// assert_eq!(schema.required, Some(vec!["amount".to_string(), "currency".to_string()]));
+/// ```
+pub struct Money;
+
+impl Money {
+    /// A number stepped by `currency`'s minor unit, such as `multipleOf: 0.01` for USD.
+    ///
+    /// Unknown currencies and currencies without a minor unit (`XAU`) get a plain number.
+    pub fn amount(currency: &str) -> Schema {
+        match minor_unit_step(currency) {
+            Some(step) => Schema::number().multiple_of(step),
+            None => Schema::number(),
+        }
+    }
+
+    /// An ISO 4217 currency code.
+    pub fn currency() -> Schema {
+        Schema::string().enum_values(ISO4217_CURRENCIES)
+    }
+
+    /// JSON Schema version of [`Money::amount`].
+    pub fn json_amount(currency: &str) -> JsonSchema {
+        match minor_unit_step(currency) {
+            Some(step) => JsonSchema::number().multiple_of(step),
+            None => JsonSchema::number(),
+        }
+    }
+
+    /// JSON Schema version of [`Money::currency`].
+    pub fn json_currency() -> JsonSchema {
+        JsonSchema::string().enum_values(ISO4217_CURRENCIES)
+    }
+}
+
+impl ToSchema for Money {
+    fn schema_name() -> &'static str {
+        "Money"
+    }
+
+    fn schema() -> Schema {
+        Schema::object()
+            .property("amount", Schema::number())
+            .property("currency", Self::currency())
+            .required(&["amount", "currency"])
+    }
+}
+
+impl ToJsonSchema for Money {
+    fn schema_name() -> &'static str {
+        "Money"
+    }
+
+    fn json_schema() -> JsonSchema {
+        JsonSchema::object()
+            .property("amount", JsonSchema::number())
+            .property("currency", Self::json_currency())
+            .required(&["amount", "currency"])
+    }
+}
+
+/// `10^-units`, parsed so that `0.01` is the closest `f64` rather than `0.1 * 0.1`.
+fn minor_unit_step(currency: &str) -> Option<f64> {
+    minor_units(currency).map(|units| format!("1e-{}", units).parse().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_step() {
+        assert_eq!(Money::amount("USD").multiple_of, Some(0.01));
+        assert_eq!(Money::amount("CLF").multiple_of, Some(0.0001));
+        assert_eq!(Money::amount("XAU").multiple_of, None);
+        assert_eq!(Money::amount("???").multiple_of, None);
+
+        let json = serde_json::to_value(Money::json_amount("BHD")).unwrap();
+        assert_eq!(json["type"], "number");
+        assert_eq!(json["multipleOf"], 0.001);
+    }
+
+    #[test]
+    fn test_money_object() {
+        let json = serde_json::to_value(Money::schema()).unwrap();
+        assert_eq!(json["properties"]["amount"]["type"], "number");
+        let codes = json["properties"]["currency"]["enum"].as_array().unwrap();
+        assert!(codes.iter().any(|code| code == "EUR"));
+
+        let json = serde_json::to_value(Money::json_schema()).unwrap();
+        assert_eq!(json["required"], serde_json::json!(["amount", "currency"]));
+    }
+}
@@ -85,6 +85,38 @@ impl OpenApiBuilder {
         self
     }
 
+    /// Register the schema of a validation group under a custom name.
+    ///
+    /// Lets request bodies differ per operation, e.g. `UserCreate` and `UserUpdate`
+    /// from the same `User` type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use domainstack_schema::{OpenApiBuilder, ToSchema, Schema};
+    ///
+    /// struct User;
+    /// impl ToSchema for User {
+    ///     fn schema_name() -> &'static str { "User" }
+    ///     fn schema() -> Schema { Schema::object() }
+    ///     fn schema_for_group(group: &str) -> Schema {
+    ///         match group {
+    ///             "update" => Schema::object().required(&["id"]),
+    ///             _ => Self::schema(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let spec = OpenApiBuilder::new("API", "1.0")
+    ///     .register_group::<User>("create", "UserCreate")
+    ///     .register_group::<User>("update", "UserUpdate")
+    ///     .build();
+    /// ```
+    pub fn register_group<T: ToSchema>(mut self, group: &str, name: impl Into<String>) -> Self {
+        self.schemas.insert(name.into(), T::schema_for_group(group));
+        self
+    }
+
     /// Manually add a schema with a custom name.
     pub fn schema(mut self, name: impl Into<String>, schema: Schema) -> Self {
         self.schemas.insert(name.into(), schema);
@@ -73,6 +73,9 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub one_of: Option<Vec<Schema>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<Schema>>,
+
     // Metadata (v0.8)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<serde_json::Value>,
@@ -134,6 +137,7 @@ impl Schema {
             any_of: None,
             all_of: None,
             one_of: None,
+            not: None,
             default: None,
             example: None,
             examples: None,
@@ -365,6 +369,43 @@ impl Schema {
         }
     }
 
+    /// Create a schema that matches anything the given schema does not match.
+    ///
+    /// # Example
+    /// ```rust
+    /// use domainstack_schema::Schema;
+    ///
+    /// // `card` and `iban` must not both be sent
+    /// let schema = Schema::negation(Schema::new().required(&["card", "iban"]));
+    /// ```
+    pub fn negation(schema: Schema) -> Self {
+        Self {
+            not: Some(Box::new(schema)),
+            ..Self::new()
+        }
+    }
+
+    /// Add a subschema to `allOf`, e.g. a cross-field constraint on an object.
+    ///
+    /// # Example
+    /// ```rust
+    /// use domainstack_schema::Schema;
+    ///
+    /// let schema = Schema::object()
+    ///     .property("email", Schema::string())
+    ///     .property("phone", Schema::string())
+    ///     .constraint(Schema::any_of(vec![
+    ///         Schema::new().required(&["email"]),
+    ///         Schema::new().required(&["phone"]),
+    ///     ]));
+    ///
+    /// assert_eq!(schema.all_of.unwrap().len(), 1);
+    /// ```
+    pub fn constraint(mut self, schema: Schema) -> Self {
+        self.all_of.get_or_insert_with(Vec::new).push(schema);
+        self
+    }
+
     /// Set a default value for this schema.
     ///
     /// # Example
@@ -711,6 +752,23 @@ mod tests {
         assert!(extensions.contains_key("x-domainstack-validations"));
     }
 
+    #[test]
+    fn test_cross_field_constraints() {
+        let schema = Schema::object()
+            .constraint(Schema::negation(Schema::new().required(&["card", "iban"])))
+            .constraint(Schema::one_of(vec![
+                Schema::new().required(&["card"]),
+                Schema::new().required(&["iban"]),
+            ]));
+
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            json["allOf"][0]["not"]["required"],
+            serde_json::json!(["card", "iban"])
+        );
+        assert_eq!(json["allOf"][1]["oneOf"].as_array().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_composition_serialization() {
         let schema = Schema::any_of(vec![Schema::string(), Schema::integer()]);
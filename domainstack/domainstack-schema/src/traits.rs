@@ -36,6 +36,15 @@ pub trait ToSchema {
 
     /// Generate the OpenAPI schema for this type.
     fn schema() -> Schema;
+
+    /// Generate the OpenAPI schema for a validation group.
+    ///
+    /// Types whose rules differ per operation (create vs update) describe each
+    /// request body separately. Defaults to [`schema`](Self::schema).
+    fn schema_for_group(group: &str) -> Schema {
+        let _ = group;
+        Self::schema()
+    }
 }
 
 // Implementations for primitive types
@@ -0,0 +1,235 @@
+//! Time source for time-relative rules such as `rules::past()`.
+//!
+//! Rules never read the system clock directly. They ask the [`RuleContext`](crate::RuleContext)
+//! for the current time, which resolves, in order:
+//!
+//! 1. the clock set with [`RuleContext::with_clock`](crate::RuleContext::with_clock),
+//! 2. the clock installed on the current thread by [`with_test_clock`] (`std` only),
+//! 3. [`SystemClock`] (`std` only).
+//!
+//! Times are nanoseconds since the Unix epoch, so the trait does not depend on a
+//! particular date/time crate.
+
+use core::fmt::Debug;
+
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+/// A source of the current time.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::{Clock, FixedClock};
+///
+/// let clock = FixedClock::from_unix_secs(1_700_000_000);
+/// assert_eq!(clock.now_unix_nanos(), 1_700_000_000_000_000_000);
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// Nanoseconds since 1970-01-01T00:00:00Z; negative for earlier times.
+    fn now_unix_nanos(&self) -> i128;
+
+    /// The current time as a chrono `DateTime<Utc>`.
+    ///
+    /// Saturates at chrono's representable range.
+    #[cfg(feature = "chrono")]
+    fn now_utc(&self) -> DateTime<Utc> {
+        utc_from_unix_nanos(self.now_unix_nanos())
+    }
+}
+
+/// The operating system's wall clock.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_unix_nanos(&self) -> i128 {
+        match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(since) => since.as_nanos() as i128,
+            Err(before) => -(before.duration().as_nanos() as i128),
+        }
+    }
+}
+
+/// A clock stopped at a given instant, for tests and "as of" validation.
+///
+/// With the `chrono` feature it converts from any `DateTime`, and with the `time`
+/// feature from `OffsetDateTime`:
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{TimeZone, Utc};
+/// use domainstack::{Clock, FixedClock};
+///
+/// let clock = FixedClock::from(Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap());
+/// assert_eq!(clock.now_utc().to_rfc3339(), "2024-02-29T12:00:00+00:00");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedClock {
+    unix_nanos: i128,
+}
+
+impl FixedClock {
+    /// A clock stopped `nanos` nanoseconds after the Unix epoch.
+    pub const fn from_unix_nanos(nanos: i128) -> Self {
+        Self { unix_nanos: nanos }
+    }
+
+    /// A clock stopped `secs` seconds after the Unix epoch.
+    pub const fn from_unix_secs(secs: i64) -> Self {
+        Self::from_unix_nanos(secs as i128 * NANOS_PER_SEC)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_unix_nanos(&self) -> i128 {
+        self.unix_nanos
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> From<DateTime<Tz>> for FixedClock {
+    fn from(at: DateTime<Tz>) -> Self {
+        Self::from_unix_nanos(
+            at.timestamp() as i128 * NANOS_PER_SEC + at.timestamp_subsec_nanos() as i128,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for FixedClock {
+    fn from(at: time::OffsetDateTime) -> Self {
+        Self::from_unix_nanos(at.unix_timestamp_nanos())
+    }
+}
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+#[cfg(feature = "chrono")]
+pub(crate) fn utc_from_unix_nanos(nanos: i128) -> DateTime<Utc> {
+    let secs = nanos.div_euclid(NANOS_PER_SEC);
+    let subsec = nanos.rem_euclid(NANOS_PER_SEC) as u32;
+    i64::try_from(secs)
+        .ok()
+        .and_then(|secs| Utc.timestamp_opt(secs, subsec).single())
+        .unwrap_or(if nanos < 0 {
+            DateTime::<Utc>::MIN_UTC
+        } else {
+            DateTime::<Utc>::MAX_UTC
+        })
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static TEST_CLOCK: core::cell::RefCell<Option<Arc<dyn Clock>>> = const { core::cell::RefCell::new(None) };
+}
+
+/// Run `f` with `clock` as the current thread's clock.
+///
+/// Rules applied inside `f` without a clock in their [`RuleContext`](crate::RuleContext)
+/// read this clock instead of the system clock, so tests of `past()`, `future()` or
+/// `age_range()` don't depend on when they run. The previous clock is restored when
+/// `f` returns or panics. Other threads are unaffected.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use domainstack::prelude::*;
+/// use domainstack::{with_test_clock, FixedClock};
+///
+/// let day_before_18th_birthday = Utc.with_ymd_and_hms(2024, 6, 14, 12, 0, 0).unwrap();
+/// let birth_date = NaiveDate::from_ymd_opt(2006, 6, 15).unwrap();
+///
+/// with_test_clock(FixedClock::from(day_before_18th_birthday), || {
+///     assert!(!rules::age_range(18, 120).apply(&birth_date).is_empty());
+/// });
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn with_test_clock<R>(clock: impl Clock + 'static, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<dyn Clock>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            TEST_CLOCK.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = TEST_CLOCK.with(|current| current.borrow_mut().replace(Arc::new(clock)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Current time from `clock`, else the thread's test clock, else the system clock.
+#[cfg(feature = "std")]
+pub(crate) fn now_unix_nanos(clock: Option<&dyn Clock>) -> i128 {
+    if let Some(clock) = clock {
+        return clock.now_unix_nanos();
+    }
+    TEST_CLOCK
+        .with(|current| {
+            current
+                .borrow()
+                .as_ref()
+                .map(|clock| clock.now_unix_nanos())
+        })
+        .unwrap_or_else(|| SystemClock.now_unix_nanos())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolution_order() {
+        let context_clock = FixedClock::from_unix_secs(100);
+        assert_eq!(now_unix_nanos(Some(&context_clock)), 100 * NANOS_PER_SEC);
+
+        let before = now_unix_nanos(None);
+        assert!(before > 1_600_000_000 * NANOS_PER_SEC);
+
+        with_test_clock(FixedClock::from_unix_secs(-5), || {
+            assert_eq!(now_unix_nanos(None), -5 * NANOS_PER_SEC);
+            assert_eq!(now_unix_nanos(Some(&context_clock)), 100 * NANOS_PER_SEC);
+
+            with_test_clock(FixedClock::from_unix_secs(7), || {
+                assert_eq!(now_unix_nanos(None), 7 * NANOS_PER_SEC);
+            });
+            assert_eq!(now_unix_nanos(None), -5 * NANOS_PER_SEC);
+        });
+
+        assert!(now_unix_nanos(None) >= before);
+    }
+
+    #[test]
+    fn test_test_clock_restored_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_test_clock(FixedClock::from_unix_secs(0), || panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert!(now_unix_nanos(None) > 0);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_chrono_round_trip() {
+        let at = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let clock = FixedClock::from(at);
+        assert_eq!(clock.now_unix_nanos(), -750_000_000);
+        assert_eq!(clock.now_utc(), at);
+
+        assert_eq!(
+            FixedClock::from_unix_nanos(i128::MAX).now_utc(),
+            DateTime::<Utc>::MAX_UTC
+        );
+    }
+}
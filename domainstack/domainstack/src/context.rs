@@ -1,5 +1,6 @@
-use crate::Path;
-use std::sync::Arc;
+use crate::{Clock, Path};
+use alloc::string::String;
+use alloc::sync::Arc;
 
 /// Context information available to validation rules.
 ///
@@ -49,6 +50,10 @@ pub struct RuleContext {
     /// This can be included in error messages to help users understand what value failed validation.
     /// For sensitive fields (passwords, tokens), this should be `None`.
     pub value_debug: Option<String>,
+
+    // Clock used by time-relative rules such as `rules::past()`; set with
+    // `with_clock`, read with `clock()`. Child contexts inherit it.
+    clock: Option<Arc<dyn Clock>>,
 }
 
 impl RuleContext {
@@ -68,6 +73,7 @@ impl RuleContext {
             field_name: Some(field_name.into()),
             parent_path: Path::root(),
             value_debug: None,
+            clock: None,
         }
     }
 
@@ -88,6 +94,7 @@ impl RuleContext {
             field_name: None,
             parent_path: Path::root(),
             value_debug: None,
+            clock: None,
         }
     }
 
@@ -114,6 +121,7 @@ impl RuleContext {
             field_name: Some(field_name.into()),
             parent_path,
             value_debug: None,
+            clock: self.clock.clone(),
         }
     }
 
@@ -134,6 +142,60 @@ impl RuleContext {
         self
     }
 
+    /// Sets the clock read by time-relative rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domainstack::{FixedClock, RuleContext};
+    ///
+    /// let ctx = RuleContext::root("due_date").with_clock(FixedClock::from_unix_secs(0));
+    /// assert!(ctx.clock().is_some());
+    /// assert!(ctx.child("day").clock().is_some());
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// The clock set with [`with_clock`](Self::with_clock), if any.
+    ///
+    /// When `None`, time-relative rules fall back to the thread's test clock and then
+    /// the system clock; see [`now_unix_nanos`](Self::now_unix_nanos).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domainstack::{FixedClock, RuleContext};
+    ///
+    /// assert!(RuleContext::root("due_date").clock().is_none());
+    ///
+    /// let ctx = RuleContext::root("due_date").with_clock(FixedClock::from_unix_secs(0));
+    /// assert_eq!(ctx.clock().map(|clock| clock.now_unix_nanos()), Some(0));
+    /// ```
+    pub fn clock(&self) -> Option<&dyn Clock> {
+        self.clock.as_deref()
+    }
+
+    /// The current time in nanoseconds since the Unix epoch.
+    ///
+    /// Reads this context's clock if set, else the clock installed by
+    /// [`with_test_clock`](crate::with_test_clock) on the current thread, else the
+    /// system clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domainstack::{FixedClock, RuleContext};
+    ///
+    /// let ctx = RuleContext::anonymous().with_clock(FixedClock::from_unix_secs(60));
+    /// assert_eq!(ctx.now_unix_nanos(), 60_000_000_000);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn now_unix_nanos(&self) -> i128 {
+        crate::clock::now_unix_nanos(self.clock())
+    }
+
     /// Gets the full path to the current field.
     ///
     /// Combines parent_path with field_name to produce the complete path.
@@ -1,6 +1,8 @@
 use crate::{Meta, Path, Violation};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use smallvec::SmallVec;
-use std::collections::BTreeMap;
 
 /// Represents a collection of validation violations.
 ///
@@ -237,7 +239,7 @@ impl ValidationError {
         F: Fn(String) -> String,
     {
         for violation in &mut self.violations {
-            let old_message = std::mem::take(&mut violation.message);
+            let old_message = core::mem::take(&mut violation.message);
             violation.message = f(old_message);
         }
         self
@@ -272,8 +274,8 @@ impl ValidationError {
     }
 }
 
-impl std::fmt::Display for ValidationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.violations.is_empty() {
             write!(f, "No validation errors")
         } else if self.violations.len() == 1 {
@@ -284,6 +286,7 @@ impl std::fmt::Display for ValidationError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ValidationError {}
 
 #[cfg(test)]
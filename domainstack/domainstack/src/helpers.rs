@@ -29,11 +29,9 @@ pub fn validate<T: ?Sized + 'static>(
         path.clone()
     };
 
-    let ctx = RuleContext {
-        field_name,
-        parent_path,
-        value_debug: None,
-    };
+    let mut ctx = RuleContext::anonymous();
+    ctx.field_name = field_name;
+    ctx.parent_path = parent_path;
 
     let err = rule.apply_with_context(value, &ctx);
 
@@ -20,7 +20,7 @@
 //! - **Type-state tracking**: phantom types to enforce "validated" at compile time
 //! - **Schema + client parity**: generate OpenAPI and TypeScript/Zod from the same Rust rules
 //! - **Framework adapters**: one-line boundary extraction (Axum / Actix / Rocket)
-//! - **Lean core**: zero-deps base, opt-in features for regex / async / chrono / serde
+//! - **Lean core**: zero-deps base, opt-in features for regex / async / chrono / time / decimal / phone / json / serde / unicode / url
 //!
 //! ## Quick Start
 //!
@@ -54,7 +54,26 @@
 //! - [Core Concepts](https://github.com/blackwell-systems/domainstack/blob/main/domainstack/domainstack/docs/CORE_CONCEPTS.md) - Foundation principles and patterns
 //! - [Rules Reference](https://github.com/blackwell-systems/domainstack/blob/main/domainstack/domainstack/docs/RULES.md) - All 37 validation rules
 //! - [Examples](https://github.com/blackwell-systems/domainstack/tree/main/domainstack/domainstack-examples) - 9 runnable examples
+//!
+//! ## `no_std` Support
+//!
+//! The core crate works on `core` + `alloc`. Disable default features to drop the
+//! `std` dependency:
+//!
+//! ```toml
+//! domainstack = { version = "1", default-features = false, features = ["regex", "chrono"] }
+//! ```
+//!
+//! Without `std`, `ValidationError` does not implement `std::error::Error`, the
+//! clock-based date/time rules (`past`, `future`, `age_range`, `within`) are unavailable (use
+//! `past_as_of` / `future_as_of`), as is `within_radius`, and the `async`, `url` and
+//! `phone` features cannot be enabled (they imply `std`).
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+mod clock;
 mod context;
 mod error;
 mod helpers;
@@ -68,18 +87,30 @@ mod async_validate;
 
 pub mod prelude;
 pub mod rules;
+pub mod telemetry;
 pub mod typestate;
 
+#[cfg(feature = "std")]
+pub use clock::{with_test_clock, SystemClock};
+pub use clock::{Clock, FixedClock};
 pub use context::RuleContext;
 pub use error::ValidationError;
 pub use helpers::validate;
 pub use path::{Path, PathSegment};
 pub use rule::Rule;
-pub use validate::Validate;
+pub use typestate::ValidateTransition;
+pub use validate::{Validate, ValidateGroup, ValidateWith, DEFAULT_GROUP};
 pub use violation::{Meta, Violation};
 
 #[cfg(feature = "async")]
 pub use async_validate::{AsyncRule, AsyncValidate, ValidationContext};
 
+/// Support items for code generated by `domainstack-derive`. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::rules::cross_field::{DependsOn, ViaOptionValue, ViaValue};
+    pub use crate::validate::{NestedGroup, ViaValidate, ViaValidateGroup};
+}
+
 #[cfg(feature = "derive")]
-pub use domainstack_derive::Validate;
+pub use domainstack_derive::{Validate, ValidatePartial, ValidateTransition};
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Represents a path to a field in a nested structure.
 ///
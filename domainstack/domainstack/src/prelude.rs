@@ -1,4 +1,5 @@
 pub use crate::{
     context::RuleContext, error::ValidationError, helpers::validate, path::Path, path::PathSegment,
-    rule::Rule, rules, validate::Validate, violation::Meta, violation::Violation,
+    rule::Rule, rules, typestate::ValidateTransition, validate::Validate, validate::ValidateGroup,
+    validate::ValidateWith, violation::Meta, violation::Violation,
 };
@@ -1,5 +1,6 @@
 use crate::{Path, RuleContext, ValidationError};
-use std::sync::Arc;
+use alloc::string::String;
+use alloc::sync::Arc;
 
 type RuleFn<T> = Arc<dyn Fn(&T, &RuleContext) -> ValidationError + Send + Sync>;
 
@@ -74,8 +75,8 @@ impl<T: ?Sized> Clone for Rule<T> {
     }
 }
 
-impl<T: ?Sized> std::fmt::Debug for Rule<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: ?Sized> core::fmt::Debug for Rule<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("Rule { <validation closure> }")
     }
 }
@@ -0,0 +1,659 @@
+//! Calendar rules: weekdays, business hours and holidays.
+//!
+//! These work on any [`Temporal`] value. Dates and naive date-times are read as
+//! written; values fixed to an offset are read in their own offset, or in the
+//! [`Zone`] passed to [`within_business_hours`].
+
+use crate::rules::datetime::{wall_time_in, Temporal};
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+// ~2.7 million years either side of the epoch, beyond chrono's and time's ranges
+const MAX_DAYS: i128 = 1_000_000_000;
+
+/// Day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Monday to Friday.
+    pub const WORKDAYS: [Weekday; 5] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+    ];
+
+    /// Saturday and Sunday.
+    pub const WEEKEND: [Weekday; 2] = [Weekday::Saturday, Weekday::Sunday];
+
+    /// English name, e.g. `"Monday"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    fn from_days_since_monday(days: u8) -> Self {
+        match days {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+impl core::fmt::Display for Weekday {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Weekday> for Weekday {
+    fn from(day: chrono::Weekday) -> Self {
+        Self::from_days_since_monday(day.num_days_from_monday() as u8)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Weekday> for Weekday {
+    fn from(day: time::Weekday) -> Self {
+        Self::from_days_since_monday(day.number_days_from_monday())
+    }
+}
+
+/// A proleptic Gregorian calendar date, independent of the date/time crate in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl CalendarDate {
+    /// The date `year-month-day`. Not checked; out-of-range days roll over.
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Day of the week.
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_days_since_monday((self.days_since_epoch() + 3).rem_euclid(7) as u8)
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date `days` days after 1970-01-01 (Howard Hinnant's `civil_from_days`).
+    pub(crate) fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self::new(year as i32, month, day)
+    }
+}
+
+impl core::fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for CalendarDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        Self::new(date.year(), date.month() as u8, date.day() as u8)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for CalendarDate {
+    fn from(date: time::Date) -> Self {
+        Self::new(date.year(), date.month() as u8, date.day())
+    }
+}
+
+/// UTC date and nanoseconds since midnight of an instant.
+pub(crate) fn civil_from_unix_nanos(unix_nanos: i128) -> (CalendarDate, u64) {
+    let days = unix_nanos
+        .div_euclid(NANOS_PER_DAY)
+        .clamp(-MAX_DAYS, MAX_DAYS);
+    let nanos_of_day = unix_nanos.rem_euclid(NANOS_PER_DAY) as u64;
+    (
+        CalendarDate::from_days_since_epoch(days as i64),
+        nanos_of_day,
+    )
+}
+
+/// Nanoseconds since the epoch of a date and time of day read as UTC.
+pub(crate) fn unix_nanos_from_civil(date: &CalendarDate, nanos_of_day: u64) -> i128 {
+    i128::from(date.days_since_epoch()) * NANOS_PER_DAY + i128::from(nanos_of_day)
+}
+
+/// ISO 8601 / RFC 3339 text: fractional seconds only when non-zero, and the
+/// offset as `+hh:mm` when given (`+00:00` for UTC, as chrono's `to_rfc3339`).
+pub(crate) fn format_iso8601(
+    date: &CalendarDate,
+    nanos_of_day: Option<u64>,
+    offset_secs: Option<i32>,
+) -> String {
+    let mut out = date.to_string();
+    if let Some(nanos) = nanos_of_day {
+        let secs = nanos / 1_000_000_000;
+        let subsec = nanos % 1_000_000_000;
+        out.push_str(&format!(
+            "T{:02}:{:02}:{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        ));
+        if subsec % 1_000_000 == 0 && subsec != 0 {
+            out.push_str(&format!(".{:03}", subsec / 1_000_000));
+        } else if subsec % 1_000 == 0 && subsec != 0 {
+            out.push_str(&format!(".{:06}", subsec / 1_000));
+        } else if subsec != 0 {
+            out.push_str(&format!(".{:09}", subsec));
+        }
+    }
+    if let Some(offset) = offset_secs {
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.unsigned_abs();
+        out.push_str(&format!(
+            "{}{:02}:{:02}",
+            sign,
+            offset / 3600,
+            offset / 60 % 60
+        ));
+    }
+    out
+}
+
+/// A time zone: the UTC offset in effect at each instant.
+///
+/// Implemented for chrono's `Utc`, `FixedOffset` and `Local` and for
+/// `time::UtcOffset`. For zones with daylight saving from other crates (such as
+/// `chrono-tz`), implement it on a type of your own.
+pub trait Zone: Send + Sync + 'static {
+    /// Seconds east of UTC at `unix_nanos` nanoseconds since the epoch.
+    fn utc_offset_secs(&self, unix_nanos: i128) -> i32;
+}
+
+#[cfg(feature = "chrono")]
+impl Zone for chrono::Utc {
+    fn utc_offset_secs(&self, _unix_nanos: i128) -> i32 {
+        0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Zone for chrono::FixedOffset {
+    fn utc_offset_secs(&self, _unix_nanos: i128) -> i32 {
+        self.local_minus_utc()
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "std"))]
+impl Zone for chrono::Local {
+    fn utc_offset_secs(&self, unix_nanos: i128) -> i32 {
+        use chrono::{Offset, TimeZone};
+        let utc = crate::clock::utc_from_unix_nanos(unix_nanos).naive_utc();
+        self.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Zone for time::UtcOffset {
+    fn utc_offset_secs(&self, _unix_nanos: i128) -> i32 {
+        self.whole_seconds()
+    }
+}
+
+/// Opening days and hours for [`within_business_hours`].
+///
+/// Times are `(hour, minute)` in the zone passed to the rule. The opening time is
+/// inclusive and the closing time exclusive; use `(24, 0)` to close at midnight.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::{BusinessHours, Weekday};
+///
+/// // Monday to Friday 09:00-17:00
+/// let office = BusinessHours::default();
+///
+/// let shop = BusinessHours::default()
+///     .days([Weekday::Tuesday, Weekday::Wednesday, Weekday::Saturday])
+///     .hours((10, 0), (18, 30));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessHours {
+    /// Days of the week the business is open (default: Monday to Friday)
+    pub days: Vec<Weekday>,
+    /// Opening time, inclusive (default: 09:00)
+    pub opens: (u8, u8),
+    /// Closing time, exclusive (default: 17:00)
+    pub closes: (u8, u8),
+}
+
+impl Default for BusinessHours {
+    fn default() -> Self {
+        Self {
+            days: Weekday::WORKDAYS.to_vec(),
+            opens: (9, 0),
+            closes: (17, 0),
+        }
+    }
+}
+
+impl BusinessHours {
+    /// Open on these days of the week.
+    pub fn days(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.days = days.into_iter().collect();
+        self
+    }
+
+    /// Open from `opens` until `closes`, as `(hour, minute)`.
+    pub fn hours(mut self, opens: (u8, u8), closes: (u8, u8)) -> Self {
+        self.opens = opens;
+        self.closes = closes;
+        self
+    }
+}
+
+fn nanos_since_midnight((hour, minute): (u8, u8)) -> u64 {
+    (u64::from(hour) * 60 + u64::from(minute)) * 60 * 1_000_000_000
+}
+
+fn format_hour_minute((hour, minute): (u8, u8)) -> String {
+    format!("{:02}:{:02}", hour, minute)
+}
+
+/// A set of holidays for [`not_holiday`].
+///
+/// [`Holidays`] covers fixed dates; implement this trait for calendars computed
+/// from rules, such as Easter or "last Monday in May".
+pub trait HolidayCalendar: Send + Sync + 'static {
+    /// The holiday's name if `date` is a holiday.
+    fn holiday(&self, date: &CalendarDate) -> Option<&str>;
+}
+
+/// A [`HolidayCalendar`] of named dates, one-off or recurring every year.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::{CalendarDate, HolidayCalendar, Holidays};
+///
+/// let holidays = Holidays::new()
+///     .every_year(12, 25, "Christmas Day")
+///     .on(2024, 4, 1, "Easter Monday");
+///
+/// assert_eq!(holidays.holiday(&CalendarDate::new(2030, 12, 25)), Some("Christmas Day"));
+/// assert_eq!(holidays.holiday(&CalendarDate::new(2025, 4, 1)), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Holidays {
+    dates: BTreeMap<CalendarDate, String>,
+    annual: BTreeMap<(u8, u8), String>,
+}
+
+impl Holidays {
+    /// An empty calendar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a one-off holiday.
+    pub fn on(mut self, year: i32, month: u8, day: u8, name: impl Into<String>) -> Self {
+        self.dates
+            .insert(CalendarDate::new(year, month, day), name.into());
+        self
+    }
+
+    /// Add a holiday that falls on the same date every year.
+    pub fn every_year(mut self, month: u8, day: u8, name: impl Into<String>) -> Self {
+        self.annual.insert((month, day), name.into());
+        self
+    }
+}
+
+impl HolidayCalendar for Holidays {
+    fn holiday(&self, date: &CalendarDate) -> Option<&str> {
+        self.dates
+            .get(date)
+            .or_else(|| self.annual.get(&(date.month, date.day)))
+            .map(String::as_str)
+    }
+}
+
+/// Validates that a date falls on one of the given days of the week.
+///
+/// Values fixed to an offset are read in that offset; dates and naive
+/// date-times as written.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use domainstack::rules::Weekday;
+/// use chrono::NaiveDate;
+///
+/// let rule = rules::weekday_in(Weekday::WORKDAYS);
+///
+/// assert!(rule.apply(&NaiveDate::from_ymd_opt(2024, 3, 29).unwrap()).is_empty()); // Friday
+/// let err = rule.apply(&NaiveDate::from_ymd_opt(2024, 3, 30).unwrap());
+/// assert_eq!(err.violations[0].meta.get("weekday"), Some("Saturday"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `weekday_not_allowed`
+/// - Message: `"Must fall on Monday, Tuesday, ..."`
+/// - Meta: `{"weekday": "Saturday", "allowed": "Monday,Tuesday,..."}`
+pub fn weekday_in<T: Temporal>(days: impl IntoIterator<Item = Weekday>) -> Rule<T> {
+    let days: Vec<Weekday> = days.into_iter().collect();
+    let names: Vec<&str> = days.iter().map(|day| day.name()).collect();
+    let message = format!("Must fall on {}", names.join(", "));
+    let allowed = names.join(",");
+
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        let weekday = value.date().weekday();
+        if days.contains(&weekday) {
+            ValidationError::default()
+        } else {
+            let mut err =
+                ValidationError::single(ctx.full_path(), "weekday_not_allowed", message.clone());
+            err.violations[0].meta.insert("weekday", weekday.name());
+            err.violations[0].meta.insert("allowed", allowed.clone());
+            err
+        }
+    })
+}
+
+/// Validates that a date-time falls within business hours in a time zone.
+///
+/// Values fixed to an offset are converted to `zone` first; naive date-times are
+/// taken to already be local time in `zone`. Dates only need to fall on an
+/// opening day.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use domainstack::rules::BusinessHours;
+/// use chrono::{FixedOffset, TimeZone, Utc};
+///
+/// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let rule = rules::within_business_hours(new_york, BusinessHours::default());
+///
+/// // 15:00 UTC on a Friday is 10:00 in New York
+/// assert!(rule.apply(&Utc.with_ymd_and_hms(2024, 3, 1, 15, 0, 0).unwrap()).is_empty());
+///
+/// // 23:00 UTC is 18:00, after closing
+/// let err = rule.apply(&Utc.with_ymd_and_hms(2024, 3, 1, 23, 0, 0).unwrap());
+/// assert_eq!(err.violations[0].meta.get("time"), Some("18:00"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `outside_business_hours`
+/// - Message: `"Must be within business hours"`
+/// - Meta: `{"weekday": "Friday", "time": "18:00", "opens": "09:00", "closes": "17:00"}`
+///   (`time` is omitted for dates)
+pub fn within_business_hours<T: Temporal>(zone: impl Zone, hours: BusinessHours) -> Rule<T> {
+    let opens = nanos_since_midnight(hours.opens);
+    let closes = nanos_since_midnight(hours.closes);
+
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        let (date, time) = wall_time_in(value, &zone);
+        let weekday = date.weekday();
+        let open_day = hours.days.contains(&weekday);
+        let open_time = time.map_or(true, |time| time >= opens && time < closes);
+
+        if open_day && open_time {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "outside_business_hours",
+                "Must be within business hours",
+            );
+            let meta = &mut err.violations[0].meta;
+            meta.insert("weekday", weekday.name());
+            if let Some(time) = time {
+                let minutes = time / 60_000_000_000;
+                meta.insert(
+                    "time",
+                    format_hour_minute(((minutes / 60) as u8, (minutes % 60) as u8)),
+                );
+            }
+            meta.insert("opens", format_hour_minute(hours.opens));
+            meta.insert("closes", format_hour_minute(hours.closes));
+            err
+        }
+    })
+}
+
+/// Validates that a date is not a holiday in `calendar`.
+///
+/// Values fixed to an offset are read in that offset; dates and naive
+/// date-times as written.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use domainstack::rules::Holidays;
+/// use chrono::NaiveDate;
+///
+/// let rule = rules::not_holiday(Holidays::new().every_year(1, 1, "New Year's Day"));
+///
+/// assert!(rule.apply(&NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()).is_empty());
+/// let err = rule.apply(&NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+/// assert_eq!(err.violations[0].meta.get("holiday"), Some("New Year's Day"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `holiday`
+/// - Message: `"Must not fall on a holiday"`
+/// - Meta: `{"date": "2025-01-01", "holiday": "New Year's Day"}`
+pub fn not_holiday<T: Temporal>(calendar: impl HolidayCalendar) -> Rule<T> {
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        let date = value.date();
+        match calendar.holiday(&date) {
+            None => ValidationError::default(),
+            Some(name) => {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "holiday",
+                    "Must not fall on a holiday",
+                );
+                err.violations[0].meta.insert("date", date.to_string());
+                err.violations[0].meta.insert("holiday", name.to_string());
+                err
+            }
+        }
+    })
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_civil_conversions_match_chrono() {
+        for days in (-800_000..800_000).step_by(997) {
+            let expected = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+                + chrono::Duration::days(i64::from(days));
+            let civil = CalendarDate::from_days_since_epoch(i64::from(days));
+            assert_eq!(civil, CalendarDate::from(expected));
+            assert_eq!(civil.days_since_epoch(), i64::from(days));
+            assert_eq!(
+                civil.weekday(),
+                Weekday::from(chrono::Datelike::weekday(&expected))
+            );
+        }
+
+        let (day, nanos) = civil_from_unix_nanos(-1);
+        assert_eq!(day, CalendarDate::new(1969, 12, 31));
+        assert_eq!(nanos, 86_400_000_000_000 - 1);
+        assert_eq!(civil_from_unix_nanos(i128::MAX).0.year, 2_739_877);
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        let day = CalendarDate::new(2024, 2, 29);
+        assert_eq!(format_iso8601(&day, None, None), "2024-02-29");
+        assert_eq!(
+            format_iso8601(&day, Some(45_296_000_000_000), Some(0)),
+            "2024-02-29T12:34:56+00:00"
+        );
+        assert_eq!(
+            format_iso8601(&day, Some(1_500_000), Some(-(5 * 3600 + 30 * 60))),
+            "2024-02-29T00:00:00.001500-05:30"
+        );
+    }
+
+    #[test]
+    fn test_weekday_in() {
+        let rule = weekday_in(Weekday::WEEKEND);
+        assert!(rule.apply(&date(2024, 3, 30)).is_empty());
+        assert!(rule.apply(&date(2024, 3, 31)).is_empty());
+
+        let err = rule.apply(&date(2024, 4, 1));
+        assert_eq!(err.violations[0].code, "weekday_not_allowed");
+        assert_eq!(err.violations[0].message, "Must fall on Saturday, Sunday");
+        assert_eq!(
+            err.violations[0].meta.get("allowed"),
+            Some("Saturday,Sunday")
+        );
+
+        // Read in the value's own offset: Saturday 23:30 at -02:00 is Sunday in UTC
+        let offset = FixedOffset::west_opt(2 * 3600).unwrap();
+        let late_saturday = offset.with_ymd_and_hms(2024, 3, 30, 23, 30, 0).unwrap();
+        assert!(weekday_in([Weekday::Saturday])
+            .apply(&late_saturday)
+            .is_empty());
+        assert!(!weekday_in([Weekday::Saturday])
+            .apply(&late_saturday.with_timezone(&Utc))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_within_business_hours() {
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let rule = within_business_hours(tokyo, BusinessHours::default());
+
+        // 00:00 UTC Monday is 09:00 in Tokyo: opening time is inclusive
+        assert!(rule
+            .apply(&Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap())
+            .is_empty());
+        // 08:00 UTC is 17:00: closing time is exclusive
+        let err = rule.apply(&Utc.with_ymd_and_hms(2024, 4, 1, 8, 0, 0).unwrap());
+        assert_eq!(err.violations[0].code, "outside_business_hours");
+        assert_eq!(err.violations[0].meta.get("time"), Some("17:00"));
+        assert_eq!(err.violations[0].meta.get("closes"), Some("17:00"));
+
+        // 15:00 UTC on Friday is already Saturday in Tokyo
+        let err = rule.apply(&Utc.with_ymd_and_hms(2024, 3, 29, 15, 0, 0).unwrap());
+        assert_eq!(err.violations[0].meta.get("weekday"), Some("Saturday"));
+
+        // Naive date-times are already local time
+        let rule = within_business_hours(tokyo, BusinessHours::default().hours((8, 30), (24, 0)));
+        assert!(rule
+            .apply(&date(2024, 4, 1).and_hms_opt(23, 59, 59).unwrap())
+            .is_empty());
+        assert!(!rule
+            .apply(&date(2024, 4, 1).and_hms_opt(8, 29, 0).unwrap())
+            .is_empty());
+
+        // Dates only need an opening day
+        let rule = within_business_hours(Utc, BusinessHours::default());
+        assert!(rule.apply(&date(2024, 4, 1)).is_empty());
+        let err = rule.apply(&date(2024, 3, 31));
+        assert_eq!(err.violations[0].meta.get("weekday"), Some("Sunday"));
+        assert_eq!(err.violations[0].meta.get("time"), None);
+    }
+
+    #[test]
+    fn test_not_holiday() {
+        let holidays =
+            Holidays::new()
+                .every_year(12, 25, "Christmas Day")
+                .on(2024, 4, 1, "Easter Monday");
+        let rule = not_holiday(holidays.clone());
+
+        assert!(rule.apply(&date(2025, 4, 1)).is_empty());
+        let err = rule.apply(&date(2024, 4, 1));
+        assert_eq!(err.violations[0].code, "holiday");
+        assert_eq!(err.violations[0].meta.get("date"), Some("2024-04-01"));
+        assert_eq!(err.violations[0].meta.get("holiday"), Some("Easter Monday"));
+
+        // Read in the value's own offset
+        let christmas_eve_in_utc = Utc.with_ymd_and_hms(2031, 12, 24, 23, 0, 0).unwrap();
+        assert!(not_holiday(holidays.clone())
+            .apply(&christmas_eve_in_utc)
+            .is_empty());
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        assert!(!not_holiday(holidays)
+            .apply(&christmas_eve_in_utc.with_timezone(&paris))
+            .is_empty());
+    }
+}
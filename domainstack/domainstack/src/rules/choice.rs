@@ -1,4 +1,6 @@
 use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::ToString;
 
 /// Validates that a value equals the specified value.
 ///
@@ -29,7 +31,7 @@ use crate::{Rule, RuleContext, ValidationError};
 /// - Meta: `{"expected": "value"}`
 pub fn equals<T>(expected: T) -> Rule<T>
 where
-    T: PartialEq + Clone + std::fmt::Display + Send + Sync + 'static,
+    T: PartialEq + Clone + core::fmt::Display + Send + Sync + 'static,
 {
     Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value == expected {
@@ -77,7 +79,7 @@ where
 /// - Meta: `{"forbidden": "value"}`
 pub fn not_equals<T>(forbidden: T) -> Rule<T>
 where
-    T: PartialEq + Clone + std::fmt::Display + Send + Sync + 'static,
+    T: PartialEq + Clone + core::fmt::Display + Send + Sync + 'static,
 {
     Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value != forbidden {
@@ -128,7 +130,7 @@ where
 /// - Meta: `{"allowed": "[value1, value2, ...]"}`
 pub fn one_of<T>(allowed: &[T]) -> Rule<T>
 where
-    T: PartialEq + Clone + std::fmt::Debug + Send + Sync + 'static,
+    T: PartialEq + Clone + core::fmt::Debug + Send + Sync + 'static,
 {
     let allowed_vec = allowed.to_vec();
 
@@ -1,6 +1,10 @@
 use crate::{Rule, RuleContext, ValidationError};
-use std::collections::HashSet;
-use std::hash::Hash;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
 
 /// Validates that a collection has at least the minimum number of items.
 ///
@@ -80,6 +84,8 @@ pub fn max_items<T: 'static>(max: usize) -> Rule<[T]> {
 /// Validates that all items in a collection are unique (no duplicates).
 ///
 /// Works with any slice type `[T]` where T implements `Eq` and `Hash`.
+/// Without the `std` feature there is no `HashSet`, so duplicates are found
+/// with a quadratic `Eq` scan instead.
 ///
 /// # Examples
 ///
@@ -104,14 +110,7 @@ where
     T: Eq + Hash + 'static,
 {
     Rule::new(|value: &[T], ctx: &RuleContext| {
-        let mut seen = HashSet::new();
-        let mut duplicate_count = 0;
-
-        for item in value {
-            if !seen.insert(item) {
-                duplicate_count += 1;
-            }
-        }
+        let duplicate_count = count_duplicates(value);
 
         if duplicate_count > 0 {
             let mut err = ValidationError::single(
@@ -132,6 +131,21 @@ where
     })
 }
 
+#[cfg(feature = "std")]
+fn count_duplicates<T: Eq + Hash>(value: &[T]) -> usize {
+    let mut seen = std::collections::HashSet::new();
+    value.iter().filter(|item| !seen.insert(*item)).count()
+}
+
+#[cfg(not(feature = "std"))]
+fn count_duplicates<T: Eq>(value: &[T]) -> usize {
+    value
+        .iter()
+        .enumerate()
+        .filter(|(i, item)| value[..*i].contains(item))
+        .count()
+}
+
 /// Validates that all string items in a collection are non-empty.
 ///
 /// This is useful for ensuring no empty strings exist in a `Vec<String>`.
@@ -201,6 +215,375 @@ pub fn non_empty_items() -> Rule<[String]> {
     })
 }
 
+/// Validates that no two items share the key extracted by `key`.
+///
+/// Unlike [`unique`], every repeated item gets its own violation, at
+/// `items[3].email` when `field` is `"email"`, pointing back at the first item
+/// with the same key. Without the `std` feature keys are compared with a
+/// quadratic `Eq` scan.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// struct Member {
+///     email: String,
+/// }
+///
+/// let rule = rules::unique_by("email", |m: &Member| m.email.to_lowercase());
+/// let members = vec![
+///     Member { email: "ada@example.com".to_string() },
+///     Member { email: "grace@example.com".to_string() },
+///     Member { email: "Ada@Example.com".to_string() },
+/// ];
+///
+/// let err = validate("members", members.as_slice(), &rule).unwrap_err();
+/// assert_eq!(err.violations[0].path.to_string(), "members[2].email");
+/// assert_eq!(err.violations[0].meta.get("duplicate_of"), Some("0"));
+/// ```
+///
+/// # Error Code
+/// - Code: `duplicate_item`
+/// - Message: `"Must be unique (same {field} as item {first})"`
+/// - Meta: `{"duplicate_of": "0"}`
+pub fn unique_by<T, K, F>(field: &'static str, key: F) -> Rule<[T]>
+where
+    T: 'static,
+    K: Eq + Hash,
+    F: Fn(&T) -> K + Send + Sync + 'static,
+{
+    Rule::new(move |value: &[T], ctx: &RuleContext| {
+        let keys: Vec<K> = value.iter().map(&key).collect();
+        let mut err = ValidationError::default();
+        for (i, first) in duplicate_indices(&keys) {
+            let mut violation = ValidationError::single(
+                ctx.full_path().index(i).field(field),
+                "duplicate_item",
+                format!("Must be unique (same {} as item {})", field, first),
+            );
+            violation.violations[0]
+                .meta
+                .insert("duplicate_of", first.to_string());
+            err.extend(violation);
+        }
+        err
+    })
+}
+
+/// Pairs each repeated key's index with the index of its first occurrence.
+#[cfg(feature = "std")]
+fn duplicate_indices<K: Eq + Hash>(keys: &[K]) -> Vec<(usize, usize)> {
+    let mut first = std::collections::HashMap::new();
+    keys.iter()
+        .enumerate()
+        .filter_map(|(i, key)| {
+            let first = *first.entry(key).or_insert(i);
+            (first != i).then_some((i, first))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "std"))]
+fn duplicate_indices<K: Eq>(keys: &[K]) -> Vec<(usize, usize)> {
+    keys.iter()
+        .enumerate()
+        .filter_map(|(i, key)| {
+            let first = keys[..i].iter().position(|k| k == key)?;
+            Some((i, first))
+        })
+        .collect()
+}
+
+/// Validates that a collection is sorted according to `compare`.
+///
+/// Each item that `compare` orders before its predecessor gets a violation at
+/// its index. Equal neighbours are allowed.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::sorted_by(|a: &u32, b: &u32| a.cmp(b));
+/// assert!(rule.apply(&[1, 2, 2, 5]).is_empty());
+///
+/// let err = validate("steps", &[1, 3, 2][..], &rule).unwrap_err();
+/// assert_eq!(err.violations[0].path.to_string(), "steps[2]");
+/// ```
+///
+/// # Error Code
+/// - Code: `not_sorted`
+/// - Message: `"Must not come before item {previous}"`
+/// - Meta: `{"previous": "1"}`
+pub fn sorted_by<T, F>(compare: F) -> Rule<[T]>
+where
+    T: 'static,
+    F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+{
+    Rule::new(move |value: &[T], ctx: &RuleContext| {
+        let mut err = ValidationError::default();
+        for (i, pair) in value.windows(2).enumerate() {
+            if compare(&pair[0], &pair[1]) == Ordering::Greater {
+                let mut violation = ValidationError::single(
+                    ctx.full_path().index(i + 1),
+                    "not_sorted",
+                    format!("Must not come before item {}", i),
+                );
+                violation.violations[0]
+                    .meta
+                    .insert("previous", i.to_string());
+                err.extend(violation);
+            }
+        }
+        err
+    })
+}
+
+/// Validates that every item in a collection is one of `allowed`.
+///
+/// The collection counterpart of [`one_of`](super::one_of): each item outside
+/// the set gets a violation at its index.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::subset_of(&["read", "write", "admin"]);
+/// assert!(rule.apply(&["read", "write"]).is_empty());
+///
+/// let err = validate("scopes", &["read", "delete"][..], &rule).unwrap_err();
+/// assert_eq!(err.violations[0].path.to_string(), "scopes[1]");
+/// ```
+///
+/// # Error Code
+/// - Code: `not_in_set`
+/// - Message: `"Must be one of: {allowed:?}"`
+/// - Meta: `{"allowed": "[\"read\", \"write\", \"admin\"]"}`
+pub fn subset_of<T>(allowed: &[T]) -> Rule<[T]>
+where
+    T: PartialEq + Clone + Debug + Send + Sync + 'static,
+{
+    let allowed_vec = allowed.to_vec();
+
+    Rule::new(move |value: &[T], ctx: &RuleContext| {
+        let allowed_str = format!("{:?}", allowed_vec);
+        let mut err = ValidationError::default();
+        for (i, item) in value.iter().enumerate() {
+            if !allowed_vec.contains(item) {
+                let mut violation = ValidationError::single(
+                    ctx.full_path().index(i),
+                    "not_in_set",
+                    format!("Must be one of: {}", allowed_str),
+                );
+                violation.violations[0]
+                    .meta
+                    .insert("allowed", allowed_str.clone());
+                err.extend(violation);
+            }
+        }
+        err
+    })
+}
+
+/// Validates that a collection contains `item`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::contains_item("owner");
+/// assert!(rule.apply(&["owner", "viewer"]).is_empty());
+/// assert!(!rule.apply(&["viewer"]).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `missing_item`
+/// - Message: `"Must contain {item:?}"`
+/// - Meta: `{"item": "\"owner\""}`
+pub fn contains_item<T>(item: T) -> Rule<[T]>
+where
+    T: PartialEq + Debug + Send + Sync + 'static,
+{
+    Rule::new(move |value: &[T], ctx: &RuleContext| {
+        if value.contains(&item) {
+            ValidationError::default()
+        } else {
+            let item_str = format!("{:?}", item);
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "missing_item",
+                format!("Must contain {}", item_str),
+            );
+            err.violations[0].meta.insert("item", item_str);
+            err
+        }
+    })
+}
+
+/// A number [`sum_min`] and [`sum_max`] can add up without wrapping.
+///
+/// Implemented for integers (with checked addition), floats and, with the
+/// `decimal` feature, `rust_decimal::Decimal`.
+pub trait CheckedSum: PartialOrd + Copy + Display + Send + Sync + 'static {
+    /// The total of no items.
+    const ZERO: Self;
+
+    /// `self + other`, or `None` if it overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_sum_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedSum for $ty {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_sum_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_sum_for_floats {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedSum for $ty {
+                const ZERO: Self = 0.0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_sum_for_floats!(f32, f64);
+
+#[cfg(feature = "decimal")]
+impl CheckedSum for rust_decimal::Decimal {
+    const ZERO: Self = rust_decimal::Decimal::ZERO;
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        rust_decimal::Decimal::checked_add(self, other)
+    }
+}
+
+/// The total of `value` over `items`. On overflow the sum stops and the error says
+/// which way it went: `Greater` past the type's maximum, `Less` past its minimum.
+fn checked_total<T, N: CheckedSum>(items: &[T], value: impl Fn(&T) -> N) -> Result<N, Ordering> {
+    items.iter().try_fold(N::ZERO, |total, item| {
+        let item = value(item);
+        total.checked_add(item).ok_or(if item < N::ZERO {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        })
+    })
+}
+
+/// Validates that the values extracted by `value` add up to at least `min`.
+///
+/// A total that overflows below `N`'s minimum fails with `actual` set to
+/// `"overflow"`; one that overflows above its maximum passes.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// struct Split {
+///     percent: u32,
+/// }
+///
+/// let rule = rules::sum_min(|s: &Split| s.percent, 100);
+/// assert!(rule.apply(&[Split { percent: 60 }, Split { percent: 40 }]).is_empty());
+/// assert!(!rule.apply(&[Split { percent: 60 }]).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `sum_too_small`
+/// - Message: `"Total must be at least {min}"`
+/// - Meta: `{"min": "100", "actual": "60"}`
+pub fn sum_min<T, N, F>(value: F, min: N) -> Rule<[T]>
+where
+    T: 'static,
+    N: CheckedSum,
+    F: Fn(&T) -> N + Send + Sync + 'static,
+{
+    Rule::new(move |items: &[T], ctx: &RuleContext| {
+        let actual = match checked_total(items, &value) {
+            Ok(total) if total < min => total.to_string(),
+            Err(Ordering::Less) => "overflow".to_string(),
+            _ => return ValidationError::default(),
+        };
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "sum_too_small",
+            format!("Total must be at least {}", min),
+        );
+        err.violations[0].meta.insert("min", min.to_string());
+        err.violations[0].meta.insert("actual", actual);
+        err
+    })
+}
+
+/// Validates that the values extracted by `value` add up to at most `max`.
+///
+/// A total that overflows `N`, such as two `u32::MAX` quantities, fails with
+/// `actual` set to `"overflow"` instead of wrapping around.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// struct Line {
+///     qty: u32,
+/// }
+///
+/// let rule = rules::sum_max(|l: &Line| l.qty, 100);
+/// assert!(rule.apply(&[Line { qty: 60 }, Line { qty: 40 }]).is_empty());
+///
+/// let err = rule.apply(&[Line { qty: 60 }, Line { qty: 50 }]);
+/// assert_eq!(err.violations[0].code, "sum_too_large");
+/// assert_eq!(err.violations[0].meta.get("actual"), Some("110"));
+/// ```
+///
+/// # Error Code
+/// - Code: `sum_too_large`
+/// - Message: `"Total must be at most {max}"`
+/// - Meta: `{"max": "100", "actual": "110"}`
+pub fn sum_max<T, N, F>(value: F, max: N) -> Rule<[T]>
+where
+    T: 'static,
+    N: CheckedSum,
+    F: Fn(&T) -> N + Send + Sync + 'static,
+{
+    Rule::new(move |items: &[T], ctx: &RuleContext| {
+        let actual = match checked_total(items, &value) {
+            Ok(total) if total > max => total.to_string(),
+            Err(Ordering::Greater) => "overflow".to_string(),
+            _ => return ValidationError::default(),
+        };
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "sum_too_large",
+            format!("Total must be at most {}", max),
+        );
+        err.violations[0].meta.insert("max", max.to_string());
+        err.violations[0].meta.insert("actual", actual);
+        err
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -592,6 +975,106 @@ mod tests {
         assert!(result.violations[0].message.contains("found 2 empty items"));
     }
 
+    #[test]
+    fn test_unique_by_reports_each_duplicate() {
+        let rule = unique_by("email", |(email, _): &(&str, u32)| email.to_lowercase());
+        let items = [("a@x.io", 1), ("b@x.io", 2), ("A@x.io", 3), ("a@x.io", 4)];
+        let result = rule.apply_with_context(&items, &RuleContext::root("items"));
+
+        let found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.path.to_string(), v.meta.get("duplicate_of")))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("items[2].email".to_string(), Some("0")),
+                ("items[3].email".to_string(), Some("0")),
+            ]
+        );
+        assert_eq!(result.violations[0].code, "duplicate_item");
+        assert_eq!(
+            result.violations[0].message,
+            "Must be unique (same email as item 0)"
+        );
+
+        assert!(rule.apply(&[("a@x.io", 1), ("b@x.io", 1)]).is_empty());
+    }
+
+    #[test]
+    fn test_sorted_by() {
+        let rule = sorted_by(|a: &i32, b: &i32| a.cmp(b));
+        assert!(rule.apply(&[]).is_empty());
+        assert!(rule.apply(&[1, 1, 2]).is_empty());
+
+        let result = rule.apply_with_context(&[3, 1, 2, 0], &RuleContext::root("ids"));
+        let paths: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| v.path.to_string())
+            .collect();
+        assert_eq!(paths, ["ids[1]", "ids[3]"]);
+        assert_eq!(result.violations[1].meta.get("previous"), Some("2"));
+
+        let descending = sorted_by(|a: &i32, b: &i32| b.cmp(a));
+        assert!(descending.apply(&[3, 2, 1]).is_empty());
+    }
+
+    #[test]
+    fn test_subset_of_and_contains_item() {
+        let rule = subset_of(&["a", "b"]);
+        assert!(rule.apply(&[]).is_empty());
+        assert!(rule.apply(&["b", "a", "b"]).is_empty());
+
+        let result = rule.apply_with_context(&["a", "c", "d"], &RuleContext::root("tags"));
+        assert_eq!(result.violations.len(), 2);
+        assert_eq!(result.violations[0].path.to_string(), "tags[1]");
+        assert_eq!(result.violations[0].code, "not_in_set");
+
+        let rule = contains_item(7);
+        assert!(rule.apply(&[1, 7]).is_empty());
+        let result = rule.apply(&[1, 2]);
+        assert_eq!(result.violations[0].code, "missing_item");
+        assert_eq!(result.violations[0].meta.get("item"), Some("7"));
+    }
+
+    #[test]
+    fn test_sum_bounds() {
+        let rule = sum_min(|v: &f64| *v, 1.0).and(sum_max(|v: &f64| *v, 1.0));
+        assert!(rule.apply(&[0.25, 0.75]).is_empty());
+
+        let result = rule.apply(&[0.5]);
+        assert_eq!(result.violations[0].code, "sum_too_small");
+        assert_eq!(result.violations[0].meta.get("actual"), Some("0.5"));
+
+        let result = rule.apply(&[0.5, 0.75]);
+        assert_eq!(result.violations[0].code, "sum_too_large");
+
+        // An empty collection sums to zero
+        let result = sum_min(|v: &u32| *v, 1).apply(&[]);
+        assert_eq!(result.violations[0].meta.get("actual"), Some("0"));
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        struct Line {
+            qty: u32,
+        }
+
+        // Wrapping would make this total 1
+        let lines = [Line { qty: u32::MAX }, Line { qty: 2 }];
+        let result = sum_max(|l: &Line| l.qty, 100u32).apply(&lines);
+        assert_eq!(result.violations[0].code, "sum_too_large");
+        assert_eq!(result.violations[0].meta.get("actual"), Some("overflow"));
+        assert!(sum_min(|l: &Line| l.qty, 100u32).apply(&lines).is_empty());
+
+        let result = sum_min(|v: &i8| *v, -10).apply(&[-100, -100]);
+        assert_eq!(result.violations[0].code, "sum_too_small");
+        assert_eq!(result.violations[0].meta.get("actual"), Some("overflow"));
+        assert!(sum_max(|v: &i8| *v, 10).apply(&[-100, -100]).is_empty());
+    }
+
     // Whitespace strings are NOT empty (intentional behavior)
     #[test]
     fn test_non_empty_items_whitespace_is_valid() {
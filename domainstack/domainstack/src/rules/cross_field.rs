@@ -0,0 +1,533 @@
+//! Cross-field rules: presence dependencies and comparisons between sibling fields.
+//!
+//! These rules run on values gathered from several fields of one struct, such as
+//! presence flags or a pair of field values, and report at the field they are about:
+//! `required_if("state", "country")` puts its violation at `state`, not at the struct.
+//! `#[derive(Validate)]` generates the same checks from struct-level attributes.
+//!
+//! A field counts as present when [`Presence::is_present`] says so: `Some` for
+//! options, non-empty for strings and collections.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// Whether a field holds a value, for the presence-based cross-field rules.
+pub trait Presence {
+    /// Returns `true` if the field counts as filled in.
+    fn is_present(&self) -> bool;
+}
+
+impl<T> Presence for Option<T> {
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl Presence for str {
+    fn is_present(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl Presence for String {
+    fn is_present(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> Presence for [T] {
+    fn is_present(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> Presence for Vec<T> {
+    fn is_present(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T: Presence + ?Sized> Presence for &T {
+    fn is_present(&self) -> bool {
+        (**self).is_present()
+    }
+}
+
+/// Validates that `field` is present when a condition on `depends_on` holds.
+///
+/// Applied to `(condition, field_present)`; the violation is reported at `field`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// // `state` is required for US addresses
+/// let rule = rules::required_if("state", "country");
+/// let country = "US";
+/// let state: Option<&str> = None;
+///
+/// let err = validate("address", &(country == "US", state.is_some()), &rule).unwrap_err();
+/// assert_eq!(err.violations[0].path.to_string(), "address.state");
+/// assert!(rule.apply(&(false, false)).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `required`
+/// - Message: `"Required when {depends_on} is set"`
+/// - Meta: `{"depends_on": "country"}`
+pub fn required_if(field: &'static str, depends_on: &'static str) -> Rule<(bool, bool)> {
+    Rule::new(
+        move |&(condition, present): &(bool, bool), ctx: &RuleContext| {
+            if condition && !present {
+                let mut err = ValidationError::single(
+                    ctx.full_path().field(field),
+                    "required",
+                    format!("Required when {} is set", depends_on),
+                );
+                err.violations[0].meta.insert("depends_on", depends_on);
+                err
+            } else {
+                ValidationError::default()
+            }
+        },
+    )
+}
+
+/// Validates that `field` is present when `depends_on` has a specific value.
+///
+/// Like [`required_if`], applied to `(depends_on == value, field_present)`, but the
+/// message and meta name the value.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::required_if_equals("state", "country", "US");
+/// let country = Some("US");
+/// let state: Option<&str> = None;
+///
+/// let err = rule.apply(&(country == Some("US"), state.is_some()));
+/// assert_eq!(err.violations[0].message, "Required when country is US");
+/// assert_eq!(err.violations[0].meta.get("value"), Some("US"));
+/// ```
+///
+/// # Error Code
+/// - Code: `required`
+/// - Message: `"Required when {depends_on} is {value}"`
+/// - Meta: `{"depends_on": "country", "value": "US"}`
+pub fn required_if_equals(
+    field: &'static str,
+    depends_on: &'static str,
+    value: impl Display,
+) -> Rule<(bool, bool)> {
+    let value = value.to_string();
+    Rule::new(
+        move |&(condition, present): &(bool, bool), ctx: &RuleContext| {
+            if condition && !present {
+                let mut err = ValidationError::single(
+                    ctx.full_path().field(field),
+                    "required",
+                    format!("Required when {} is {}", depends_on, value),
+                );
+                err.violations[0].meta.insert("depends_on", depends_on);
+                err.violations[0].meta.insert("value", value.clone());
+                err
+            } else {
+                ValidationError::default()
+            }
+        },
+    )
+}
+
+/// Compares a `depends_on` field with a `required_if` value, used by the derive macro.
+///
+/// `(&&DependsOn(&field)).equals_value(&value)` compares the inner value of an
+/// `Option` field (`None` never matches) and the field itself otherwise.
+#[doc(hidden)]
+pub struct DependsOn<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ViaOptionValue<V: ?Sized> {
+    fn equals_value(&self, value: &V) -> bool;
+}
+
+impl<T: PartialEq<V>, V: ?Sized> ViaOptionValue<V> for &DependsOn<'_, Option<T>> {
+    fn equals_value(&self, value: &V) -> bool {
+        matches!(self.0, Some(inner) if inner == value)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaValue<V: ?Sized> {
+    fn equals_value(&self, value: &V) -> bool;
+}
+
+impl<T: PartialEq<V> + ?Sized, V: ?Sized> ViaValue<V> for DependsOn<'_, T> {
+    fn equals_value(&self, value: &V) -> bool {
+        self.0 == value
+    }
+}
+
+/// Validates that `field` is present unless `other` is.
+///
+/// Applied to `(other_present, field_present)`; the violation is reported at `field`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// // An email is needed unless a phone number was given
+/// let rule = rules::required_unless("email", "phone");
+/// assert!(rule.apply(&(true, false)).is_empty());
+/// assert!(rule.apply(&(false, true)).is_empty());
+/// assert!(!rule.apply(&(false, false)).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `required`
+/// - Message: `"Required unless {other} is set"`
+/// - Meta: `{"unless": "phone"}`
+pub fn required_unless(field: &'static str, other: &'static str) -> Rule<(bool, bool)> {
+    Rule::new(
+        move |&(other_present, present): &(bool, bool), ctx: &RuleContext| {
+            if !other_present && !present {
+                let mut err = ValidationError::single(
+                    ctx.full_path().field(field),
+                    "required",
+                    format!("Required unless {} is set", other),
+                );
+                err.violations[0].meta.insert("unless", other);
+                err
+            } else {
+                ValidationError::default()
+            }
+        },
+    )
+}
+
+/// Validates that `a` and `b` are not both present.
+///
+/// Applied to `(a_present, b_present)`; the violation is reported at `b`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::mutually_exclusive("card", "iban");
+/// assert!(rule.apply(&(true, false)).is_empty());
+/// assert!(rule.apply(&(false, false)).is_empty());
+///
+/// let err = rule.apply(&(true, true));
+/// assert_eq!(err.violations[0].path.to_string(), "iban");
+/// ```
+///
+/// # Error Code
+/// - Code: `mutually_exclusive`
+/// - Message: `"Cannot be set together with {a}"`
+/// - Meta: `{"conflicts_with": "card"}`
+pub fn mutually_exclusive(a: &'static str, b: &'static str) -> Rule<(bool, bool)> {
+    Rule::new(
+        move |&(a_present, b_present): &(bool, bool), ctx: &RuleContext| {
+            if a_present && b_present {
+                conflict(ctx, b, a)
+            } else {
+                ValidationError::default()
+            }
+        },
+    )
+}
+
+/// Validates that at least one of `fields` is present.
+///
+/// Applied to one presence flag per field, in the same order. The violation is
+/// reported at the struct itself, since no single field is at fault.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::at_least_one_of(&["email", "phone"]);
+/// assert!(rule.apply(&[false, true]).is_empty());
+///
+/// let err = rule.apply(&[false, false]);
+/// assert_eq!(err.violations[0].meta.get("fields"), Some("email, phone"));
+/// ```
+///
+/// # Error Code
+/// - Code: `at_least_one_required`
+/// - Message: `"At least one of {fields} is required"`
+/// - Meta: `{"fields": "email, phone"}`
+///
+/// # Panics
+///
+/// Applying the rule panics if the number of flags differs from the number of fields.
+pub fn at_least_one_of(fields: &[&'static str]) -> Rule<[bool]> {
+    let fields = fields.to_vec();
+
+    Rule::new(move |present: &[bool], ctx: &RuleContext| {
+        check_flags(&fields, present);
+        if present.contains(&true) {
+            ValidationError::default()
+        } else {
+            missing_all(ctx, &fields, "at_least_one_required", "At least one of")
+        }
+    })
+}
+
+/// Validates that exactly one of `fields` is present.
+///
+/// Applied to one presence flag per field, in the same order. If none is present
+/// the violation is reported at the struct; if several are, each one after the
+/// first gets a `mutually_exclusive` violation naming the first.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::exactly_one_of(&["card", "iban", "paypal"]);
+/// assert!(rule.apply(&[false, true, false]).is_empty());
+/// assert_eq!(rule.apply(&[false, false, false]).violations[0].code, "exactly_one_required");
+///
+/// let err = rule.apply(&[true, false, true]);
+/// assert_eq!(err.violations[0].path.to_string(), "paypal");
+/// assert_eq!(err.violations[0].code, "mutually_exclusive");
+/// ```
+///
+/// # Error Codes
+/// - Code: `exactly_one_required`
+/// - Message: `"Exactly one of {fields} is required"`
+/// - Meta: `{"fields": "card, iban, paypal"}`
+///
+/// - Code: `mutually_exclusive`
+/// - Message: `"Cannot be set together with {first}"`
+/// - Meta: `{"conflicts_with": "card"}`
+///
+/// # Panics
+///
+/// Applying the rule panics if the number of flags differs from the number of fields.
+pub fn exactly_one_of(fields: &[&'static str]) -> Rule<[bool]> {
+    let fields = fields.to_vec();
+
+    Rule::new(move |present: &[bool], ctx: &RuleContext| {
+        check_flags(&fields, present);
+        let mut set = fields
+            .iter()
+            .zip(present)
+            .filter(|(_, &present)| present)
+            .map(|(field, _)| *field);
+
+        let Some(first) = set.next() else {
+            return missing_all(ctx, &fields, "exactly_one_required", "Exactly one of");
+        };
+        let mut err = ValidationError::default();
+        for field in set {
+            err.extend(conflict(ctx, field, first));
+        }
+        err
+    })
+}
+
+/// Validates that two field values are equal, e.g. a password and its confirmation.
+///
+/// Applied to `(a, b)`; the violation is reported at `b`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::fields_equal("password", "password_confirmation");
+/// assert!(rule.apply(&("hunter2", "hunter2")).is_empty());
+///
+/// let err = rule.apply(&("hunter2", "hunter3"));
+/// assert_eq!(err.violations[0].path.to_string(), "password_confirmation");
+/// ```
+///
+/// # Error Code
+/// - Code: `fields_not_equal`
+/// - Message: `"Must match {a}"`
+/// - Meta: `{"other": "password"}`
+pub fn fields_equal<T>(a: &'static str, b: &'static str) -> Rule<(T, T)>
+where
+    T: PartialEq + 'static,
+{
+    Rule::new(move |(a_value, b_value): &(T, T), ctx: &RuleContext| {
+        if a_value == b_value {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path().field(b),
+                "fields_not_equal",
+                format!("Must match {}", a),
+            );
+            err.violations[0].meta.insert("other", a);
+            err
+        }
+    })
+}
+
+/// Validates that the value of `a` is strictly less than the value of `b`.
+///
+/// Applied to `(a, b)`; the violation is reported at `a`. Values that don't compare,
+/// such as a `NaN`, fail.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::field_lt("min_price", "max_price");
+/// assert!(rule.apply(&(10, 20)).is_empty());
+///
+/// let err = rule.apply(&(20, 20));
+/// assert_eq!(err.violations[0].path.to_string(), "min_price");
+/// ```
+///
+/// # Error Code
+/// - Code: `not_less_than_field`
+/// - Message: `"Must be less than {b}"`
+/// - Meta: `{"other": "max_price"}`
+pub fn field_lt<T>(a: &'static str, b: &'static str) -> Rule<(T, T)>
+where
+    T: PartialOrd + 'static,
+{
+    Rule::new(move |(a_value, b_value): &(T, T), ctx: &RuleContext| {
+        if a_value < b_value {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path().field(a),
+                "not_less_than_field",
+                format!("Must be less than {}", b),
+            );
+            err.violations[0].meta.insert("other", b);
+            err
+        }
+    })
+}
+
+fn check_flags(fields: &[&'static str], present: &[bool]) {
+    assert_eq!(
+        fields.len(),
+        present.len(),
+        "expected one presence flag per field"
+    );
+}
+
+fn conflict(ctx: &RuleContext, field: &'static str, other: &'static str) -> ValidationError {
+    let mut err = ValidationError::single(
+        ctx.full_path().field(field),
+        "mutually_exclusive",
+        format!("Cannot be set together with {}", other),
+    );
+    err.violations[0].meta.insert("conflicts_with", other);
+    err
+}
+
+fn missing_all(
+    ctx: &RuleContext,
+    fields: &[&'static str],
+    code: &'static str,
+    quantifier: &str,
+) -> ValidationError {
+    let list = fields.join(", ");
+    let mut err = ValidationError::single(
+        ctx.full_path(),
+        code,
+        format!("{} {} is required", quantifier, list),
+    );
+    err.violations[0].meta.insert("fields", list);
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presence() {
+        assert!(Some(0).is_present());
+        assert!(!None::<u8>.is_present());
+        assert!(!"".is_present());
+        assert!(String::from("x").is_present());
+        assert!(!Vec::<u8>::new().is_present());
+        assert!([1][..].is_present());
+    }
+
+    #[test]
+    fn test_required_if_and_unless() {
+        let rule = required_if("state", "country");
+        assert!(rule.apply(&(true, true)).is_empty());
+        assert!(rule.apply(&(false, false)).is_empty());
+
+        let err = rule.apply_with_context(&(true, false), &RuleContext::root("address"));
+        assert_eq!(err.violations[0].path.to_string(), "address.state");
+        assert_eq!(err.violations[0].code, "required");
+        assert_eq!(err.violations[0].meta.get("depends_on"), Some("country"));
+
+        let rule = required_if_equals("state", "country", "US");
+        let err = rule.apply(&(true, false));
+        assert_eq!(err.violations[0].message, "Required when country is US");
+        assert_eq!(err.violations[0].meta.get("depends_on"), Some("country"));
+        assert_eq!(err.violations[0].meta.get("value"), Some("US"));
+        assert!(rule.apply(&(false, false)).is_empty());
+
+        let rule = required_unless("email", "phone");
+        let err = rule.apply(&(false, false));
+        assert_eq!(err.violations[0].path.to_string(), "email");
+        assert_eq!(err.violations[0].message, "Required unless phone is set");
+        assert!(rule.apply(&(true, true)).is_empty());
+    }
+
+    #[test]
+    fn test_groups() {
+        let rule = mutually_exclusive("card", "iban");
+        let err = rule.apply(&(true, true));
+        assert_eq!(err.violations[0].path.to_string(), "iban");
+        assert_eq!(err.violations[0].meta.get("conflicts_with"), Some("card"));
+
+        let rule = at_least_one_of(&["email", "phone"]);
+        let err = rule.apply_with_context(&[false, false], &RuleContext::root("contact"));
+        assert_eq!(err.violations[0].path.to_string(), "contact");
+        assert_eq!(
+            err.violations[0].message,
+            "At least one of email, phone is required"
+        );
+
+        let rule = exactly_one_of(&["a", "b", "c"]);
+        assert!(rule.apply(&[false, false, true]).is_empty());
+        let err = rule.apply(&[true, true, true]);
+        let paths: Vec<_> = err.violations.iter().map(|v| v.path.to_string()).collect();
+        assert_eq!(paths, ["b", "c"]);
+        assert_eq!(err.violations[1].meta.get("conflicts_with"), Some("a"));
+    }
+
+    #[test]
+    #[should_panic(expected = "one presence flag per field")]
+    fn test_flag_count_mismatch() {
+        at_least_one_of(&["email", "phone"]).apply(&[true]);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let rule = fields_equal("password", "confirm");
+        assert!(rule.apply(&(1, 1)).is_empty());
+        assert_eq!(rule.apply(&(1, 2)).violations[0].code, "fields_not_equal");
+
+        let rule = field_lt("min", "max");
+        assert!(rule.apply(&(1.0, 2.0)).is_empty());
+        let err = rule.apply(&(2.0, 1.0));
+        assert_eq!(err.violations[0].path.to_string(), "min");
+        assert_eq!(err.violations[0].message, "Must be less than max");
+        assert!(!rule.apply(&(f64::NAN, 1.0)).is_empty());
+    }
+}
@@ -1,8 +1,286 @@
-use crate::{Rule, RuleContext, ValidationError};
-use chrono::{DateTime, Datelike, NaiveDate, Utc};
+//! Date/time rules for chrono and `time` values.
+//!
+//! The rules are generic over [`Temporal`], implemented for chrono's
+//! `DateTime<Tz>`, `NaiveDateTime` and `NaiveDate` (`chrono` feature) and for
+//! `time`'s `OffsetDateTime`, `PrimitiveDateTime` and `Date` (`time` feature).
+//! [`past`], [`future`], [`before`], [`after`] and [`age_range`] keep their
+//! chrono `DateTime<Utc>` / `NaiveDate` signatures; their `temporal_*` forms
+//! accept any [`Temporal`] value.
+//!
+//! Values fixed to an offset are compared as instants. Naive date-times are read
+//! as UTC, and dates compare by calendar day, so a date is "in the past" from the
+//! day after it.
+//!
+//! ```
+//! # #[cfg(feature = "time")] {
+//! use domainstack::prelude::*;
+//! use time::macros::datetime;
+//!
+//! let rule = rules::temporal_before(datetime!(2024-03-29 17:00 UTC))
+//!     .and(rules::date_granularity(core::time::Duration::from_secs(3600)));
+//!
+//! assert!(rule.apply(&datetime!(2024-03-29 09:00 -05:00)).is_empty());
+//! assert!(!rule.apply(&datetime!(2024-03-29 12:00 -05:00)).is_empty());
+//! # }
+//! ```
+
+use crate::rules::calendar::{civil_from_unix_nanos, format_iso8601, unix_nanos_from_civil, Zone};
+use crate::rules::CalendarDate;
+use crate::{FixedClock, Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::time::Duration;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+/// A date or date-time the datetime and calendar rules can validate.
+///
+/// Implemented for the chrono and `time` types listed in the
+/// [module documentation](self), and for [`FixedClock`] so a clock's instant can
+/// be used as an "as of" bound.
+pub trait Temporal: Clone + PartialOrd + Send + Sync + 'static {
+    /// Nanoseconds since 1970-01-01T00:00:00Z, or `None` for naive date-times
+    /// and dates, which don't name an instant.
+    fn unix_nanos(&self) -> Option<i128>;
+
+    /// The calendar date as written, in the value's own offset.
+    fn date(&self) -> CalendarDate;
+
+    /// Nanoseconds since midnight as written, or `None` for dates.
+    fn nanos_of_day(&self) -> Option<u64>;
+
+    /// ISO 8601 form used in error messages and meta.
+    fn to_iso8601(&self) -> String;
+}
+
+/// Position on the UTC timeline, reading naive values as UTC and dates as midnight.
+fn timeline_nanos<T: Temporal>(value: &T) -> i128 {
+    value
+        .unix_nanos()
+        .unwrap_or_else(|| unix_nanos_from_civil(&value.date(), value.nanos_of_day().unwrap_or(0)))
+}
+
+/// Compare `value` with the instant `unix_nanos`; naive values compare with its UTC
+/// date and time, dates with its UTC date.
+fn cmp_instant<T: Temporal>(value: &T, unix_nanos: i128) -> Ordering {
+    if let Some(nanos) = value.unix_nanos() {
+        return nanos.cmp(&unix_nanos);
+    }
+    let (date, time) = civil_from_unix_nanos(unix_nanos);
+    match value.nanos_of_day() {
+        Some(nanos_of_day) => (value.date(), nanos_of_day).cmp(&(date, time)),
+        None => value.date().cmp(&date),
+    }
+}
+
+/// Date and time of day of `value` in `zone`. Naive values and dates are already
+/// local time and are returned as written.
+pub(crate) fn wall_time_in<T: Temporal>(value: &T, zone: &dyn Zone) -> (CalendarDate, Option<u64>) {
+    match value.unix_nanos() {
+        Some(nanos) => {
+            let offset = i128::from(zone.utc_offset_secs(nanos)) * 1_000_000_000;
+            let (date, time) = civil_from_unix_nanos(nanos.saturating_add(offset));
+            (date, Some(time))
+        }
+        None => (value.date(), value.nanos_of_day()),
+    }
+}
+
+/// The current time according to the context's clock (see [`crate::Clock`]).
+#[cfg(feature = "std")]
+fn now(ctx: &RuleContext) -> i128 {
+    ctx.now_unix_nanos()
+}
+
+/// Compact duration text for messages and meta: `"1h30m"`, `"15m"`, `"500ms"`.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let mut secs = duration.as_secs();
+    let mut out = String::new();
+    for (unit, size) in [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)] {
+        if secs >= size {
+            out.push_str(&format!("{}{}", secs / size, unit));
+            secs %= size;
+        }
+    }
+    let nanos = duration.subsec_nanos();
+    if nanos % 1_000_000 == 0 && nanos != 0 {
+        out.push_str(&format!("{}ms", nanos / 1_000_000));
+    } else if nanos % 1_000 == 0 && nanos != 0 {
+        out.push_str(&format!("{}us", nanos / 1_000));
+    } else if nanos != 0 {
+        out.push_str(&format!("{}ns", nanos));
+    }
+    if out.is_empty() {
+        out.push_str("0s");
+    }
+    out
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Temporal for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone + Send + Sync + 'static,
+    Tz::Offset: core::fmt::Display + Send + Sync,
+{
+    fn unix_nanos(&self) -> Option<i128> {
+        Some(
+            i128::from(self.timestamp()) * 1_000_000_000
+                + i128::from(self.timestamp_subsec_nanos()),
+        )
+    }
+
+    fn date(&self) -> CalendarDate {
+        self.date_naive().into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        self.naive_local().nanos_of_day()
+    }
+
+    fn to_iso8601(&self) -> String {
+        self.to_rfc3339()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Temporal for chrono::NaiveDateTime {
+    fn unix_nanos(&self) -> Option<i128> {
+        None
+    }
+
+    fn date(&self) -> CalendarDate {
+        chrono::NaiveDateTime::date(self).into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        use chrono::Timelike;
+        Some(
+            u64::from(self.num_seconds_from_midnight()) * 1_000_000_000
+                + u64::from(self.nanosecond()),
+        )
+    }
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(&Temporal::date(self), Temporal::nanos_of_day(self), None)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Temporal for chrono::NaiveDate {
+    fn unix_nanos(&self) -> Option<i128> {
+        None
+    }
+
+    fn date(&self) -> CalendarDate {
+        (*self).into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        None
+    }
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(&(*self).into(), None, None)
+    }
+}
+
+#[cfg(feature = "time")]
+fn time_nanos_of_day(time: time::Time) -> u64 {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    (u64::from(hour) * 3600 + u64::from(minute) * 60 + u64::from(second)) * 1_000_000_000
+        + u64::from(nanosecond)
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::OffsetDateTime {
+    fn unix_nanos(&self) -> Option<i128> {
+        Some(self.unix_timestamp_nanos())
+    }
+
+    fn date(&self) -> CalendarDate {
+        time::OffsetDateTime::date(*self).into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        Some(time_nanos_of_day(self.time()))
+    }
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(
+            &Temporal::date(self),
+            Temporal::nanos_of_day(self),
+            Some(self.offset().whole_seconds()),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::PrimitiveDateTime {
+    fn unix_nanos(&self) -> Option<i128> {
+        None
+    }
+
+    fn date(&self) -> CalendarDate {
+        time::PrimitiveDateTime::date(*self).into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        Some(time_nanos_of_day(self.time()))
+    }
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(&Temporal::date(self), Temporal::nanos_of_day(self), None)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::Date {
+    fn unix_nanos(&self) -> Option<i128> {
+        None
+    }
+
+    fn date(&self) -> CalendarDate {
+        (*self).into()
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        None
+    }
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(&(*self).into(), None, None)
+    }
+}
+
+impl Temporal for FixedClock {
+    fn unix_nanos(&self) -> Option<i128> {
+        Some(crate::Clock::now_unix_nanos(self))
+    }
+
+    fn date(&self) -> CalendarDate {
+        civil_from_unix_nanos(crate::Clock::now_unix_nanos(self)).0
+    }
+
+    fn nanos_of_day(&self) -> Option<u64> {
+        Some(civil_from_unix_nanos(crate::Clock::now_unix_nanos(self)).1)
+    }
+
+    fn to_iso8601(&self) -> String {
+        let (date, time) = civil_from_unix_nanos(crate::Clock::now_unix_nanos(self));
+        format_iso8601(&date, Some(time), Some(0))
+    }
+}
 
 /// Validates that a datetime is in the past (before now).
 ///
+/// Takes a `DateTime<Utc>`; [`temporal_past`] accepts any [`Temporal`] value.
+///
+/// "Now" comes from the [`RuleContext`] clock, falling back to the thread's test
+/// clock and then the system clock, so it requires the `std` feature. Use
+/// [`past_as_of`] to validate against a fixed instant.
+///
 /// Useful for validating birth dates, historical events, or any datetime
 /// that must have already occurred.
 ///
@@ -26,10 +304,38 @@ use chrono::{DateTime, Datelike, NaiveDate, Utc};
 /// # Error Code
 /// - Code: `not_in_past`
 /// - Message: `"Must be in the past"`
+#[cfg(all(feature = "chrono", feature = "std"))]
 pub fn past() -> Rule<DateTime<Utc>> {
-    Rule::new(|value: &DateTime<Utc>, ctx: &RuleContext| {
-        let now = Utc::now();
-        if *value < now {
+    temporal_past()
+}
+
+/// Generic form of [`past`] for any [`Temporal`] value, such as chrono's
+/// `NaiveDateTime` or `time::OffsetDateTime`.
+///
+/// "Now" comes from the [`RuleContext`] clock, falling back to the thread's test
+/// clock and then the system clock, so it requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{Duration, Utc};
+///
+/// let rule = rules::temporal_past();
+///
+/// assert!(rule.apply(&(Utc::now() - Duration::days(1)).naive_utc()).is_empty());
+/// assert!(!rule.apply(&(Utc::now() + Duration::days(1)).naive_utc()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_in_past`
+/// - Message: `"Must be in the past"`
+#[cfg(feature = "std")]
+pub fn temporal_past<T: Temporal>() -> Rule<T> {
+    Rule::new(|value: &T, ctx: &RuleContext| {
+        if cmp_instant(value, now(ctx)) == Ordering::Less {
             ValidationError::default()
         } else {
             ValidationError::single(ctx.full_path(), "not_in_past", "Must be in the past")
@@ -39,6 +345,11 @@ pub fn past() -> Rule<DateTime<Utc>> {
 
 /// Validates that a datetime is in the future (after now).
 ///
+/// Takes a `DateTime<Utc>`; [`temporal_future`] accepts any [`Temporal`] value.
+///
+/// "Now" comes from the [`RuleContext`] clock, as for [`past`], so it requires
+/// the `std` feature. Use [`future_as_of`] to validate against a fixed instant.
+///
 /// Useful for validating event dates, deadlines, or any datetime
 /// that must not have occurred yet.
 ///
@@ -62,10 +373,37 @@ pub fn past() -> Rule<DateTime<Utc>> {
 /// # Error Code
 /// - Code: `not_in_future`
 /// - Message: `"Must be in the future"`
+#[cfg(all(feature = "chrono", feature = "std"))]
 pub fn future() -> Rule<DateTime<Utc>> {
-    Rule::new(|value: &DateTime<Utc>, ctx: &RuleContext| {
-        let now = Utc::now();
-        if *value > now {
+    temporal_future()
+}
+
+/// Generic form of [`future`] for any [`Temporal`] value.
+///
+/// "Now" comes from the [`RuleContext`] clock, as for [`temporal_past`], so it
+/// requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{Duration, Utc};
+///
+/// let rule = rules::temporal_future();
+///
+/// assert!(rule.apply(&(Utc::now() + Duration::days(1)).date_naive()).is_empty());
+/// assert!(!rule.apply(&(Utc::now() - Duration::days(1)).date_naive()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_in_future`
+/// - Message: `"Must be in the future"`
+#[cfg(feature = "std")]
+pub fn temporal_future<T: Temporal>() -> Rule<T> {
+    Rule::new(|value: &T, ctx: &RuleContext| {
+        if cmp_instant(value, now(ctx)) == Ordering::Greater {
             ValidationError::default()
         } else {
             ValidationError::single(ctx.full_path(), "not_in_future", "Must be in the future")
@@ -73,8 +411,89 @@ pub fn future() -> Rule<DateTime<Utc>> {
     })
 }
 
+/// Validates that a datetime was in the past as of a given instant.
+///
+/// Like [`past`], but "now" is `as_of`: a historical business date, or the time
+/// a request was received. Needs no clock, so it also works without `std`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{TimeZone, Utc};
+///
+/// let close_of_business = Utc.with_ymd_and_hms(2024, 3, 29, 17, 0, 0).unwrap();
+/// let rule = rules::past_as_of(close_of_business);
+///
+/// assert!(rule.apply(&Utc.with_ymd_and_hms(2024, 3, 29, 9, 30, 0).unwrap()).is_empty());
+/// let err = rule.apply(&Utc.with_ymd_and_hms(2024, 3, 29, 17, 0, 0).unwrap());
+/// assert_eq!(err.violations[0].meta.get("as_of"), Some("2024-03-29T17:00:00+00:00"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_in_past`
+/// - Message: `"Must be in the past"`
+/// - Meta: `{"as_of": "2024-03-29T17:00:00+00:00"}`
+pub fn past_as_of<T: Temporal, A: Temporal>(as_of: A) -> Rule<T> {
+    let at = timeline_nanos(&as_of);
+    let as_of = as_of.to_iso8601();
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        if cmp_instant(value, at) == Ordering::Less {
+            ValidationError::default()
+        } else {
+            let mut err =
+                ValidationError::single(ctx.full_path(), "not_in_past", "Must be in the past");
+            err.violations[0].meta.insert("as_of", as_of.clone());
+            err
+        }
+    })
+}
+
+/// Validates that a datetime was in the future as of a given instant.
+///
+/// Like [`future`], but "now" is `as_of`. Needs no clock, so it also works
+/// without `std`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{TimeZone, Utc};
+///
+/// let submitted_at = Utc.with_ymd_and_hms(2024, 3, 29, 17, 0, 0).unwrap();
+/// let rule = rules::future_as_of(submitted_at);
+///
+/// assert!(rule.apply(&Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap()).is_empty());
+/// assert!(!rule.apply(&Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_in_future`
+/// - Message: `"Must be in the future"`
+/// - Meta: `{"as_of": "2024-03-29T17:00:00+00:00"}`
+pub fn future_as_of<T: Temporal, A: Temporal>(as_of: A) -> Rule<T> {
+    let at = timeline_nanos(&as_of);
+    let as_of = as_of.to_iso8601();
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        if cmp_instant(value, at) == Ordering::Greater {
+            ValidationError::default()
+        } else {
+            let mut err =
+                ValidationError::single(ctx.full_path(), "not_in_future", "Must be in the future");
+            err.violations[0].meta.insert("as_of", as_of.clone());
+            err
+        }
+    })
+}
+
 /// Validates that a datetime is before the specified datetime.
 ///
+/// Takes a `DateTime<Utc>`; [`temporal_before`] accepts any [`Temporal`] value.
+///
 /// Useful for validating ranges, ensuring an event occurs before another,
 /// or checking temporal constraints.
 ///
@@ -111,17 +530,43 @@ pub fn future() -> Rule<DateTime<Utc>> {
 /// - Code: `not_before`
 /// - Message: `"Must be before {limit}"`
 /// - Meta: `{"limit": "2025-12-31T23:59:59Z"}`
+#[cfg(feature = "chrono")]
 pub fn before(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
-    Rule::new(move |value: &DateTime<Utc>, ctx: &RuleContext| {
+    temporal_before(limit)
+}
+
+/// Generic form of [`before`] for any [`Temporal`] value.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::NaiveDate;
+///
+/// let rule = rules::temporal_before(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+///
+/// assert!(rule.apply(&NaiveDate::from_ymd_opt(2025, 6, 15).unwrap()).is_empty());
+/// assert!(!rule.apply(&NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_before`
+/// - Message: `"Must be before {limit}"`
+/// - Meta: `{"limit": "2025-12-31"}`
+pub fn temporal_before<T: Temporal>(limit: T) -> Rule<T> {
+    let limit_text = limit.to_iso8601();
+    Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value < limit {
             ValidationError::default()
         } else {
             let mut err = ValidationError::single(
                 ctx.full_path(),
                 "not_before",
-                format!("Must be before {}", limit.to_rfc3339()),
+                format!("Must be before {}", limit_text),
             );
-            err.violations[0].meta.insert("limit", limit.to_rfc3339());
+            err.violations[0].meta.insert("limit", limit_text.clone());
             err
         }
     })
@@ -129,6 +574,8 @@ pub fn before(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
 
 /// Validates that a datetime is after the specified datetime.
 ///
+/// Takes a `DateTime<Utc>`; [`temporal_after`] accepts any [`Temporal`] value.
+///
 /// Useful for validating ranges, ensuring an event occurs after another,
 /// or checking temporal constraints.
 ///
@@ -165,17 +612,43 @@ pub fn before(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
 /// - Code: `not_after`
 /// - Message: `"Must be after {limit}"`
 /// - Meta: `{"limit": "2025-01-01T00:00:00Z"}`
+#[cfg(feature = "chrono")]
 pub fn after(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
-    Rule::new(move |value: &DateTime<Utc>, ctx: &RuleContext| {
+    temporal_after(limit)
+}
+
+/// Generic form of [`after`] for any [`Temporal`] value.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::NaiveDate;
+///
+/// let rule = rules::temporal_after(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+///
+/// assert!(rule.apply(&NaiveDate::from_ymd_opt(2025, 6, 15).unwrap()).is_empty());
+/// assert!(!rule.apply(&NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_after`
+/// - Message: `"Must be after {limit}"`
+/// - Meta: `{"limit": "2025-01-01"}`
+pub fn temporal_after<T: Temporal>(limit: T) -> Rule<T> {
+    let limit_text = limit.to_iso8601();
+    Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value > limit {
             ValidationError::default()
         } else {
             let mut err = ValidationError::single(
                 ctx.full_path(),
                 "not_after",
-                format!("Must be after {}", limit.to_rfc3339()),
+                format!("Must be after {}", limit_text),
             );
-            err.violations[0].meta.insert("limit", limit.to_rfc3339());
+            err.violations[0].meta.insert("limit", limit_text.clone());
             err
         }
     })
@@ -183,6 +656,11 @@ pub fn after(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
 
 /// Validates that a birth date corresponds to an age within the specified range.
 ///
+/// Takes a `NaiveDate`; [`temporal_age_range`] accepts any [`Temporal`] value.
+///
+/// Today's date comes from the [`RuleContext`] clock, as for [`past`], so it
+/// requires the `std` feature.
+///
 /// Calculates age based on the current date and validates it falls within min-max years.
 /// Useful for age verification, eligibility checks, etc.
 ///
@@ -216,11 +694,41 @@ pub fn after(limit: DateTime<Utc>) -> Rule<DateTime<Utc>> {
 /// - Code: `age_out_of_range`
 /// - Message: `"Age must be between {min} and {max} years"`
 /// - Meta: `{"min": "18", "max": "120", "age": "10"}`
-pub fn age_range(min: u32, max: u32) -> Rule<NaiveDate> {
-    Rule::new(move |birth_date: &NaiveDate, ctx: &RuleContext| {
-        let today = Utc::now().date_naive();
+#[cfg(all(feature = "chrono", feature = "std"))]
+pub fn age_range(min: u32, max: u32) -> Rule<chrono::NaiveDate> {
+    temporal_age_range(min, max)
+}
 
-        match calculate_age(*birth_date, today) {
+/// Generic form of [`age_range`] for any [`Temporal`] birth date or date-time.
+///
+/// Today's date comes from the [`RuleContext`] clock, as for [`temporal_past`], so
+/// it requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{Datelike, Utc};
+///
+/// let rule = rules::temporal_age_range(18, 120);
+///
+/// let today = Utc::now().naive_utc();
+/// assert!(rule.apply(&today.with_year(today.year() - 30).unwrap()).is_empty());
+/// assert!(!rule.apply(&today.with_year(today.year() - 10).unwrap()).is_empty());
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `age_out_of_range`
+/// - Message: `"Age must be between {min} and {max} years"`
+/// - Meta: `{"min": "18", "max": "120", "age": "10"}`
+#[cfg(feature = "std")]
+pub fn temporal_age_range<T: Temporal>(min: u32, max: u32) -> Rule<T> {
+    Rule::new(move |birth_date: &T, ctx: &RuleContext| {
+        let today = civil_from_unix_nanos(now(ctx)).0;
+
+        match calculate_age(birth_date.date(), today) {
             None => {
                 // Birth date is in the future - invalid
                 let mut err = ValidationError::single(
@@ -230,7 +738,7 @@ pub fn age_range(min: u32, max: u32) -> Rule<NaiveDate> {
                 );
                 err.violations[0]
                     .meta
-                    .insert("birth_date", birth_date.to_string());
+                    .insert("birth_date", birth_date.to_iso8601());
                 err
             }
             Some(age) if age >= min && age <= max => ValidationError::default(),
@@ -249,20 +757,124 @@ pub fn age_range(min: u32, max: u32) -> Rule<NaiveDate> {
     })
 }
 
+/// Validates that a date-time is within `max` of now, in either direction.
+///
+/// "Now" comes from the [`RuleContext`] clock, as for [`past`], so it requires the
+/// `std` feature. Combine with [`past`] or [`future`] for a one-sided window, such
+/// as "seen in the last 24 hours" or "booked at most 30 days ahead".
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::{Duration, Utc};
+///
+/// let rule = rules::within(core::time::Duration::from_secs(24 * 3600));
+///
+/// assert!(rule.apply(&(Utc::now() - Duration::hours(23))).is_empty());
+/// let err = rule.apply(&(Utc::now() + Duration::hours(25)));
+/// assert_eq!(err.violations[0].meta.get("max"), Some("1d"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `not_within`
+/// - Message: `"Must be within {max} of now"`
+/// - Meta: `{"max": "1d"}`
+#[cfg(feature = "std")]
+pub fn within<T: Temporal>(max: Duration) -> Rule<T> {
+    let max_nanos = max.as_nanos();
+    let max_text = format_duration(max);
+    Rule::new(move |value: &T, ctx: &RuleContext| {
+        let distance = timeline_nanos(value)
+            .saturating_sub(now(ctx))
+            .unsigned_abs();
+        if distance <= max_nanos {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "not_within",
+                format!("Must be within {} of now", max_text),
+            );
+            err.violations[0].meta.insert("max", max_text.clone());
+            err
+        }
+    })
+}
+
+/// Validates that a time of day falls on a multiple of `step` since midnight,
+/// e.g. "on the hour" or "on a 15-minute slot".
+///
+/// The time is read as written, in the value's own offset. Steps should divide a
+/// day evenly; dates always pass.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use domainstack::prelude::*;
+/// use chrono::NaiveDate;
+///
+/// let rule = rules::date_granularity(core::time::Duration::from_secs(3600));
+///
+/// let slot = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+/// assert!(rule.apply(&slot.and_hms_opt(14, 0, 0).unwrap()).is_empty());
+///
+/// let err = rule.apply(&slot.and_hms_opt(14, 30, 0).unwrap());
+/// assert_eq!(err.violations[0].meta.get("granularity"), Some("1h"));
+/// # }
+/// ```
+///
+/// # Error Code
+/// - Code: `granularity_mismatch`
+/// - Message: `"Must be on a {granularity} boundary"`
+/// - Meta: `{"granularity": "1h"}`
+pub fn date_granularity<T: Temporal>(step: Duration) -> Rule<T> {
+    assert!(!step.is_zero(), "date_granularity: step cannot be zero");
+    let step_nanos = step.as_nanos();
+    let step_text = format_duration(step);
+    Rule::new(
+        move |value: &T, ctx: &RuleContext| match value.nanos_of_day() {
+            Some(nanos) if u128::from(nanos) % step_nanos != 0 => {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "granularity_mismatch",
+                    format!("Must be on a {} boundary", step_text),
+                );
+                err.violations[0]
+                    .meta
+                    .insert("granularity", step_text.clone());
+                err
+            }
+            _ => ValidationError::default(),
+        },
+    )
+}
+
 /// Helper function to calculate age from birth date to a given date.
 /// Returns None if birth_date is in the future (invalid age).
-fn calculate_age(birth_date: NaiveDate, current_date: NaiveDate) -> Option<u32> {
+#[cfg(feature = "std")]
+fn calculate_age(
+    birth_date: impl Into<CalendarDate>,
+    current_date: impl Into<CalendarDate>,
+) -> Option<u32> {
+    let (birth_date, current_date) = (birth_date.into(), current_date.into());
+
     // Handle future birth dates gracefully
     if birth_date > current_date {
         return None;
     }
 
-    let mut age = current_date.year() - birth_date.year();
+    let mut age = current_date.year - birth_date.year;
 
     // Adjust if birthday hasn't occurred yet this year
-    if current_date.month() < birth_date.month()
-        || (current_date.month() == birth_date.month() && current_date.day() < birth_date.day())
-    {
+    if (current_date.month, current_date.day) < (birth_date.month, birth_date.day) {
         age -= 1;
     }
 
@@ -270,10 +882,10 @@ fn calculate_age(birth_date: NaiveDate, current_date: NaiveDate) -> Option<u32>
     Some(age as u32)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std", feature = "chrono"))]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
 
     #[test]
     fn test_past_valid() {
@@ -428,6 +1040,74 @@ mod tests {
         assert!(result.violations[0].meta.get("birth_date").is_some());
     }
 
+    #[test]
+    fn test_as_of_rules() {
+        let as_of = Utc::now() - Duration::days(3650);
+        let day_before = as_of - Duration::days(1);
+        let day_after = as_of + Duration::days(1);
+
+        assert!(past_as_of(as_of).apply(&day_before).is_empty());
+        assert_eq!(
+            past_as_of(as_of).apply(&day_after).violations[0].code,
+            "not_in_past"
+        );
+        assert_eq!(
+            past_as_of(as_of).apply(&as_of).violations[0].code,
+            "not_in_past"
+        );
+
+        assert!(future_as_of(as_of).apply(&day_after).is_empty());
+        let err = future_as_of(as_of).apply(&day_before);
+        assert_eq!(err.violations[0].code, "not_in_future");
+        assert_eq!(
+            err.violations[0].meta.get("as_of"),
+            Some(as_of.to_rfc3339().as_str())
+        );
+    }
+
+    #[test]
+    fn test_rules_read_context_clock() {
+        let new_year = NaiveDate::from_ymd_opt(2030, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        let ctx = RuleContext::root("at").with_clock(crate::FixedClock::from(new_year));
+
+        let later = new_year + Duration::seconds(1);
+        assert!(future().apply_with_context(&later, &ctx).is_empty());
+        assert!(!past().apply_with_context(&later, &ctx).is_empty());
+
+        // 2012-01-01 turns 18 on the context's date, not today
+        let birth_date = NaiveDate::from_ymd_opt(2012, 1, 1).unwrap();
+        assert!(age_range(18, 120)
+            .apply_with_context(&birth_date, &ctx)
+            .is_empty());
+        let ctx = ctx.with_clock(crate::FixedClock::from(new_year - Duration::seconds(1)));
+        let err = age_range(18, 120).apply_with_context(&birth_date, &ctx);
+        assert_eq!(err.violations[0].meta.get("age"), Some("17"));
+    }
+
+    #[test]
+    fn test_rules_read_test_clock() {
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc();
+        crate::with_test_clock(crate::FixedClock::from(leap_day), || {
+            assert!(past().apply(&(leap_day - Duration::seconds(1))).is_empty());
+            assert!(!past().apply(&leap_day).is_empty());
+            assert!(future()
+                .apply(&(leap_day + Duration::seconds(1)))
+                .is_empty());
+
+            let born_leap_day = NaiveDate::from_ymd_opt(2006, 2, 28).unwrap();
+            let err = age_range(19, 120).apply(&born_leap_day);
+            assert_eq!(err.violations[0].meta.get("age"), Some("18"));
+        });
+    }
+
     #[test]
     fn test_calculate_age() {
         let birth_date = NaiveDate::from_ymd_opt(2000, 6, 15).unwrap();
@@ -450,6 +1130,189 @@ mod tests {
         assert_eq!(calculate_age(future_birth, current), None);
     }
 
+    #[test]
+    fn test_naive_and_zoned_values() {
+        use chrono::{FixedOffset, TimeZone};
+
+        let noon = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        crate::with_test_clock(crate::FixedClock::from(noon.and_utc()), || {
+            // Naive date-times are read as UTC
+            assert!(temporal_past()
+                .apply(&(noon - Duration::seconds(1)))
+                .is_empty());
+            assert!(!temporal_past().apply(&noon).is_empty());
+            assert!(temporal_future()
+                .apply(&(noon + Duration::seconds(1)))
+                .is_empty());
+
+            // Dates compare by day: today is neither past nor future
+            assert!(temporal_past()
+                .apply(&noon.date().pred_opt().unwrap())
+                .is_empty());
+            assert!(!temporal_past().apply(&noon.date()).is_empty());
+            assert!(!temporal_future().apply(&noon.date()).is_empty());
+
+            // Zoned values compare as instants: 13:00+02:00 is 11:00 UTC
+            let berlin = FixedOffset::east_opt(2 * 3600).unwrap();
+            let local = berlin.with_ymd_and_hms(2024, 2, 29, 13, 0, 0).unwrap();
+            assert!(temporal_past().apply(&local).is_empty());
+        });
+
+        let limit = noon - Duration::days(1);
+        let err = temporal_after(limit).apply(&limit);
+        assert_eq!(
+            err.violations[0].meta.get("limit"),
+            Some("2024-02-28T12:00:00")
+        );
+        assert!(temporal_before(noon.date()).apply(&limit.date()).is_empty());
+    }
+
+    #[test]
+    fn test_chrono_signatures_infer_without_annotations() {
+        // `past`, `future`, `before`, `after` and `age_range` fix their types, so
+        // rules bound before use need no annotation.
+        let past_rule = past();
+        let future_rule = future();
+        let before_rule = before(Utc::now());
+        let after_rule = after(Utc::now() - Duration::days(1));
+        let age_rule = age_range(18, 120);
+
+        let _: (&Rule<DateTime<Utc>>, &Rule<NaiveDate>) = (&past_rule, &age_rule);
+        assert!(past_rule
+            .apply(&(Utc::now() - Duration::days(1)))
+            .is_empty());
+        assert!(future_rule
+            .apply(&(Utc::now() + Duration::days(1)))
+            .is_empty());
+        assert!(before_rule
+            .and(after_rule)
+            .apply(&(Utc::now() - Duration::hours(1)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_as_of_accepts_other_types() {
+        let clock = crate::FixedClock::from_unix_secs(1_700_000_000);
+        let at = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+        let err = past_as_of(clock).apply(&at);
+        assert_eq!(
+            err.violations[0].meta.get("as_of"),
+            Some("2023-11-14T22:13:20+00:00")
+        );
+        // Dates compare by day
+        assert!(!past_as_of(clock).apply(&at.date_naive()).is_empty());
+        assert!(past_as_of(clock)
+            .apply(&at.date_naive().pred_opt().unwrap())
+            .is_empty());
+        assert!(future_as_of(at.date_naive()).apply(&at).is_empty());
+    }
+
+    #[test]
+    fn test_within() {
+        let now = NaiveDate::from_ymd_opt(2024, 3, 29)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc();
+        let rule = within(core::time::Duration::from_secs(90 * 60));
+        crate::with_test_clock(crate::FixedClock::from(now), || {
+            assert!(rule.apply(&(now + Duration::minutes(90))).is_empty());
+            assert!(rule.apply(&(now - Duration::minutes(90))).is_empty());
+
+            let err = rule.apply(&(now - Duration::minutes(91)));
+            assert_eq!(err.violations[0].code, "not_within");
+            assert_eq!(err.violations[0].message, "Must be within 1h30m of now");
+
+            assert!(within(core::time::Duration::from_secs(90 * 60))
+                .apply(&now.naive_utc())
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn test_date_granularity() {
+        let day = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+        let quarter_hour = date_granularity(core::time::Duration::from_secs(15 * 60));
+
+        assert!(quarter_hour
+            .apply(&day.and_hms_opt(9, 45, 0).unwrap())
+            .is_empty());
+        let err = quarter_hour.apply(&day.and_hms_opt(9, 45, 1).unwrap());
+        assert_eq!(err.violations[0].code, "granularity_mismatch");
+        assert_eq!(err.violations[0].message, "Must be on a 15m boundary");
+        assert!(date_granularity(core::time::Duration::from_secs(15 * 60))
+            .apply(&day)
+            .is_empty());
+
+        let rule = date_granularity(core::time::Duration::from_millis(1500));
+        let err = rule.apply(&day.and_hms_opt(0, 0, 1).unwrap().and_utc());
+        assert_eq!(err.violations[0].meta.get("granularity"), Some("1s500ms"));
+    }
+
+    #[test]
+    #[should_panic(expected = "step cannot be zero")]
+    fn test_date_granularity_zero_step() {
+        let _ = date_granularity::<NaiveDate>(core::time::Duration::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_time_crate_values() {
+        use time::macros::datetime;
+        use time::{Date, Month};
+
+        let date = |year, month, day| Date::from_calendar_date(year, month, day).unwrap();
+
+        let clock = crate::FixedClock::from(datetime!(2024-03-29 12:00 UTC));
+        crate::with_test_clock(clock, || {
+            assert!(temporal_past()
+                .apply(&datetime!(2024-03-29 13:00 +02:00))
+                .is_empty());
+            assert!(!temporal_past()
+                .apply(&datetime!(2024-03-29 12:00 UTC))
+                .is_empty());
+            assert!(temporal_future()
+                .apply(&datetime!(2024-03-29 12:00:01))
+                .is_empty());
+            assert!(temporal_past()
+                .apply(&date(2024, Month::March, 28))
+                .is_empty());
+            assert!(!temporal_past()
+                .apply(&date(2024, Month::March, 29))
+                .is_empty());
+
+            let err = temporal_age_range(18, 120).apply(&date(2006, Month::March, 30));
+            assert_eq!(err.violations[0].meta.get("age"), Some("17"));
+        });
+
+        let err = temporal_before(datetime!(2024-03-29 17:00:00.25 -05:00))
+            .apply(&datetime!(2024-03-30 00:00 +01:00));
+        assert_eq!(
+            err.violations[0].meta.get("limit"),
+            Some("2024-03-29T17:00:00.250-05:00")
+        );
+        assert_eq!(
+            temporal_after(date(2024, Month::March, 29))
+                .apply(&date(2024, Month::March, 29))
+                .violations[0]
+                .meta
+                .get("limit"),
+            Some("2024-03-29")
+        );
+
+        let on_the_hour = date_granularity(core::time::Duration::from_secs(3600));
+        assert!(on_the_hour
+            .apply(&datetime!(2024-03-29 09:00 +05:30))
+            .is_empty());
+        assert!(!date_granularity(core::time::Duration::from_secs(3600))
+            .apply(&datetime!(2024-03-29 09:30))
+            .is_empty());
+    }
+
     #[test]
     fn test_before_after_composition() {
         // Event must be within a specific window
@@ -0,0 +1,209 @@
+//! Rules for `rust_decimal::Decimal` (`decimal` feature): digit precision, scale,
+//! range and step checks for money and measurement fields.
+//!
+//! Precision and scale follow SQL `NUMERIC(p, s)`: precision counts all significant
+//! digits and scale the digits after the decimal point. Trailing fractional zeros
+//! don't count, so `12.50` has precision 3 and scale 1.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::ToString;
+
+/// Re-exported so callers and derive output can name the type without a direct
+/// `rust_decimal` dependency.
+pub use rust_decimal::Decimal;
+
+/// Validates that a decimal has at most `max_digits` significant digits.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::decimal::{self, Decimal};
+///
+/// let rule = decimal::precision(5);
+/// assert!(rule.apply(&Decimal::new(99999, 2)).is_empty());   // 999.99
+/// assert!(rule.apply(&Decimal::new(123450, 3)).is_empty());  // 123.450
+/// assert!(!rule.apply(&Decimal::new(100001, 2)).is_empty()); // 1000.01
+/// ```
+///
+/// # Error Code
+/// - Code: `precision_exceeded`
+/// - Message: `"Must have at most {max_digits} digits"`
+/// - Meta: `{"max": "5", "actual": "6"}`
+pub fn precision(max_digits: u32) -> Rule<Decimal> {
+    Rule::new(move |value: &Decimal, ctx: &RuleContext| {
+        let actual = digits(value);
+        if actual <= max_digits {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "precision_exceeded",
+                format!("Must have at most {} digits", max_digits),
+            );
+            err.violations[0].meta.insert("max", max_digits.to_string());
+            err.violations[0].meta.insert("actual", actual.to_string());
+            err
+        }
+    })
+}
+
+/// Validates that a decimal has at most `max` digits after the decimal point.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::decimal::{self, Decimal};
+///
+/// let rule = decimal::scale(2);
+/// assert!(rule.apply(&Decimal::new(1999, 2)).is_empty());   // 19.99
+/// assert!(rule.apply(&Decimal::new(19900, 3)).is_empty());  // 19.900
+/// assert!(!rule.apply(&Decimal::new(19999, 3)).is_empty()); // 19.999
+/// ```
+///
+/// # Error Code
+/// - Code: `scale_exceeded`
+/// - Message: `"Must have at most {max} decimal places"`
+/// - Meta: `{"max": "2", "actual": "3"}`
+pub fn scale(max: u32) -> Rule<Decimal> {
+    Rule::new(move |value: &Decimal, ctx: &RuleContext| {
+        let actual = value.normalize().scale();
+        if actual <= max {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "scale_exceeded",
+                format!("Must have at most {} decimal places", max),
+            );
+            err.violations[0].meta.insert("max", max.to_string());
+            err.violations[0].meta.insert("actual", actual.to_string());
+            err
+        }
+    })
+}
+
+/// Validates that a decimal is within `min..=max`.
+///
+/// Same checks and error as [`rules::range`](crate::rules::range), typed for `Decimal`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::decimal::{self, Decimal};
+///
+/// let rule = decimal::range(Decimal::ZERO, Decimal::new(10000, 2));
+/// assert!(rule.apply(&Decimal::new(4250, 2)).is_empty());
+/// assert!(!rule.apply(&Decimal::new(-1, 2)).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `out_of_range`
+/// - Message: `"Must be between {min} and {max}"`
+/// - Meta: `{"min": "0", "max": "100.00"}`
+pub fn range(min: Decimal, max: Decimal) -> Rule<Decimal> {
+    crate::rules::range(min, max)
+}
+
+/// Validates that a decimal is a whole multiple of `step`, such as a price in
+/// 0.05 increments.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::decimal::{self, Decimal};
+///
+/// let rule = decimal::multiple_of(Decimal::new(5, 2)); // 0.05
+/// assert!(rule.apply(&Decimal::new(1995, 2)).is_empty());
+/// assert!(!rule.apply(&Decimal::new(1999, 2)).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `not_multiple`
+/// - Message: `"Must be a multiple of {step}"`
+/// - Meta: `{"divisor": "0.05"}`
+///
+/// # Panics
+/// Panics if `step` is zero.
+pub fn multiple_of(step: Decimal) -> Rule<Decimal> {
+    crate::rules::multiple_of(step)
+}
+
+/// Significant digits, ignoring trailing fractional zeros: `0.05` has 2, `1200` has 4.
+fn digits(value: &Decimal) -> u32 {
+    let normalized = value.normalize();
+    let mut mantissa = normalized.mantissa().unsigned_abs();
+    let mut count = 1;
+    while mantissa >= 10 {
+        mantissa /= 10;
+        count += 1;
+    }
+    count.max(normalized.scale())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(num: i64, scale: u32) -> Decimal {
+        Decimal::new(num, scale)
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(&Decimal::ZERO), 1);
+        assert_eq!(digits(&dec(5, 2)), 2);
+        assert_eq!(digits(&dec(1250, 2)), 3);
+        assert_eq!(digits(&dec(1200, 0)), 4);
+        assert_eq!(digits(&dec(-99999, 3)), 5);
+        assert_eq!(digits(&Decimal::MAX), 29);
+    }
+
+    #[test]
+    fn test_precision() {
+        let rule = precision(4);
+        assert!(rule.apply(&dec(99990, 3)).is_empty());
+        assert!(rule.apply(&dec(-1, 4)).is_empty());
+
+        let err = rule.apply(&dec(-12345, 2));
+        assert_eq!(err.violations[0].code, "precision_exceeded");
+        assert_eq!(err.violations[0].message, "Must have at most 4 digits");
+        assert_eq!(err.violations[0].meta.get("actual"), Some("5"));
+
+        // 0.00001 has five fractional digits
+        assert!(!rule.apply(&dec(1, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_scale() {
+        let rule = scale(2);
+        assert!(rule.apply(&dec(100, 0)).is_empty());
+        assert!(rule.apply(&dec(12_000, 4)).is_empty());
+
+        let err = rule.apply(&dec(-1, 3));
+        assert_eq!(err.violations[0].code, "scale_exceeded");
+        assert_eq!(err.violations[0].meta.get("max"), Some("2"));
+        assert_eq!(err.violations[0].meta.get("actual"), Some("3"));
+    }
+
+    #[test]
+    fn test_range_and_multiple_of() {
+        let rule = range(dec(-50, 1), dec(50, 1)).and(multiple_of(dec(25, 2)));
+        assert!(rule.apply(&dec(-500, 2)).is_empty());
+        assert!(rule.apply(&dec(275, 2)).is_empty());
+
+        let err = rule.apply(&dec(51, 1));
+        assert_eq!(err.violations[0].code, "out_of_range");
+        assert_eq!(err.violations[0].meta.get("max"), Some("5.0"));
+
+        let err = rule.apply(&dec(26, 1));
+        assert_eq!(err.violations[0].code, "not_multiple");
+        assert_eq!(err.violations[0].meta.get("divisor"), Some("0.25"));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor cannot be zero")]
+    fn test_multiple_of_zero() {
+        multiple_of(Decimal::ZERO);
+    }
+}
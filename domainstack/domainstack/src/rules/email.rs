@@ -0,0 +1,521 @@
+//! Email address rules: an RFC 5321/5322 mailbox parser with configurable
+//! strictness.
+//!
+//! Addresses are parsed by hand, so `email()` works without the `regex`
+//! feature. The accepted syntax is the SMTP mailbox (`local-part@domain`)
+//! without comments or folding whitespace, which RFC 5321 does not allow on
+//! the wire. Internationalized domains are checked in their Punycode form,
+//! and UTF-8 local parts follow RFC 6531.
+
+use crate::rules::{idna, network};
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Maximum length of a local part in octets (RFC 5321, section 4.5.3.1.1).
+const MAX_LOCAL_LEN: usize = 64;
+
+/// Maximum length of a whole address in octets (RFC 5321 path limit minus the angle brackets).
+const MAX_EMAIL_LEN: usize = 254;
+
+/// Options for [`email_with`].
+///
+/// The defaults accept what mail providers commonly issue: dot-atom local
+/// parts (including `+` tags and UTF-8), host name domains with at least two
+/// labels, and any TLD. Quoted local parts and IP-literal domains are valid
+/// RFC 5321 but rejected by default, since few real mailboxes use them.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::EmailOptions;
+///
+/// let options = EmailOptions {
+///     allow_plus_addressing: false,
+///     ..EmailOptions::default()
+/// }
+/// .deny_tld("zip")
+/// .deny_tld("mov");
+/// assert_eq!(options.denied_tlds, ["zip", "mov"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailOptions {
+    /// Accept `user@[192.0.2.1]` and `user@[IPv6:2001:db8::1]`.
+    pub allow_ip_literal: bool,
+    /// Accept quoted local parts such as `"john doe"@example.com`.
+    pub allow_quoted_local: bool,
+    /// Accept `+` in the local part, as in `jane+newsletter@example.com`.
+    pub allow_plus_addressing: bool,
+    /// Accept non-ASCII characters in the local part (RFC 6531).
+    pub allow_utf8_local: bool,
+    /// If non-empty, only these top-level domains are accepted.
+    pub allowed_tlds: Vec<String>,
+    /// Top-level domains that are never accepted.
+    pub denied_tlds: Vec<String>,
+}
+
+impl Default for EmailOptions {
+    fn default() -> Self {
+        Self {
+            allow_ip_literal: false,
+            allow_quoted_local: false,
+            allow_plus_addressing: true,
+            allow_utf8_local: true,
+            allowed_tlds: Vec::new(),
+            denied_tlds: Vec::new(),
+        }
+    }
+}
+
+impl EmailOptions {
+    /// Add a top-level domain to the allow list, e.g. `"com"` or `".de"`.
+    pub fn allow_tld(mut self, tld: impl Into<String>) -> Self {
+        self.allowed_tlds.push(tld.into());
+        self
+    }
+
+    /// Add a top-level domain to the deny list.
+    pub fn deny_tld(mut self, tld: impl Into<String>) -> Self {
+        self.denied_tlds.push(tld.into());
+        self
+    }
+}
+
+/// Validates that a string is an email address, using [`EmailOptions::default`].
+///
+/// No `regex` feature is needed. See [`email_with`] for the accepted syntax
+/// and the failure reasons.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::email();
+/// assert!(rule.apply("user@example.com").is_empty());
+/// assert!(rule.apply("jane+news@bücher.de").is_empty());
+/// assert!(!rule.apply("invalid-email").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_email`
+/// - Message: `"Invalid email format"`
+/// - Meta: `{"reason": "..."}` (see [`email_with`])
+pub fn email() -> Rule<str> {
+    email_with(EmailOptions::default())
+}
+
+/// Validates that a string is an email address, with configurable strictness.
+///
+/// The local part is a dot-atom (RFC 5322 `atext` separated by single dots)
+/// or, if allowed, a quoted string. The domain is a host name of at least two
+/// labels whose TLD is not numeric; Unicode labels are converted to Punycode
+/// before the DNS length limits are applied. IP literals are bracketed, with
+/// an `IPv6:` tag for IPv6.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::EmailOptions;
+///
+/// let rule = rules::email_with(EmailOptions {
+///     allow_ip_literal: true,
+///     allow_plus_addressing: false,
+///     ..EmailOptions::default()
+/// });
+/// assert!(rule.apply("ops@[IPv6:2001:db8::1]").is_empty());
+///
+/// let err = rule.apply("jane+spam@example.com");
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("plus_addressing"));
+///
+/// let err = rules::email_with(EmailOptions::default().allow_tld("com")).apply("a@example.org");
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("tld_not_allowed"));
+/// assert_eq!(err.violations[0].meta.get("tld"), Some("org"));
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_email`
+/// - Message: `"Invalid email format"`
+/// - Meta: `{"reason": ...}`, the first failed check:
+///   - `missing_at` - no `@` separating local part and domain
+///   - `local_empty`, `local_too_long` (over 64 octets), `local_syntax`
+///   - `quoted_local`, `utf8_local`, `plus_addressing` - disallowed by the options
+///   - `domain_empty`, `domain_syntax`, `domain_too_long` (over 253 octets), `missing_tld`
+///   - `ip_literal` - IP-literal domain when not allowed
+///   - `too_long` - whole address over 254 octets
+///   - `tld_not_allowed` - TLD filtered by the options; also sets `tld`
+pub fn email_with(options: EmailOptions) -> Rule<str> {
+    let allowed_tlds: Vec<String> = options.allowed_tlds.iter().map(|t| tld_key(t)).collect();
+    let denied_tlds: Vec<String> = options.denied_tlds.iter().map(|t| tld_key(t)).collect();
+
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        let failure = check_email(value, &options).and_then(|tld| {
+            // IP literals have no TLD to filter
+            let allowed = tld.is_empty() || allowed_tlds.is_empty() || allowed_tlds.contains(&tld);
+            if allowed && !denied_tlds.contains(&tld) {
+                Ok(())
+            } else {
+                Err(("tld_not_allowed", Some(tld)))
+            }
+        });
+
+        let Err((reason, tld)) = failure else {
+            return ValidationError::default();
+        };
+        let mut err =
+            ValidationError::single(ctx.full_path(), "invalid_email", "Invalid email format");
+        err.violations[0].meta.insert("reason", reason);
+        if let Some(tld) = tld {
+            err.violations[0].meta.insert("tld", tld);
+        }
+        err
+    })
+}
+
+type Failure = (&'static str, Option<String>);
+
+/// Check an address, returning its ASCII TLD (empty for IP literals).
+fn check_email(value: &str, options: &EmailOptions) -> Result<String, Failure> {
+    let (local, domain) = split_address(value)?;
+
+    check_local(local, options)?;
+
+    let (ascii_domain_len, tld) = if domain.starts_with('[') {
+        if !options.allow_ip_literal {
+            return Err(("ip_literal", None));
+        }
+        if !is_address_literal(domain) {
+            return Err(("domain_syntax", None));
+        }
+        (domain.len(), String::new())
+    } else {
+        let ascii = check_domain(domain)?;
+        let tld = ascii.rsplit('.').next().unwrap_or_default().to_string();
+        (ascii.len(), tld)
+    };
+
+    if local.len() + 1 + ascii_domain_len > MAX_EMAIL_LEN {
+        return Err(("too_long", None));
+    }
+    Ok(tld)
+}
+
+/// Split at the `@` ending the local part. A quoted local part may itself contain `@`.
+fn split_address(value: &str) -> Result<(&str, &str), Failure> {
+    let at = if value.starts_with('"') {
+        quoted_end(value).ok_or(("local_syntax", None))? + 1
+    } else {
+        value.find('@').ok_or(("missing_at", None))?
+    };
+
+    match value[at..].strip_prefix('@') {
+        Some(domain) => Ok((&value[..at], domain)),
+        None if at == value.len() => Err(("missing_at", None)),
+        None => Err(("local_syntax", None)),
+    }
+}
+
+/// Byte index of the closing quote of a quoted string starting at index 0.
+fn quoted_end(value: &str) -> Option<usize> {
+    let bytes = value.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => return Some(i),
+            b'\\' => i += 2,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn check_local(local: &str, options: &EmailOptions) -> Result<(), Failure> {
+    if local.is_empty() {
+        return Err(("local_empty", None));
+    }
+    if local.len() > MAX_LOCAL_LEN {
+        return Err(("local_too_long", None));
+    }
+
+    if let Some(quoted) = local.strip_prefix('"') {
+        if !options.allow_quoted_local {
+            return Err(("quoted_local", None));
+        }
+        check_quoted_content(&quoted[..quoted.len() - 1])?;
+    } else if !is_dot_atom(local) {
+        return Err(("local_syntax", None));
+    }
+
+    if !options.allow_utf8_local && !local.is_ascii() {
+        return Err(("utf8_local", None));
+    }
+    if !options.allow_plus_addressing && local.contains('+') {
+        return Err(("plus_addressing", None));
+    }
+    Ok(())
+}
+
+/// RFC 5321 `QcontentSMTP`: printable ASCII except `"` and `\`, or a `\`-escaped printable.
+fn check_quoted_content(content: &str) -> Result<(), Failure> {
+    let mut bytes = content.bytes();
+    while let Some(b) = bytes.next() {
+        let valid = match b {
+            b'\\' => bytes
+                .next()
+                .is_some_and(|escaped| (32..=126).contains(&escaped)),
+            b'"' => false,
+            _ => (32..=126).contains(&b) || !b.is_ascii(),
+        };
+        if !valid {
+            return Err(("local_syntax", None));
+        }
+    }
+    Ok(())
+}
+
+/// RFC 5322 `dot-atom-text`, with non-ASCII allowed as `atext` (RFC 6532).
+fn is_dot_atom(local: &str) -> bool {
+    local
+        .split('.')
+        .all(|atom| !atom.is_empty() && atom.bytes().all(|b| is_atext(b) || !b.is_ascii()))
+}
+
+/// RFC 5322 `atext`.
+fn is_atext(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&b)
+}
+
+/// Validate a host name domain and return its ASCII form.
+fn check_domain(domain: &str) -> Result<String, Failure> {
+    if domain.is_empty() {
+        return Err(("domain_empty", None));
+    }
+
+    let mut ascii = String::with_capacity(domain.len());
+    let mut labels = 0;
+    for label in domain.split('.') {
+        if labels > 0 {
+            ascii.push('.');
+        }
+        ascii.push_str(&idna::to_ascii_label(label).ok_or(("domain_syntax", None))?);
+        labels += 1;
+    }
+
+    if ascii.len() > idna::MAX_DOMAIN_LEN {
+        return Err(("domain_too_long", None));
+    }
+    if labels < 2 {
+        return Err(("missing_tld", None));
+    }
+    if ascii
+        .rsplit('.')
+        .next()
+        .is_some_and(|tld| tld.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return Err(("domain_syntax", None));
+    }
+    Ok(ascii)
+}
+
+/// `[IPv4]` or `[IPv6:addr]` (RFC 5321 `address-literal`; general literals are not supported).
+fn is_address_literal(domain: &str) -> bool {
+    let Some(inner) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) else {
+        return false;
+    };
+    match inner.get(..5) {
+        Some(tag) if tag.eq_ignore_ascii_case("IPv6:") => {
+            network::parse_ipv6(&inner[5..]).is_some()
+        }
+        _ => network::parse_ipv4(inner).is_some(),
+    }
+}
+
+/// Normalize a configured TLD to the form returned by [`check_email`].
+fn tld_key(tld: &str) -> String {
+    let tld = tld.strip_prefix('.').unwrap_or(tld);
+    idna::to_ascii_label(tld).unwrap_or_else(|| tld.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(rule: &Rule<str>, value: &str) -> Option<String> {
+        let err = rule.apply(value);
+        err.violations
+            .first()
+            .map(|v| v.meta.get("reason").unwrap_or_default().to_string())
+    }
+
+    #[test]
+    fn test_email_valid() {
+        let rule = email();
+        assert!(rule.apply("user@example.com").is_empty());
+        assert!(rule.apply("test.user@domain.co.uk").is_empty());
+    }
+
+    #[test]
+    fn test_email_invalid() {
+        let rule = email();
+
+        let result = rule.apply("not-an-email");
+        assert!(!result.is_empty());
+        assert_eq!(result.violations[0].code, "invalid_email");
+
+        let result = rule.apply("missing-domain@");
+        assert!(!result.is_empty());
+
+        let result = rule.apply("@missing-user.com");
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn test_valid_addresses() {
+        let rule = email();
+        for valid in [
+            "user@example.com",
+            "first.last@sub.example.co.uk",
+            "jane+tag@example.com",
+            "o'brien@example.ie",
+            "!#$%&'*+-/=?^_`{|}~@example.com",
+            "user@xn--bcher-kva.de",
+            "user@bücher.de",
+            "用户@例子.广告",
+            "x@a-b.io",
+        ] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+    }
+
+    #[test]
+    fn test_syntax_reasons() {
+        let rule = email();
+        let long_local = "a".repeat(65);
+        let long_label = "a".repeat(64);
+        let long_domain = ["a".repeat(60).as_str(); 5].join(".");
+        let long_address = format!(
+            "{}@{}.com",
+            "a".repeat(64),
+            ["b".repeat(60).as_str(); 4].join(".")
+        );
+
+        for (value, expected) in [
+            ("plainaddress", "missing_at"),
+            ("", "missing_at"),
+            ("@example.com", "local_empty"),
+            (long_local.as_str(), "missing_at"),
+            (".user@example.com", "local_syntax"),
+            ("user.@example.com", "local_syntax"),
+            ("us..er@example.com", "local_syntax"),
+            ("us er@example.com", "local_syntax"),
+            ("us(er)@example.com", "local_syntax"),
+            ("user@", "domain_empty"),
+            ("user@example..com", "domain_syntax"),
+            ("user@-example.com", "domain_syntax"),
+            ("user@example.com.", "domain_syntax"),
+            ("user@exa mple.com", "domain_syntax"),
+            ("user@a@example.com", "domain_syntax"),
+            ("user@example.123", "domain_syntax"),
+            ("user@localhost", "missing_tld"),
+            ("user@[192.0.2.1]", "ip_literal"),
+            ("\"john doe\"@example.com", "quoted_local"),
+        ] {
+            assert_eq!(reason(&rule, value).as_deref(), Some(expected), "{value:?}");
+        }
+
+        let value = format!("{}@example.com", long_local);
+        assert_eq!(reason(&rule, &value).as_deref(), Some("local_too_long"));
+        let value = format!("user@{}.com", long_label);
+        assert_eq!(reason(&rule, &value).as_deref(), Some("domain_syntax"));
+        let value = format!("user@{}", long_domain);
+        assert_eq!(reason(&rule, &value).as_deref(), Some("domain_too_long"));
+        assert_eq!(reason(&rule, &long_address).as_deref(), Some("too_long"));
+    }
+
+    #[test]
+    fn test_quoted_local_parts() {
+        let rule = email_with(EmailOptions {
+            allow_quoted_local: true,
+            ..EmailOptions::default()
+        });
+        for valid in [
+            r#""john doe"@example.com"#,
+            r#""a@b"@example.com"#,
+            r#""quote\"inside"@example.com"#,
+            r#"""@example.com"#,
+        ] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+        for invalid in [
+            r#""unterminated@example.com"#,
+            r#""bad"x@example.com"#,
+            "\"tab\there\"@example.com",
+        ] {
+            assert_eq!(
+                reason(&rule, invalid).as_deref(),
+                Some("local_syntax"),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ip_literals() {
+        let rule = email_with(EmailOptions {
+            allow_ip_literal: true,
+            ..EmailOptions::default()
+        });
+        for valid in ["a@[192.0.2.1]", "a@[IPv6:2001:db8::1]", "a@[ipv6:::1]"] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+        for invalid in [
+            "a@[256.0.0.1]",
+            "a@[2001:db8::1]",
+            "a@[IPv6:1.2.3.4]",
+            "a@[example.com]",
+            "a@[192.0.2.1",
+        ] {
+            assert_eq!(
+                reason(&rule, invalid).as_deref(),
+                Some("domain_syntax"),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_policy_options() {
+        let rule = email_with(EmailOptions {
+            allow_plus_addressing: false,
+            allow_utf8_local: false,
+            ..EmailOptions::default()
+        });
+        assert_eq!(
+            reason(&rule, "a+b@example.com").as_deref(),
+            Some("plus_addressing")
+        );
+        assert_eq!(
+            reason(&rule, "jürgen@example.com").as_deref(),
+            Some("utf8_local")
+        );
+        assert!(rule.apply("jurgen@bücher.de").is_empty());
+
+        let rule = email_with(EmailOptions::default().allow_tld(".COM").allow_tld("中国"));
+        assert!(rule.apply("a@example.com").is_empty());
+        assert!(rule.apply("a@example.中国").is_empty());
+        let err = rule.apply("a@example.org");
+        assert_eq!(
+            err.violations[0].meta.get("reason"),
+            Some("tld_not_allowed")
+        );
+        assert_eq!(err.violations[0].meta.get("tld"), Some("org"));
+
+        let rule = email_with(EmailOptions::default().deny_tld("zip"));
+        assert!(rule.apply("a@example.com").is_empty());
+        assert_eq!(
+            reason(&rule, "a@example.ZIP").as_deref(),
+            Some("tld_not_allowed")
+        );
+    }
+}
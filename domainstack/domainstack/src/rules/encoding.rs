@@ -0,0 +1,267 @@
+//! Base64 and JSON helpers shared by the identifier and encoding rules.
+//!
+//! Only what the rules need: a strict RFC 4648 decoder and a syntax check for
+//! a JSON object, so `jwt_structure` can inspect a token header without
+//! pulling in a JSON dependency.
+
+use alloc::vec::Vec;
+
+/// Maximum nesting depth accepted by [`is_json_object`].
+const MAX_JSON_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Base64Alphabet {
+    /// `+` and `/`, padding required.
+    Standard,
+    /// `-` and `_`, padding optional.
+    UrlSafe,
+}
+
+/// Decode RFC 4648 base64.
+///
+/// Returns `None` for characters outside the alphabet, misplaced or missing
+/// padding, truncated input, or non-zero bits after the last byte (so every
+/// accepted string is the canonical encoding of its bytes).
+pub(crate) fn decode_base64(input: &str, alphabet: Base64Alphabet) -> Option<Vec<u8>> {
+    let input = input.as_bytes();
+    let unpadded = input
+        .strip_suffix(b"==")
+        .or_else(|| input.strip_suffix(b"="));
+    let (data, padded) = match unpadded {
+        Some(data) => (data, true),
+        None => (input, false),
+    };
+
+    let complete = input.len() % 4 == 0;
+    let padding_ok = match alphabet {
+        Base64Alphabet::Standard => complete,
+        Base64Alphabet::UrlSafe => !padded || complete,
+    };
+    if !padding_ok || data.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 3 / 4);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &byte in data {
+        acc = (acc << 6) | u32::from(sextet(byte, alphabet)?);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    (acc == 0).then_some(out)
+}
+
+fn sextet(byte: u8, alphabet: Base64Alphabet) -> Option<u8> {
+    match (byte, alphabet) {
+        (b'A'..=b'Z', _) => Some(byte - b'A'),
+        (b'a'..=b'z', _) => Some(byte - b'a' + 26),
+        (b'0'..=b'9', _) => Some(byte - b'0' + 52),
+        (b'+', Base64Alphabet::Standard) | (b'-', Base64Alphabet::UrlSafe) => Some(62),
+        (b'/', Base64Alphabet::Standard) | (b'_', Base64Alphabet::UrlSafe) => Some(63),
+        _ => None,
+    }
+}
+
+/// Whether `bytes` is UTF-8 holding exactly one JSON object (RFC 8259).
+pub(crate) fn is_json_object(bytes: &[u8]) -> bool {
+    if core::str::from_utf8(bytes).is_err() {
+        return false;
+    }
+
+    let mut json = JsonCursor { bytes, pos: 0 };
+    json.skip_whitespace();
+    if json.peek() != Some(b'{') || !json.value(0) {
+        return false;
+    }
+    json.skip_whitespace();
+    json.pos == bytes.len()
+}
+
+struct JsonCursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl JsonCursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matched = self.peek() == Some(byte);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> bool {
+        if depth > MAX_JSON_DEPTH {
+            return false;
+        }
+        match self.peek() {
+            Some(b'{') => self.sequence(b'}', depth, true),
+            Some(b'[') => self.sequence(b']', depth, false),
+            Some(b'"') => self.string(),
+            Some(b't') => self.literal(b"true"),
+            Some(b'f') => self.literal(b"false"),
+            Some(b'n') => self.literal(b"null"),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => false,
+        }
+    }
+
+    /// An object (`keyed`) or array, starting at its opening bracket.
+    fn sequence(&mut self, close: u8, depth: usize, keyed: bool) -> bool {
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.eat(close) {
+            return true;
+        }
+        loop {
+            self.skip_whitespace();
+            if keyed {
+                if self.peek() != Some(b'"') || !self.string() {
+                    return false;
+                }
+                self.skip_whitespace();
+                if !self.eat(b':') {
+                    return false;
+                }
+                self.skip_whitespace();
+            }
+            if !self.value(depth + 1) {
+                return false;
+            }
+            self.skip_whitespace();
+            if self.eat(close) {
+                return true;
+            }
+            if !self.eat(b',') {
+                return false;
+            }
+        }
+    }
+
+    fn string(&mut self) -> bool {
+        self.pos += 1;
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            match byte {
+                b'"' => return true,
+                b'\\' => match self.peek() {
+                    Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => self.pos += 1,
+                    Some(b'u') => {
+                        self.pos += 1;
+                        for _ in 0..4 {
+                            if !self.peek().is_some_and(|b| b.is_ascii_hexdigit()) {
+                                return false;
+                            }
+                            self.pos += 1;
+                        }
+                    }
+                    _ => return false,
+                },
+                0x00..=0x1f => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    fn literal(&mut self, word: &[u8]) -> bool {
+        let matched = self.bytes[self.pos..].starts_with(word);
+        if matched {
+            self.pos += word.len();
+        }
+        matched
+    }
+
+    fn number(&mut self) -> bool {
+        self.eat(b'-');
+        if !self.eat(b'0') && self.digits() == 0 {
+            return false;
+        }
+        if self.eat(b'.') && self.digits() == 0 {
+            return false;
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            let _ = self.eat(b'+') || self.eat(b'-');
+            if self.digits() == 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64() {
+        use Base64Alphabet::*;
+
+        assert_eq!(decode_base64("", Standard), Some(Vec::new()));
+        assert_eq!(decode_base64("TWFu", Standard), Some(b"Man".to_vec()));
+        assert_eq!(decode_base64("TWE=", Standard), Some(b"Ma".to_vec()));
+        assert_eq!(decode_base64("TQ==", Standard), Some(b"M".to_vec()));
+        assert_eq!(decode_base64("-_8", UrlSafe), Some(vec![0xfb, 0xff]));
+        assert_eq!(decode_base64("-_8=", UrlSafe), Some(vec![0xfb, 0xff]));
+
+        for invalid in ["TQ", "TQ=", "T===", "TQ==TQ==", "TR==", "T", "+/8 ", "-_8="] {
+            assert_eq!(decode_base64(invalid, Standard), None, "{invalid}");
+        }
+        for invalid in ["TQ=", "T", "+/8", "TQ==="] {
+            assert_eq!(decode_base64(invalid, UrlSafe), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_is_json_object() {
+        for valid in [
+            r#"{}"#,
+            r#" {"alg":"HS256","typ":"JWT"} "#,
+            r#"{"a":[1,-2.5e3,true,false,null,{"b":"é\n"}]}"#,
+        ] {
+            assert!(is_json_object(valid.as_bytes()), "{valid}");
+        }
+        for invalid in [
+            "",
+            "[]",
+            r#""alg""#,
+            r#"{"alg":}"#,
+            r#"{"alg":"HS256",}"#,
+            r#"{alg:"HS256"}"#,
+            r#"{"a":01}"#,
+            r#"{"a":"\x"}"#,
+            r#"{} {}"#,
+        ] {
+            assert!(!is_json_object(invalid.as_bytes()), "{invalid}");
+        }
+
+        let deep = "[".repeat(40) + &"]".repeat(40);
+        assert!(!is_json_object(format!("{{\"a\":{}}}", deep).as_bytes()));
+    }
+}
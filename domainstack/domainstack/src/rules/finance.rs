@@ -0,0 +1,755 @@
+//! Checksum-aware rules for financial identifiers: card numbers, IBAN,
+//! BIC/SWIFT, ISIN and ABA routing numbers.
+//!
+//! Every rule reports a single code per identifier and puts the failed check
+//! in the `reason` meta key (`"format"`, `"length"`, `"country"` or
+//! `"checksum"`), so clients can tell a typo from an unsupported input.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Validates that a string of digits passes the Luhn (mod 10) checksum.
+///
+/// Only ASCII digits are accepted; at least two are required.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::luhn();
+/// assert!(rule.apply("79927398713").is_empty());
+/// assert!(!rule.apply("79927398710").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_luhn`
+/// - Message: `"Must pass the Luhn checksum"`
+/// - Meta: `{"reason": "format" | "checksum"}`
+pub fn luhn() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let reason = if value.len() < 2 || !value.bytes().all(|b| b.is_ascii_digit()) {
+            "format"
+        } else if !luhn_valid(value.bytes().map(|b| b - b'0')) {
+            "checksum"
+        } else {
+            return ValidationError::default();
+        };
+
+        failure(ctx, "invalid_luhn", "Must pass the Luhn checksum", reason)
+    })
+}
+
+/// Card network detected from a card number's prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+    DinersClub,
+    Jcb,
+    UnionPay,
+}
+
+impl CardBrand {
+    /// Stable identifier used in violation meta, e.g. `"visa"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "visa",
+            CardBrand::Mastercard => "mastercard",
+            CardBrand::Amex => "amex",
+            CardBrand::Discover => "discover",
+            CardBrand::DinersClub => "diners_club",
+            CardBrand::Jcb => "jcb",
+            CardBrand::UnionPay => "unionpay",
+        }
+    }
+
+    fn valid_length(&self, len: usize) -> bool {
+        match self {
+            CardBrand::Visa => matches!(len, 13 | 16 | 19),
+            CardBrand::Mastercard => len == 16,
+            CardBrand::Amex => len == 15,
+            CardBrand::DinersClub => (14..=19).contains(&len),
+            CardBrand::Discover | CardBrand::Jcb | CardBrand::UnionPay => (16..=19).contains(&len),
+        }
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Detect the card brand from a card number's prefix.
+///
+/// Spaces and hyphens are ignored. Only the prefix is inspected; use
+/// [`credit_card`] to validate the number itself.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::{card_brand, CardBrand};
+///
+/// assert_eq!(card_brand("4111 1111 1111 1111"), Some(CardBrand::Visa));
+/// assert_eq!(card_brand("3782-822463-10005"), Some(CardBrand::Amex));
+/// assert_eq!(card_brand("9999"), None);
+/// ```
+pub fn card_brand(number: &str) -> Option<CardBrand> {
+    let digits = card_digits(number)?;
+    brand_of(&digits)
+}
+
+/// Validates a payment card number: length, brand and Luhn checksum.
+///
+/// Digits may be grouped with spaces or hyphens. Numbers with a recognized
+/// prefix must have a length valid for that brand; other numbers must be
+/// 12-19 digits long.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::credit_card();
+/// assert!(rule.apply("4111 1111 1111 1111").is_empty());
+///
+/// let err = rule.apply("4111 1111 1111 1112");
+/// assert_eq!(err.violations[0].meta.get("brand"), Some("visa"));
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("checksum"));
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_credit_card`
+/// - Message: `"Must be a valid card number"`
+/// - Meta: `{"reason": "format" | "length" | "checksum", "brand": "visa" | ... | "unknown"}`
+pub fn credit_card() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let Some(digits) = card_digits(value) else {
+            let mut err = failure(ctx, "invalid_credit_card", CARD_MESSAGE, "format");
+            err.violations[0].meta.insert("brand", "unknown");
+            return err;
+        };
+
+        let brand = brand_of(&digits);
+        let length_ok = match brand {
+            Some(brand) => brand.valid_length(digits.len()),
+            None => (12..=19).contains(&digits.len()),
+        };
+
+        let reason = if !length_ok {
+            "length"
+        } else if !luhn_valid(digits.iter().copied()) {
+            "checksum"
+        } else {
+            return ValidationError::default();
+        };
+
+        let mut err = failure(ctx, "invalid_credit_card", CARD_MESSAGE, reason);
+        err.violations[0]
+            .meta
+            .insert("brand", brand.map_or("unknown", |b| b.as_str()));
+        err
+    })
+}
+
+const CARD_MESSAGE: &str = "Must be a valid card number";
+
+/// Validates an International Bank Account Number (ISO 13616).
+///
+/// Checks the country code against the IBAN registry, the length for that
+/// country, and the mod-97 check digits. Spaces between groups and lowercase
+/// letters are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::iban();
+/// assert!(rule.apply("GB82 WEST 1234 5698 7654 32").is_empty());
+/// assert!(rule.apply("DE89370400440532013000").is_empty());
+///
+/// let err = rule.apply("DE8937040044053201300");
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("length"));
+/// assert_eq!(err.violations[0].meta.get("expected_length"), Some("22"));
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_iban`
+/// - Message: `"Must be a valid IBAN"`
+/// - Meta: `{"reason": "format" | "country" | "length" | "checksum"}`, plus
+///   `country` once it is known and `expected_length` for length failures
+pub fn iban() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let compact: Vec<u8> = value
+            .bytes()
+            .filter(|&b| b != b' ')
+            .map(|b| b.to_ascii_uppercase())
+            .collect();
+
+        let well_formed = compact.len() >= 5
+            && compact[..2].iter().all(u8::is_ascii_uppercase)
+            && compact[2..4].iter().all(u8::is_ascii_digit)
+            && compact[4..].iter().all(u8::is_ascii_alphanumeric);
+        if !well_formed {
+            return failure(ctx, "invalid_iban", "Must be a valid IBAN", "format");
+        }
+
+        // Both bytes are ASCII letters, checked above.
+        let country = core::str::from_utf8(&compact[..2]).unwrap_or_default();
+        let Some(expected) = iban_length(country) else {
+            let mut err = failure(ctx, "invalid_iban", "Must be a valid IBAN", "country");
+            err.violations[0].meta.insert("country", country);
+            return err;
+        };
+
+        if compact.len() != expected {
+            let mut err = failure(ctx, "invalid_iban", "Must be a valid IBAN", "length");
+            err.violations[0].meta.insert("country", country);
+            err.violations[0].meta.insert("expected_length", expected);
+            return err;
+        }
+
+        let rearranged = compact[4..].iter().chain(&compact[..4]);
+        if mod97(rearranged) != 1 {
+            let mut err = failure(ctx, "invalid_iban", "Must be a valid IBAN", "checksum");
+            err.violations[0].meta.insert("country", country);
+            return err;
+        }
+
+        ValidationError::default()
+    })
+}
+
+/// Validates a BIC / SWIFT code (ISO 9362).
+///
+/// Eight or eleven uppercase characters: a four-letter institution code, a
+/// two-letter country code, a two-character location code and an optional
+/// three-character branch code.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::bic();
+/// assert!(rule.apply("DEUTDEFF").is_empty());
+/// assert!(rule.apply("DEUTDEFF500").is_empty());
+/// assert!(!rule.apply("DEUTDEF").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_bic`
+/// - Message: `"Must be a valid BIC/SWIFT code"`
+pub fn bic() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let b = value.as_bytes();
+        let upper_alnum = |c: &u8| c.is_ascii_uppercase() || c.is_ascii_digit();
+        let valid = (b.len() == 8 || b.len() == 11)
+            && b[..6].iter().all(u8::is_ascii_uppercase)
+            && b[6..].iter().all(upper_alnum);
+
+        if valid {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_bic",
+                "Must be a valid BIC/SWIFT code",
+            )
+        }
+    })
+}
+
+/// Validates an International Securities Identification Number (ISO 6166).
+///
+/// Twelve characters: a two-letter country code, nine uppercase alphanumeric
+/// characters and a Luhn check digit computed over the letter-expanded code.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::isin();
+/// assert!(rule.apply("US0378331005").is_empty());
+/// assert!(!rule.apply("US0378331006").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_isin`
+/// - Message: `"Must be a valid ISIN"`
+/// - Meta: `{"reason": "format" | "checksum"}`
+pub fn isin() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let b = value.as_bytes();
+        let well_formed = b.len() == 12
+            && b[..2].iter().all(u8::is_ascii_uppercase)
+            && b[2..11]
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && b[11].is_ascii_digit();
+        if !well_formed {
+            return failure(ctx, "invalid_isin", "Must be a valid ISIN", "format");
+        }
+
+        // Letters expand to two digits (A = 10 ... Z = 35) before the Luhn check.
+        let mut digits = Vec::with_capacity(24);
+        for &c in b {
+            if c.is_ascii_digit() {
+                digits.push(c - b'0');
+            } else {
+                let n = c - b'A' + 10;
+                digits.push(n / 10);
+                digits.push(n % 10);
+            }
+        }
+
+        if luhn_valid(digits.into_iter()) {
+            ValidationError::default()
+        } else {
+            failure(ctx, "invalid_isin", "Must be a valid ISIN", "checksum")
+        }
+    })
+}
+
+/// Validates a US ABA routing transit number.
+///
+/// Nine digits with a valid Federal Reserve prefix (`00`-`12`, `21`-`32`,
+/// `61`-`72` or `80`) and the 3-7-1 weighted checksum.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::aba_routing();
+/// assert!(rule.apply("021000021").is_empty());
+/// assert!(!rule.apply("021000022").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_aba_routing`
+/// - Message: `"Must be a valid ABA routing number"`
+/// - Meta: `{"reason": "format" | "checksum"}`
+pub fn aba_routing() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let b = value.as_bytes();
+        if b.len() != 9 || !b.iter().all(u8::is_ascii_digit) {
+            return failure(ctx, "invalid_aba_routing", ABA_MESSAGE, "format");
+        }
+
+        let d: Vec<u32> = b.iter().map(|&c| u32::from(c - b'0')).collect();
+        let prefix = d[0] * 10 + d[1];
+        if !matches!(prefix, 0..=12 | 21..=32 | 61..=72 | 80) {
+            return failure(ctx, "invalid_aba_routing", ABA_MESSAGE, "format");
+        }
+
+        let sum = 3 * (d[0] + d[3] + d[6]) + 7 * (d[1] + d[4] + d[7]) + (d[2] + d[5] + d[8]);
+        if sum % 10 == 0 {
+            ValidationError::default()
+        } else {
+            failure(ctx, "invalid_aba_routing", ABA_MESSAGE, "checksum")
+        }
+    })
+}
+
+const ABA_MESSAGE: &str = "Must be a valid ABA routing number";
+
+fn failure(
+    ctx: &RuleContext,
+    code: &'static str,
+    message: &'static str,
+    reason: &'static str,
+) -> ValidationError {
+    let mut err = ValidationError::single(ctx.full_path(), code, message);
+    err.violations[0].meta.insert("reason", reason);
+    err
+}
+
+/// Luhn check over digit values, most significant first.
+fn luhn_valid(digits: impl DoubleEndedIterator<Item = u8>) -> bool {
+    let sum: u32 = digits
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let d = u32::from(d);
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Card digits with space/hyphen grouping removed, or `None` if malformed.
+fn card_digits(value: &str) -> Option<Vec<u8>> {
+    let b = value.as_bytes();
+    if !b.first()?.is_ascii_digit() || !b.last()?.is_ascii_digit() {
+        return None;
+    }
+
+    let mut digits = Vec::with_capacity(b.len());
+    for &c in b {
+        match c {
+            b'0'..=b'9' => digits.push(c - b'0'),
+            b' ' | b'-' => {}
+            _ => return None,
+        }
+    }
+    Some(digits)
+}
+
+fn brand_of(digits: &[u8]) -> Option<CardBrand> {
+    let prefix = |n: usize| -> u32 {
+        digits
+            .iter()
+            .take(n)
+            .fold(0, |acc, &d| acc * 10 + u32::from(d))
+    };
+    if digits.len() < 4 {
+        return None;
+    }
+
+    let brand = match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+        (4, ..) => CardBrand::Visa,
+        (_, 51..=55, ..) | (.., 2221..=2720) => CardBrand::Mastercard,
+        (_, 34 | 37, ..) => CardBrand::Amex,
+        (.., 6011) | (_, 65, ..) | (_, _, 644..=649, _) => CardBrand::Discover,
+        (_, 36 | 38 | 39, ..) | (_, _, 300..=305, _) => CardBrand::DinersClub,
+        (.., 3528..=3589) => CardBrand::Jcb,
+        (_, 62, ..) => CardBrand::UnionPay,
+        _ => return None,
+    };
+    Some(brand)
+}
+
+/// Remainder of the letter-expanded number modulo 97 (ISO 7064 MOD 97-10).
+fn mod97<'a>(chars: impl Iterator<Item = &'a u8>) -> u32 {
+    chars.fold(0, |acc, &c| {
+        if c.is_ascii_digit() {
+            (acc * 10 + u32::from(c - b'0')) % 97
+        } else {
+            (acc * 100 + u32::from(c - b'A' + 10)) % 97
+        }
+    })
+}
+
+/// IBAN length per country, from the SWIFT IBAN registry.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .binary_search_by(|(code, _)| (*code).cmp(country))
+        .ok()
+        .map(|i| IBAN_LENGTHS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(err: &ValidationError) -> Option<&str> {
+        err.violations[0].meta.get("reason")
+    }
+
+    #[test]
+    fn test_luhn() {
+        let rule = luhn();
+        assert!(rule.apply("79927398713").is_empty());
+        assert!(rule.apply("0000000000").is_empty());
+
+        let err = rule.apply("79927398710");
+        assert_eq!(err.violations[0].code, "invalid_luhn");
+        assert_eq!(reason(&err), Some("checksum"));
+
+        for value in ["", "7", "7992 7398 713", "abc"] {
+            assert_eq!(reason(&rule.apply(value)), Some("format"), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_card_brands() {
+        let cases = [
+            ("4111111111111111", CardBrand::Visa),
+            ("5555555555554444", CardBrand::Mastercard),
+            ("2223003122003222", CardBrand::Mastercard),
+            ("378282246310005", CardBrand::Amex),
+            ("6011111111111117", CardBrand::Discover),
+            ("6500000000000002", CardBrand::Discover),
+            ("3056930009020004", CardBrand::DinersClub),
+            ("36227206271667", CardBrand::DinersClub),
+            ("3530111333300000", CardBrand::Jcb),
+            ("6200000000000005", CardBrand::UnionPay),
+        ];
+
+        let rule = credit_card();
+        for (number, brand) in cases {
+            assert_eq!(card_brand(number), Some(brand), "{number}");
+            assert!(rule.apply(number).is_empty(), "{number}");
+        }
+        assert_eq!(card_brand("1234567890123"), None);
+        assert_eq!(card_brand("41"), None);
+    }
+
+    #[test]
+    fn test_credit_card_grouping() {
+        let rule = credit_card();
+        assert!(rule.apply("4111 1111 1111 1111").is_empty());
+        assert!(rule.apply("4111-1111-1111-1111").is_empty());
+
+        for value in [
+            "",
+            " 4111111111111111",
+            "4111111111111111-",
+            "4111.1111.1111.1111",
+        ] {
+            let err = rule.apply(value);
+            assert_eq!(err.violations[0].code, "invalid_credit_card");
+            assert_eq!(reason(&err), Some("format"), "{value:?}");
+            assert_eq!(err.violations[0].meta.get("brand"), Some("unknown"));
+        }
+    }
+
+    #[test]
+    fn test_credit_card_failures_include_brand() {
+        let rule = credit_card();
+
+        let err = rule.apply("4111111111111112");
+        assert_eq!(reason(&err), Some("checksum"));
+        assert_eq!(err.violations[0].meta.get("brand"), Some("visa"));
+
+        // Amex numbers are 15 digits
+        let err = rule.apply("3782822463100050");
+        assert_eq!(reason(&err), Some("length"));
+        assert_eq!(err.violations[0].meta.get("brand"), Some("amex"));
+
+        let err = rule.apply("12345678901");
+        assert_eq!(reason(&err), Some("length"));
+        assert_eq!(err.violations[0].meta.get("brand"), Some("unknown"));
+    }
+
+    #[test]
+    fn test_card_brand_display() {
+        assert_eq!(CardBrand::DinersClub.to_string(), "diners_club");
+        assert_eq!(CardBrand::UnionPay.as_str(), "unionpay");
+    }
+
+    #[test]
+    fn test_iban_valid() {
+        let rule = iban();
+        for value in [
+            "GB82WEST12345698765432",
+            "GB82 WEST 1234 5698 7654 32",
+            "gb82 west 1234 5698 7654 32",
+            "DE89370400440532013000",
+            "FR1420041010050500013M02606",
+            "NO9386011117947",
+            "BE68539007547034",
+        ] {
+            assert!(rule.apply(value).is_empty(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_iban_failures() {
+        let rule = iban();
+
+        let err = rule.apply("GB82WEST12345698765431");
+        assert_eq!(err.violations[0].code, "invalid_iban");
+        assert_eq!(reason(&err), Some("checksum"));
+        assert_eq!(err.violations[0].meta.get("country"), Some("GB"));
+
+        let err = rule.apply("GB82WEST1234569876543");
+        assert_eq!(reason(&err), Some("length"));
+        assert_eq!(err.violations[0].meta.get("expected_length"), Some("22"));
+
+        let err = rule.apply("XX82WEST12345698765432");
+        assert_eq!(reason(&err), Some("country"));
+        assert_eq!(err.violations[0].meta.get("country"), Some("XX"));
+
+        for value in [
+            "",
+            "GB82",
+            "1282WEST12345698765432",
+            "GBXXWEST12345698765432",
+            "GB82-WEST",
+        ] {
+            assert_eq!(reason(&rule.apply(value)), Some("format"), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_iban_lengths_are_sorted() {
+        assert!(IBAN_LENGTHS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(iban_length("DE"), Some(22));
+        assert_eq!(iban_length("US"), None);
+    }
+
+    #[test]
+    fn test_bic() {
+        let rule = bic();
+        for value in ["DEUTDEFF", "DEUTDEFF500", "NEDSZAJJXXX", "BOFAUS3N"] {
+            assert!(rule.apply(value).is_empty(), "{value}");
+        }
+        for value in [
+            "",
+            "DEUTDEF",
+            "DEUTDEFF5",
+            "deutdeff",
+            "DEU1DEFF",
+            "DEUTD1FF",
+            "DEUTDEFF50O!",
+        ] {
+            assert_eq!(
+                rule.apply(value).violations[0].code,
+                "invalid_bic",
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_isin() {
+        let rule = isin();
+        for value in [
+            "US0378331005",
+            "AU0000XVGZA3",
+            "GB0002634946",
+            "US5949181045",
+        ] {
+            assert!(rule.apply(value).is_empty(), "{value}");
+        }
+
+        let err = rule.apply("US0378331006");
+        assert_eq!(err.violations[0].code, "invalid_isin");
+        assert_eq!(reason(&err), Some("checksum"));
+
+        for value in [
+            "",
+            "US037833100",
+            "us0378331005",
+            "1S0378331005",
+            "US037833100X",
+        ] {
+            assert_eq!(reason(&rule.apply(value)), Some("format"), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_aba_routing() {
+        let rule = aba_routing();
+        for value in ["011000015", "021000021", "111000025", "322271627"] {
+            assert!(rule.apply(value).is_empty(), "{value}");
+        }
+
+        let err = rule.apply("021000022");
+        assert_eq!(err.violations[0].code, "invalid_aba_routing");
+        assert_eq!(reason(&err), Some("checksum"));
+
+        // 13 is not a Federal Reserve routing symbol
+        for value in ["", "02100002", "0210000210", "02100002a", "130000000"] {
+            assert_eq!(reason(&rule.apply(value)), Some("format"), "{value}");
+        }
+    }
+}
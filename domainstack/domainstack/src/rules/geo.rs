@@ -0,0 +1,754 @@
+//! Geographic rules: WGS 84 coordinates, bounding boxes, distances and GeoJSON
+//! polygon structure.
+//!
+//! GeoJSON (RFC 7946) positions are `[longitude, latitude]`, in that order, while
+//! [`Point`] names its fields to avoid mixing them up. [`polygon`] validates the
+//! `coordinates` of a GeoJSON `Polygon`, with paths pointing into the arrays:
+//!
+//! ```
+//! use domainstack::prelude::*;
+//! use domainstack::rules::geo::{self, PolygonPolicy};
+//!
+//! // The ring ends at (0, 1) instead of returning to (0, 0)
+//! let coordinates = vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]];
+//! let rule = geo::polygon(PolygonPolicy::default());
+//!
+//! let err = validate("area.coordinates", coordinates.as_slice(), &rule).unwrap_err();
+//! assert_eq!(err.violations[0].code, "ring_not_closed");
+//! assert_eq!(err.violations[0].path.to_string(), "area.coordinates[0][3]");
+//! ```
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Mean Earth radius in meters, used for great-circle distances.
+#[cfg(feature = "std")]
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Fewest positions in a closed ring: a triangle plus its closing position.
+const MIN_RING_POSITIONS: usize = 4;
+
+/// A type with WGS 84 coordinates in degrees.
+///
+/// Implement this for your own location types to use them with [`within_bbox`]
+/// and [`within_radius`].
+pub trait GeoPoint {
+    /// Latitude in degrees, positive north.
+    fn lat(&self) -> f64;
+    /// Longitude in degrees, positive east.
+    fn lon(&self) -> f64;
+}
+
+/// A latitude/longitude pair in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    /// Latitude in degrees, positive north.
+    pub lat: f64,
+    /// Longitude in degrees, positive east.
+    pub lon: f64,
+}
+
+impl Point {
+    /// Creates a point from latitude and longitude, in that order.
+    pub const fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+}
+
+impl GeoPoint for Point {
+    fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    fn lon(&self) -> f64 {
+        self.lon
+    }
+}
+
+/// A longitude/latitude rectangle, in GeoJSON `bbox` order.
+///
+/// A box whose `west` edge is greater than its `east` edge crosses the
+/// antimeridian: `BoundingBox::new(176.0, -21.0, -178.0, -12.0)` covers Fiji.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// Minimum longitude.
+    pub west: f64,
+    /// Minimum latitude.
+    pub south: f64,
+    /// Maximum longitude, less than `west` when crossing the antimeridian.
+    pub east: f64,
+    /// Maximum latitude.
+    pub north: f64,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box from its edges, in GeoJSON `bbox` order.
+    pub const fn new(west: f64, south: f64, east: f64, north: f64) -> Self {
+        Self {
+            west,
+            south,
+            east,
+            north,
+        }
+    }
+
+    /// Returns `true` if `point` lies inside the box or on its edge.
+    pub fn contains<P: GeoPoint + ?Sized>(&self, point: &P) -> bool {
+        let (lat, lon) = (point.lat(), point.lon());
+        if !(self.south <= lat && lat <= self.north) {
+            return false;
+        }
+        if self.west <= self.east {
+            self.west <= lon && lon <= self.east
+        } else {
+            self.west <= lon || lon <= self.east
+        }
+    }
+}
+
+impl fmt::Display for BoundingBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.west, self.south, self.east, self.north
+        )
+    }
+}
+
+/// Validates that a latitude is between -90 and 90 degrees.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::latitude();
+/// assert!(rule.apply(&51.5074).is_empty());
+/// assert!(!rule.apply(&91.0).is_empty());
+/// assert!(!rule.apply(&f64::NAN).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_latitude`
+/// - Message: `"Latitude must be between -90 and 90"`
+/// - Meta: `{"min": "-90", "max": "90"}`
+pub fn latitude() -> Rule<f64> {
+    Rule::new(|value: &f64, ctx: &RuleContext| {
+        if valid_latitude(*value) {
+            ValidationError::default()
+        } else {
+            coordinate_error(ctx.full_path(), "invalid_latitude", "Latitude", 90)
+        }
+    })
+}
+
+/// Validates that a longitude is between -180 and 180 degrees.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::longitude();
+/// assert!(rule.apply(&-0.1278).is_empty());
+/// assert!(!rule.apply(&180.5).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_longitude`
+/// - Message: `"Longitude must be between -180 and 180"`
+/// - Meta: `{"min": "-180", "max": "180"}`
+pub fn longitude() -> Rule<f64> {
+    Rule::new(|value: &f64, ctx: &RuleContext| {
+        if valid_longitude(*value) {
+            ValidationError::default()
+        } else {
+            coordinate_error(ctx.full_path(), "invalid_longitude", "Longitude", 180)
+        }
+    })
+}
+
+/// Validates that a point lies inside `bbox`, edges included.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::{BoundingBox, Point};
+///
+/// // Mainland France, roughly
+/// let rule = rules::within_bbox(BoundingBox::new(-5.2, 42.3, 8.3, 51.1));
+/// assert!(rule.apply(&Point::new(48.8566, 2.3522)).is_empty()); // Paris
+/// assert!(!rule.apply(&Point::new(51.5074, -0.1278)).is_empty()); // London
+/// ```
+///
+/// # Error Code
+/// - Code: `outside_bbox`
+/// - Message: `"Must be within the bounding box"`
+/// - Meta: `{"bbox": "[-5.2, 42.3, 8.3, 51.1]", "lat": "51.5074", "lon": "-0.1278"}`
+pub fn within_bbox<P: GeoPoint + 'static>(bbox: BoundingBox) -> Rule<P> {
+    Rule::new(move |point: &P, ctx: &RuleContext| {
+        if bbox.contains(point) {
+            return ValidationError::default();
+        }
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "outside_bbox",
+            "Must be within the bounding box",
+        );
+        let meta = &mut err.violations[0].meta;
+        meta.insert("bbox", bbox.to_string());
+        meta.insert("lat", point.lat().to_string());
+        meta.insert("lon", point.lon().to_string());
+        err
+    })
+}
+
+/// Validates that a point is at most `meters` from `center`, along the Earth's surface.
+///
+/// Distances use the haversine formula on a sphere of the Earth's mean radius,
+/// which is within 0.5% of the ellipsoidal distance. Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::Point;
+///
+/// let office = Point::new(52.5200, 13.4050); // Berlin
+/// let rule = rules::within_radius(office, 50_000.0);
+/// assert!(rule.apply(&Point::new(52.3906, 13.0645)).is_empty()); // Potsdam
+/// assert!(!rule.apply(&Point::new(53.5511, 9.9937)).is_empty()); // Hamburg
+/// ```
+///
+/// # Error Code
+/// - Code: `outside_radius`
+/// - Message: `"Must be within 50000 meters of 52.52, 13.405"`
+/// - Meta: `{"center": "52.52, 13.405", "max": "50000", "actual": "255251"}`
+#[cfg(feature = "std")]
+pub fn within_radius<P: GeoPoint + 'static>(center: Point, meters: f64) -> Rule<P> {
+    Rule::new(move |point: &P, ctx: &RuleContext| {
+        // Written so that NaN coordinates are out of range
+        let distance = distance_meters(&center, point);
+        if distance <= meters {
+            return ValidationError::default();
+        }
+        let center_text = format!("{}, {}", center.lat, center.lon);
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "outside_radius",
+            format!("Must be within {} meters of {}", meters, center_text),
+        );
+        let meta = &mut err.violations[0].meta;
+        meta.insert("center", center_text);
+        meta.insert("max", meters.to_string());
+        meta.insert("actual", format!("{:.0}", distance));
+        err
+    })
+}
+
+/// Great-circle distance between two points in meters (haversine formula).
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn distance_meters<A: GeoPoint + ?Sized, B: GeoPoint + ?Sized>(a: &A, b: &B) -> f64 {
+    let (lat_a, lat_b) = (a.lat().to_radians(), b.lat().to_radians());
+    let half_dlat = (lat_b - lat_a) / 2.0;
+    let half_dlon = (b.lon() - a.lon()).to_radians() / 2.0;
+    let h = half_dlat.sin().powi(2) + lat_a.cos() * lat_b.cos() * half_dlon.sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * h.sqrt().min(1.0).asin()
+}
+
+/// Structural checks for GeoJSON polygons; see [`polygon`].
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::geo::PolygonPolicy;
+///
+/// let policy = PolygonPolicy {
+///     max_vertices: Some(1_000),
+///     rfc7946_winding: true,
+///     ..Default::default()
+/// };
+/// assert!(!policy.allow_self_intersection);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolygonPolicy {
+    /// Most positions across all rings, closing positions included.
+    pub max_vertices: Option<usize>,
+    /// Require the RFC 7946 right-hand rule: exterior ring counterclockwise,
+    /// holes clockwise.
+    pub rfc7946_winding: bool,
+    /// Accept rings whose edges cross or touch.
+    pub allow_self_intersection: bool,
+}
+
+/// Validates the `coordinates` of a GeoJSON `Polygon`: a list of linear rings,
+/// the exterior first and holes after it.
+///
+/// Always checked:
+/// - Each position is `[longitude, latitude]` or `[longitude, latitude, altitude]`,
+///   with longitude and latitude in range
+/// - Each ring is closed, with its last position equal to its first
+/// - Each ring has at least 4 positions, not counting repeated consecutive ones
+///
+/// Depending on `policy`, also the total vertex count, winding order, and that no
+/// two edges of a ring cross or touch. Rings are not checked against each other.
+///
+/// Positions can be `[f64; 2]`, `Vec<f64>` or anything else that is `AsRef<[f64]>`.
+/// Violations point into the arrays: `coordinates[0][3]` is the fourth position of
+/// the exterior ring, and `coordinates[0][3][1]` its latitude.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::geo::{self, PolygonPolicy};
+///
+/// let rule = geo::polygon(PolygonPolicy::default());
+///
+/// let square = vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]];
+/// assert!(rule.apply(&square).is_empty());
+///
+/// // Edges 0 -> 1 and 2 -> 3 cross
+/// let bowtie = vec![vec![[0.0, 0.0], [1.0, 1.0], [1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]];
+/// let err = rule.apply(&bowtie);
+/// assert_eq!(err.violations[0].code, "self_intersection");
+/// assert_eq!(err.violations[0].path.to_string(), "[0][2]");
+/// ```
+///
+/// # Error Codes
+/// - Code: `empty_polygon`
+///   - Message: `"Polygon must have at least one ring"`
+/// - Code: `too_many_vertices` (at the polygon; nothing else is checked)
+///   - Message: `"Polygon must have at most {max} vertices"`
+///   - Meta: `{"max": "1000", "actual": "1200"}`
+/// - Code: `invalid_position` (at the position)
+///   - Message: `"Position must be [longitude, latitude] or [longitude, latitude, altitude]"`
+///   - Meta: `{"len": "1"}`
+/// - Codes: `invalid_longitude` / `invalid_latitude` (at the coordinate), as for
+///   [`longitude`] and [`latitude`]
+/// - Code: `ring_not_closed` (at the ring's last position)
+///   - Message: `"Ring must end at its first position"`
+/// - Code: `ring_too_short` (at the ring)
+///   - Message: `"Ring must have at least 4 positions"`
+///   - Meta: `{"min": "4", "actual": "3"}`
+/// - Code: `self_intersection` (at the later edge's first position)
+///   - Message: `"Ring edges starting at positions {a} and {b} intersect"`
+///   - Meta: `{"edges": "[0, 2]"}`
+/// - Code: `invalid_winding` (at the ring)
+///   - Message: `"Exterior ring must be counterclockwise"` / `"Hole must be clockwise"`
+///   - Meta: `{"expected": "counterclockwise"}`
+pub fn polygon<P>(policy: PolygonPolicy) -> Rule<[Vec<P>]>
+where
+    P: AsRef<[f64]> + 'static,
+{
+    Rule::new(move |rings: &[Vec<P>], ctx: &RuleContext| {
+        let path = ctx.full_path();
+        if rings.is_empty() {
+            return ValidationError::single(
+                path,
+                "empty_polygon",
+                "Polygon must have at least one ring",
+            );
+        }
+
+        // Checked first so that oversized input never reaches the quadratic checks
+        let vertices: usize = rings.iter().map(Vec::len).sum();
+        if let Some(max) = policy.max_vertices {
+            if vertices > max {
+                let mut err = ValidationError::single(
+                    path,
+                    "too_many_vertices",
+                    format!("Polygon must have at most {} vertices", max),
+                );
+                err.violations[0].meta.insert("max", max.to_string());
+                err.violations[0]
+                    .meta
+                    .insert("actual", vertices.to_string());
+                return err;
+            }
+        }
+
+        let mut err = ValidationError::default();
+        for (ring_index, ring) in rings.iter().enumerate() {
+            let ring_path = path.clone().index(ring_index);
+            let before = err.violations.len();
+
+            let mut positions = Vec::with_capacity(ring.len());
+            for (index, position) in ring.iter().enumerate() {
+                let position_path = ring_path.clone().index(index);
+                let coords = position.as_ref();
+                if !(2..=3).contains(&coords.len()) {
+                    let mut violation = ValidationError::single(
+                        position_path,
+                        "invalid_position",
+                        "Position must be [longitude, latitude] or [longitude, latitude, altitude]",
+                    );
+                    violation.violations[0]
+                        .meta
+                        .insert("len", coords.len().to_string());
+                    err.extend(violation);
+                    continue;
+                }
+                let (lon, lat) = (coords[0], coords[1]);
+                if !valid_longitude(lon) {
+                    err.extend(coordinate_error(
+                        position_path.clone().index(0),
+                        "invalid_longitude",
+                        "Longitude",
+                        180,
+                    ));
+                }
+                if !valid_latitude(lat) {
+                    err.extend(coordinate_error(
+                        position_path.index(1),
+                        "invalid_latitude",
+                        "Latitude",
+                        90,
+                    ));
+                }
+                positions.push((index, lon, lat));
+            }
+            if err.violations.len() > before {
+                continue;
+            }
+
+            if let (Some(first), Some(last)) = (positions.first(), positions.last()) {
+                if (first.1, first.2) != (last.1, last.2) {
+                    err.push(
+                        ring_path.clone().index(last.0),
+                        "ring_not_closed",
+                        "Ring must end at its first position",
+                    );
+                }
+            }
+
+            // Repeated consecutive positions add zero-length edges but no shape
+            positions.dedup_by(|b, a| (a.1, a.2) == (b.1, b.2));
+            if positions.len() < MIN_RING_POSITIONS {
+                let mut violation = ValidationError::single(
+                    ring_path,
+                    "ring_too_short",
+                    format!("Ring must have at least {} positions", MIN_RING_POSITIONS),
+                );
+                violation.violations[0]
+                    .meta
+                    .insert("min", MIN_RING_POSITIONS.to_string());
+                violation.violations[0]
+                    .meta
+                    .insert("actual", positions.len().to_string());
+                err.extend(violation);
+                continue;
+            }
+            if err.violations.len() > before {
+                continue;
+            }
+
+            if !policy.allow_self_intersection {
+                if let Some((a, b)) = crossing_edges(&positions) {
+                    let mut violation = ValidationError::single(
+                        ring_path.clone().index(b),
+                        "self_intersection",
+                        format!("Ring edges starting at positions {} and {} intersect", a, b),
+                    );
+                    violation.violations[0]
+                        .meta
+                        .insert("edges", format!("[{}, {}]", a, b));
+                    err.extend(violation);
+                    continue;
+                }
+            }
+
+            if policy.rfc7946_winding {
+                let counterclockwise = signed_area(&positions) > 0.0;
+                let (expected, message) = if ring_index == 0 {
+                    ("counterclockwise", "Exterior ring must be counterclockwise")
+                } else {
+                    ("clockwise", "Hole must be clockwise")
+                };
+                if counterclockwise != (ring_index == 0) {
+                    let mut violation =
+                        ValidationError::single(ring_path, "invalid_winding", message);
+                    violation.violations[0].meta.insert("expected", expected);
+                    err.extend(violation);
+                }
+            }
+        }
+        err
+    })
+}
+
+fn valid_latitude(value: f64) -> bool {
+    (-90.0..=90.0).contains(&value)
+}
+
+fn valid_longitude(value: f64) -> bool {
+    (-180.0..=180.0).contains(&value)
+}
+
+fn coordinate_error(
+    path: impl Into<crate::Path>,
+    code: &'static str,
+    name: &str,
+    bound: u32,
+) -> ValidationError {
+    let mut err = ValidationError::single(
+        path,
+        code,
+        format!("{} must be between -{} and {}", name, bound, bound),
+    );
+    err.violations[0].meta.insert("min", format!("-{}", bound));
+    err.violations[0].meta.insert("max", bound.to_string());
+    err
+}
+
+/// First pair of non-adjacent edges that cross or touch, as the original indices of
+/// their first positions. `ring` is closed and free of repeated consecutive positions.
+fn crossing_edges(ring: &[(usize, f64, f64)]) -> Option<(usize, usize)> {
+    let edges = ring.len() - 1;
+    let point = |i: usize| (ring[i].1, ring[i].2);
+    for i in 0..edges {
+        for j in i + 2..edges {
+            // The last edge ends where the first one starts
+            if i == 0 && j == edges - 1 {
+                continue;
+            }
+            if segments_intersect(point(i), point(i + 1), point(j), point(j + 1)) {
+                return Some((ring[i].0, ring[j].0));
+            }
+        }
+    }
+    None
+}
+
+fn segments_intersect(p1: (f64, f64), p2: (f64, f64), q1: (f64, f64), q2: (f64, f64)) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    // Collinear or touching: an endpoint lies on the other segment
+    (d1 == 0.0 && within_extent(q1, q2, p1))
+        || (d2 == 0.0 && within_extent(q1, q2, p2))
+        || (d3 == 0.0 && within_extent(p1, p2, q1))
+        || (d4 == 0.0 && within_extent(p1, p2, q2))
+}
+
+/// Cross product of `a -> b` and `a -> c`: positive when `c` is left of `a -> b`.
+fn orientation(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Whether `p`, known to be collinear with `a` and `b`, lies between them.
+fn within_extent(a: (f64, f64), b: (f64, f64), p: (f64, f64)) -> bool {
+    a.0.min(b.0) <= p.0 && p.0 <= a.0.max(b.0) && a.1.min(b.1) <= p.1 && p.1 <= a.1.max(b.1)
+}
+
+/// Twice the signed area of a closed ring (shoelace formula), positive when
+/// counterclockwise.
+fn signed_area(ring: &[(usize, f64, f64)]) -> f64 {
+    ring.windows(2)
+        .map(|pair| pair[0].1 * pair[1].2 - pair[1].1 * pair[0].2)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn ring(points: &[(f64, f64)]) -> Vec<[f64; 2]> {
+        points.iter().map(|&(lon, lat)| [lon, lat]).collect()
+    }
+
+    #[test]
+    fn test_latitude_longitude() {
+        assert!(latitude().apply(&-90.0).is_empty());
+        assert!(longitude().apply(&180.0).is_empty());
+
+        let err = latitude().apply(&-90.5);
+        assert_eq!(err.violations[0].code, "invalid_latitude");
+        assert_eq!(err.violations[0].meta.get("min"), Some("-90"));
+
+        let err = longitude().apply(&f64::INFINITY);
+        assert_eq!(err.violations[0].code, "invalid_longitude");
+        assert_eq!(
+            err.violations[0].message,
+            "Longitude must be between -180 and 180"
+        );
+    }
+
+    #[test]
+    fn test_within_bbox() {
+        let rule = within_bbox(BoundingBox::new(-10.0, 35.0, 30.0, 60.0));
+        assert!(rule.apply(&Point::new(35.0, -10.0)).is_empty());
+
+        let err = rule.apply(&Point::new(61.0, 0.0));
+        assert_eq!(err.violations[0].code, "outside_bbox");
+        assert_eq!(
+            err.violations[0].meta.get("bbox"),
+            Some("[-10, 35, 30, 60]")
+        );
+        assert_eq!(err.violations[0].meta.get("lat"), Some("61"));
+
+        assert!(!rule.apply(&Point::new(f64::NAN, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn test_within_bbox_antimeridian() {
+        let fiji = BoundingBox::new(176.0, -21.0, -178.0, -12.0);
+        assert!(fiji.contains(&Point::new(-18.0, 178.4)));
+        assert!(fiji.contains(&Point::new(-16.0, -179.9)));
+        assert!(!fiji.contains(&Point::new(-16.0, 0.0)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_within_radius() {
+        let paris = Point::new(48.8566, 2.3522);
+        let london = Point::new(51.5074, -0.1278);
+        let distance = distance_meters(&paris, &london);
+        assert!((distance - 343_560.0).abs() < 1_000.0, "{}", distance);
+
+        let rule = within_radius(paris, 300_000.0);
+        assert!(rule.apply(&paris).is_empty());
+        let err = rule.apply(&london);
+        assert_eq!(err.violations[0].code, "outside_radius");
+        assert_eq!(err.violations[0].meta.get("max"), Some("300000"));
+        assert_eq!(
+            err.violations[0].meta.get("center"),
+            Some("48.8566, 2.3522")
+        );
+
+        assert!(!rule.apply(&Point::new(f64::NAN, 2.0)).is_empty());
+    }
+
+    #[test]
+    fn test_polygon_valid() {
+        let rule = polygon(PolygonPolicy {
+            rfc7946_winding: true,
+            ..Default::default()
+        });
+        let exterior = ring(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)]);
+        let hole = ring(&[(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)]);
+        assert!(rule.apply(&[exterior, hole]).is_empty());
+
+        // Altitude and repeated positions are fine
+        let positions: Vec<Vec<f64>> = vec![
+            vec![0.0, 0.0, 10.0],
+            vec![1.0, 0.0, 10.0],
+            vec![1.0, 0.0, 10.0],
+            vec![1.0, 1.0, 10.0],
+            vec![0.0, 0.0, 10.0],
+        ];
+        let rule = polygon(PolygonPolicy::default());
+        assert!(rule.apply(&[positions]).is_empty());
+    }
+
+    #[test]
+    fn test_polygon_structure() {
+        let rule = polygon(PolygonPolicy::default());
+        let empty: [Vec<[f64; 2]>; 0] = [];
+        assert_eq!(rule.apply(&empty).violations[0].code, "empty_polygon");
+
+        let positions: Vec<Vec<f64>> = vec![
+            vec![0.0, 0.0],
+            vec![200.0, 0.0],
+            vec![1.0],
+            vec![0.0, -95.0],
+            vec![0.0, 0.0],
+        ];
+        let err = polygon(PolygonPolicy::default()).apply(&[positions]);
+        let found: Vec<_> = err
+            .violations
+            .iter()
+            .map(|v| (v.code, v.path.to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("invalid_longitude", "[0][1][0]".to_string()),
+                ("invalid_position", "[0][2]".to_string()),
+                ("invalid_latitude", "[0][3][1]".to_string()),
+            ]
+        );
+
+        let open = ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        let err = rule.apply(&[open]);
+        assert_eq!(err.violations[0].code, "ring_not_closed");
+        assert_eq!(err.violations[0].path.to_string(), "[0][2]");
+        assert_eq!(err.violations[1].code, "ring_too_short");
+        assert_eq!(err.violations[1].meta.get("actual"), Some("3"));
+    }
+
+    #[test]
+    fn test_polygon_self_intersection() {
+        let rule = polygon(PolygonPolicy::default());
+        let bowtie = ring(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0), (0.0, 0.0)]);
+        let err = rule.apply(core::slice::from_ref(&bowtie));
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].meta.get("edges"), Some("[0, 2]"));
+
+        // A vertex touching another edge
+        let touching = ring(&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (2.0, 2.0),
+            (2.0, 0.0),
+            (0.0, 2.0),
+            (0.0, 0.0),
+        ]);
+        assert_eq!(
+            rule.apply(&[touching]).violations[0].code,
+            "self_intersection"
+        );
+
+        let lenient = polygon(PolygonPolicy {
+            allow_self_intersection: true,
+            ..Default::default()
+        });
+        assert!(lenient.apply(&[bowtie]).is_empty());
+    }
+
+    #[test]
+    fn test_polygon_winding() {
+        let rule = polygon(PolygonPolicy {
+            rfc7946_winding: true,
+            ..Default::default()
+        });
+        let clockwise = ring(&[(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)]);
+        let err = rule.apply(&[clockwise.clone(), clockwise.clone()]);
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "invalid_winding");
+        assert_eq!(err.violations[0].path.to_string(), "[0]");
+        assert_eq!(
+            err.violations[0].meta.get("expected"),
+            Some("counterclockwise")
+        );
+
+        assert!(polygon(PolygonPolicy::default())
+            .apply(&[clockwise])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_polygon_max_vertices() {
+        let rule = polygon(PolygonPolicy {
+            max_vertices: Some(4),
+            ..Default::default()
+        });
+        let square = ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        let err = rule.apply(&[square]);
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "too_many_vertices");
+        assert_eq!(err.violations[0].meta.get("actual"), Some("5"));
+    }
+}
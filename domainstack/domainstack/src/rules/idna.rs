@@ -0,0 +1,191 @@
+//! Internationalized domain name helpers shared by the hostname-style rules.
+//!
+//! This is deliberately a small subset of IDNA 2008: labels are lowercased,
+//! restricted to letters, digits and hyphens, and Punycode-encoded
+//! (RFC 3492) so the DNS length limits can be checked against the ASCII form.
+//! It does not apply the full UTS #46 mapping tables.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Maximum length of a single DNS label in its ASCII form.
+pub(crate) const MAX_LABEL_LEN: usize = 63;
+
+/// Maximum length of a domain name in its ASCII form, without a trailing dot.
+pub(crate) const MAX_DOMAIN_LEN: usize = 253;
+
+/// Convert one label to its ASCII (A-label) form.
+///
+/// Returns `None` if the label is empty, too long, starts or ends with a
+/// hyphen, or contains characters that are not letters, digits or hyphens.
+pub(crate) fn to_ascii_label(label: &str) -> Option<String> {
+    if label.is_empty() || label.starts_with('-') || label.ends_with('-') {
+        return None;
+    }
+
+    let ascii = if label.is_ascii() {
+        if !label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        {
+            return None;
+        }
+        label.to_ascii_lowercase()
+    } else {
+        let mut code_points = Vec::with_capacity(label.len());
+        for c in label.chars() {
+            if !(c.is_alphanumeric() || c == '-') {
+                return None;
+            }
+            code_points.extend(c.to_lowercase().map(u32::from));
+        }
+        let mut encoded = String::from("xn--");
+        encoded.push_str(&punycode_encode(&code_points)?);
+        encoded
+    };
+
+    (ascii.len() <= MAX_LABEL_LEN).then_some(ascii)
+}
+
+/// Convert a dot-separated domain name to its ASCII form.
+///
+/// A single trailing dot (the DNS root) is not allowed here; callers strip it
+/// first when they accept absolute names.
+pub(crate) fn to_ascii_domain(domain: &str) -> Option<String> {
+    let mut ascii = String::with_capacity(domain.len());
+    for (i, label) in domain.split('.').enumerate() {
+        if i > 0 {
+            ascii.push('.');
+        }
+        ascii.push_str(&to_ascii_label(label)?);
+    }
+
+    (ascii.len() <= MAX_DOMAIN_LEN).then_some(ascii)
+}
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+/// Punycode encoding from RFC 3492, section 6.3.
+fn punycode_encode(input: &[u32]) -> Option<String> {
+    let mut output: String = input
+        .iter()
+        .filter(|&&c| c < 0x80)
+        .filter_map(|&c| char::from_u32(c))
+        .collect();
+
+    let basic = output.len() as u32;
+    let mut handled = basic;
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let total = input.len() as u32;
+
+    while handled < total {
+        let m = input.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for &c in input {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        T_MIN
+                    } else if k >= bias + T_MAX {
+                        T_MAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1)?;
+        n = n.checked_add(1)?;
+    }
+
+    Some(output)
+}
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn digit(d: u32) -> char {
+    match d {
+        0..=25 => (b'a' + d as u8) as char,
+        _ => (b'0' + (d - 26) as u8) as char,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_label_is_lowercased() {
+        assert_eq!(to_ascii_label("Example").as_deref(), Some("example"));
+        assert_eq!(to_ascii_label("a-1").as_deref(), Some("a-1"));
+    }
+
+    #[test]
+    fn test_unicode_label_is_punycode_encoded() {
+        assert_eq!(to_ascii_label("bücher").as_deref(), Some("xn--bcher-kva"));
+        assert_eq!(to_ascii_label("München").as_deref(), Some("xn--mnchen-3ya"));
+        assert_eq!(to_ascii_label("例え").as_deref(), Some("xn--r8jz45g"));
+    }
+
+    #[test]
+    fn test_invalid_labels() {
+        assert!(to_ascii_label("").is_none());
+        assert!(to_ascii_label("-abc").is_none());
+        assert!(to_ascii_label("abc-").is_none());
+        assert!(to_ascii_label("a_b").is_none());
+        assert!(to_ascii_label("ü_b").is_none());
+        assert!(to_ascii_label(&"a".repeat(64)).is_none());
+    }
+
+    #[test]
+    fn test_domain_length_uses_ascii_form() {
+        let label = "a".repeat(63);
+        let domain = [label.as_str(); 4].join(".");
+        assert_eq!(domain.len(), 255);
+        assert!(to_ascii_domain(&domain).is_none());
+        assert_eq!(
+            to_ascii_domain("bücher.example").as_deref(),
+            Some("xn--bcher-kva.example")
+        );
+    }
+}
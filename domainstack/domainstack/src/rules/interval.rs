@@ -0,0 +1,349 @@
+//! Interval rules: ordered `(start, end)` pairs and overlap checks across collections.
+//!
+//! Intervals are half-open, `[start, end)`: a booking that checks out on the day the
+//! next one checks in does not overlap it. The rules work on any `PartialOrd` type,
+//! such as numbers or chrono / `time` dates.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Display;
+
+/// Validates that a `(start, end)` pair is ordered, with `start` strictly before `end`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::valid_range();
+/// assert!(rule.apply(&(9, 17)).is_empty());
+/// assert!(!rule.apply(&(17, 9)).is_empty());
+/// assert!(!rule.apply(&(9, 9)).is_empty()); // empty interval
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_range`
+/// - Message: `"Start must be before end"`
+/// - Meta: `{"start": "17", "end": "9"}`
+pub fn valid_range<T>() -> Rule<(T, T)>
+where
+    T: PartialOrd + Display + 'static,
+{
+    Rule::new(|(start, end): &(T, T), ctx: &RuleContext| {
+        if start < end {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "invalid_range",
+                "Start must be before end",
+            );
+            err.violations[0].meta.insert("start", start.to_string());
+            err.violations[0].meta.insert("end", end.to_string());
+            err
+        }
+    })
+}
+
+/// A value [`max_span`] can measure the distance `end - start` between.
+///
+/// Implemented for integers, floats, `rust_decimal::Decimal` with the `decimal`
+/// feature, and the chrono and `time` dates, times and date-times, whose span is a
+/// chrono or `time` `Duration`.
+pub trait Span: PartialOrd + 'static {
+    /// The type of `end - start`, such as `u32` for `u32` or chrono's `Duration` for `NaiveDate`.
+    type Output: PartialOrd + Display + Send + Sync + 'static;
+
+    /// `end - start` for `start <= end`, or `None` if it overflows `Output`.
+    fn span(start: &Self, end: &Self) -> Option<Self::Output>;
+}
+
+macro_rules! impl_span_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Span for $ty {
+                type Output = $ty;
+
+                fn span(start: &Self, end: &Self) -> Option<$ty> {
+                    end.checked_sub(*start)
+                }
+            }
+        )*
+    };
+}
+
+impl_span_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_span_by_subtraction {
+    ($($ty:ty => $output:ty),* $(,)?) => {
+        $(
+            impl Span for $ty {
+                type Output = $output;
+
+                fn span(start: &Self, end: &Self) -> Option<$output> {
+                    Some(*end - *start)
+                }
+            }
+        )*
+    };
+}
+
+impl_span_by_subtraction!(f32 => f32, f64 => f64);
+
+#[cfg(feature = "decimal")]
+impl Span for rust_decimal::Decimal {
+    type Output = rust_decimal::Decimal;
+
+    fn span(start: &Self, end: &Self) -> Option<rust_decimal::Decimal> {
+        end.checked_sub(*start)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl_span_by_subtraction!(
+    chrono::NaiveDate => chrono::Duration,
+    chrono::NaiveDateTime => chrono::Duration,
+    chrono::NaiveTime => chrono::Duration,
+);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone + 'static> Span for chrono::DateTime<Tz> {
+    type Output = chrono::Duration;
+
+    fn span(start: &Self, end: &Self) -> Option<chrono::Duration> {
+        Some(end.naive_utc() - start.naive_utc())
+    }
+}
+
+#[cfg(feature = "time")]
+impl_span_by_subtraction!(
+    time::Date => time::Duration,
+    time::PrimitiveDateTime => time::Duration,
+    time::OffsetDateTime => time::Duration,
+    time::Time => time::Duration,
+);
+
+/// Validates that a `(start, end)` pair spans at most `max`, measured as `end - start`.
+///
+/// Reversed pairs are not measured and pass; combine with [`valid_range`] to reject
+/// them. A span too large for its type, such as `i32::MIN` to `i32::MAX`, is
+/// reported with `actual` set to `"overflow"`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::valid_range().and(rules::max_span(14));
+/// assert!(rule.apply(&(1, 15)).is_empty());
+///
+/// let err = rule.apply(&(1, 16));
+/// assert_eq!(err.violations[0].code, "span_too_long");
+/// assert_eq!(err.violations[0].meta.get("actual"), Some("15"));
+/// ```
+///
+/// # Error Code
+/// - Code: `span_too_long`
+/// - Message: `"Range must span at most {max}"`
+/// - Meta: `{"max": "14", "actual": "15"}`
+pub fn max_span<T: Span>(max: T::Output) -> Rule<(T, T)> {
+    Rule::new(move |(start, end): &(T, T), ctx: &RuleContext| {
+        // Reversed (or unordered, e.g. NaN) pairs are `valid_range`'s to report
+        if matches!(start.partial_cmp(end), Some(Ordering::Greater) | None) {
+            return ValidationError::default();
+        }
+        let actual = match T::span(start, end) {
+            Some(span) if span <= max => return ValidationError::default(),
+            Some(span) => span.to_string(),
+            None => "overflow".to_string(),
+        };
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "span_too_long",
+            format!("Range must span at most {}", max),
+        );
+        err.violations[0].meta.insert("max", max.to_string());
+        err.violations[0].meta.insert("actual", actual);
+        err
+    })
+}
+
+/// Validates that no two items of a collection have overlapping intervals.
+///
+/// `interval` extracts each item's `(start, end)`. Reports one violation per
+/// overlapping pair, with both indices in `indices`. Empty or reversed intervals
+/// overlap nothing; check them with [`valid_range`] through `each`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// struct Stay {
+///     check_in: u32,
+///     check_out: u32,
+/// }
+///
+/// let rule = rules::non_overlapping(|stay: &Stay| (stay.check_in, stay.check_out));
+///
+/// let stays = [
+///     Stay { check_in: 1, check_out: 4 },
+///     Stay { check_in: 4, check_out: 6 }, // back to back: fine
+///     Stay { check_in: 3, check_out: 5 }, // overlaps both
+/// ];
+/// let err = rule.apply(&stays);
+/// assert_eq!(err.violations.len(), 2);
+/// assert_eq!(err.violations[0].meta.get("indices"), Some("[0, 2]"));
+/// assert_eq!(err.violations[1].meta.get("indices"), Some("[1, 2]"));
+/// ```
+///
+/// # Error Code
+/// - Code: `overlapping_intervals`
+/// - Message: `"Items {first} and {second} overlap"`
+/// - Meta: `{"indices": "[0, 2]"}`
+pub fn non_overlapping<I, T, F>(interval: F) -> Rule<[I]>
+where
+    I: 'static,
+    T: PartialOrd,
+    F: Fn(&I) -> (T, T) + Send + Sync + 'static,
+{
+    Rule::new(move |items: &[I], ctx: &RuleContext| {
+        let mut intervals: Vec<(usize, T, T)> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let (start, end) = interval(item);
+                (index, start, end)
+            })
+            .filter(|(_, start, end)| start < end)
+            .collect();
+        intervals.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+
+        // Sweep in start order: each interval can only overlap the ones that start
+        // before it ends
+        let mut overlaps = Vec::new();
+        for (i, (first, _, end)) in intervals.iter().enumerate() {
+            for (second, start, _) in intervals[i + 1..].iter() {
+                if start >= end {
+                    break;
+                }
+                overlaps.push((*first.min(second), *first.max(second)));
+            }
+        }
+        overlaps.sort_unstable();
+
+        let mut err = ValidationError::default();
+        for (first, second) in overlaps {
+            let mut violation = ValidationError::single(
+                ctx.full_path(),
+                "overlapping_intervals",
+                format!("Items {} and {} overlap", first, second),
+            );
+            violation.violations[0]
+                .meta
+                .insert("indices", format!("[{}, {}]", first, second));
+            err.extend(violation);
+        }
+        err
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_range() {
+        let rule = valid_range();
+        assert!(rule.apply(&(1.5, 2.0)).is_empty());
+
+        let err = rule.apply(&(2.0, 1.5));
+        assert_eq!(err.violations[0].code, "invalid_range");
+        assert_eq!(err.violations[0].meta.get("start"), Some("2"));
+        assert_eq!(err.violations[0].meta.get("end"), Some("1.5"));
+
+        // NaN is not ordered
+        assert!(!rule.apply(&(f64::NAN, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn test_max_span() {
+        let rule = max_span(10u32);
+        assert!(rule.apply(&(5u32, 15)).is_empty());
+        let err = rule.apply(&(5, 16));
+        assert_eq!(err.violations[0].message, "Range must span at most 10");
+        assert_eq!(err.violations[0].meta.get("max"), Some("10"));
+
+        let signed = max_span(10i64);
+        assert!(signed.apply(&(15i64, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_max_span_reversed_unsigned() {
+        assert!(max_span(14u32).apply(&(5u32, 1)).is_empty());
+
+        let rule = valid_range().and(max_span(14u32));
+        let err = rule.apply(&(5u32, 1));
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "invalid_range");
+    }
+
+    #[test]
+    fn test_max_span_overflow() {
+        let err = max_span(14i32).apply(&(i32::MIN, i32::MAX));
+        assert_eq!(err.violations[0].code, "span_too_long");
+        assert_eq!(err.violations[0].meta.get("actual"), Some("overflow"));
+
+        let err = max_span(14i8).apply(&(-100i8, 100));
+        assert_eq!(err.violations[0].meta.get("actual"), Some("overflow"));
+        assert!(max_span(i8::MAX).apply(&(0i8, i8::MAX)).is_empty());
+    }
+
+    #[test]
+    fn test_max_span_floats() {
+        let rule = max_span(1.5);
+        assert!(rule.apply(&(1.0, 2.5)).is_empty());
+        assert!(!rule.apply(&(1.0, 2.6)).is_empty());
+        assert!(rule.apply(&(f64::NAN, 2.0)).is_empty());
+    }
+
+    #[test]
+    fn test_non_overlapping() {
+        let rule = non_overlapping(|range: &(i32, i32)| *range);
+
+        assert!(rule.apply(&[]).is_empty());
+        assert!(rule.apply(&[(0, 10), (10, 20), (20, 30)]).is_empty());
+
+        // Empty and reversed intervals are ignored
+        assert!(rule.apply(&[(0, 10), (5, 5), (8, 2)]).is_empty());
+
+        let err = rule.apply(&[(20, 30), (0, 10), (5, 25), (40, 50)]);
+        assert_eq!(err.violations.len(), 2);
+        assert_eq!(err.violations[0].code, "overlapping_intervals");
+        assert_eq!(err.violations[0].meta.get("indices"), Some("[0, 2]"));
+        assert_eq!(err.violations[0].message, "Items 0 and 2 overlap");
+        assert_eq!(err.violations[1].meta.get("indices"), Some("[1, 2]"));
+    }
+
+    #[test]
+    fn test_non_overlapping_nested_intervals() {
+        let rule = non_overlapping(|range: &(i32, i32)| *range);
+        let err = rule.apply(&[(0, 100), (10, 20), (30, 40), (90, 110)]);
+        let pairs: Vec<_> = err
+            .violations
+            .iter()
+            .map(|v| v.meta.get("indices").unwrap().to_string())
+            .collect();
+        assert_eq!(pairs, ["[0, 1]", "[0, 2]", "[0, 3]"]);
+    }
+
+    #[test]
+    fn test_non_overlapping_path() {
+        let rule = non_overlapping(|range: &(u8, u8)| *range);
+        let err = crate::validate("rooms", [(1, 3), (2, 4)].as_slice(), &rule).unwrap_err();
+        assert_eq!(err.violations[0].path.to_string(), "rooms");
+    }
+}
@@ -0,0 +1,492 @@
+//! Rules for ISO code sets: country, currency and language codes, plus
+//! BCP 47 locale tags.
+//!
+//! The code lists are embedded as sorted tables and exported so schema
+//! generators can emit them as `enum` values. Codes are matched in their
+//! canonical case (`"US"`, `"EUR"`, `"en"`); locale tags are case-insensitive
+//! as BCP 47 requires.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Validates that a string is an ISO 3166-1 alpha-2 country code.
+///
+/// Codes must be uppercase, e.g. `"US"` or `"DE"`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::iso3166_alpha2();
+/// assert!(rule.apply("DE").is_empty());
+/// assert!(!rule.apply("de").is_empty());
+/// assert!(!rule.apply("XX").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_country_code`
+/// - Message: `"Must be an ISO 3166-1 alpha-2 country code"`
+/// - Meta: `{"format": "alpha2"}`
+pub fn iso3166_alpha2() -> Rule<str> {
+    code_set(
+        ISO3166_ALPHA2,
+        "invalid_country_code",
+        "Must be an ISO 3166-1 alpha-2 country code",
+        "alpha2",
+    )
+}
+
+/// Validates that a string is an ISO 3166-1 alpha-3 country code.
+///
+/// Codes must be uppercase, e.g. `"USA"` or `"DEU"`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::iso3166_alpha3();
+/// assert!(rule.apply("DEU").is_empty());
+/// assert!(!rule.apply("DE").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_country_code`
+/// - Message: `"Must be an ISO 3166-1 alpha-3 country code"`
+/// - Meta: `{"format": "alpha3"}`
+pub fn iso3166_alpha3() -> Rule<str> {
+    code_set(
+        ISO3166_ALPHA3,
+        "invalid_country_code",
+        "Must be an ISO 3166-1 alpha-3 country code",
+        "alpha3",
+    )
+}
+
+/// Validates that a string is an ISO 4217 currency code.
+///
+/// Codes must be uppercase, e.g. `"EUR"` or `"JPY"`. Fund and precious-metal
+/// codes from List One (`"CHE"`, `"XAU"`) are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::iso4217_currency();
+/// assert!(rule.apply("EUR").is_empty());
+/// assert!(!rule.apply("EURO").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_currency_code`
+/// - Message: `"Must be an ISO 4217 currency code"`
+/// - Meta: `{"format": "alpha3"}`
+pub fn iso4217_currency() -> Rule<str> {
+    code_set(
+        ISO4217_CURRENCIES,
+        "invalid_currency_code",
+        "Must be an ISO 4217 currency code",
+        "alpha3",
+    )
+}
+
+/// Validates that a string is an ISO 639-1 language code.
+///
+/// Codes must be lowercase, e.g. `"en"` or `"pt"`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::iso639_language();
+/// assert!(rule.apply("en").is_empty());
+/// assert!(!rule.apply("eng").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_language_code`
+/// - Message: `"Must be an ISO 639-1 language code"`
+/// - Meta: `{"format": "alpha2"}`
+pub fn iso639_language() -> Rule<str> {
+    code_set(
+        ISO639_LANGUAGES,
+        "invalid_language_code",
+        "Must be an ISO 639-1 language code",
+        "alpha2",
+    )
+}
+
+/// Validates that a string is a well-formed BCP 47 (RFC 5646) language tag.
+///
+/// Tags are parsed subtag by subtag: language (with optional extended
+/// language subtags), script, region, variants, extensions and private use.
+/// Two-letter languages must be in ISO 639-1 and two-letter regions in
+/// ISO 3166-1; three-letter languages and UN M.49 numeric regions are checked
+/// for shape only. Matching is case-insensitive. Irregular grandfathered tags
+/// such as `"i-klingon"` are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::bcp47_locale();
+/// assert!(rule.apply("en-US").is_empty());
+/// assert!(rule.apply("zh-Hant-TW").is_empty());
+/// assert!(rule.apply("es-419").is_empty());
+/// assert!(!rule.apply("en_US").is_empty());
+/// assert!(!rule.apply("qq-US").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_locale`
+/// - Message: `"Must be a valid BCP 47 language tag"`
+/// - Meta: `{"reason": "syntax" | "language" | "region"}`, plus `subtag`
+///   for `language` and `region`
+pub fn bcp47_locale() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let Err(failure) = check_locale(value) else {
+            return ValidationError::default();
+        };
+
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "invalid_locale",
+            "Must be a valid BCP 47 language tag",
+        );
+        let meta = &mut err.violations[0].meta;
+        match failure {
+            LocaleFailure::Syntax => meta.insert("reason", "syntax"),
+            LocaleFailure::Language(subtag) => {
+                meta.insert("reason", "language");
+                meta.insert("subtag", subtag);
+            }
+            LocaleFailure::Region(subtag) => {
+                meta.insert("reason", "region");
+                meta.insert("subtag", subtag);
+            }
+        }
+        err
+    })
+}
+
+fn code_set(
+    table: &'static [&'static str],
+    code: &'static str,
+    message: &'static str,
+    format: &'static str,
+) -> Rule<str> {
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        if table.binary_search(&value).is_ok() {
+            return ValidationError::default();
+        }
+        let mut err = ValidationError::single(ctx.full_path(), code, message);
+        err.violations[0].meta.insert("format", format);
+        err
+    })
+}
+
+enum LocaleFailure<'a> {
+    Syntax,
+    Language(&'a str),
+    Region(&'a str),
+}
+
+fn check_locale(tag: &str) -> Result<(), LocaleFailure<'_>> {
+    let subtags: Vec<&str> = tag.split('-').collect();
+    let well_formed =
+        |s: &&str| !s.is_empty() && s.len() <= 8 && s.bytes().all(|b| b.is_ascii_alphanumeric());
+    if !subtags.iter().all(well_formed) {
+        return Err(LocaleFailure::Syntax);
+    }
+
+    let mut rest = &subtags[..];
+    if !rest[0].eq_ignore_ascii_case("x") {
+        let language = rest[0];
+        if !(2..=3).contains(&language.len()) || !is_alpha(language) {
+            return Err(LocaleFailure::Syntax);
+        }
+        if language.len() == 2 && !contains_ignore_case(ISO639_LANGUAGES, language, false) {
+            return Err(LocaleFailure::Language(language));
+        }
+        rest = &rest[1..];
+
+        // Up to three extended language subtags
+        let extlangs = rest
+            .iter()
+            .take(3)
+            .take_while(|s| s.len() == 3 && is_alpha(s))
+            .count();
+        rest = &rest[extlangs..];
+
+        if rest.first().is_some_and(|s| s.len() == 4 && is_alpha(s)) {
+            rest = &rest[1..];
+        }
+
+        if let Some(&region) = rest.first() {
+            if region.len() == 2 && is_alpha(region) {
+                if !contains_ignore_case(ISO3166_ALPHA2, region, true) {
+                    return Err(LocaleFailure::Region(region));
+                }
+                rest = &rest[1..];
+            } else if region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit()) {
+                rest = &rest[1..];
+            }
+        }
+
+        let mut variants: Vec<&str> = Vec::new();
+        while let Some(&variant) = rest.first() {
+            let is_variant = variant.len() >= 5
+                || (variant.len() == 4 && variant.as_bytes()[0].is_ascii_digit());
+            if !is_variant {
+                break;
+            }
+            if variants.iter().any(|v| v.eq_ignore_ascii_case(variant)) {
+                return Err(LocaleFailure::Syntax);
+            }
+            variants.push(variant);
+            rest = &rest[1..];
+        }
+
+        let mut singletons: Vec<u8> = Vec::new();
+        while let Some(&singleton) = rest.first() {
+            if singleton.len() != 1 || singleton.eq_ignore_ascii_case("x") {
+                break;
+            }
+            let key = singleton.as_bytes()[0].to_ascii_lowercase();
+            if singletons.contains(&key) {
+                return Err(LocaleFailure::Syntax);
+            }
+            singletons.push(key);
+            rest = &rest[1..];
+
+            let len = rest.iter().take_while(|s| s.len() >= 2).count();
+            if len == 0 {
+                return Err(LocaleFailure::Syntax);
+            }
+            rest = &rest[len..];
+        }
+    }
+
+    match rest.first() {
+        None => Ok(()),
+        Some(x) if x.eq_ignore_ascii_case("x") && rest.len() > 1 => Ok(()),
+        Some(_) => Err(LocaleFailure::Syntax),
+    }
+}
+
+fn is_alpha(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn contains_ignore_case(table: &[&str], value: &str, upper: bool) -> bool {
+    let canonical: String = if upper {
+        value.to_ascii_uppercase()
+    } else {
+        value.to_ascii_lowercase()
+    };
+    table.binary_search(&canonical.as_str()).is_ok()
+}
+
+/// ISO 3166-1 alpha-2 country codes, uppercase and sorted.
+pub const ISO3166_ALPHA2: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// ISO 3166-1 alpha-3 country codes, uppercase and sorted.
+pub const ISO3166_ALPHA3: &[&str] = &[
+    "ABW", "AFG", "AGO", "AIA", "ALA", "ALB", "AND", "ARE", "ARG", "ARM", "ASM", "ATA", "ATF",
+    "ATG", "AUS", "AUT", "AZE", "BDI", "BEL", "BEN", "BES", "BFA", "BGD", "BGR", "BHR", "BHS",
+    "BIH", "BLM", "BLR", "BLZ", "BMU", "BOL", "BRA", "BRB", "BRN", "BTN", "BVT", "BWA", "CAF",
+    "CAN", "CCK", "CHE", "CHL", "CHN", "CIV", "CMR", "COD", "COG", "COK", "COL", "COM", "CPV",
+    "CRI", "CUB", "CUW", "CXR", "CYM", "CYP", "CZE", "DEU", "DJI", "DMA", "DNK", "DOM", "DZA",
+    "ECU", "EGY", "ERI", "ESH", "ESP", "EST", "ETH", "FIN", "FJI", "FLK", "FRA", "FRO", "FSM",
+    "GAB", "GBR", "GEO", "GGY", "GHA", "GIB", "GIN", "GLP", "GMB", "GNB", "GNQ", "GRC", "GRD",
+    "GRL", "GTM", "GUF", "GUM", "GUY", "HKG", "HMD", "HND", "HRV", "HTI", "HUN", "IDN", "IMN",
+    "IND", "IOT", "IRL", "IRN", "IRQ", "ISL", "ISR", "ITA", "JAM", "JEY", "JOR", "JPN", "KAZ",
+    "KEN", "KGZ", "KHM", "KIR", "KNA", "KOR", "KWT", "LAO", "LBN", "LBR", "LBY", "LCA", "LIE",
+    "LKA", "LSO", "LTU", "LUX", "LVA", "MAC", "MAF", "MAR", "MCO", "MDA", "MDG", "MDV", "MEX",
+    "MHL", "MKD", "MLI", "MLT", "MMR", "MNE", "MNG", "MNP", "MOZ", "MRT", "MSR", "MTQ", "MUS",
+    "MWI", "MYS", "MYT", "NAM", "NCL", "NER", "NFK", "NGA", "NIC", "NIU", "NLD", "NOR", "NPL",
+    "NRU", "NZL", "OMN", "PAK", "PAN", "PCN", "PER", "PHL", "PLW", "PNG", "POL", "PRI", "PRK",
+    "PRT", "PRY", "PSE", "PYF", "QAT", "REU", "ROU", "RUS", "RWA", "SAU", "SDN", "SEN", "SGP",
+    "SGS", "SHN", "SJM", "SLB", "SLE", "SLV", "SMR", "SOM", "SPM", "SRB", "SSD", "STP", "SUR",
+    "SVK", "SVN", "SWE", "SWZ", "SXM", "SYC", "SYR", "TCA", "TCD", "TGO", "THA", "TJK", "TKL",
+    "TKM", "TLS", "TON", "TTO", "TUN", "TUR", "TUV", "TWN", "TZA", "UGA", "UKR", "UMI", "URY",
+    "USA", "UZB", "VAT", "VCT", "VEN", "VGB", "VIR", "VNM", "VUT", "WLF", "WSM", "YEM", "ZAF",
+    "ZMB", "ZWE",
+];
+
+/// ISO 4217 currency codes (List One, including fund and precious-metal codes),
+/// uppercase and sorted.
+pub const ISO4217_CURRENCIES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS",
+    "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW",
+    "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+    "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN",
+    "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL",
+    "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY",
+    "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES",
+    "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+/// ISO 639-1 two-letter language codes, lowercase and sorted.
+pub const ISO639_LANGUAGES: &[&str] = &[
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bi",
+    "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da", "de",
+    "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy",
+    "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia",
+    "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj", "kk",
+    "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln", "lo",
+    "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb", "nd",
+    "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi", "pl",
+    "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk", "sl",
+    "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk",
+    "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa",
+    "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_are_sorted_and_complete() {
+        for (table, len) in [
+            (ISO3166_ALPHA2, 249),
+            (ISO3166_ALPHA3, 249),
+            (ISO639_LANGUAGES, 183),
+        ] {
+            assert_eq!(table.len(), len);
+            assert!(table.windows(2).all(|w| w[0] < w[1]));
+        }
+        assert!(ISO4217_CURRENCIES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_country_codes() {
+        let alpha2 = iso3166_alpha2();
+        for code in ["US", "DE", "AX", "ZW"] {
+            assert!(alpha2.apply(code).is_empty(), "{code}");
+        }
+        for code in ["", "us", "UK", "XX", "USA"] {
+            let err = alpha2.apply(code);
+            assert_eq!(err.violations[0].code, "invalid_country_code", "{code}");
+            assert_eq!(err.violations[0].meta.get("format"), Some("alpha2"));
+        }
+
+        let alpha3 = iso3166_alpha3();
+        assert!(alpha3.apply("USA").is_empty());
+        assert!(alpha3.apply("GBR").is_empty());
+        let err = alpha3.apply("US");
+        assert_eq!(err.violations[0].code, "invalid_country_code");
+        assert_eq!(err.violations[0].meta.get("format"), Some("alpha3"));
+    }
+
+    #[test]
+    fn test_currency_and_language_codes() {
+        let currency = iso4217_currency();
+        for code in ["EUR", "USD", "JPY", "XAU"] {
+            assert!(currency.apply(code).is_empty(), "{code}");
+        }
+        for code in ["eur", "EU", "BTC"] {
+            assert_eq!(
+                currency.apply(code).violations[0].code,
+                "invalid_currency_code"
+            );
+        }
+
+        let language = iso639_language();
+        assert!(language.apply("en").is_empty());
+        assert!(language.apply("zu").is_empty());
+        for code in ["EN", "eng", "qq"] {
+            assert_eq!(
+                language.apply(code).violations[0].code,
+                "invalid_language_code"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bcp47_valid_tags() {
+        let rule = bcp47_locale();
+        for tag in [
+            "en",
+            "EN-us",
+            "fil",
+            "zh-Hant-TW",
+            "es-419",
+            "zh-yue-HK",
+            "sl-rozaj-biske",
+            "de-CH-1901",
+            "en-US-u-ca-gregory",
+            "en-a-bbb-b-ccc",
+            "en-US-x-twain",
+            "x-whatever",
+        ] {
+            assert!(rule.apply(tag).is_empty(), "{tag}");
+        }
+    }
+
+    #[test]
+    fn test_bcp47_invalid_tags() {
+        let rule = bcp47_locale();
+        let reason = |tag: &str| {
+            let err = rule.apply(tag);
+            assert_eq!(err.violations[0].code, "invalid_locale", "{tag}");
+            let meta = &err.violations[0].meta;
+            (
+                meta.get("reason").map(String::from),
+                meta.get("subtag").map(String::from),
+            )
+        };
+
+        for tag in [
+            "",
+            "en_US",
+            "e",
+            "english",
+            "en--US",
+            "en-US-",
+            "de-1901-1901",
+            "en-a-bbb-a-ccc",
+            "en-u",
+            "en-x",
+            "i-klingon",
+            "en-abcdefghi",
+        ] {
+            assert_eq!(reason(tag), (Some("syntax".into()), None), "{tag}");
+        }
+
+        assert_eq!(
+            reason("qq-US"),
+            (Some("language".into()), Some("qq".into()))
+        );
+        assert_eq!(reason("en-ZZ"), (Some("region".into()), Some("ZZ".into())));
+    }
+}
@@ -1,24 +1,118 @@
 pub mod choice;
 pub mod collection;
+pub mod cross_field;
+pub mod email;
+mod encoding;
+pub mod finance;
+pub mod geo;
+mod idna;
+pub mod interval;
+pub mod iso;
+pub mod money;
+pub mod network;
 pub mod numeric;
+pub mod option;
+pub mod password;
+pub mod security;
 pub mod string;
 
 pub use choice::{equals, not_equals, one_of};
-pub use collection::{max_items, min_items, non_empty_items, unique};
+pub use collection::{
+    contains_item, max_items, min_items, non_empty_items, sorted_by, subset_of, sum_max, sum_min,
+    unique, unique_by, CheckedSum,
+};
+pub use cross_field::{
+    at_least_one_of, exactly_one_of, field_lt, fields_equal, mutually_exclusive, required_if,
+    required_if_equals, required_unless, Presence,
+};
+pub use email::{email, email_with, EmailOptions};
+pub use finance::{aba_routing, bic, card_brand, credit_card, iban, isin, luhn, CardBrand};
+pub use geo::{latitude, longitude, within_bbox, BoundingBox, GeoPoint, Point};
+pub use interval::{max_span, non_overlapping, valid_range, Span};
+pub use iso::{bcp47_locale, iso3166_alpha2, iso3166_alpha3, iso4217_currency, iso639_language};
+pub use money::{money, money_range, Amount};
+pub use network::{
+    cidr, fqdn, hostname, ip_in_cidr, ipv4, ipv6, mac_address, port_range, try_ip_in_cidr,
+    InvalidCidr,
+};
 pub use numeric::{
     finite, float_max, float_min, float_range, max, min, multiple_of, negative, non_zero, positive,
     range, try_multiple_of, FiniteCheck,
 };
+pub use option::{forbidden, required};
+pub use password::{password, password_entropy_bits, PasswordPolicy};
+pub use security::{
+    no_html_tags, no_null_bytes, no_path_traversal, no_template_syntax, safe_filename,
+};
 pub use string::{
-    alpha_only, alphanumeric, ascii, contains, ends_with, len_chars, length, max_len, min_len,
-    no_whitespace, non_blank, non_empty, numeric_string, starts_with,
+    alpha_only, alphanumeric, ascii, base64, base64url, contains, ends_with, hex, jwt_structure,
+    len_chars, length, max_len, min_len, no_whitespace, non_blank, non_empty, numeric_string,
+    starts_with, ulid, uuid,
 };
 
+// Distances need `f64` trigonometry from `std`
+#[cfg(feature = "std")]
+pub use geo::within_radius;
+
 #[cfg(feature = "regex")]
-pub use string::{email, matches_regex, try_matches_regex, url};
+pub use string::{matches_regex, try_matches_regex, url};
 
-#[cfg(feature = "chrono")]
+#[cfg(feature = "regex")]
+pub mod pattern;
+
+#[cfg(feature = "regex")]
+pub use pattern::{
+    matches_regex_owned, matches_regex_with_limits, PatternError, RegexLimits, REGEX_CACHE_CAPACITY,
+};
+
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
+#[cfg(feature = "unicode")]
+pub use unicode::{
+    grapheme_len, is_nfc, no_bidi_controls, no_control_chars, not_confusable_with, single_script,
+};
+
+#[cfg(feature = "url")]
+pub mod url;
+
+#[cfg(feature = "url")]
+pub use self::url::{url_with, UrlPolicy};
+
+#[cfg(feature = "json")]
+pub use security::max_nesting;
+
+#[cfg(feature = "phone")]
+pub mod phone;
+
+#[cfg(feature = "phone")]
+pub use phone::{e164, phone_for_region, try_phone_for_region, UnknownRegion};
+
+#[cfg(feature = "decimal")]
+pub mod decimal;
+
+// `range` and `multiple_of` already accept `Decimal`; the typed versions stay in
+// `rules::decimal`
+#[cfg(feature = "decimal")]
+pub use decimal::{precision, scale};
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod calendar;
+#[cfg(any(feature = "chrono", feature = "time"))]
 pub mod datetime;
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use calendar::{
+    not_holiday, weekday_in, within_business_hours, BusinessHours, CalendarDate, HolidayCalendar,
+    Holidays, Weekday, Zone,
+};
 #[cfg(feature = "chrono")]
-pub use datetime::{after, age_range, before, future, past};
+pub use datetime::{after, before};
+#[cfg(all(feature = "chrono", feature = "std"))]
+pub use datetime::{age_range, future, past};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use datetime::{
+    date_granularity, future_as_of, past_as_of, temporal_after, temporal_before, Temporal,
+};
+#[cfg(all(any(feature = "chrono", feature = "time"), feature = "std"))]
+pub use datetime::{temporal_age_range, temporal_future, temporal_past, within};
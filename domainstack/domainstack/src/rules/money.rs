@@ -0,0 +1,401 @@
+//! Currency-aware amount rules: minor units and limits per ISO 4217 currency.
+//!
+//! JPY amounts have no decimals, USD two and KWD three. The currency usually comes
+//! from a sibling field, so the rules take the code when they are built:
+//!
+//! ```
+//! use domainstack::prelude::*;
+//!
+//! struct Payment {
+//!     amount: f64,
+//!     currency: String,
+//! }
+//!
+//! let payment = Payment { amount: 1500.5, currency: "JPY".into() };
+//! let err = validate("amount", &payment.amount, &rules::money(&payment.currency)).unwrap_err();
+//! assert_eq!(err.violations[0].code, "invalid_minor_units");
+//! assert_eq!(err.violations[0].meta.get("minor_units"), Some("0"));
+//! ```
+//!
+//! With the derive, `#[validate(money(currency = "currency"))]` does the same.
+
+use crate::rules::iso::ISO4217_CURRENCIES;
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+/// Currencies whose minor unit is not 2, sorted by code.
+const MINOR_UNIT_EXCEPTIONS: &[(&str, Option<u32>)] = &[
+    ("BHD", Some(3)),
+    ("BIF", Some(0)),
+    ("CLF", Some(4)),
+    ("CLP", Some(0)),
+    ("DJF", Some(0)),
+    ("GNF", Some(0)),
+    ("IQD", Some(3)),
+    ("ISK", Some(0)),
+    ("JOD", Some(3)),
+    ("JPY", Some(0)),
+    ("KMF", Some(0)),
+    ("KRW", Some(0)),
+    ("KWD", Some(3)),
+    ("LYD", Some(3)),
+    ("OMR", Some(3)),
+    ("PYG", Some(0)),
+    ("RWF", Some(0)),
+    ("TND", Some(3)),
+    ("UGX", Some(0)),
+    ("UYI", Some(0)),
+    ("UYW", Some(4)),
+    ("VND", Some(0)),
+    ("VUV", Some(0)),
+    ("XAF", Some(0)),
+    // Precious metals, units of account and testing codes have no minor unit
+    ("XAG", None),
+    ("XAU", None),
+    ("XBA", None),
+    ("XBB", None),
+    ("XBC", None),
+    ("XBD", None),
+    ("XDR", None),
+    ("XOF", Some(0)),
+    ("XPD", None),
+    ("XPF", Some(0)),
+    ("XPT", None),
+    ("XSU", None),
+    ("XTS", None),
+    ("XUA", None),
+    ("XXX", None),
+];
+
+/// The ISO 4217 minor unit of `currency`: the number of decimals its amounts may have.
+///
+/// Returns `None` for unknown codes and for codes without a minor unit, such as
+/// gold (`XAU`).
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::money::minor_units;
+///
+/// assert_eq!(minor_units("USD"), Some(2));
+/// assert_eq!(minor_units("JPY"), Some(0));
+/// assert_eq!(minor_units("KWD"), Some(3));
+/// assert_eq!(minor_units("XAU"), None);
+/// assert_eq!(minor_units("usd"), None);
+/// ```
+pub fn minor_units(currency: &str) -> Option<u32> {
+    if ISO4217_CURRENCIES.binary_search(&currency).is_err() {
+        return None;
+    }
+    match MINOR_UNIT_EXCEPTIONS.binary_search_by_key(&currency, |(code, _)| code) {
+        Ok(index) => MINOR_UNIT_EXCEPTIONS[index].1,
+        Err(_) => Some(2),
+    }
+}
+
+/// A money amount in major units (dollars, not cents).
+///
+/// Implemented for floats, integers and, with the `decimal` feature,
+/// `rust_decimal::Decimal`. Prefer `Decimal` or integers: floats such as
+/// `0.1 + 0.2` carry more decimals than they appear to.
+pub trait Amount: PartialOrd + Display + Send + Sync + 'static {
+    /// Number of digits after the decimal point, ignoring trailing zeros.
+    fn decimal_places(&self) -> u32;
+
+    /// `false` for NaN and infinite amounts.
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_amount_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Amount for $ty {
+                fn decimal_places(&self) -> u32 {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_amount_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_amount_for_floats {
+    ($($ty:ty),*) => {
+        $(
+            impl Amount for $ty {
+                fn decimal_places(&self) -> u32 {
+                    // `Display` prints the shortest representation that round-trips
+                    let text = self.to_string();
+                    text.split_once('.').map_or(0, |(_, fraction)| fraction.len() as u32)
+                }
+
+                fn is_finite(&self) -> bool {
+                    <$ty>::is_finite(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_amount_for_floats!(f32, f64);
+
+#[cfg(feature = "decimal")]
+impl Amount for rust_decimal::Decimal {
+    fn decimal_places(&self) -> u32 {
+        self.normalize().scale()
+    }
+}
+
+/// Validates that an amount has no more decimals than `currency` allows.
+///
+/// Unknown currency codes are reported on the amount; currencies without a minor
+/// unit accept any number of decimals. NaN and infinite amounts are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// assert!(rules::money("USD").apply(&19.99).is_empty());
+/// assert!(rules::money("KWD").apply(&1.125).is_empty());
+/// assert!(!rules::money("JPY").apply(&100.5).is_empty());
+/// assert!(!rules::money("usd").apply(&1.0).is_empty());
+/// assert!(!rules::money("USD").apply(&f64::NAN).is_empty());
+/// ```
+///
+/// # Error Codes
+/// - Code: `invalid_minor_units`
+///   - Message: `"JPY amounts must have at most 0 decimal places"`
+///   - Meta: `{"currency": "JPY", "minor_units": "0", "actual": "1"}`
+/// - Code: `unknown_currency`
+///   - Message: `"Unknown currency code"`
+///   - Meta: `{"currency": "usd"}`
+/// - Code: `not_finite`
+///   - Message: `"Must be a finite number (not NaN or infinity)"`
+///   - Meta: `{"currency": "USD"}`
+pub fn money<A: Amount>(currency: &str) -> Rule<A> {
+    let currency = String::from(currency);
+    Rule::new(move |value: &A, ctx: &RuleContext| {
+        check_minor_units(value, &currency, ctx).unwrap_or_default()
+    })
+}
+
+/// Validates the amount's minor units like [`money`], and that it lies within
+/// `min..=max` in that currency.
+///
+/// Limits in messages and meta are formatted with the currency's decimals, so a
+/// USD minimum of `1` reads `1.00 USD`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::money_range("USD", 1.0, 500.0);
+/// assert!(rule.apply(&99.95).is_empty());
+///
+/// let err = rule.apply(&0.5);
+/// assert_eq!(err.violations[0].message, "Must be between 1.00 USD and 500.00 USD");
+/// assert_eq!(err.violations[0].meta.get("min"), Some("1.00"));
+/// ```
+///
+/// # Error Codes
+/// - Code: `out_of_range`
+///   - Message: `"Must be between {min} {currency} and {max} {currency}"`
+///   - Meta: `{"currency": "USD", "minor_units": "2", "min": "1.00", "max": "500.00"}`
+/// - `invalid_minor_units`, `unknown_currency` and `not_finite`, as for [`money`]
+pub fn money_range<A: Amount>(currency: &str, min: A, max: A) -> Rule<A> {
+    let currency = String::from(currency);
+    Rule::new(move |value: &A, ctx: &RuleContext| {
+        if let Some(err) = check_minor_units(value, &currency, ctx) {
+            return err;
+        }
+        // Written so that NaN is out of range
+        if min <= *value && *value <= max {
+            return ValidationError::default();
+        }
+
+        let units = minor_units(&currency);
+        let format_limit = |limit: &A| match units {
+            Some(units) => format!("{:.*}", units as usize, limit),
+            None => limit.to_string(),
+        };
+        let (min_text, max_text) = (format_limit(&min), format_limit(&max));
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "out_of_range",
+            format!(
+                "Must be between {} {} and {} {}",
+                min_text, currency, max_text, currency
+            ),
+        );
+        let meta = &mut err.violations[0].meta;
+        meta.insert("currency", currency.as_str());
+        if let Some(units) = units {
+            meta.insert("minor_units", units.to_string());
+        }
+        meta.insert("min", min_text);
+        meta.insert("max", max_text);
+        err
+    })
+}
+
+fn check_minor_units<A: Amount>(
+    value: &A,
+    currency: &str,
+    ctx: &RuleContext,
+) -> Option<ValidationError> {
+    if ISO4217_CURRENCIES.binary_search(&currency).is_err() {
+        let mut err =
+            ValidationError::single(ctx.full_path(), "unknown_currency", "Unknown currency code");
+        err.violations[0].meta.insert("currency", currency);
+        return Some(err);
+    }
+
+    // `Display` of NaN and infinity has no decimals, so check explicitly
+    if !value.is_finite() {
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "not_finite",
+            "Must be a finite number (not NaN or infinity)",
+        );
+        err.violations[0].meta.insert("currency", currency);
+        return Some(err);
+    }
+
+    let units = minor_units(currency)?;
+    let actual = value.decimal_places();
+    if actual <= units {
+        return None;
+    }
+    let mut err = ValidationError::single(
+        ctx.full_path(),
+        "invalid_minor_units",
+        format!(
+            "{} amounts must have at most {} decimal places",
+            currency, units
+        ),
+    );
+    let meta = &mut err.violations[0].meta;
+    meta.insert("currency", currency);
+    meta.insert("minor_units", units.to_string());
+    meta.insert("actual", actual.to_string());
+    Some(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceptions_sorted_and_known() {
+        assert!(MINOR_UNIT_EXCEPTIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        for (code, _) in MINOR_UNIT_EXCEPTIONS {
+            assert!(ISO4217_CURRENCIES.contains(code), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(minor_units("EUR"), Some(2));
+        assert_eq!(minor_units("CLF"), Some(4));
+        assert_eq!(minor_units("XOF"), Some(0));
+        assert_eq!(minor_units("XDR"), None);
+        assert_eq!(minor_units("ABC"), None);
+    }
+
+    #[test]
+    fn test_decimal_places() {
+        assert_eq!(19.99f64.decimal_places(), 2);
+        assert_eq!(100.0f64.decimal_places(), 0);
+        assert_eq!((-0.125f32).decimal_places(), 3);
+        assert_eq!((0.1f64 + 0.2).decimal_places(), 17);
+        assert_eq!(1_000_000u64.decimal_places(), 0);
+    }
+
+    #[test]
+    fn test_money() {
+        let jpy = money("JPY");
+        assert!(jpy.apply(&1500i64).is_empty());
+
+        let err = money("JPY").apply(&1500.5);
+        assert_eq!(err.violations[0].code, "invalid_minor_units");
+        assert_eq!(
+            err.violations[0].message,
+            "JPY amounts must have at most 0 decimal places"
+        );
+        assert_eq!(err.violations[0].meta.get("currency"), Some("JPY"));
+        assert_eq!(err.violations[0].meta.get("actual"), Some("1"));
+
+        assert!(money("KWD").apply(&0.125).is_empty());
+        assert!(!money("KWD").apply(&0.1255).is_empty());
+
+        // No minor unit: any precision
+        assert!(money("XAU").apply(&0.123456).is_empty());
+
+        let err = money("EURO").apply(&1.0);
+        assert_eq!(err.violations[0].code, "unknown_currency");
+        assert_eq!(err.violations[0].meta.get("currency"), Some("EURO"));
+    }
+
+    #[test]
+    fn test_money_rejects_non_finite() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = money("USD").apply(&value);
+            assert_eq!(err.violations.len(), 1, "{value}");
+            assert_eq!(err.violations[0].code, "not_finite");
+            assert_eq!(err.violations[0].meta.get("currency"), Some("USD"));
+        }
+        // Also for currencies without a minor unit
+        assert!(!money("XAU").apply(&f32::INFINITY).is_empty());
+
+        let err = money_range("USD", 0.0, f64::INFINITY).apply(&f64::INFINITY);
+        assert_eq!(err.violations[0].code, "not_finite");
+        assert!(money("USD").apply(&f64::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_money_range() {
+        let rule = money_range("JPY", 100, 100_000);
+        assert!(rule.apply(&100).is_empty());
+
+        let err = rule.apply(&50);
+        assert_eq!(err.violations[0].code, "out_of_range");
+        assert_eq!(
+            err.violations[0].message,
+            "Must be between 100 JPY and 100000 JPY"
+        );
+        assert_eq!(err.violations[0].meta.get("minor_units"), Some("0"));
+
+        let rule = money_range("KWD", 0.5, 10.0);
+        let err = rule.apply(&10.5);
+        assert_eq!(err.violations[0].meta.get("min"), Some("0.500"));
+        assert_eq!(err.violations[0].meta.get("max"), Some("10.000"));
+
+        // Minor units are reported instead of the range
+        let err = rule.apply(&20.1234);
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "invalid_minor_units");
+
+        assert!(!money_range("USD", 0.0, 1.0).apply(&f64::NAN).is_empty());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_money_decimal() {
+        use rust_decimal::Decimal;
+
+        let rule = money_range("USD", Decimal::new(1, 2), Decimal::new(1000, 0));
+        assert!(rule.apply(&Decimal::new(19990, 3)).is_empty());
+        let err = rule.apply(&Decimal::new(19995, 3));
+        assert_eq!(err.violations[0].code, "invalid_minor_units");
+        let err = rule.apply(&Decimal::new(2000, 0));
+        assert_eq!(err.violations[0].meta.get("min"), Some("0.01"));
+        assert_eq!(err.violations[0].meta.get("max"), Some("1000.00"));
+    }
+}
@@ -0,0 +1,791 @@
+//! Rules for network identifiers: IP addresses, CIDR ranges, hostnames,
+//! port ranges and MAC addresses.
+//!
+//! All parsing is done by hand so the rules work without `std` and without
+//! the `regex` feature.
+
+use crate::rules::idna;
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Validates that a string is an IPv4 address in dotted-decimal notation.
+///
+/// Octets with leading zeros (`"010.0.0.1"`) are rejected, because some
+/// parsers read them as octal.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::ipv4();
+/// assert!(rule.apply("192.168.0.1").is_empty());
+/// assert!(!rule.apply("256.0.0.1").is_empty());
+/// assert!(!rule.apply("::1").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_ipv4`
+/// - Message: `"Must be a valid IPv4 address"`
+pub fn ipv4() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if parse_ipv4(value).is_some() {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_ipv4",
+                "Must be a valid IPv4 address",
+            )
+        }
+    })
+}
+
+/// Validates that a string is an IPv6 address (RFC 4291 text form).
+///
+/// Accepts `::` compression and an embedded IPv4 tail (`"::ffff:10.0.0.1"`).
+/// Zone identifiers (`"fe80::1%eth0"`) are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::ipv6();
+/// assert!(rule.apply("2001:db8::1").is_empty());
+/// assert!(rule.apply("::ffff:192.0.2.1").is_empty());
+/// assert!(!rule.apply("2001:db8:::1").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_ipv6`
+/// - Message: `"Must be a valid IPv6 address"`
+pub fn ipv6() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if parse_ipv6(value).is_some() {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_ipv6",
+                "Must be a valid IPv6 address",
+            )
+        }
+    })
+}
+
+/// Validates that a string is an IPv4 or IPv6 range in CIDR notation.
+///
+/// The address part may have host bits set (`"10.0.0.1/8"`), which is the
+/// usual notation for an interface address.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::cidr();
+/// assert!(rule.apply("10.0.0.0/8").is_empty());
+/// assert!(rule.apply("2001:db8::/32").is_empty());
+/// assert!(!rule.apply("10.0.0.0/33").is_empty());
+/// assert!(!rule.apply("10.0.0.0").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_cidr`
+/// - Message: `"Must be a valid CIDR range"`
+pub fn cidr() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if Cidr::parse(value).is_some() {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_cidr",
+                "Must be a valid CIDR range",
+            )
+        }
+    })
+}
+
+/// Validates that a string is an IP address inside the given CIDR range.
+///
+/// The address must be of the same family as the range; an IPv4 address is
+/// never inside an IPv6 range.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::ip_in_cidr("10.0.0.0/8");
+/// assert!(rule.apply("10.1.2.3").is_empty());
+/// assert!(!rule.apply("192.168.0.1").is_empty());
+/// ```
+///
+/// # Error Codes
+/// - Code: `invalid_ip`
+/// - Message: `"Must be a valid IP address"`
+///
+/// - Code: `ip_not_in_cidr`
+/// - Message: `"Must be an address in {cidr}"`
+/// - Meta: `{"cidr": "10.0.0.0/8"}`
+///
+/// # Panics
+/// Panics if `cidr` is not a valid CIDR range.
+/// Use [`try_ip_in_cidr`] for a non-panicking alternative.
+pub fn ip_in_cidr(cidr: &str) -> Rule<str> {
+    try_ip_in_cidr(cidr).expect("Invalid CIDR range")
+}
+
+/// Validates that a string is an IP address inside the given CIDR range
+/// (non-panicking version).
+///
+/// Useful when the range comes from configuration.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::try_ip_in_cidr("fd00::/8").unwrap();
+/// assert!(rule.apply("fd12::1").is_empty());
+///
+/// assert!(rules::try_ip_in_cidr("fd00::").is_err());
+/// ```
+///
+/// # Error Codes
+/// Same as [`ip_in_cidr`].
+///
+/// # Errors
+/// Returns [`InvalidCidr`] if `cidr` is not a valid CIDR range.
+pub fn try_ip_in_cidr(cidr: &str) -> Result<Rule<str>, InvalidCidr> {
+    let range = Cidr::parse(cidr).ok_or_else(|| InvalidCidr(cidr.to_string()))?;
+    let cidr = cidr.to_string();
+
+    Ok(Rule::new(move |value: &str, ctx: &RuleContext| {
+        let Some(addr) = parse_ip(value) else {
+            return ValidationError::single(
+                ctx.full_path(),
+                "invalid_ip",
+                "Must be a valid IP address",
+            );
+        };
+
+        if range.contains(addr) {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "ip_not_in_cidr",
+                format!("Must be an address in {}", cidr),
+            );
+            err.violations[0].meta.insert("cidr", &cidr);
+            err
+        }
+    }))
+}
+
+/// Error returned by [`try_ip_in_cidr`] when the range does not parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCidr(pub String);
+
+impl fmt::Display for InvalidCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CIDR range: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCidr {}
+
+/// Validates that a string is a host name (RFC 1123), including
+/// internationalized names.
+///
+/// Each label is 1-63 characters of letters, digits and hyphens, and may not
+/// start or end with a hyphen. Non-ASCII labels are checked against these
+/// limits in their Punycode form, and the whole name may be at most 253
+/// characters in that form. The last label may not be all digits, so dotted
+/// IP addresses are not accepted as host names.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::hostname();
+/// assert!(rule.apply("localhost").is_empty());
+/// assert!(rule.apply("api-1.example.com").is_empty());
+/// assert!(rule.apply("bücher.example").is_empty());
+/// assert!(!rule.apply("-bad.example.com").is_empty());
+/// assert!(!rule.apply("10.0.0.1").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_hostname`
+/// - Message: `"Must be a valid hostname"`
+pub fn hostname() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if is_hostname(value) {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_hostname",
+                "Must be a valid hostname",
+            )
+        }
+    })
+}
+
+/// Validates that a string is a fully qualified domain name.
+///
+/// Same label rules as [`hostname`], but at least two labels are required,
+/// the top-level label must be at least two characters and not all digits,
+/// and a single trailing dot (`"example.com."`) is allowed.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::fqdn();
+/// assert!(rule.apply("example.com").is_empty());
+/// assert!(rule.apply("example.com.").is_empty());
+/// assert!(!rule.apply("localhost").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_fqdn`
+/// - Message: `"Must be a fully qualified domain name"`
+pub fn fqdn() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if is_fqdn(value) {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_fqdn",
+                "Must be a fully qualified domain name",
+            )
+        }
+    })
+}
+
+/// Validates that a string is a TCP/UDP port or port range.
+///
+/// Accepts a single port (`"443"`) or an inclusive range (`"8000-8999"`).
+/// Ports must be between 1 and 65535 and the range start may not be greater
+/// than its end.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::port_range();
+/// assert!(rule.apply("443").is_empty());
+/// assert!(rule.apply("8000-8999").is_empty());
+/// assert!(!rule.apply("9000-8000").is_empty());
+/// assert!(!rule.apply("70000").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_port_range`
+/// - Message: `"Must be a port (1-65535) or port range like 8000-8999"`
+pub fn port_range() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let valid = match value.split_once('-') {
+            Some((start, end)) => match (parse_port(start), parse_port(end)) {
+                (Some(start), Some(end)) => start <= end,
+                _ => false,
+            },
+            None => parse_port(value).is_some(),
+        };
+
+        if valid {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_port_range",
+                "Must be a port (1-65535) or port range like 8000-8999",
+            )
+        }
+    })
+}
+
+/// Validates that a string is a 48-bit MAC address.
+///
+/// Accepts colon (`"00:1a:2b:3c:4d:5e"`) or hyphen (`"00-1A-2B-3C-4D-5E"`)
+/// separated octets, with the same separator throughout, and the dotted
+/// form `"001a.2b3c.4d5e"`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::mac_address();
+/// assert!(rule.apply("00:1a:2b:3c:4d:5e").is_empty());
+/// assert!(rule.apply("001a.2b3c.4d5e").is_empty());
+/// assert!(!rule.apply("00:1a-2b:3c:4d:5e").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `invalid_mac_address`
+/// - Message: `"Must be a valid MAC address"`
+pub fn mac_address() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if is_mac_address(value) {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "invalid_mac_address",
+                "Must be a valid MAC address",
+            )
+        }
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpAddr {
+    V4(u32),
+    V6(u128),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cidr {
+    addr: IpAddr,
+    prefix: u32,
+}
+
+impl Cidr {
+    fn parse(s: &str) -> Option<Self> {
+        let (addr, prefix) = s.split_once('/')?;
+        let addr = parse_ip(addr)?;
+        let max = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix = parse_decimal(prefix, 3)?;
+        (prefix <= max).then_some(Self { addr, prefix })
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix).unwrap_or(0);
+                net & mask == ip & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix).unwrap_or(0);
+                net & mask == ip & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+fn parse_ip(s: &str) -> Option<IpAddr> {
+    if s.contains(':') {
+        parse_ipv6(s).map(IpAddr::V6)
+    } else {
+        parse_ipv4(s).map(IpAddr::V4)
+    }
+}
+
+/// Parse a decimal number of at most `max_digits` digits without a leading zero.
+fn parse_decimal(s: &str, max_digits: usize) -> Option<u32> {
+    if s.is_empty() || s.len() > max_digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if s.len() > 1 && s.starts_with('0') {
+        return None;
+    }
+    s.parse().ok()
+}
+
+pub(crate) fn parse_ipv4(s: &str) -> Option<u32> {
+    let mut addr: u32 = 0;
+    let mut octets = 0;
+    for part in s.split('.') {
+        let octet = parse_decimal(part, 3).filter(|&o| o <= 255)?;
+        addr = (addr << 8) | octet;
+        octets += 1;
+    }
+    (octets == 4).then_some(addr)
+}
+
+pub(crate) fn parse_ipv6(s: &str) -> Option<u128> {
+    let (head, tail) = match s.find("::") {
+        Some(i) => (&s[..i], Some(&s[i + 2..])),
+        None => (s, None),
+    };
+
+    let groups = match tail {
+        None => {
+            let groups = parse_ipv6_groups(head, true)?;
+            if groups.len() != 8 {
+                return None;
+            }
+            groups
+        }
+        Some(tail) => {
+            if tail.contains("::") {
+                return None;
+            }
+            let head = parse_ipv6_groups(head, false)?;
+            let tail = parse_ipv6_groups(tail, true)?;
+            if head.len() + tail.len() > 7 {
+                return None;
+            }
+            let mut groups = head;
+            groups.resize(8 - tail.len(), 0);
+            groups.extend(tail);
+            groups
+        }
+    };
+
+    Some(
+        groups
+            .iter()
+            .fold(0u128, |acc, &group| (acc << 16) | u128::from(group)),
+    )
+}
+
+/// Parse colon-separated hex groups, optionally ending in an IPv4 address.
+fn parse_ipv6_groups(s: &str, allow_ipv4_tail: bool) -> Option<Vec<u16>> {
+    let mut groups = Vec::new();
+    if s.is_empty() {
+        return Some(groups);
+    }
+
+    let mut parts = s.split(':').peekable();
+    while let Some(part) = parts.next() {
+        let is_last = parts.peek().is_none();
+        if is_last && allow_ipv4_tail && part.contains('.') {
+            let v4 = parse_ipv4(part)?;
+            groups.push((v4 >> 16) as u16);
+            groups.push(v4 as u16);
+        } else {
+            if part.is_empty() || part.len() > 4 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            groups.push(u16::from_str_radix(part, 16).ok()?);
+        }
+        if groups.len() > 8 {
+            return None;
+        }
+    }
+    Some(groups)
+}
+
+fn parse_port(s: &str) -> Option<u32> {
+    parse_decimal(s, 5).filter(|&p| (1..=65535).contains(&p))
+}
+
+fn is_hostname(s: &str) -> bool {
+    idna::to_ascii_domain(s).is_some() && !last_label_is_numeric(s)
+}
+
+fn is_fqdn(s: &str) -> bool {
+    let s = s.strip_suffix('.').unwrap_or(s);
+    let Some((_, tld)) = s.rsplit_once('.') else {
+        return false;
+    };
+    tld.chars().count() >= 2 && is_hostname(s)
+}
+
+fn last_label_is_numeric(s: &str) -> bool {
+    let last = s.rsplit('.').next().unwrap_or(s);
+    last.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_mac_address(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    match bytes.len() {
+        // aa:bb:cc:dd:ee:ff or aa-bb-cc-dd-ee-ff
+        17 => {
+            let sep = bytes[2];
+            (sep == b':' || sep == b'-')
+                && bytes.iter().enumerate().all(|(i, &b)| {
+                    if i % 3 == 2 {
+                        b == sep
+                    } else {
+                        b.is_ascii_hexdigit()
+                    }
+                })
+        }
+        // aabb.ccdd.eeff
+        14 => bytes.iter().enumerate().all(|(i, &b)| {
+            if i % 5 == 4 {
+                b == b'.'
+            } else {
+                b.is_ascii_hexdigit()
+            }
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_valid() {
+        let rule = ipv4();
+        for ip in ["0.0.0.0", "127.0.0.1", "192.168.1.254", "255.255.255.255"] {
+            assert!(rule.apply(ip).is_empty(), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_ipv4_invalid() {
+        let rule = ipv4();
+        for ip in [
+            "",
+            "1.2.3",
+            "1.2.3.4.5",
+            "256.1.1.1",
+            "01.2.3.4",
+            "1.2.3.-4",
+            "1..2.3",
+            " 1.2.3.4",
+            "::1",
+        ] {
+            let result = rule.apply(ip);
+            assert_eq!(result.violations[0].code, "invalid_ipv4", "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_ipv6_valid() {
+        let rule = ipv6();
+        for ip in [
+            "::",
+            "::1",
+            "2001:db8::",
+            "2001:0db8:0000:0000:0000:ff00:0042:8329",
+            "2001:db8::ff00:42:8329",
+            "fe80::1:2:3:4:5:6",
+            "::ffff:192.0.2.128",
+            "64:ff9b::10.0.0.1",
+        ] {
+            assert!(rule.apply(ip).is_empty(), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_ipv6_invalid() {
+        let rule = ipv6();
+        for ip in [
+            "",
+            ":",
+            ":::",
+            "1::2::3",
+            "1:2:3:4:5:6:7",
+            "1:2:3:4:5:6:7:8:9",
+            "1:2:3:4:5:6:7::8",
+            "12345::",
+            "g::1",
+            ":1::",
+            "1::2:",
+            "fe80::1%eth0",
+            "1.2.3.4::",
+            "192.168.0.1",
+        ] {
+            let result = rule.apply(ip);
+            assert_eq!(result.violations[0].code, "invalid_ipv6", "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_ipv6_value() {
+        assert_eq!(parse_ipv6("::1"), Some(1));
+        assert_eq!(parse_ipv6("::ffff:1.2.3.4"), Some(0xffff_0102_0304));
+        assert_eq!(parse_ipv6("1::"), Some(1u128 << 112));
+    }
+
+    #[test]
+    fn test_cidr() {
+        let rule = cidr();
+        assert!(rule.apply("0.0.0.0/0").is_empty());
+        assert!(rule.apply("192.168.0.0/16").is_empty());
+        assert!(rule.apply("10.0.0.1/32").is_empty());
+        assert!(rule.apply("::/0").is_empty());
+        assert!(rule.apply("2001:db8::/128").is_empty());
+
+        for value in [
+            "10.0.0.0",
+            "10.0.0.0/",
+            "10.0.0.0/33",
+            "10.0.0.0/08",
+            "::/129",
+            "/8",
+        ] {
+            let result = rule.apply(value);
+            assert_eq!(result.violations[0].code, "invalid_cidr", "{value}");
+        }
+    }
+
+    #[test]
+    fn test_ip_in_cidr_v4() {
+        let rule = ip_in_cidr("10.0.0.0/8");
+        assert!(rule.apply("10.0.0.0").is_empty());
+        assert!(rule.apply("10.255.255.255").is_empty());
+
+        let result = rule.apply("11.0.0.0");
+        assert_eq!(result.violations[0].code, "ip_not_in_cidr");
+        assert_eq!(result.violations[0].meta.get("cidr"), Some("10.0.0.0/8"));
+
+        let result = rule.apply("not-an-ip");
+        assert_eq!(result.violations[0].code, "invalid_ip");
+    }
+
+    #[test]
+    fn test_ip_in_cidr_edges() {
+        let all = ip_in_cidr("0.0.0.0/0");
+        assert!(all.apply("8.8.8.8").is_empty());
+
+        let single = ip_in_cidr("192.0.2.7/32");
+        assert!(single.apply("192.0.2.7").is_empty());
+        assert!(!single.apply("192.0.2.8").is_empty());
+
+        let v6 = ip_in_cidr("2001:db8::/32");
+        assert!(v6.apply("2001:db8:ffff::1").is_empty());
+        assert!(!v6.apply("2001:db9::1").is_empty());
+    }
+
+    #[test]
+    fn test_ip_in_cidr_family_mismatch() {
+        let rule = ip_in_cidr("::/0");
+        let result = rule.apply("10.0.0.1");
+        assert_eq!(result.violations[0].code, "ip_not_in_cidr");
+    }
+
+    #[test]
+    fn test_try_ip_in_cidr_invalid() {
+        let err = try_ip_in_cidr("10.0.0.0/40").unwrap_err();
+        assert_eq!(err, InvalidCidr("10.0.0.0/40".to_string()));
+        assert_eq!(err.to_string(), "invalid CIDR range: \"10.0.0.0/40\"");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid CIDR range")]
+    fn test_ip_in_cidr_panics_on_invalid_range() {
+        let _ = ip_in_cidr("not a range");
+    }
+
+    #[test]
+    fn test_hostname() {
+        let rule = hostname();
+        for host in [
+            "localhost",
+            "example.com",
+            "a.b-c.d",
+            "1host.example",
+            "xn--bcher-kva.example",
+            "bücher.example",
+            "例え.テスト",
+        ] {
+            assert!(rule.apply(host).is_empty(), "{host}");
+        }
+
+        for host in [
+            "",
+            ".",
+            "example.com.",
+            "exa_mple.com",
+            "-example.com",
+            "example-.com",
+            "a..b",
+            "10.0.0.1",
+            "123",
+            "has space.com",
+        ] {
+            let result = rule.apply(host);
+            assert_eq!(result.violations[0].code, "invalid_hostname", "{host}");
+        }
+    }
+
+    #[test]
+    fn test_hostname_length_limits() {
+        let rule = hostname();
+        let label = "a".repeat(63);
+        assert!(rule.apply(&label).is_empty());
+        assert!(!rule.apply(&"a".repeat(64)).is_empty());
+
+        let name = [label.as_str(); 4].join(".");
+        assert!(!rule.apply(&name).is_empty());
+        assert!(rule.apply(&name[..253]).is_empty());
+    }
+
+    #[test]
+    fn test_fqdn() {
+        let rule = fqdn();
+        assert!(rule.apply("example.com").is_empty());
+        assert!(rule.apply("www.example.co.uk.").is_empty());
+        assert!(rule.apply("bücher.de").is_empty());
+
+        for host in [
+            "localhost",
+            "example.c",
+            "example.123",
+            "example.com..",
+            ".com",
+        ] {
+            let result = rule.apply(host);
+            assert_eq!(result.violations[0].code, "invalid_fqdn", "{host}");
+        }
+    }
+
+    #[test]
+    fn test_port_range() {
+        let rule = port_range();
+        for value in ["1", "80", "65535", "8000-8999", "443-443"] {
+            assert!(rule.apply(value).is_empty(), "{value}");
+        }
+
+        for value in [
+            "", "0", "65536", "080", "-80", "80-", "90-80", "1-2-3", "http",
+        ] {
+            let result = rule.apply(value);
+            assert_eq!(result.violations[0].code, "invalid_port_range", "{value}");
+        }
+    }
+
+    #[test]
+    fn test_mac_address() {
+        let rule = mac_address();
+        for mac in ["00:1a:2b:3c:4d:5e", "00-1A-2B-3C-4D-5E", "001a.2b3c.4d5e"] {
+            assert!(rule.apply(mac).is_empty(), "{mac}");
+        }
+
+        for mac in [
+            "",
+            "00:1a:2b:3c:4d",
+            "00:1a:2b:3c:4d:5e:6f",
+            "00:1a-2b:3c:4d:5e",
+            "00:1a:2b:3c:4d:5g",
+            "001a2b3c4d5e",
+            "001a:2b3c:4d5e",
+        ] {
+            let result = rule.apply(mac);
+            assert_eq!(result.violations[0].code, "invalid_mac_address", "{mac}");
+        }
+    }
+
+    #[test]
+    fn test_network_rules_use_full_path() {
+        let rule = ipv4();
+        let ctx = RuleContext::root("server");
+        let result = rule.apply_with_context("nope", &ctx);
+        assert_eq!(result.violations[0].path.to_string(), "server");
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::ToString;
 
 /// Validates that a numeric value is within the specified range (inclusive).
 ///
@@ -29,7 +31,7 @@ use crate::{Rule, RuleContext, ValidationError};
 /// - `'static`: Required for the rule to be stored and used independently of its creation context
 pub fn range<T>(min: T, max: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static,
 {
     Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value < min || *value > max {
@@ -69,7 +71,7 @@ where
 /// See [`range()`] for explanation of generic type bounds.
 pub fn min<T>(min: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static,
 {
     Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value < min {
@@ -108,7 +110,7 @@ where
 /// See [`range()`] for explanation of generic type bounds.
 pub fn max<T>(max: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static,
 {
     Rule::new(move |value: &T, ctx: &RuleContext| {
         if *value > max {
@@ -331,7 +333,7 @@ impl FiniteCheck for f64 {
 /// - Code: `out_of_range` if not within bounds
 pub fn float_range<T>(min: T, max: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static + FiniteCheck,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static + FiniteCheck,
 {
     finite().and(range(min, max))
 }
@@ -357,7 +359,7 @@ where
 /// - Code: `below_minimum` if less than minimum
 pub fn float_min<T>(minimum: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static + FiniteCheck,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static + FiniteCheck,
 {
     finite().and(min(minimum))
 }
@@ -383,7 +385,7 @@ where
 /// - Code: `above_maximum` if greater than maximum
 pub fn float_max<T>(maximum: T) -> Rule<T>
 where
-    T: PartialOrd + Copy + std::fmt::Display + Send + Sync + 'static + FiniteCheck,
+    T: PartialOrd + Copy + core::fmt::Display + Send + Sync + 'static + FiniteCheck,
 {
     finite().and(max(maximum))
 }
@@ -412,11 +414,11 @@ where
 /// version that validates the divisor at construction time.
 pub fn multiple_of<T>(divisor: T) -> Rule<T>
 where
-    T: std::ops::Rem<Output = T>
+    T: core::ops::Rem<Output = T>
         + PartialEq
         + Default
         + Copy
-        + std::fmt::Display
+        + core::fmt::Display
         + Send
         + Sync
         + 'static,
@@ -467,11 +469,11 @@ where
 /// - `None` if divisor is zero
 pub fn try_multiple_of<T>(divisor: T) -> Option<Rule<T>>
 where
-    T: std::ops::Rem<Output = T>
+    T: core::ops::Rem<Output = T>
         + PartialEq
         + Default
         + Copy
-        + std::fmt::Display
+        + core::fmt::Display
         + Send
         + Sync
         + 'static,
@@ -0,0 +1,80 @@
+use crate::{Rule, RuleContext, ValidationError};
+
+/// Validates that an optional value is present.
+///
+/// Typically combined with validation groups, e.g. an `id` that must be sent on update.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::required::<u64>();
+/// assert!(rule.apply(&Some(42)).is_empty());
+/// assert!(!rule.apply(&None).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `required`
+/// - Message: `"Field is required"`
+pub fn required<T: 'static>() -> Rule<Option<T>> {
+    Rule::new(|value: &Option<T>, ctx: &RuleContext| {
+        if value.is_some() {
+            ValidationError::default()
+        } else {
+            ValidationError::single(ctx.full_path(), "required", "Field is required")
+        }
+    })
+}
+
+/// Validates that an optional value is absent.
+///
+/// Typically combined with validation groups, e.g. an `id` that must not be sent on create.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::forbidden::<u64>();
+/// assert!(rule.apply(&None).is_empty());
+/// assert!(!rule.apply(&Some(42)).is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `forbidden`
+/// - Message: `"Field must not be set"`
+pub fn forbidden<T: 'static>() -> Rule<Option<T>> {
+    Rule::new(|value: &Option<T>, ctx: &RuleContext| {
+        if value.is_none() {
+            ValidationError::default()
+        } else {
+            ValidationError::single(ctx.full_path(), "forbidden", "Field must not be set")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required() {
+        let rule = required::<String>();
+        assert!(rule.apply(&Some("x".to_string())).is_empty());
+
+        let result = rule.apply(&None);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].code, "required");
+    }
+
+    #[test]
+    fn test_forbidden() {
+        let rule = forbidden::<i32>();
+        assert!(rule.apply(&None).is_empty());
+
+        let result = rule.apply(&Some(1));
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].code, "forbidden");
+    }
+}
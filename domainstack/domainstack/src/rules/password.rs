@@ -0,0 +1,462 @@
+//! Password policy rule: length, character classes, forbidden substrings,
+//! entropy and repeated characters.
+//!
+//! Unlike most rules, [`password`] reports one violation per unmet
+//! requirement, all on the same path, so a signup form can render the policy
+//! as a checklist.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Requirements checked by [`password`].
+///
+/// Lengths are counted in characters, not bytes. Forbidden substrings are
+/// matched case-insensitively; empty ones are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::PasswordPolicy;
+///
+/// let policy = PasswordPolicy {
+///     min_length: 12,
+///     require_uppercase: true,
+///     require_digit: true,
+///     min_entropy_bits: Some(60),
+///     ..PasswordPolicy::default()
+/// }
+/// .forbid("password");
+/// assert_eq!(policy.max_length, 128);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Minimum number of characters.
+    pub min_length: usize,
+    /// Maximum number of characters.
+    pub max_length: usize,
+    /// Require at least one lowercase letter.
+    pub require_lowercase: bool,
+    /// Require at least one uppercase letter.
+    pub require_uppercase: bool,
+    /// Require at least one ASCII digit.
+    pub require_digit: bool,
+    /// Require at least one character that is not a letter or digit.
+    pub require_symbol: bool,
+    /// Minimum estimated entropy in bits; see [`password_entropy_bits`].
+    pub min_entropy_bits: Option<u32>,
+    /// Maximum run of the same character, e.g. `Some(2)` rejects `"aaa"`.
+    pub max_repeats: Option<usize>,
+    /// Substrings the password must not contain.
+    pub forbidden: Vec<String>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            min_entropy_bits: None,
+            max_repeats: None,
+            forbidden: Vec::new(),
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Forbid a substring, such as a common password or a known weak word.
+    pub fn forbid(mut self, substring: impl Into<String>) -> Self {
+        self.forbidden.push(substring.into());
+        self
+    }
+
+    /// Forbid a value identifying the user, such as their username or email.
+    ///
+    /// For email addresses the local part is forbidden too, so
+    /// `"jane.doe@example.com"` also rules out passwords containing `"jane.doe"`.
+    pub fn forbid_identity(mut self, value: impl AsRef<str>) -> Self {
+        let value = value.as_ref();
+        if let Some((local, _)) = value.split_once('@') {
+            self.forbidden.push(local.to_string());
+        }
+        self.forbidden.push(value.to_string());
+        self
+    }
+}
+
+/// Validates a password against a [`PasswordPolicy`].
+///
+/// Every unmet requirement produces its own violation, in this order:
+/// length, character classes, forbidden substrings, repeated characters,
+/// entropy. Violations never include the password itself.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::PasswordPolicy;
+///
+/// let rule = rules::password(PasswordPolicy {
+///     min_length: 10,
+///     require_uppercase: true,
+///     require_digit: true,
+///     ..PasswordPolicy::default()
+/// });
+/// assert!(rule.apply("Correct horse 9").is_empty());
+///
+/// let err = rule.apply("hunter2");
+/// let codes: Vec<_> = err.violations.iter().map(|v| v.code).collect();
+/// assert_eq!(codes, ["password_too_short", "password_missing_class"]);
+/// assert_eq!(err.violations[1].meta.get("class"), Some("uppercase"));
+/// ```
+///
+/// # Error Codes
+/// - `password_too_short` - Meta: `{"min": "10", "actual": "7"}`
+/// - `password_too_long` - Meta: `{"max": "128", "actual": "200"}`
+/// - `password_missing_class` - Meta: `{"class": "lowercase" | "uppercase" | "digit" | "symbol"}`
+/// - `password_forbidden_substring` - Meta: `{"index": "0"}` (position in `forbidden`)
+/// - `password_repeated_chars` - Meta: `{"max": "2"}`
+/// - `password_low_entropy` - Meta: `{"min": "60", "actual": "41"}`
+pub fn password(policy: PasswordPolicy) -> Rule<str> {
+    let forbidden: Vec<String> = policy.forbidden.iter().map(|s| s.to_lowercase()).collect();
+
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        let mut err = ValidationError::default();
+        let mut fail = |code: &'static str, message: String, meta: &[(&'static str, String)]| {
+            err.push(ctx.full_path(), code, message);
+            let violation = err
+                .violations
+                .last_mut()
+                .expect("violation was just pushed");
+            for (key, value) in meta {
+                violation.meta.insert(key, value);
+            }
+        };
+
+        let len = value.chars().count();
+        if len < policy.min_length {
+            fail(
+                "password_too_short",
+                format!("Must be at least {} characters", policy.min_length),
+                &[
+                    ("min", policy.min_length.to_string()),
+                    ("actual", len.to_string()),
+                ],
+            );
+        } else if len > policy.max_length {
+            fail(
+                "password_too_long",
+                format!("Must be at most {} characters", policy.max_length),
+                &[
+                    ("max", policy.max_length.to_string()),
+                    ("actual", len.to_string()),
+                ],
+            );
+        }
+
+        let classes = CharClasses::of(value);
+        for (required, present, class, message) in [
+            (
+                policy.require_lowercase,
+                classes.lowercase,
+                "lowercase",
+                "Must contain a lowercase letter",
+            ),
+            (
+                policy.require_uppercase,
+                classes.uppercase,
+                "uppercase",
+                "Must contain an uppercase letter",
+            ),
+            (
+                policy.require_digit,
+                classes.digit,
+                "digit",
+                "Must contain a digit",
+            ),
+            (
+                policy.require_symbol,
+                classes.symbol,
+                "symbol",
+                "Must contain a symbol",
+            ),
+        ] {
+            if required && !present {
+                fail(
+                    "password_missing_class",
+                    message.to_string(),
+                    &[("class", class.to_string())],
+                );
+            }
+        }
+
+        let lowered = value.to_lowercase();
+        for (index, substring) in forbidden.iter().enumerate() {
+            if !substring.is_empty() && lowered.contains(substring.as_str()) {
+                fail(
+                    "password_forbidden_substring",
+                    "Must not contain a forbidden word".to_string(),
+                    &[("index", index.to_string())],
+                );
+            }
+        }
+
+        if let Some(max) = policy.max_repeats {
+            if longest_run(value) > max {
+                fail(
+                    "password_repeated_chars",
+                    format!(
+                        "Must not repeat a character more than {} times in a row",
+                        max
+                    ),
+                    &[("max", max.to_string())],
+                );
+            }
+        }
+
+        if let Some(min) = policy.min_entropy_bits {
+            let bits = password_entropy_bits(value);
+            if bits < min {
+                fail(
+                    "password_low_entropy",
+                    "Must be harder to guess".to_string(),
+                    &[("min", min.to_string()), ("actual", bits.to_string())],
+                );
+            }
+        }
+
+        err
+    })
+}
+
+/// Estimate a password's entropy in bits from its length and character pool.
+///
+/// The pool is the sum of the classes present: 26 lowercase, 26 uppercase,
+/// 10 digits, 33 ASCII symbols (including space) and 100 for any non-ASCII
+/// character. Runs of a repeated character count once. This is a rough
+/// brute-force estimate; it does not detect dictionary words or keyboard
+/// patterns.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::password_entropy_bits;
+///
+/// assert_eq!(password_entropy_bits(""), 0);
+/// assert_eq!(password_entropy_bits("aaaaaaaa"), 4); // one distinct run
+/// assert!(password_entropy_bits("correct horse battery staple") > 100);
+/// ```
+pub fn password_entropy_bits(value: &str) -> u32 {
+    let classes = CharClasses::of(value);
+    let pool = [
+        (classes.lowercase, 26),
+        (classes.uppercase, 26),
+        (classes.digit, 10),
+        (classes.ascii_symbol, 33),
+        (classes.non_ascii, 100),
+    ]
+    .iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum::<u32>();
+
+    let mut chars = value.chars().peekable();
+    let mut runs = 0u32;
+    while let Some(c) = chars.next() {
+        while chars.peek() == Some(&c) {
+            chars.next();
+        }
+        runs += 1;
+    }
+
+    if pool == 0 {
+        0
+    } else {
+        (f64::from(runs) * log2(f64::from(pool))) as u32
+    }
+}
+
+struct CharClasses {
+    lowercase: bool,
+    uppercase: bool,
+    digit: bool,
+    symbol: bool,
+    ascii_symbol: bool,
+    non_ascii: bool,
+}
+
+impl CharClasses {
+    fn of(value: &str) -> Self {
+        let mut classes = CharClasses {
+            lowercase: false,
+            uppercase: false,
+            digit: false,
+            symbol: false,
+            ascii_symbol: false,
+            non_ascii: false,
+        };
+        for c in value.chars() {
+            classes.lowercase |= c.is_lowercase();
+            classes.uppercase |= c.is_uppercase();
+            classes.digit |= c.is_ascii_digit();
+            classes.symbol |= !c.is_alphanumeric();
+            classes.ascii_symbol |= c.is_ascii() && !c.is_ascii_alphanumeric();
+            classes.non_ascii |= !c.is_ascii();
+        }
+        classes
+    }
+}
+
+fn longest_run(value: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for c in value.chars() {
+        run = if previous == Some(c) { run + 1 } else { 1 };
+        longest = longest.max(run);
+        previous = Some(c);
+    }
+    longest
+}
+
+/// Base-2 logarithm for `x >= 1` using only `core` float arithmetic.
+fn log2(mut x: f64) -> f64 {
+    let mut result = 0.0;
+    while x >= 2.0 {
+        x /= 2.0;
+        result += 1.0;
+    }
+    let mut bit = 0.5;
+    for _ in 0..24 {
+        x *= x;
+        if x >= 2.0 {
+            x /= 2.0;
+            result += bit;
+        }
+        bit /= 2.0;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(err: &ValidationError) -> Vec<&'static str> {
+        err.violations.iter().map(|v| v.code).collect()
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let rule = password(PasswordPolicy::default());
+        assert!(rule.apply("12345678").is_empty());
+
+        let err = rule.apply("1234567");
+        assert_eq!(codes(&err), ["password_too_short"]);
+        assert_eq!(err.violations[0].meta.get("min"), Some("8"));
+        assert_eq!(err.violations[0].meta.get("actual"), Some("7"));
+
+        let err = rule.apply(&"x".repeat(129));
+        assert_eq!(codes(&err), ["password_too_long"]);
+    }
+
+    #[test]
+    fn test_reports_every_unmet_requirement() {
+        let rule = password(PasswordPolicy {
+            min_length: 12,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: true,
+            min_entropy_bits: Some(60),
+            max_repeats: Some(2),
+            forbidden: vec!["acme".to_string()],
+            ..PasswordPolicy::default()
+        });
+
+        let err = rule.apply("acmeeee");
+        assert_eq!(
+            codes(&err),
+            [
+                "password_too_short",
+                "password_missing_class",
+                "password_missing_class",
+                "password_missing_class",
+                "password_forbidden_substring",
+                "password_repeated_chars",
+                "password_low_entropy",
+            ]
+        );
+        let classes: Vec<_> = err.violations[1..4]
+            .iter()
+            .map(|v| v.meta.get("class").unwrap())
+            .collect();
+        assert_eq!(classes, ["uppercase", "digit", "symbol"]);
+        assert!(err.violations.iter().all(|v| !v.message.contains("acme")));
+
+        assert!(rule.apply("Tr0ub4dor&3-horse").is_empty());
+    }
+
+    #[test]
+    fn test_character_classes_are_unicode_aware() {
+        let rule = password(PasswordPolicy {
+            min_length: 1,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_symbol: true,
+            ..PasswordPolicy::default()
+        });
+        assert!(rule.apply("Ñandú!").is_empty());
+        assert_eq!(codes(&rule.apply("ÑANDÚ1")), ["password_missing_class"; 2]);
+    }
+
+    #[test]
+    fn test_forbidden_substrings() {
+        let policy = PasswordPolicy::default()
+            .forbid("")
+            .forbid("Password")
+            .forbid_identity("jane.doe@example.com");
+        assert_eq!(policy.forbidden.len(), 4);
+
+        let rule = password(policy);
+        assert!(rule.apply("s3cret-phrase").is_empty());
+
+        let err = rule.apply("MyPASSWORD1");
+        assert_eq!(codes(&err), ["password_forbidden_substring"]);
+        assert_eq!(err.violations[0].meta.get("index"), Some("1"));
+
+        let err = rule.apply("jane.doe2024");
+        assert_eq!(err.violations[0].meta.get("index"), Some("2"));
+    }
+
+    #[test]
+    fn test_max_repeats() {
+        let rule = password(PasswordPolicy {
+            min_length: 1,
+            max_repeats: Some(2),
+            ..PasswordPolicy::default()
+        });
+        assert!(rule.apply("aabbaa").is_empty());
+        let err = rule.apply("abbbc");
+        assert_eq!(codes(&err), ["password_repeated_chars"]);
+        assert_eq!(err.violations[0].meta.get("max"), Some("2"));
+    }
+
+    #[test]
+    fn test_entropy_estimate() {
+        assert_eq!(password_entropy_bits(""), 0);
+        // 8 lowercase characters: 8 * log2(26) = 37.6
+        assert_eq!(password_entropy_bits("abcdefgh"), 37);
+        // 4 classes: 62 + 33 = 95 symbols, 10 * log2(95) = 65.7
+        assert_eq!(password_entropy_bits("aB3$aB3$aB"), 65);
+        // Repeated runs count once
+        assert_eq!(password_entropy_bits("aaaabbbb"), 9);
+        assert!((log2(1024.0) - 10.0).abs() < 1e-6);
+        assert!((log2(3.0) - 1.584_962_5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,409 @@
+//! Runtime regex patterns loaded from configuration.
+//!
+//! [`matches_regex`](super::matches_regex) takes a `&'static str` written by the
+//! developer. Patterns that come from tenant configuration or user input are
+//! untrusted: they are compiled under [`RegexLimits`] and shared through a bounded
+//! global cache, so rebuilding a rule for the same pattern does not recompile it.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use core::fmt;
+use regex::{Regex, RegexBuilder};
+
+/// Maximum number of compiled patterns kept in the global cache.
+///
+/// When the cache is full, the least recently used pattern is evicted.
+pub const REGEX_CACHE_CAPACITY: usize = 256;
+
+/// Resource limits for compiling and matching untrusted regex patterns.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::RegexLimits;
+///
+/// let limits = RegexLimits {
+///     max_input_len: 256,
+///     ..RegexLimits::default()
+/// };
+/// assert_eq!(limits.max_pattern_len, 1024);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RegexLimits {
+    /// Maximum pattern length in bytes.
+    pub max_pattern_len: usize,
+    /// Maximum size of the compiled program in bytes.
+    pub size_limit: usize,
+    /// Maximum cache size of the lazy DFA used while matching, in bytes.
+    pub dfa_size_limit: usize,
+    /// Maximum nesting depth of groups and repetitions.
+    pub nest_limit: u32,
+    /// Maximum input length in bytes; longer values fail without being matched.
+    pub max_input_len: usize,
+}
+
+impl Default for RegexLimits {
+    fn default() -> Self {
+        Self {
+            max_pattern_len: 1024,
+            size_limit: 1 << 20,
+            dfa_size_limit: 1 << 20,
+            nest_limit: 50,
+            max_input_len: 4096,
+        }
+    }
+}
+
+/// Error returned when a runtime regex pattern cannot be turned into a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern is longer than [`RegexLimits::max_pattern_len`].
+    TooLong { len: usize, max: usize },
+    /// The compiled pattern exceeds [`RegexLimits::size_limit`].
+    TooBig { limit: usize },
+    /// The pattern is not valid regex syntax (including exceeding the nest limit).
+    Syntax(String),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::TooLong { len, max } => write!(
+                f,
+                "regex pattern is {} bytes long, exceeding the limit of {} bytes",
+                len, max
+            ),
+            PatternError::TooBig { limit } => write!(
+                f,
+                "compiled regex pattern exceeds the size limit of {} bytes",
+                limit
+            ),
+            PatternError::Syntax(msg) => write!(f, "invalid regex pattern: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatternError {}
+
+/// Validates that a string matches a regex pattern known only at runtime.
+///
+/// Requires the `regex` feature to be enabled.
+///
+/// The pattern is compiled under the default [`RegexLimits`] and stored in a
+/// global cache of up to [`REGEX_CACHE_CAPACITY`] patterns, so building a rule
+/// for a pattern that was seen before reuses the compiled regex. Without the
+/// `std` feature there is no cache and every call compiles the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// // e.g. loaded from tenant configuration
+/// let pattern = String::from(r"^[A-Z]{2}-\d{4}$");
+///
+/// let rule = rules::matches_regex_owned(pattern).unwrap();
+/// assert!(rule.apply("AB-1234").is_empty());
+/// assert!(!rule.apply("ab-1234").is_empty());
+///
+/// // Invalid or oversized patterns fail when the rule is built
+/// assert!(rules::matches_regex_owned("[unclosed".to_string()).is_err());
+/// assert!(rules::matches_regex_owned("a".repeat(5000)).is_err());
+/// ```
+///
+/// # Error Codes
+/// - Code: `pattern_mismatch`
+/// - Message: `"Does not match required pattern"`
+/// - Meta: `{"pattern": "regex"}`
+///
+/// - Code: `pattern_input_too_long`
+/// - Message: `"Must be at most {max} bytes to be matched"`
+/// - Meta: `{"max": "4096", "actual": "5000"}`
+///
+/// # Errors
+/// Returns a [`PatternError`] if the pattern is invalid or exceeds a limit.
+pub fn matches_regex_owned(pattern: impl Into<String>) -> Result<Rule<str>, PatternError> {
+    matches_regex_with_limits(pattern, RegexLimits::default())
+}
+
+/// Validates that a string matches a runtime regex pattern under custom limits.
+///
+/// Requires the `regex` feature to be enabled. See [`matches_regex_owned`] for
+/// caching behavior and error codes.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::RegexLimits;
+///
+/// let limits = RegexLimits {
+///     max_input_len: 8,
+///     ..RegexLimits::default()
+/// };
+///
+/// let rule = rules::matches_regex_with_limits(r"^\d+$", limits).unwrap();
+/// assert!(rule.apply("1234").is_empty());
+///
+/// let result = rule.apply("123456789");
+/// assert_eq!(result.violations[0].code, "pattern_input_too_long");
+/// ```
+///
+/// # Errors
+/// Returns a [`PatternError`] if the pattern is invalid or exceeds a limit.
+pub fn matches_regex_with_limits(
+    pattern: impl Into<String>,
+    limits: RegexLimits,
+) -> Result<Rule<str>, PatternError> {
+    let pattern = pattern.into();
+    let re = compile(&pattern, &limits)?;
+    let pattern: Arc<str> = pattern.into();
+    let max_input_len = limits.max_input_len;
+
+    Ok(Rule::new(move |value: &str, ctx: &RuleContext| {
+        if value.len() > max_input_len {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "pattern_input_too_long",
+                format!("Must be at most {} bytes to be matched", max_input_len),
+            );
+            err.violations[0].meta.insert("max", max_input_len);
+            err.violations[0].meta.insert("actual", value.len());
+            err
+        } else if re.is_match(value) {
+            ValidationError::default()
+        } else {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "pattern_mismatch",
+                "Does not match required pattern",
+            );
+            err.violations[0].meta.insert("pattern", &*pattern);
+            err
+        }
+    }))
+}
+
+fn build(pattern: &str, limits: &RegexLimits) -> Result<Regex, PatternError> {
+    if pattern.len() > limits.max_pattern_len {
+        return Err(PatternError::TooLong {
+            len: pattern.len(),
+            max: limits.max_pattern_len,
+        });
+    }
+
+    RegexBuilder::new(pattern)
+        .size_limit(limits.size_limit)
+        .dfa_size_limit(limits.dfa_size_limit)
+        .nest_limit(limits.nest_limit)
+        .build()
+        .map_err(|err| match err {
+            regex::Error::CompiledTooBig(limit) => PatternError::TooBig { limit },
+            regex::Error::Syntax(msg) => PatternError::Syntax(msg),
+            other => PatternError::Syntax(other.to_string()),
+        })
+}
+
+#[cfg(not(feature = "std"))]
+fn compile(pattern: &str, limits: &RegexLimits) -> Result<Arc<Regex>, PatternError> {
+    build(pattern, limits).map(Arc::new)
+}
+
+#[cfg(feature = "std")]
+fn compile(pattern: &str, limits: &RegexLimits) -> Result<Arc<Regex>, PatternError> {
+    use std::sync::Mutex;
+
+    static CACHE: Mutex<cache::RegexCache> = Mutex::new(cache::RegexCache::new());
+
+    if let Some(re) = CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(pattern, limits)
+    {
+        return Ok(re);
+    }
+
+    // Compile outside the lock so a slow pattern doesn't block other callers
+    let re = Arc::new(build(pattern, limits)?);
+    CACHE.lock().unwrap_or_else(|e| e.into_inner()).insert(
+        pattern,
+        limits,
+        re.clone(),
+        REGEX_CACHE_CAPACITY,
+    );
+    Ok(re)
+}
+
+#[cfg(feature = "std")]
+mod cache {
+    use super::RegexLimits;
+    use alloc::collections::BTreeMap;
+    use alloc::string::{String, ToString};
+    use alloc::sync::Arc;
+    use regex::Regex;
+
+    type Key = (String, RegexLimits);
+
+    /// Least-recently-used cache of compiled patterns.
+    pub(super) struct RegexCache {
+        entries: BTreeMap<Key, (Arc<Regex>, u64)>,
+        tick: u64,
+    }
+
+    impl RegexCache {
+        pub(super) const fn new() -> Self {
+            Self {
+                entries: BTreeMap::new(),
+                tick: 0,
+            }
+        }
+
+        #[cfg(test)]
+        pub(super) fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        pub(super) fn get(&mut self, pattern: &str, limits: &RegexLimits) -> Option<Arc<Regex>> {
+            self.tick += 1;
+            let tick = self.tick;
+            let (re, last_used) = self.entries.get_mut(&(pattern.to_string(), *limits))?;
+            *last_used = tick;
+            Some(re.clone())
+        }
+
+        pub(super) fn insert(
+            &mut self,
+            pattern: &str,
+            limits: &RegexLimits,
+            re: Arc<Regex>,
+            capacity: usize,
+        ) {
+            let key = (pattern.to_string(), *limits);
+            if !self.entries.contains_key(&key) && self.entries.len() >= capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (_, last_used))| *last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+
+            self.tick += 1;
+            self.entries.insert(key, (re, self.tick));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_regex_owned_valid() {
+        let rule = matches_regex_owned(String::from(r"^\d{3}-\d{4}$")).unwrap();
+        assert!(rule.apply("123-4567").is_empty());
+    }
+
+    #[test]
+    fn test_matches_regex_owned_mismatch() {
+        let rule = matches_regex_owned(String::from(r"^\d{3}-\d{4}$")).unwrap();
+        let result = rule.apply("1234567");
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].code, "pattern_mismatch");
+        assert_eq!(
+            result.violations[0].meta.get("pattern"),
+            Some(r"^\d{3}-\d{4}$")
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_syntax_error() {
+        let err = matches_regex_owned("[unclosed").unwrap_err();
+        assert!(matches!(err, PatternError::Syntax(_)));
+        assert!(err.to_string().starts_with("invalid regex pattern"));
+    }
+
+    #[test]
+    fn test_pattern_length_limit() {
+        let err = matches_regex_owned("a".repeat(2000)).unwrap_err();
+        assert_eq!(
+            err,
+            PatternError::TooLong {
+                len: 2000,
+                max: 1024
+            }
+        );
+    }
+
+    #[test]
+    fn test_compiled_size_limit() {
+        let limits = RegexLimits {
+            size_limit: 1000,
+            ..RegexLimits::default()
+        };
+        let err = matches_regex_with_limits(r"\w{100}", limits).unwrap_err();
+        assert_eq!(err, PatternError::TooBig { limit: 1000 });
+        assert_eq!(
+            err.to_string(),
+            "compiled regex pattern exceeds the size limit of 1000 bytes"
+        );
+    }
+
+    #[test]
+    fn test_nest_limit() {
+        let limits = RegexLimits {
+            nest_limit: 3,
+            ..RegexLimits::default()
+        };
+        assert!(matches_regex_with_limits("((((a))))", limits).is_err());
+        assert!(matches_regex_with_limits("((a))", limits).is_ok());
+    }
+
+    #[test]
+    fn test_input_length_cap() {
+        let limits = RegexLimits {
+            max_input_len: 5,
+            ..RegexLimits::default()
+        };
+        let rule = matches_regex_with_limits(".*", limits).unwrap();
+        assert!(rule.apply("12345").is_empty());
+
+        let result = rule.apply("123456");
+        assert_eq!(result.violations[0].code, "pattern_input_too_long");
+        assert_eq!(result.violations[0].meta.get("max"), Some("5"));
+        assert_eq!(result.violations[0].meta.get("actual"), Some("6"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_compiled_pattern_is_shared() {
+        let limits = RegexLimits::default();
+        let a = compile(r"^shared-\d+$", &limits).unwrap();
+        let b = compile(r"^shared-\d+$", &limits).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let limits = RegexLimits::default();
+        let mut cache = cache::RegexCache::new();
+
+        for pattern in ["a", "b", "c"] {
+            cache.insert(pattern, &limits, Arc::new(Regex::new(pattern).unwrap()), 3);
+        }
+        // Touch "a" so "b" becomes the oldest entry
+        assert!(cache.get("a", &limits).is_some());
+
+        cache.insert("d", &limits, Arc::new(Regex::new("d").unwrap()), 3);
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get("b", &limits).is_none());
+        assert!(cache.get("a", &limits).is_some());
+        assert!(cache.get("d", &limits).is_some());
+    }
+}
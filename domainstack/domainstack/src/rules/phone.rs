@@ -0,0 +1,430 @@
+//! Phone number rules (`phone` feature), backed by the libphonenumber metadata
+//! embedded in the `phonenumber` crate.
+//!
+//! Store numbers in canonical E.164 form (`+14155552671`). [`e164`] accepts only
+//! that form; [`phone_for_region`] accepts what users type for a region, and
+//! [`parse`] turns it into the canonical form to store:
+//!
+//! ```
+//! use domainstack::prelude::*;
+//! use domainstack::rules::phone::{self, PhoneType};
+//!
+//! let input = "020 7946 0958";
+//! validate("phone", input, &rules::phone_for_region("GB")).unwrap();
+//!
+//! let number = phone::parse(input, Some("GB")).unwrap();
+//! assert_eq!(number.e164, "+442079460958");
+//! assert_eq!(number.region.as_deref(), Some("GB"));
+//! assert_eq!(number.number_type, PhoneType::FixedLine);
+//! ```
+//!
+//! Violations for numbers that parse carry `normalized`, `country` and `type`
+//! meta, so clients can suggest the canonical form.
+
+use crate::{Rule, RuleContext, ValidationError};
+use core::fmt;
+use phonenumber::country;
+use phonenumber::metadata::DATABASE;
+use phonenumber::{Mode, PhoneNumber, Type};
+
+/// Longest E.164 number: a `+` and 15 digits.
+const E164_MAX_DIGITS: usize = 15;
+
+/// What a phone number is used for, as far as its numbering plan tells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PhoneType {
+    /// Mobile numbers.
+    Mobile,
+    /// Fixed line numbers.
+    FixedLine,
+    /// Either; some plans, such as North America's, don't tell them apart.
+    FixedLineOrMobile,
+    /// Freephone numbers.
+    TollFree,
+    /// Premium rate numbers.
+    PremiumRate,
+    /// Numbers whose call cost is shared with the recipient.
+    SharedCost,
+    /// Voice over IP numbers.
+    Voip,
+    /// Personal numbers, routed to a mobile or fixed line.
+    PersonalNumber,
+    /// Pager numbers.
+    Pager,
+    /// Universal access (company) numbers.
+    Uan,
+    /// Voicemail access numbers.
+    Voicemail,
+    /// Anything else.
+    Unknown,
+}
+
+impl PhoneType {
+    /// The `type` meta value, such as `"mobile"` or `"fixed_line"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneType::Mobile => "mobile",
+            PhoneType::FixedLine => "fixed_line",
+            PhoneType::FixedLineOrMobile => "fixed_line_or_mobile",
+            PhoneType::TollFree => "toll_free",
+            PhoneType::PremiumRate => "premium_rate",
+            PhoneType::SharedCost => "shared_cost",
+            PhoneType::Voip => "voip",
+            PhoneType::PersonalNumber => "personal_number",
+            PhoneType::Pager => "pager",
+            PhoneType::Uan => "uan",
+            PhoneType::Voicemail => "voicemail",
+            PhoneType::Unknown => "unknown",
+        }
+    }
+}
+
+impl From<Type> for PhoneType {
+    fn from(value: Type) -> Self {
+        match value {
+            Type::Mobile => PhoneType::Mobile,
+            Type::FixedLine => PhoneType::FixedLine,
+            Type::FixedLineOrMobile => PhoneType::FixedLineOrMobile,
+            Type::TollFree => PhoneType::TollFree,
+            Type::PremiumRate => PhoneType::PremiumRate,
+            Type::SharedCost => PhoneType::SharedCost,
+            Type::Voip => PhoneType::Voip,
+            Type::PersonalNumber => PhoneType::PersonalNumber,
+            Type::Pager => PhoneType::Pager,
+            Type::Uan => PhoneType::Uan,
+            Type::Voicemail => PhoneType::Voicemail,
+            _ => PhoneType::Unknown,
+        }
+    }
+}
+
+/// A valid phone number in canonical form, from [`parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneInfo {
+    /// Canonical E.164 form, such as `"+14155552671"`.
+    pub e164: String,
+    /// ISO 3166 alpha-2 region, or `None` for non-geographic numbers (`+800`).
+    pub region: Option<String>,
+    /// Number type from the region's numbering plan.
+    pub number_type: PhoneType,
+}
+
+/// Parses and validates a phone number, returning its canonical E.164 form.
+///
+/// `region` (ISO 3166 alpha-2, such as `"US"`) is used for numbers written in
+/// national format; numbers starting with `+` carry their own country code.
+/// Returns `None` if the number is not valid, or `region` is not a known region.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::phone::{self, PhoneType};
+///
+/// let number = phone::parse("+1 (415) 555-2671", None).unwrap();
+/// assert_eq!(number.e164, "+14155552671");
+/// assert_eq!(number.region.as_deref(), Some("US"));
+///
+/// let number = phone::parse("06 12 34 56 78", Some("FR")).unwrap();
+/// assert_eq!(number.e164, "+33612345678");
+/// assert_eq!(number.number_type, PhoneType::Mobile);
+///
+/// assert!(phone::parse("12345", Some("US")).is_none());
+/// ```
+pub fn parse(value: &str, region: Option<&str>) -> Option<PhoneInfo> {
+    let region = match region {
+        Some(region) => Some(region_id(region)?),
+        None => None,
+    };
+    let number = phonenumber::parse(region, value).ok()?;
+    if !number.is_valid() {
+        return None;
+    }
+    Some(info(&number))
+}
+
+/// Validates that a string is a valid phone number in canonical E.164 form:
+/// `+`, the country code and the national number, with nothing in between.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::e164();
+/// assert!(rule.apply("+14155552671").is_empty());
+/// assert!(rule.apply("+442079460958").is_empty());
+///
+/// // A valid number, but formatted
+/// let err = rule.apply("+1 415-555-2671");
+/// assert_eq!(err.violations[0].code, "not_e164");
+/// assert_eq!(err.violations[0].meta.get("normalized"), Some("+14155552671"));
+///
+/// // Right shape, but no such number
+/// assert_eq!(rule.apply("+10005550000").violations[0].code, "invalid_phone");
+/// ```
+///
+/// # Error Codes
+/// - Code: `invalid_phone`
+///   - Message: `"Must be a valid phone number"`
+/// - Code: `not_e164`
+///   - Message: `"Must be in E.164 format, like +14155552671"`
+///   - Meta: `{"normalized": "+14155552671", "country": "US", "type": "fixed_line_or_mobile"}`
+pub fn e164() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let number = match phonenumber::parse(None, value) {
+            Ok(number) if number.is_valid() => number,
+            _ => return invalid(ctx),
+        };
+        let canonical = number.format().mode(Mode::E164).to_string();
+        if canonical == value && is_e164_shaped(value) {
+            return ValidationError::default();
+        }
+
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "not_e164",
+            "Must be in E.164 format, like +14155552671",
+        );
+        insert_meta(&mut err, &info(&number));
+        err
+    })
+}
+
+/// Validates that a string is a valid phone number of `region` (ISO 3166
+/// alpha-2, such as `"GB"`), written in national or international format.
+///
+/// Use [`parse`] with the same region to get the E.164 form to store.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::phone_for_region("GB");
+/// assert!(rule.apply("07400 123456").is_empty());
+/// assert!(rule.apply("+44 7400 123456").is_empty());
+///
+/// let err = rule.apply("+33 6 12 34 56 78");
+/// assert_eq!(err.violations[0].code, "wrong_region");
+/// assert_eq!(err.violations[0].meta.get("country"), Some("FR"));
+/// assert_eq!(err.violations[0].meta.get("type"), Some("mobile"));
+/// ```
+///
+/// # Error Codes
+/// - Code: `invalid_phone`
+///   - Message: `"Must be a valid phone number"`
+///   - Meta: `{"region": "GB"}`
+/// - Code: `wrong_region`
+///   - Message: `"Must be a GB phone number"`
+///   - Meta: `{"region": "GB", "normalized": "+33612345678", "country": "FR", "type": "mobile"}`
+///
+/// # Panics
+/// Panics if `region` is not a region in the numbering-plan metadata.
+/// Use [`try_phone_for_region`] for a non-panicking alternative.
+pub fn phone_for_region(region: &str) -> Rule<str> {
+    try_phone_for_region(region).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Validates that a string is a valid phone number of `region`
+/// (non-panicking version).
+///
+/// Useful when the region comes from configuration or user input.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::try_phone_for_region("gb").unwrap();
+/// assert!(rule.apply("07400 123456").is_empty());
+///
+/// assert!(rules::try_phone_for_region("XX").is_err());
+/// ```
+///
+/// # Error Codes
+/// Same as [`phone_for_region`].
+///
+/// # Errors
+/// Returns [`UnknownRegion`] if `region` is not a region in the numbering-plan
+/// metadata.
+pub fn try_phone_for_region(region: &str) -> Result<Rule<str>, UnknownRegion> {
+    let id = region_id(region).ok_or_else(|| UnknownRegion(String::from(region)))?;
+    let region = String::from(id.as_ref());
+    Ok(Rule::new(move |value: &str, ctx: &RuleContext| {
+        let number = match phonenumber::parse(Some(id), value) {
+            Ok(number) if number.is_valid() => number,
+            _ => {
+                let mut err = invalid(ctx);
+                err.violations[0].meta.insert("region", region.as_str());
+                return err;
+            }
+        };
+        let info = info(&number);
+        if info.region.as_deref() == Some(region.as_str()) {
+            return ValidationError::default();
+        }
+
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "wrong_region",
+            format!("Must be a {} phone number", region),
+        );
+        err.violations[0].meta.insert("region", region.as_str());
+        insert_meta(&mut err, &info);
+        err
+    }))
+}
+
+/// Error returned by [`try_phone_for_region`] for a region without numbering-plan
+/// metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRegion(pub String);
+
+impl fmt::Display for UnknownRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown phone numbering region: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownRegion {}
+
+fn region_id(region: &str) -> Option<country::Id> {
+    let id: country::Id = region.to_ascii_uppercase().parse().ok()?;
+    // The ID list is CLDR's; only regions with a numbering plan can be used
+    DATABASE.by_id(id.as_ref()).map(|_| id)
+}
+
+fn info(number: &PhoneNumber) -> PhoneInfo {
+    PhoneInfo {
+        e164: number.format().mode(Mode::E164).to_string(),
+        region: number.country().id().map(|id| String::from(id.as_ref())),
+        number_type: number.number_type(&DATABASE).into(),
+    }
+}
+
+/// `+` followed by up to 15 digits, the first not zero.
+fn is_e164_shaped(value: &str) -> bool {
+    let Some(digits) = value.strip_prefix('+') else {
+        return false;
+    };
+    (2..=E164_MAX_DIGITS).contains(&digits.len())
+        && !digits.starts_with('0')
+        && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn invalid(ctx: &RuleContext) -> ValidationError {
+    ValidationError::single(
+        ctx.full_path(),
+        "invalid_phone",
+        "Must be a valid phone number",
+    )
+}
+
+fn insert_meta(err: &mut ValidationError, info: &PhoneInfo) {
+    let meta = &mut err.violations[0].meta;
+    meta.insert("normalized", info.e164.as_str());
+    if let Some(region) = &info.region {
+        meta.insert("country", region.as_str());
+    }
+    meta.insert("type", info.number_type.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let number = parse("+81 90-1234-5678", None).unwrap();
+        assert_eq!(number.e164, "+819012345678");
+        assert_eq!(number.region.as_deref(), Some("JP"));
+        assert_eq!(number.number_type, PhoneType::Mobile);
+
+        // Region codes are case-insensitive
+        let number = parse("(415) 555-2671", Some("us")).unwrap();
+        assert_eq!(number.e164, "+14155552671");
+        assert_eq!(number.number_type, PhoneType::FixedLineOrMobile);
+
+        // Non-geographic numbers have no region
+        let number = parse("+800 1234 5678", None).unwrap();
+        assert_eq!(number.region, None);
+
+        assert_eq!(parse("0612345678", None), None);
+        assert_eq!(parse("0612345678", Some("ZZ")), None);
+        assert_eq!(parse("not a number", Some("FR")), None);
+    }
+
+    #[test]
+    fn test_e164() {
+        let rule = e164();
+        assert!(rule.apply("+33612345678").is_empty());
+
+        for formatted in ["+33 6 12 34 56 78", " +33612345678", "+33(0)612345678"] {
+            let err = rule.apply(formatted);
+            assert_eq!(err.violations[0].code, "not_e164", "{}", formatted);
+            assert_eq!(
+                err.violations[0].meta.get("normalized"),
+                Some("+33612345678")
+            );
+            assert_eq!(err.violations[0].meta.get("country"), Some("FR"));
+        }
+
+        for invalid in ["", "+", "33612345678", "0612345678", "+3361234567890123"] {
+            assert_eq!(
+                rule.apply(invalid).violations[0].code,
+                "invalid_phone",
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_phone_for_region() {
+        let rule = phone_for_region("de");
+        assert!(rule.apply("030 901820").is_empty());
+        assert!(rule.apply("+49 1512 3456789").is_empty());
+
+        let err = rule.apply("+1 415 555 2671");
+        assert_eq!(err.violations[0].code, "wrong_region");
+        assert_eq!(err.violations[0].message, "Must be a DE phone number");
+        assert_eq!(err.violations[0].meta.get("region"), Some("DE"));
+        assert_eq!(
+            err.violations[0].meta.get("normalized"),
+            Some("+14155552671")
+        );
+
+        let err = rule.apply("12");
+        assert_eq!(err.violations[0].code, "invalid_phone");
+        assert_eq!(err.violations[0].meta.get("region"), Some("DE"));
+    }
+
+    #[test]
+    fn test_shared_country_code() {
+        // +1 covers the US, Canada and the Caribbean
+        let rule = phone_for_region("CA");
+        assert!(rule.apply("(604) 555-0123").is_empty());
+
+        let err = rule.apply("(415) 555-2671");
+        assert_eq!(err.violations[0].code, "wrong_region");
+        assert_eq!(err.violations[0].meta.get("country"), Some("US"));
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown phone numbering region")]
+    fn test_unknown_region() {
+        phone_for_region("XX");
+    }
+
+    #[test]
+    fn test_try_phone_for_region() {
+        let rule = try_phone_for_region("de").unwrap();
+        assert!(rule.apply("030 123456").is_empty());
+
+        let err = try_phone_for_region("XX").unwrap_err();
+        assert_eq!(err, UnknownRegion("XX".to_string()));
+        assert_eq!(err.to_string(), "unknown phone numbering region: \"XX\"");
+        assert!(try_phone_for_region("").is_err());
+    }
+}
@@ -0,0 +1,476 @@
+//! Rules for input that reaches file systems, shells, HTML or template engines.
+//!
+//! They reject the usual injection markers up front so the input can be
+//! refused with a field error. They are a baseline, not a sanitizer: output
+//! must still be escaped or quoted for the context it ends up in.
+//!
+//! `max_nesting` needs the `json` feature; the string rules have no
+//! dependencies.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::string::{String, ToString};
+
+/// Longest file name most file systems accept, in bytes.
+const MAX_FILENAME_BYTES: usize = 255;
+
+/// Characters Windows forbids in file names.
+const RESERVED_FILENAME_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*'];
+
+/// Device names Windows reserves, with or without an extension.
+const RESERVED_FILENAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Openers of Jinja/Twig/Handlebars, JavaScript template literal, Ruby and ERB/JSP syntax.
+const TEMPLATE_TOKENS: &[&str] = &["{{", "{%", "{#", "${", "#{", "<%"];
+
+/// Validates that a string is a single, portable file name.
+///
+/// Rejects empty names, names over 255 bytes, path separators, control
+/// characters, the characters Windows forbids (`<>:"|?*`), `.` and `..`,
+/// trailing dots or spaces, a leading `-` (read as an option by most
+/// commands) and Windows device names such as `CON` or `nul.txt`.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::safe_filename();
+/// assert!(rule.apply("report-2024.pdf").is_empty());
+///
+/// let err = rule.apply("../etc/passwd");
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("separator"));
+/// assert!(!rule.apply("-rf").is_empty());
+/// assert!(!rule.apply("CON.txt").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `unsafe_filename`
+/// - Message: `"Must be a safe file name"`
+/// - Meta: `{"reason": "separator"}`, one of `empty`, `too_long`, `separator`,
+///   `control_char`, `reserved_char`, `dot_name`, `trailing_dot_or_space`,
+///   `leading_dash` or `reserved_name`
+pub fn safe_filename() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let Some(reason) = unsafe_filename_reason(value) else {
+            return ValidationError::default();
+        };
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "unsafe_filename",
+            "Must be a safe file name",
+        );
+        err.violations[0].meta.insert("reason", reason);
+        err
+    })
+}
+
+/// Validates that a relative path stays inside the directory it is joined to.
+///
+/// Rejects `..` segments and absolute paths (a leading `/` or `\`, or a
+/// drive letter such as `C:`), which replace the base directory when joined.
+/// Both separators are checked, and percent-encoded dots and separators
+/// (`%2e%2e%2f`, also double-encoded) are decoded first. Symlinks inside the
+/// base directory are out of scope.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_path_traversal();
+/// assert!(rule.apply("avatars/2024/me.png").is_empty());
+/// assert!(rule.apply("notes..txt").is_empty());
+///
+/// let err = rule.apply("avatars/%2e%2e/%2e%2e/etc/passwd");
+/// assert_eq!(err.violations[0].meta.get("reason"), Some("parent_segment"));
+/// assert!(!rule.apply("/etc/passwd").is_empty());
+/// assert!(!rule.apply("C:\\Windows").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `path_traversal`
+/// - Message: `"Must not point outside the base directory"`
+/// - Meta: `{"reason": "parent_segment"}` or `{"reason": "absolute_path"}`
+pub fn no_path_traversal() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let path = decode_path(value);
+        let bytes = path.as_bytes();
+        let reason = if bytes.first().is_some_and(|&b| b == b'/' || b == b'\\')
+            || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+        {
+            "absolute_path"
+        } else if path.split(['/', '\\']).any(|segment| segment == "..") {
+            "parent_segment"
+        } else {
+            return ValidationError::default();
+        };
+
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "path_traversal",
+            "Must not point outside the base directory",
+        );
+        err.violations[0].meta.insert("reason", reason);
+        err
+    })
+}
+
+/// Validates that a string contains no NUL characters.
+///
+/// C APIs, including most file system and process calls, stop at the first
+/// NUL, so `"image.png\0.exe"` can be checked as one name and used as another.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_null_bytes();
+/// assert!(rule.apply("image.png").is_empty());
+///
+/// let err = rule.apply("image.png\0.exe");
+/// assert_eq!(err.violations[0].meta.get("position"), Some("9"));
+/// ```
+///
+/// # Error Code
+/// - Code: `null_byte`
+/// - Message: `"Must not contain null bytes"`
+/// - Meta: `{"position": "9"}` (first NUL, counted in chars)
+pub fn no_null_bytes() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let Some(position) = value.chars().position(|c| c == '\0') else {
+            return ValidationError::default();
+        };
+        let mut err =
+            ValidationError::single(ctx.full_path(), "null_byte", "Must not contain null bytes");
+        err.violations[0]
+            .meta
+            .insert("position", position.to_string());
+        err
+    })
+}
+
+/// Validates that a string contains nothing an HTML parser would read as markup.
+///
+/// Rejects `<` followed by a letter, `/`, `!` or `?`: tags, closing tags,
+/// comments, doctypes and processing instructions. A `<` used as a comparison
+/// (`"a < b"`, `"<3"`) is allowed.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_html_tags();
+/// assert!(rule.apply("Fish & chips for < 10 EUR").is_empty());
+///
+/// let err = rule.apply("Nice<script>alert(1)</script>");
+/// assert_eq!(err.violations[0].meta.get("position"), Some("4"));
+/// ```
+///
+/// # Error Code
+/// - Code: `html_tag`
+/// - Message: `"Must not contain HTML tags"`
+/// - Meta: `{"position": "4"}` (the tag's `<`, counted in chars)
+pub fn no_html_tags() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let mut chars = value.chars().peekable();
+        let mut position = 0;
+        while let Some(c) = chars.next() {
+            let opens_tag = c == '<'
+                && chars
+                    .peek()
+                    .is_some_and(|&next| next.is_ascii_alphabetic() || "/!?".contains(next));
+            if opens_tag {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "html_tag",
+                    "Must not contain HTML tags",
+                );
+                err.violations[0]
+                    .meta
+                    .insert("position", position.to_string());
+                return err;
+            }
+            position += 1;
+        }
+        ValidationError::default()
+    })
+}
+
+/// Validates that a string contains no template engine syntax.
+///
+/// Rejects the openers `{{`, `{%`, `{#` (Jinja, Twig, Handlebars, Liquid),
+/// `${` (JavaScript template literals, shells, EL), `#{` (Ruby, Pug) and
+/// `<%` (ERB, JSP), so user text rendered through a template cannot inject
+/// expressions.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_template_syntax();
+/// assert!(rule.apply("Costs $5 {per item}").is_empty());
+///
+/// let err = rule.apply("Hi {{ config.SECRET_KEY }}");
+/// assert_eq!(err.violations[0].meta.get("token"), Some("{{"));
+/// assert_eq!(err.violations[0].meta.get("position"), Some("3"));
+/// ```
+///
+/// # Error Code
+/// - Code: `template_syntax`
+/// - Message: `"Must not contain template syntax"`
+/// - Meta: `{"token": "{{", "position": "3"}` (first opener, counted in chars)
+pub fn no_template_syntax() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let found = TEMPLATE_TOKENS
+            .iter()
+            .filter_map(|token| value.find(token).map(|index| (index, *token)))
+            .min();
+        let Some((index, token)) = found else {
+            return ValidationError::default();
+        };
+
+        let mut err = ValidationError::single(
+            ctx.full_path(),
+            "template_syntax",
+            "Must not contain template syntax",
+        );
+        err.violations[0].meta.insert("token", token);
+        err.violations[0]
+            .meta
+            .insert("position", value[..index].chars().count().to_string());
+        err
+    })
+}
+
+/// Validates that a JSON value nests arrays and objects at most `max` levels deep.
+///
+/// Scalars have depth 0 and `{"a": [1]}` has depth 2. The walk is iterative
+/// and stops at the first container past the limit, so hostile documents
+/// can't exhaust the stack here. Parse with a recursion limit as well:
+/// `serde_json` stops at 128 levels by default.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use serde_json::json;
+///
+/// let rule = rules::max_nesting(2);
+/// assert!(rule.apply(&json!({"tags": ["a", "b"]})).is_empty());
+///
+/// let err = rule.apply(&json!({"a": {"b": {"c": 1}}}));
+/// assert_eq!(err.violations[0].code, "too_deeply_nested");
+/// ```
+///
+/// # Error Code
+/// - Code: `too_deeply_nested`
+/// - Message: `"Must be nested at most {max} levels deep"`
+/// - Meta: `{"max": "2"}`
+#[cfg(feature = "json")]
+pub fn max_nesting(max: usize) -> Rule<serde_json::Value> {
+    use alloc::format;
+    use alloc::vec;
+    use serde_json::Value;
+
+    Rule::new(move |value: &Value, ctx: &RuleContext| {
+        let mut stack = vec![(value, 0usize)];
+        while let Some((value, depth)) = stack.pop() {
+            match value {
+                Value::Array(items) if depth < max => {
+                    stack.extend(items.iter().map(|item| (item, depth + 1)));
+                }
+                Value::Object(fields) if depth < max => {
+                    stack.extend(fields.values().map(|field| (field, depth + 1)));
+                }
+                Value::Array(_) | Value::Object(_) => {
+                    let mut err = ValidationError::single(
+                        ctx.full_path(),
+                        "too_deeply_nested",
+                        format!("Must be nested at most {} levels deep", max),
+                    );
+                    err.violations[0].meta.insert("max", max);
+                    return err;
+                }
+                _ => {}
+            }
+        }
+        ValidationError::default()
+    })
+}
+
+fn unsafe_filename_reason(name: &str) -> Option<&'static str> {
+    let stem = name.split('.').next().unwrap_or_default().trim_end();
+    let reason = if name.is_empty() {
+        "empty"
+    } else if name.len() > MAX_FILENAME_BYTES {
+        "too_long"
+    } else if name.contains(['/', '\\']) {
+        "separator"
+    } else if name.chars().any(char::is_control) {
+        "control_char"
+    } else if name.contains(RESERVED_FILENAME_CHARS) {
+        "reserved_char"
+    } else if name == "." || name == ".." {
+        "dot_name"
+    } else if name.ends_with(['.', ' ']) {
+        "trailing_dot_or_space"
+    } else if name.starts_with('-') {
+        "leading_dash"
+    } else if RESERVED_FILENAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        "reserved_name"
+    } else {
+        return None;
+    };
+    Some(reason)
+}
+
+/// Decode percent-encoded `.`, `/`, `\` and `%` until none are left, so
+/// double encoding (`%252e`) is caught too.
+fn decode_path(value: &str) -> String {
+    let mut path = value.to_string();
+    loop {
+        let mut decoded = String::with_capacity(path.len());
+        let mut rest = path.as_str();
+        while let Some(index) = rest.find('%') {
+            decoded.push_str(&rest[..index]);
+            let escape = rest.get(index..index + 3).unwrap_or_default();
+            let c = match escape.to_ascii_lowercase().as_str() {
+                "%2e" => Some('.'),
+                "%2f" => Some('/'),
+                "%5c" => Some('\\'),
+                "%25" => Some('%'),
+                _ => None,
+            };
+            match c {
+                Some(c) => {
+                    decoded.push(c);
+                    rest = &rest[index + 3..];
+                }
+                None => {
+                    decoded.push('%');
+                    rest = &rest[index + 1..];
+                }
+            }
+        }
+        decoded.push_str(rest);
+
+        if decoded == path {
+            return path;
+        }
+        path = decoded;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(rule: &Rule<str>, value: &str) -> Option<String> {
+        rule.apply(value)
+            .violations
+            .first()
+            .and_then(|v| v.meta.get("reason").map(ToString::to_string))
+    }
+
+    #[test]
+    fn test_safe_filename() {
+        let rule = safe_filename();
+        for name in ["a", "report.final.pdf", ".env", "console.log", "COM10"] {
+            assert!(rule.apply(name).is_empty(), "{name}");
+        }
+
+        let long = "a".repeat(256);
+        let cases = [
+            ("", "empty"),
+            (long.as_str(), "too_long"),
+            ("dir\\file", "separator"),
+            ("line\nbreak", "control_char"),
+            ("what?.txt", "reserved_char"),
+            ("..", "dot_name"),
+            ("file.", "trailing_dot_or_space"),
+            ("file ", "trailing_dot_or_space"),
+            ("--help", "leading_dash"),
+            ("nul", "reserved_name"),
+            ("Lpt1 .tar.gz", "reserved_name"),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(reason(&rule, name).as_deref(), Some(expected), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn test_no_path_traversal() {
+        let rule = no_path_traversal();
+        for path in ["", "a/b/c", "a/./b", "...", "a/..b/c", "100%", "%zz"] {
+            assert!(rule.apply(path).is_empty(), "{path}");
+        }
+
+        let cases = [
+            ("..", "parent_segment"),
+            ("a/../../b", "parent_segment"),
+            ("a\\..\\b", "parent_segment"),
+            ("%2E%2E%2Fsecret", "parent_segment"),
+            ("%252e%252e/secret", "parent_segment"),
+            ("\\\\server\\share", "absolute_path"),
+            ("%2fetc", "absolute_path"),
+            ("d:file", "absolute_path"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(reason(&rule, path).as_deref(), Some(expected), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_markup_and_template_rules() {
+        let html = no_html_tags();
+        for text in ["1 < 2", "<3", "a<", "<<", "< b>"] {
+            assert!(html.apply(text).is_empty(), "{text}");
+        }
+        for text in ["<b>", "</p>", "<!-- x -->", "<?php", "é<img src=x>"] {
+            assert!(!html.apply(text).is_empty(), "{text}");
+        }
+        let err = html.apply("é<img src=x>");
+        assert_eq!(err.violations[0].meta.get("position"), Some("1"));
+
+        let template = no_template_syntax();
+        assert!(template.apply("{ } $ # % {x}").is_empty());
+        let err = template.apply("<%= x %> then ${y}");
+        assert_eq!(err.violations[0].meta.get("token"), Some("<%"));
+        assert_eq!(err.violations[0].meta.get("position"), Some("0"));
+
+        let err = no_null_bytes().apply_with_context("a\0", &RuleContext::root("name"));
+        assert_eq!(err.violations[0].path.to_string(), "name");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_max_nesting() {
+        use serde_json::json;
+
+        assert!(max_nesting(0).apply(&json!("scalar")).is_empty());
+        assert!(!max_nesting(0).apply(&json!([])).is_empty());
+        assert!(max_nesting(2).apply(&json!([1, 2, {}])).is_empty());
+        assert!(!max_nesting(1).apply(&json!([1, 2, {}])).is_empty());
+
+        let mut deep = json!(1);
+        for _ in 0..1_000 {
+            deep = json!([deep]);
+        }
+        let err = max_nesting(64).apply(&deep);
+        assert_eq!(
+            err.violations[0].message,
+            "Must be nested at most 64 levels deep"
+        );
+        assert_eq!(err.violations[0].meta.get("max"), Some("64"));
+        assert!(max_nesting(1_000).apply(&deep).is_empty());
+    }
+}
@@ -0,0 +1,412 @@
+//! Unicode safety rules for user-visible text such as display names and
+//! handles.
+//!
+//! Lengths are counted in extended grapheme clusters (what a reader sees as
+//! one character), and the spoofing checks follow Unicode Technical Standard
+//! #39. Requires the `unicode` feature.
+
+use crate::{Rule, RuleContext, ValidationError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use unicode_security::mixed_script::AugmentedScriptSet;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Validates that a string's grapheme cluster count is within the specified range.
+///
+/// Unlike [`len_chars`](super::len_chars), a flag, an emoji with skin tone or
+/// a letter with combining accents counts as one.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::grapheme_len(1, 3);
+/// assert!(rule.apply("👩‍👩‍👧🇨🇭e\u{301}").is_empty()); // 3 graphemes, 6 chars
+/// assert!(!rule.apply("").is_empty());
+/// assert!(!rule.apply("abcd").is_empty());
+/// ```
+///
+/// # Error Codes
+/// - Code: `min_graphemes` if too few grapheme clusters
+/// - Code: `max_graphemes` if too many grapheme clusters
+/// - Meta: `{"min": "1", "max": "3", "actual": "4"}`
+pub fn grapheme_len(min: usize, max: usize) -> Rule<str> {
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        let count = value.graphemes(true).count();
+
+        let (code, message) = if count < min {
+            (
+                "min_graphemes",
+                format!("Must be at least {} characters", min),
+            )
+        } else if count > max {
+            (
+                "max_graphemes",
+                format!("Must be at most {} characters", max),
+            )
+        } else {
+            return ValidationError::default();
+        };
+
+        let mut err = ValidationError::single(ctx.full_path(), code, message);
+        err.violations[0].meta.insert("min", min.to_string());
+        err.violations[0].meta.insert("max", max.to_string());
+        err.violations[0].meta.insert("actual", count.to_string());
+        err
+    })
+}
+
+/// Validates that a string is in Unicode Normalization Form C.
+///
+/// Text in NFC compares and hashes consistently, so `"é"` typed as one code
+/// point and as `e` + combining acute cannot register as two different names.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::is_nfc();
+/// assert!(rule.apply("caf\u{e9}").is_empty());
+/// assert!(!rule.apply("cafe\u{301}").is_empty());
+/// ```
+///
+/// # Error Code
+/// - Code: `not_nfc`
+/// - Message: `"Must be in Unicode normalization form C"`
+pub fn is_nfc() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        if unicode_normalization::is_nfc(value) {
+            ValidationError::default()
+        } else {
+            ValidationError::single(
+                ctx.full_path(),
+                "not_nfc",
+                "Must be in Unicode normalization form C",
+            )
+        }
+    })
+}
+
+/// Validates that a string contains no bidirectional control characters.
+///
+/// Rejects the Unicode `Bidi_Control` characters: the embedding, override
+/// and isolate controls (U+202A-U+202E, U+2066-U+2069) and the LRM, RLM and
+/// ALM marks, which can make text display in a different order than it is
+/// stored ("Trojan Source").
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_bidi_controls();
+/// assert!(rule.apply("שלום world").is_empty());
+///
+/// let err = rule.apply("invoice\u{202E}fdp.exe");
+/// assert_eq!(err.violations[0].meta.get("char"), Some("U+202E"));
+/// assert_eq!(err.violations[0].meta.get("position"), Some("7"));
+/// ```
+///
+/// # Error Code
+/// - Code: `bidi_control`
+/// - Message: `"Must not contain bidirectional control characters"`
+/// - Meta: `{"char": "U+202E", "position": "7"}` (first offending character, counted in chars)
+pub fn no_bidi_controls() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        reject_char(
+            value,
+            ctx,
+            is_bidi_control,
+            "bidi_control",
+            "Must not contain bidirectional control characters",
+        )
+    })
+}
+
+/// Validates that a string contains no control characters.
+///
+/// Rejects general category `Cc`: C0 controls (including tab and newline),
+/// DEL and C1 controls.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::no_control_chars();
+/// assert!(rule.apply("Jane Doe").is_empty());
+///
+/// let err = rule.apply("Jane\nDoe");
+/// assert_eq!(err.violations[0].meta.get("char"), Some("U+000A"));
+/// ```
+///
+/// # Error Code
+/// - Code: `control_char`
+/// - Message: `"Must not contain control characters"`
+/// - Meta: `{"char": "U+000A", "position": "4"}` (first offending character, counted in chars)
+pub fn no_control_chars() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        reject_char(
+            value,
+            ctx,
+            char::is_control,
+            "control_char",
+            "Must not contain control characters",
+        )
+    })
+}
+
+/// Validates that a string does not mix scripts.
+///
+/// Uses the UTS #39 single-script test: common characters (digits,
+/// punctuation, spaces) go with any script, and Han may be combined with
+/// Hiragana/Katakana (Japanese), Hangul (Korean) or Bopomofo. A Latin handle
+/// with a Cyrillic `а` is rejected.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::single_script();
+/// assert!(rule.apply("paypal_2024").is_empty());
+/// assert!(rule.apply("東京タワー").is_empty());
+///
+/// let err = rule.apply("p\u{430}ypal"); // Cyrillic а
+/// assert_eq!(err.violations[0].meta.get("position"), Some("1"));
+/// ```
+///
+/// # Error Code
+/// - Code: `mixed_script`
+/// - Message: `"Must not mix characters from different scripts"`
+/// - Meta: `{"position": "1"}` (first character whose script conflicts with the ones before it)
+pub fn single_script() -> Rule<str> {
+    Rule::new(|value: &str, ctx: &RuleContext| {
+        let mut scripts = AugmentedScriptSet::default();
+        for (position, c) in value.chars().enumerate() {
+            scripts.intersect_with(AugmentedScriptSet::for_char(c));
+            if scripts.is_empty() {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "mixed_script",
+                    "Must not mix characters from different scripts",
+                );
+                err.violations[0]
+                    .meta
+                    .insert("position", position.to_string());
+                return err;
+            }
+        }
+        ValidationError::default()
+    })
+}
+
+/// Validates that a string cannot be confused with any of the given names.
+///
+/// Two strings are confusable when their UTS #39 skeletons are equal, e.g.
+/// `"pаypal"` (Cyrillic `а`) and `"paypal"`, or `"AdmIn"` and `"admin"`.
+/// Comparison is case-insensitive. Use it to protect reserved handles or
+/// existing usernames; exact matches are rejected too.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+///
+/// let rule = rules::not_confusable_with(["admin", "support"]);
+/// assert!(rule.apply("jane").is_empty());
+///
+/// let err = rule.apply("\u{430}dm\u{456}n"); // Cyrillic а and і
+/// assert_eq!(err.violations[0].code, "confusable");
+/// assert_eq!(err.violations[0].meta.get("matches"), Some("admin"));
+/// ```
+///
+/// # Error Code
+/// - Code: `confusable`
+/// - Message: `"Is too similar to a reserved name"`
+/// - Meta: `{"matches": "admin"}` (the entry it is confusable with)
+pub fn not_confusable_with<I, S>(names: I) -> Rule<str>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let reserved: Vec<(Skeletons, String)> = names
+        .into_iter()
+        .map(|name| {
+            let name = name.as_ref();
+            (Skeletons::of(name), name.to_string())
+        })
+        .collect();
+
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        let value = Skeletons::of(value);
+        match reserved
+            .iter()
+            .find(|(reserved, _)| reserved.matches(&value))
+        {
+            Some((_, name)) => {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "confusable",
+                    "Is too similar to a reserved name",
+                );
+                err.violations[0].meta.insert("matches", name);
+                err
+            }
+            None => ValidationError::default(),
+        }
+    })
+}
+
+/// UTS #39 skeletons of a string as written and lowercased.
+///
+/// Both are needed: lowercasing first makes `"ADMIN"` match `"admin"`, but
+/// would turn `"paypaI"` into `"paypai"`, whose capital `I` only maps to `l`
+/// in the skeleton of the original.
+struct Skeletons {
+    original: String,
+    lowercase: String,
+}
+
+impl Skeletons {
+    fn of(value: &str) -> Self {
+        Self {
+            original: unicode_security::skeleton(value).collect(),
+            lowercase: unicode_security::skeleton(&value.to_lowercase()).collect(),
+        }
+    }
+
+    fn matches(&self, other: &Self) -> bool {
+        self.original == other.original || self.lowercase == other.lowercase
+    }
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Fail on the first char matching `reject`, naming it and its char index in meta.
+fn reject_char(
+    value: &str,
+    ctx: &RuleContext,
+    reject: impl Fn(char) -> bool,
+    code: &'static str,
+    message: &'static str,
+) -> ValidationError {
+    let Some((position, c)) = value.chars().enumerate().find(|&(_, c)| reject(c)) else {
+        return ValidationError::default();
+    };
+
+    let mut err = ValidationError::single(ctx.full_path(), code, message);
+    err.violations[0]
+        .meta
+        .insert("char", format!("U+{:04X}", u32::from(c)));
+    err.violations[0]
+        .meta
+        .insert("position", position.to_string());
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_len() {
+        let rule = grapheme_len(2, 4);
+        for valid in ["ab", "🇯🇵🇨🇭", "n\u{303}o\u{308}", "👍🏽👍🏽👍🏽👍🏽"]
+        {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+
+        let err = rule.apply("👨‍👩‍👧‍👦");
+        assert_eq!(err.violations[0].code, "min_graphemes");
+        assert_eq!(err.violations[0].meta.get("actual"), Some("1"));
+
+        let err = rule.apply("abcde");
+        assert_eq!(err.violations[0].code, "max_graphemes");
+        assert_eq!(err.violations[0].meta.get("max"), Some("4"));
+    }
+
+    #[test]
+    fn test_is_nfc() {
+        let rule = is_nfc();
+        for valid in ["", "plain", "Åström", "한국어"] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+        // Decomposed Å, and decomposed Hangul syllable
+        for invalid in ["A\u{30A}ström", "\u{1112}\u{1161}\u{11AB}"] {
+            assert_eq!(rule.apply(invalid).violations[0].code, "not_nfc");
+        }
+    }
+
+    #[test]
+    fn test_no_bidi_controls() {
+        let rule = no_bidi_controls();
+        assert!(rule.apply("مرحبا hello").is_empty());
+        for (input, char) in [
+            ("a\u{202E}b", "U+202E"),
+            ("a\u{2067}b", "U+2067"),
+            ("a\u{200F}b", "U+200F"),
+            ("a\u{61C}b", "U+061C"),
+        ] {
+            let err = rule.apply(input);
+            assert_eq!(err.violations[0].code, "bidi_control");
+            assert_eq!(err.violations[0].meta.get("char"), Some(char));
+            assert_eq!(err.violations[0].meta.get("position"), Some("1"));
+        }
+    }
+
+    #[test]
+    fn test_no_control_chars() {
+        let rule = no_control_chars();
+        assert!(rule.apply("Zoë 🎉").is_empty());
+        // Zero-width joiner is a format character, not a control
+        assert!(rule.apply("👩‍💻").is_empty());
+        for invalid in ["\t", "a\u{0}", "a\u{7F}", "a\u{85}"] {
+            assert_eq!(rule.apply(invalid).violations[0].code, "control_char");
+        }
+    }
+
+    #[test]
+    fn test_single_script() {
+        let rule = single_script();
+        for valid in [
+            "",
+            "hello world 42!",
+            "Ελληνικά",
+            "日本語のテキスト",
+            "한국어 漢字",
+        ] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+        let err = rule.apply("hello мир");
+        assert_eq!(err.violations[0].code, "mixed_script");
+        assert_eq!(err.violations[0].meta.get("position"), Some("6"));
+    }
+
+    #[test]
+    fn test_not_confusable_with() {
+        let rule = not_confusable_with(vec![String::from("admin"), String::from("paypal")]);
+        for valid in ["administrator", "jane", "paypal2"] {
+            assert!(rule.apply(valid).is_empty(), "{valid}");
+        }
+        for (invalid, matches) in [
+            ("admin", "admin"),
+            ("ADMIN", "admin"),
+            ("\u{430}dmin", "admin"),
+            ("paypaI", "paypal"),
+            ("p\u{430}yp\u{430}l", "paypal"),
+        ] {
+            let err = rule.apply(invalid);
+            assert_eq!(err.violations[0].code, "confusable", "{invalid}");
+            assert_eq!(err.violations[0].meta.get("matches"), Some(matches));
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! URL policy rule for user-supplied endpoints such as webhooks.
+//!
+//! [`url_with`] parses the value with the WHATWG-compliant [`url`](https://docs.rs/url)
+//! crate, so hosts are checked in the same normalized form an HTTP client
+//! would connect to: `http://0x7f.1/` is the loopback address `127.0.0.1`,
+//! and `http://EXAMPLE.com.` differs from `http://example.com` only by the
+//! trailing dot.
+//!
+//! The private-address check covers IP literals and `localhost` names. It does
+//! not resolve DNS, so a public name pointing at a private address still
+//! passes; re-check the resolved address before connecting if that matters.
+
+use crate::rules::idna;
+use crate::{Rule, RuleContext, ValidationError};
+use ::url::{Host, Url};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Requirements checked by [`url_with`].
+///
+/// Host patterns are matched case-insensitively, after converting Unicode
+/// names to Punycode. `"example.com"` matches only that host, while
+/// `"*.example.com"` matches its subdomains. IP hosts are written without
+/// brackets (`"192.0.2.1"`, `"2001:db8::1"`).
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::rules::UrlPolicy;
+///
+/// let policy = UrlPolicy {
+///     schemes: vec!["https".to_string()],
+///     ..UrlPolicy::default()
+/// }
+/// .deny_host("*.internal.example.com")
+/// .deny_port(22);
+/// assert!(!policy.allow_private_addresses);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlPolicy {
+    /// Accepted schemes, lowercase.
+    pub schemes: Vec<String>,
+    /// If non-empty, only these host patterns are accepted.
+    pub allowed_hosts: Vec<String>,
+    /// Host patterns that are never accepted.
+    pub denied_hosts: Vec<String>,
+    /// If non-empty, only these ports are accepted. Scheme default ports count
+    /// as the URL's port.
+    pub allowed_ports: Vec<u16>,
+    /// Ports that are never accepted.
+    pub denied_ports: Vec<u16>,
+    /// Accept a username or password before the host.
+    pub allow_credentials: bool,
+    /// Accept loopback, private, link-local and other non-public addresses.
+    pub allow_private_addresses: bool,
+    /// Maximum length of the input in bytes.
+    pub max_length: usize,
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        Self {
+            schemes: alloc::vec!["http".to_string(), "https".to_string()],
+            allowed_hosts: Vec::new(),
+            denied_hosts: Vec::new(),
+            allowed_ports: Vec::new(),
+            denied_ports: Vec::new(),
+            allow_credentials: false,
+            allow_private_addresses: false,
+            max_length: 2048,
+        }
+    }
+}
+
+impl UrlPolicy {
+    /// Add a host pattern to the allow list.
+    pub fn allow_host(mut self, pattern: impl Into<String>) -> Self {
+        self.allowed_hosts.push(pattern.into());
+        self
+    }
+
+    /// Add a host pattern to the deny list.
+    pub fn deny_host(mut self, pattern: impl Into<String>) -> Self {
+        self.denied_hosts.push(pattern.into());
+        self
+    }
+
+    /// Add a port to the deny list.
+    pub fn deny_port(mut self, port: u16) -> Self {
+        self.denied_ports.push(port);
+        self
+    }
+}
+
+/// Validates an absolute URL against a [`UrlPolicy`].
+///
+/// Checks run in order and stop at the first failure: length, syntax, scheme,
+/// credentials, host presence, host lists, private addresses, then ports.
+///
+/// Requires the `url` feature.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::prelude::*;
+/// use domainstack::rules::UrlPolicy;
+///
+/// let rule = rules::url_with(UrlPolicy {
+///     schemes: vec!["https".to_string()],
+///     ..UrlPolicy::default()
+/// });
+/// assert!(rule.apply("https://hooks.example.com/events").is_empty());
+///
+/// let err = rule.apply("http://hooks.example.com/events");
+/// assert_eq!(err.violations[0].code, "url_scheme_not_allowed");
+///
+/// let err = rule.apply("https://169.254.169.254/latest/meta-data");
+/// assert_eq!(err.violations[0].code, "url_private_address");
+/// assert_eq!(err.violations[0].meta.get("range"), Some("link_local"));
+/// ```
+///
+/// # Error Codes
+/// - `url_too_long`: `"Must be at most {max} characters"`, meta `max`, `actual`
+/// - `invalid_url`: `"Invalid URL format"`, meta `reason`
+/// - `url_scheme_not_allowed`: `"URL scheme is not allowed"`, meta `scheme`, `allowed`
+/// - `url_credentials`: `"URL must not contain credentials"`
+/// - `url_missing_host`: `"URL must include a host"`
+/// - `url_host_not_allowed`: `"URL host is not allowed"`, meta `host`
+/// - `url_private_address`: `"URL must not point to a private or local address"`,
+///   meta `host`, `range` (`loopback`, `private`, `link_local`, `shared`,
+///   `unspecified`, `multicast` or `reserved`)
+/// - `url_port_not_allowed`: `"URL port is not allowed"`, meta `port`
+pub fn url_with(policy: UrlPolicy) -> Rule<str> {
+    let allowed_hosts: Vec<String> = policy.allowed_hosts.iter().map(|h| host_key(h)).collect();
+    let denied_hosts: Vec<String> = policy.denied_hosts.iter().map(|h| host_key(h)).collect();
+
+    Rule::new(move |value: &str, ctx: &RuleContext| {
+        if value.len() > policy.max_length {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "url_too_long",
+                format!("Must be at most {} characters", policy.max_length),
+            );
+            err.violations[0].meta.insert("max", policy.max_length);
+            err.violations[0].meta.insert("actual", value.len());
+            return err;
+        }
+
+        let url = match Url::parse(value) {
+            Ok(url) => url,
+            Err(e) => {
+                let mut err =
+                    ValidationError::single(ctx.full_path(), "invalid_url", "Invalid URL format");
+                err.violations[0].meta.insert("reason", e.to_string());
+                return err;
+            }
+        };
+
+        if !policy
+            .schemes
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(url.scheme()))
+        {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "url_scheme_not_allowed",
+                "URL scheme is not allowed",
+            );
+            err.violations[0].meta.insert("scheme", url.scheme());
+            err.violations[0]
+                .meta
+                .insert("allowed", policy.schemes.join(","));
+            return err;
+        }
+
+        if !policy.allow_credentials && (!url.username().is_empty() || url.password().is_some()) {
+            return ValidationError::single(
+                ctx.full_path(),
+                "url_credentials",
+                "URL must not contain credentials",
+            );
+        }
+
+        let Some(host) = url.host() else {
+            return ValidationError::single(
+                ctx.full_path(),
+                "url_missing_host",
+                "URL must include a host",
+            );
+        };
+        let host_name = host_name(&host);
+
+        let allowed =
+            allowed_hosts.is_empty() || allowed_hosts.iter().any(|p| host_matches(&host_name, p));
+        if !allowed || denied_hosts.iter().any(|p| host_matches(&host_name, p)) {
+            let mut err = ValidationError::single(
+                ctx.full_path(),
+                "url_host_not_allowed",
+                "URL host is not allowed",
+            );
+            err.violations[0].meta.insert("host", host_name);
+            return err;
+        }
+
+        if !policy.allow_private_addresses {
+            if let Some(range) = private_range(&host) {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "url_private_address",
+                    "URL must not point to a private or local address",
+                );
+                err.violations[0].meta.insert("host", host_name);
+                err.violations[0].meta.insert("range", range);
+                return err;
+            }
+        }
+
+        if let Some(port) = url.port_or_known_default() {
+            let allowed = policy.allowed_ports.is_empty() || policy.allowed_ports.contains(&port);
+            if !allowed || policy.denied_ports.contains(&port) {
+                let mut err = ValidationError::single(
+                    ctx.full_path(),
+                    "url_port_not_allowed",
+                    "URL port is not allowed",
+                );
+                err.violations[0].meta.insert("port", port);
+                return err;
+            }
+        }
+
+        ValidationError::default()
+    })
+}
+
+/// Host as matched against patterns: lowercase, Punycode, no brackets or trailing dot.
+fn host_name<S: AsRef<str>>(host: &Host<S>) -> String {
+    match host {
+        Host::Domain(domain) => domain.as_ref().trim_end_matches('.').to_ascii_lowercase(),
+        Host::Ipv4(addr) => addr.to_string(),
+        Host::Ipv6(addr) => addr.to_string(),
+    }
+}
+
+/// Normalize a configured host pattern to the form returned by [`host_name`].
+fn host_key(pattern: &str) -> String {
+    let (wildcard, host) = match pattern.strip_prefix("*.") {
+        Some(rest) => ("*.", rest),
+        None => ("", pattern),
+    };
+    let host = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.');
+
+    // Reuse the URL parser so IP patterns get the same canonical form as hosts
+    let host = if host.contains(':') {
+        Host::parse(&format!("[{host}]")).map(|h| host_name(&h))
+    } else {
+        Host::parse(host).map(|h| host_name(&h))
+    }
+    .ok()
+    .or_else(|| idna::to_ascii_domain(host))
+    .unwrap_or_else(|| host.to_ascii_lowercase());
+
+    format!("{wildcard}{host}")
+}
+
+fn host_matches(host: &str, pattern: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => host
+            .strip_suffix(suffix)
+            .is_some_and(|sub| sub.len() > 1 && sub.ends_with('.')),
+        None => host == pattern,
+    }
+}
+
+/// The non-public range a host falls in, if any.
+fn private_range(host: &Host<&str>) -> Option<&'static str> {
+    match host {
+        Host::Domain(domain) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            // RFC 6761: `localhost` and its subdomains are loopback names
+            (domain == "localhost" || domain.ends_with(".localhost")).then_some("loopback")
+        }
+        Host::Ipv4(addr) => ipv4_range(u32::from(*addr)),
+        Host::Ipv6(addr) => ipv6_range(u128::from(*addr)),
+    }
+}
+
+fn ipv4_range(addr: u32) -> Option<&'static str> {
+    let in_net = |net: u32, prefix: u32| addr >> (32 - prefix) == net >> (32 - prefix);
+    if in_net(0x7F00_0000, 8) {
+        Some("loopback")
+    } else if in_net(0x0A00_0000, 8) || in_net(0xAC10_0000, 12) || in_net(0xC0A8_0000, 16) {
+        Some("private")
+    } else if in_net(0xA9FE_0000, 16) {
+        Some("link_local")
+    } else if in_net(0x6440_0000, 10) {
+        Some("shared")
+    } else if in_net(0, 8) {
+        Some("unspecified")
+    } else if in_net(0xE000_0000, 4) {
+        Some("multicast")
+    } else if in_net(0xF000_0000, 4) {
+        Some("reserved")
+    } else {
+        None
+    }
+}
+
+fn ipv6_range(addr: u128) -> Option<&'static str> {
+    let in_net = |net: u128, prefix: u32| addr >> (128 - prefix) == net >> (128 - prefix);
+    if addr == 1 {
+        Some("loopback")
+    } else if addr == 0 {
+        Some("unspecified")
+    } else if in_net(0xFFFF_0000_0000, 96) {
+        // IPv4-mapped addresses reach the embedded IPv4 host
+        ipv4_range(addr as u32)
+    } else if in_net(0xFC00 << 112, 7) {
+        Some("private")
+    } else if in_net(0xFE80 << 112, 10) {
+        Some("link_local")
+    } else if in_net(0xFF00 << 112, 8) {
+        Some("multicast")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn code(rule: &Rule<str>, value: &str) -> Option<&'static str> {
+        rule.apply(value).violations.first().map(|v| v.code)
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let rule = url_with(UrlPolicy::default());
+        assert!(rule.apply("https://example.com").is_empty());
+        assert!(rule
+            .apply("http://example.com:8080/path?q=1#top")
+            .is_empty());
+        assert!(rule.apply("https://bücher.de/").is_empty());
+        assert!(rule.apply("https://8.8.8.8/").is_empty());
+
+        assert_eq!(code(&rule, "not a url"), Some("invalid_url"));
+        assert_eq!(code(&rule, "/relative/path"), Some("invalid_url"));
+        assert_eq!(code(&rule, "http://exa mple.com"), Some("invalid_url"));
+        assert_eq!(
+            code(&rule, "ftp://example.com"),
+            Some("url_scheme_not_allowed")
+        );
+        assert_eq!(
+            code(&rule, "https://user:pw@example.com"),
+            Some("url_credentials")
+        );
+        assert_eq!(
+            code(&rule, "https://token@example.com"),
+            Some("url_credentials")
+        );
+
+        let err = rule.apply(&format!("https://example.com/{}", "a".repeat(2048)));
+        assert_eq!(err.violations[0].code, "url_too_long");
+        assert_eq!(err.violations[0].meta.get("max"), Some("2048"));
+    }
+
+    #[test]
+    fn test_private_addresses() {
+        let rule = url_with(UrlPolicy::default());
+        for (value, range) in [
+            ("http://127.0.0.1/", "loopback"),
+            ("http://0x7f.1/", "loopback"),
+            ("http://2130706433/", "loopback"),
+            ("http://localhost:3000/", "loopback"),
+            ("http://api.LOCALHOST./", "loopback"),
+            ("http://[::1]/", "loopback"),
+            ("http://[::ffff:10.0.0.1]/", "private"),
+            ("http://10.1.2.3/", "private"),
+            ("http://172.31.255.255/", "private"),
+            ("http://192.168.0.1/", "private"),
+            ("http://[fd12:3456::1]/", "private"),
+            ("http://169.254.169.254/", "link_local"),
+            ("http://[fe80::1]/", "link_local"),
+            ("http://100.64.0.1/", "shared"),
+            ("http://0.0.0.0/", "unspecified"),
+            ("http://[::]/", "unspecified"),
+            ("http://224.0.0.1/", "multicast"),
+            ("http://255.255.255.255/", "reserved"),
+        ] {
+            let err = rule.apply(value);
+            assert_eq!(err.violations[0].code, "url_private_address", "{value}");
+            assert_eq!(err.violations[0].meta.get("range"), Some(range), "{value}");
+        }
+        assert!(rule.apply("http://172.32.0.1/").is_empty());
+
+        let rule = url_with(UrlPolicy {
+            allow_private_addresses: true,
+            ..UrlPolicy::default()
+        });
+        assert!(rule.apply("http://localhost:3000/").is_empty());
+    }
+
+    #[test]
+    fn test_host_lists() {
+        let rule = url_with(
+            UrlPolicy::default()
+                .allow_host("*.example.com")
+                .allow_host("Bücher.de"),
+        );
+        assert!(rule.apply("https://hooks.example.com/").is_empty());
+        assert!(rule.apply("https://a.b.example.com/").is_empty());
+        assert!(rule.apply("https://xn--bcher-kva.de/").is_empty());
+
+        let err = rule.apply("https://example.com/");
+        assert_eq!(err.violations[0].code, "url_host_not_allowed");
+        assert_eq!(err.violations[0].meta.get("host"), Some("example.com"));
+        assert_eq!(
+            code(&rule, "https://evilexample.com/"),
+            Some("url_host_not_allowed")
+        );
+
+        let rule = url_with(
+            UrlPolicy::default()
+                .deny_host("metadata.google.internal")
+                .deny_host("[2001:DB8::1]")
+                .deny_host("192.0.2.1"),
+        );
+        assert!(rule.apply("https://example.com/").is_empty());
+        for denied in [
+            "http://metadata.google.internal/",
+            "http://[2001:db8:0::1]/",
+            "http://3221225985/",
+        ] {
+            assert_eq!(
+                code(&rule, denied),
+                Some("url_host_not_allowed"),
+                "{denied}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ports_and_host_presence() {
+        let rule = url_with(UrlPolicy::default().deny_port(22).deny_port(80));
+        assert!(rule.apply("https://example.com/").is_empty());
+        let err = rule.apply("http://example.com/");
+        assert_eq!(err.violations[0].code, "url_port_not_allowed");
+        assert_eq!(err.violations[0].meta.get("port"), Some("80"));
+        assert_eq!(
+            code(&rule, "https://example.com:22/"),
+            Some("url_port_not_allowed")
+        );
+
+        let rule = url_with(UrlPolicy {
+            allowed_ports: vec![443],
+            ..UrlPolicy::default()
+        });
+        assert!(rule.apply("https://example.com/").is_empty());
+        assert_eq!(
+            code(&rule, "https://example.com:8443/"),
+            Some("url_port_not_allowed")
+        );
+
+        let rule = url_with(UrlPolicy {
+            schemes: vec!["mailto".to_string(), "https".to_string()],
+            ..UrlPolicy::default()
+        });
+        assert_eq!(
+            code(&rule, "mailto:ops@example.com"),
+            Some("url_missing_host")
+        );
+    }
+}
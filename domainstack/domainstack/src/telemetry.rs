@@ -0,0 +1,435 @@
+//! Tracing spans and metrics for validation outcomes.
+//!
+//! With the `tracing` feature, each validation runs inside a `validate` span that
+//! carries the type name, and every violation is reported as a `DEBUG` event with
+//! its path and code. With the `metrics` feature, outcomes are counted in
+//! [`VALIDATIONS_TOTAL`] and violations in [`VIOLATIONS_TOTAL`].
+//!
+//! `#[derive(Validate)]` output and the HTTP adapters (`domainstack-http`, and
+//! through it Axum, Actix and Rocket) already use [`enter`]; call it yourself in
+//! manual `Validate` impls:
+//!
+//! ```
+//! use domainstack::prelude::*;
+//! use domainstack::telemetry;
+//!
+//! struct Username(String);
+//!
+//! impl Validate for Username {
+//!     fn validate(&self) -> Result<(), ValidationError> {
+//!         let scope = telemetry::enter(core::any::type_name::<Self>());
+//!         scope.finish(validate("username", self.0.as_str(), &rules::min_len(3)))
+//!     }
+//! }
+//!
+//! assert!(Username("al".to_string()).validate().is_err());
+//! ```
+//!
+//! Violations are recorded once, by the outermost scope, so nested types are not
+//! double counted and paths are complete (`rooms[0].adults`). Metric paths replace
+//! collection indices with `*` (`rooms[*].adults`) to keep label cardinality bounded.
+//!
+//! Without either feature, scopes are zero-sized and do nothing.
+
+use crate::ValidationError;
+
+/// Counter of validations, labelled by `type` and `outcome` (`valid` / `invalid`).
+pub const VALIDATIONS_TOTAL: &str = "domainstack_validations_total";
+
+/// Counter of violations, labelled by `type`, `path` and `code`.
+pub const VIOLATIONS_TOTAL: &str = "domainstack_violations_total";
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+std::thread_local! {
+    static CURRENT: core::cell::Cell<Option<&'static str>> = const { core::cell::Cell::new(None) };
+}
+
+/// An in-progress validation, created by [`enter`] and completed by [`ValidationScope::finish`].
+#[must_use = "call `finish` with the validation result"]
+pub struct ValidationScope {
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    state: Option<ScopeState>,
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+struct ScopeState {
+    type_name: &'static str,
+    previous: Option<&'static str>,
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+impl Drop for ScopeState {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+/// Starts observing the validation of `type_name`.
+///
+/// Re-entering the type that is already being validated (e.g. an adapter calling a
+/// derived `validate()`) reuses the existing scope instead of opening a second span.
+pub fn enter(type_name: &'static str) -> ValidationScope {
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    {
+        let previous = CURRENT.with(|current| current.get());
+        if previous == Some(type_name) {
+            return ValidationScope { state: None };
+        }
+        CURRENT.with(|current| current.set(Some(type_name)));
+
+        ValidationScope {
+            state: Some(ScopeState {
+                type_name,
+                previous,
+                #[cfg(feature = "tracing")]
+                span: tracing::debug_span!(
+                    "validate",
+                    type_name,
+                    valid = tracing::field::Empty,
+                    violations = tracing::field::Empty,
+                )
+                .entered(),
+            }),
+        }
+    }
+
+    #[cfg(not(any(feature = "tracing", feature = "metrics")))]
+    {
+        let _ = type_name;
+        ValidationScope {}
+    }
+}
+
+impl ValidationScope {
+    /// Records the outcome of the validation and returns it unchanged.
+    #[allow(clippy::result_large_err)]
+    pub fn finish<T>(self, result: Result<T, ValidationError>) -> Result<T, ValidationError> {
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        if let Some(state) = &self.state {
+            let err = result.as_ref().err();
+
+            #[cfg(feature = "tracing")]
+            {
+                state.span.record("valid", err.is_none());
+                state
+                    .span
+                    .record("violations", err.map_or(0, |e| e.violations.len()));
+            }
+
+            if state.previous.is_none() {
+                record(state.type_name, err);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+fn record(type_name: &'static str, err: Option<&ValidationError>) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(
+        VALIDATIONS_TOTAL,
+        "type" => type_name,
+        "outcome" => if err.is_some() { "invalid" } else { "valid" },
+    )
+    .increment(1);
+
+    for violation in err.iter().flat_map(|e| &e.violations) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            type_name,
+            path = %violation.path,
+            code = violation.code,
+            "validation violation"
+        );
+
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            VIOLATIONS_TOTAL,
+            "type" => type_name,
+            "path" => path_label(&violation.path),
+            "code" => violation.code,
+        )
+        .increment(1);
+    }
+}
+
+/// Formats a path with collection indices replaced by `*`.
+#[cfg(feature = "metrics")]
+fn path_label(path: &crate::Path) -> alloc::string::String {
+    use crate::PathSegment;
+    use alloc::string::String;
+
+    let mut label = String::new();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::Field(name) => {
+                if !label.is_empty() {
+                    label.push('.');
+                }
+                label.push_str(name);
+            }
+            PathSegment::Index(_) => label.push_str("[*]"),
+        }
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Path;
+
+    #[allow(clippy::result_large_err)]
+    fn failing() -> Result<(), ValidationError> {
+        Err(ValidationError::single(
+            Path::root().field("rooms").index(2).field("adults"),
+            "out_of_range",
+            "Must be between 1 and 4",
+        ))
+    }
+
+    #[test]
+    fn test_finish_returns_result_unchanged() {
+        assert_eq!(enter("Booking").finish(Ok::<_, ValidationError>(5)), Ok(5));
+
+        let err = enter("Booking").finish(failing()).unwrap_err();
+        assert_eq!(err.violations[0].code, "out_of_range");
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_path_label_replaces_indices() {
+        let path = Path::root()
+            .field("rooms")
+            .index(3)
+            .field("guests")
+            .index(0);
+        assert_eq!(path_label(&path), "rooms[*].guests[*]");
+    }
+
+    #[cfg(feature = "metrics")]
+    mod metrics_recorder {
+        use super::*;
+        use metrics::{
+            Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString,
+            Unit,
+        };
+        use std::sync::{Arc, Mutex};
+
+        type Counts = Arc<Mutex<Vec<(String, Vec<(String, String)>, u64)>>>;
+
+        #[derive(Default)]
+        struct TestRecorder {
+            counts: Counts,
+        }
+
+        struct TestCounter {
+            key: Key,
+            counts: Counts,
+        }
+
+        impl CounterFn for TestCounter {
+            fn increment(&self, value: u64) {
+                let labels = self
+                    .key
+                    .labels()
+                    .map(|l| (l.key().to_string(), l.value().to_string()))
+                    .collect();
+                self.counts
+                    .lock()
+                    .unwrap()
+                    .push((self.key.name().to_string(), labels, value));
+            }
+
+            fn absolute(&self, _value: u64) {}
+        }
+
+        impl Recorder for TestRecorder {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                Counter::from_arc(Arc::new(TestCounter {
+                    key: key.clone(),
+                    counts: self.counts.clone(),
+                }))
+            }
+
+            fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+                Gauge::noop()
+            }
+
+            fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+                Histogram::noop()
+            }
+        }
+
+        fn labels(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        }
+
+        #[test]
+        fn test_counts_outcomes_and_violations() {
+            let recorder = TestRecorder::default();
+            metrics::with_local_recorder(&recorder, || {
+                let _ = enter("Booking").finish(Ok::<_, ValidationError>(()));
+                let _ = enter("Booking").finish(failing());
+            });
+
+            let counts = recorder.counts.lock().unwrap();
+            assert_eq!(
+                *counts,
+                vec![
+                    (
+                        VALIDATIONS_TOTAL.to_string(),
+                        labels(&[("type", "Booking"), ("outcome", "valid")]),
+                        1
+                    ),
+                    (
+                        VALIDATIONS_TOTAL.to_string(),
+                        labels(&[("type", "Booking"), ("outcome", "invalid")]),
+                        1
+                    ),
+                    (
+                        VIOLATIONS_TOTAL.to_string(),
+                        labels(&[
+                            ("type", "Booking"),
+                            ("path", "rooms[*].adults"),
+                            ("code", "out_of_range")
+                        ]),
+                        1
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_only_outermost_scope_records() {
+            let recorder = TestRecorder::default();
+            metrics::with_local_recorder(&recorder, || {
+                let outer = enter("Booking");
+                let inner = enter("Room").finish(failing());
+                let _ = outer.finish(inner);
+            });
+
+            let counts = recorder.counts.lock().unwrap();
+            assert_eq!(counts.len(), 2);
+            assert!(counts
+                .iter()
+                .all(|(_, labels, _)| labels[0] == ("type".to_string(), "Booking".to_string())));
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing_subscriber {
+        use super::*;
+        use std::fmt::Debug;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        type Recorded = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+        #[derive(Default)]
+        struct Fields(Vec<(String, String)>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0
+                    .push((field.name().to_string(), format!("{:?}", value)));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push((field.name().to_string(), value.to_string()));
+            }
+        }
+
+        #[derive(Default, Clone)]
+        struct TestSubscriber {
+            next_id: Arc<AtomicU64>,
+            spans: Recorded,
+            events: Recorded,
+        }
+
+        impl Subscriber for TestSubscriber {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = Fields::default();
+                span.record(&mut fields);
+                self.spans.lock().unwrap().push(fields.0);
+                Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut fields = Fields::default();
+                values.record(&mut fields);
+                let index = span.into_u64() as usize - 1;
+                self.spans.lock().unwrap()[index].extend(fields.0);
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields::default();
+                event.record(&mut fields);
+                self.events.lock().unwrap().push(fields.0);
+            }
+
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        fn field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+            fields
+                .iter()
+                .rev()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.as_str())
+        }
+
+        #[test]
+        fn test_span_and_violation_events() {
+            let subscriber = TestSubscriber::default();
+            tracing::subscriber::with_default(subscriber.clone(), || {
+                let _ = enter("Booking").finish(failing());
+            });
+
+            let spans = subscriber.spans.lock().unwrap();
+            assert_eq!(spans.len(), 1);
+            assert_eq!(field(&spans[0], "type_name"), Some("Booking"));
+            assert_eq!(field(&spans[0], "valid"), Some("false"));
+            assert_eq!(field(&spans[0], "violations"), Some("1"));
+
+            let events = subscriber.events.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(field(&events[0], "path"), Some("rooms[2].adults"));
+            assert_eq!(field(&events[0], "code"), Some("out_of_range"));
+        }
+
+        #[test]
+        fn test_reentering_same_type_reuses_span() {
+            let subscriber = TestSubscriber::default();
+            tracing::subscriber::with_default(subscriber.clone(), || {
+                let outer = enter("Booking");
+                let inner = enter("Booking").finish(failing());
+                let _ = outer.finish(inner);
+            });
+
+            assert_eq!(subscriber.spans.lock().unwrap().len(), 1);
+            assert_eq!(subscriber.events.lock().unwrap().len(), 1);
+        }
+    }
+}
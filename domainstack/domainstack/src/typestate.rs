@@ -195,6 +195,53 @@
 //! - Validation is trivial and cheap to repeat
 //! - The type already uses "valid-by-construction" pattern (validation in constructor)
 //!
+//! # State Transitions
+//!
+//! Phantom markers track *whether* data was validated. Domain aggregates also move through
+//! runtime states (`Pending -> Confirmed -> Completed`), and not every move is legal.
+//! [`ValidateTransition`] checks a proposed state change and reports an illegal one as a
+//! standard `invalid_transition` violation:
+//!
+//! ```rust
+//! use domainstack::typestate::ValidateTransition;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum BookingStatus {
+//!     Pending,
+//!     Confirmed,
+//!     Cancelled,
+//! }
+//!
+//! impl ValidateTransition for BookingStatus {
+//!     fn state_name(&self) -> &'static str {
+//!         match self {
+//!             Self::Pending => "Pending",
+//!             Self::Confirmed => "Confirmed",
+//!             Self::Cancelled => "Cancelled",
+//!         }
+//!     }
+//!
+//!     fn can_transition_to(&self, next: &Self) -> bool {
+//!         matches!(
+//!             (self, next),
+//!             (Self::Pending, Self::Confirmed | Self::Cancelled) | (Self::Confirmed, Self::Cancelled)
+//!         )
+//!     }
+//! }
+//!
+//! assert!(BookingStatus::Pending.validate_transition(&BookingStatus::Cancelled).is_ok());
+//!
+//! let err = BookingStatus::Cancelled
+//!     .validate_transition(&BookingStatus::Confirmed)
+//!     .unwrap_err();
+//! assert_eq!(err.violations[0].code, "invalid_transition");
+//! assert_eq!(err.violations[0].meta.get("from"), Some("Cancelled"));
+//! assert_eq!(err.violations[0].meta.get("to"), Some("Confirmed"));
+//! ```
+//!
+//! With the `derive` feature, `#[derive(ValidateTransition)]` generates the impl from a
+//! declarative table: `#[transitions(Pending -> [Confirmed, Cancelled], Confirmed -> Cancelled)]`.
+//!
 //! # Performance
 //!
 //! Phantom types have **zero runtime cost**:
@@ -223,6 +270,9 @@
 //! );
 //! ```
 
+use crate::{Path, ValidationError};
+use alloc::format;
+
 /// Marker type indicating that data has **not** been validated.
 ///
 /// This is typically the default state for types with validation state tracking.
@@ -284,10 +334,62 @@ pub struct Unvalidated;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Validated;
 
+/// Validates state changes of a domain state machine.
+///
+/// Implementors describe which transitions are legal; the provided methods turn an
+/// illegal transition into a `ValidationError` so it flows through the same error
+/// contract (HTTP envelopes, field maps) as every other violation.
+///
+/// See the [module documentation](self#state-transitions) for an example.
+pub trait ValidateTransition {
+    /// Stable name of the current state, reported in violation metadata.
+    fn state_name(&self) -> &'static str;
+
+    /// Returns `true` if moving from `self` to `next` is allowed.
+    fn can_transition_to(&self, next: &Self) -> bool;
+
+    /// Validates a transition from `self` to `next`.
+    ///
+    /// # Error Code
+    /// - Code: `invalid_transition`
+    /// - Message: `"Cannot transition from {from} to {to}"`
+    /// - Meta: `{"from": "Cancelled", "to": "Confirmed"}`
+    #[allow(clippy::result_large_err)]
+    fn validate_transition(&self, next: &Self) -> Result<(), ValidationError> {
+        if self.can_transition_to(next) {
+            return Ok(());
+        }
+
+        let from = self.state_name();
+        let to = next.state_name();
+        let mut err = ValidationError::single(
+            Path::root(),
+            "invalid_transition",
+            format!("Cannot transition from {} to {}", from, to),
+        );
+        err.violations[0].meta.insert("from", from);
+        err.violations[0].meta.insert("to", to);
+        Err(err)
+    }
+
+    /// Validates the transition and returns `next` on success.
+    ///
+    /// Convenient for aggregate methods that replace their state in one step:
+    /// `self.status = self.status.transition(BookingStatus::Cancelled)?;`
+    #[allow(clippy::result_large_err)]
+    fn transition(&self, next: Self) -> Result<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        self.validate_transition(&next)?;
+        Ok(next)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{rules, validate, ValidationError};
+    use crate::{rules, validate};
     use std::marker::PhantomData;
 
     // Example domain type using phantom types
@@ -491,4 +593,62 @@ mod tests {
         assert_eq!(errors.violations.len(), 1);
         assert!(errors.violations[0].path.to_string().contains("username"));
     }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum OrderStatus {
+        Pending,
+        Shipped,
+        Cancelled,
+    }
+
+    impl ValidateTransition for OrderStatus {
+        fn state_name(&self) -> &'static str {
+            match self {
+                Self::Pending => "Pending",
+                Self::Shipped => "Shipped",
+                Self::Cancelled => "Cancelled",
+            }
+        }
+
+        fn can_transition_to(&self, next: &Self) -> bool {
+            matches!(
+                (self, next),
+                (Self::Pending, Self::Shipped) | (Self::Pending, Self::Cancelled)
+            )
+        }
+    }
+
+    #[test]
+    fn test_valid_transition() {
+        assert!(OrderStatus::Pending
+            .validate_transition(&OrderStatus::Shipped)
+            .is_ok());
+        assert_eq!(
+            OrderStatus::Pending.transition(OrderStatus::Cancelled),
+            Ok(OrderStatus::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_invalid_transition_violation() {
+        let err = OrderStatus::Shipped
+            .validate_transition(&OrderStatus::Pending)
+            .unwrap_err();
+
+        assert_eq!(err.violations.len(), 1);
+        assert_eq!(err.violations[0].code, "invalid_transition");
+        assert_eq!(
+            err.violations[0].message,
+            "Cannot transition from Shipped to Pending"
+        );
+        assert_eq!(err.violations[0].meta.get("from"), Some("Shipped"));
+        assert_eq!(err.violations[0].meta.get("to"), Some("Pending"));
+    }
+
+    #[test]
+    fn test_self_transition_not_implied() {
+        assert!(OrderStatus::Pending
+            .validate_transition(&OrderStatus::Pending)
+            .is_err());
+    }
 }
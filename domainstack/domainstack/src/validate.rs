@@ -5,6 +5,125 @@ pub trait Validate {
     fn validate(&self) -> Result<(), ValidationError>;
 }
 
+/// Validation that depends on caller-supplied context.
+///
+/// `Validate` takes no arguments, so rules that depend on runtime state - the current
+/// tenant's limits, feature flags, the caller's role - can't be expressed with it.
+/// `ValidateWith<Ctx>` threads a plain, synchronous context value through validation.
+/// Unlike [`ValidationContext`](crate::ValidationContext), it needs no `async` feature
+/// and the context is statically typed.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::{ValidateWith, ValidationError};
+///
+/// struct TenantLimits {
+///     max_rooms: usize,
+/// }
+///
+/// struct Booking {
+///     rooms: Vec<String>,
+/// }
+///
+/// impl ValidateWith<TenantLimits> for Booking {
+///     fn validate_with(&self, ctx: &TenantLimits) -> Result<(), ValidationError> {
+///         if self.rooms.len() > ctx.max_rooms {
+///             return Err(ValidationError::single("rooms", "too_many_items", "Too many rooms"));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let limits = TenantLimits { max_rooms: 2 };
+/// let booking = Booking { rooms: vec!["101".into(), "102".into(), "103".into()] };
+/// assert!(booking.validate_with(&limits).is_err());
+/// ```
+///
+/// With the `derive` feature, `#[derive(Validate)]` generates this trait instead of
+/// `Validate` when the type carries a `#[validate(context = TenantLimits)]` attribute.
+/// Rule arguments and `check` expressions can then refer to the context as `ctx`.
+pub trait ValidateWith<Ctx: ?Sized> {
+    #[allow(clippy::result_large_err)]
+    fn validate_with(&self, ctx: &Ctx) -> Result<(), ValidationError>;
+}
+
+/// Group validated by [`Validate::validate`] on types that use validation groups.
+pub const DEFAULT_GROUP: &str = "default";
+
+/// Validation with per-operation rule groups.
+///
+/// The same DTO often needs different rules per operation: an `id` is required on
+/// update but must be absent on create, and admins may exceed normal limits.
+/// Rules tagged with groups run only when one of their groups is validated;
+/// untagged rules run for every group.
+///
+/// # Examples
+///
+/// ```
+/// use domainstack::{ValidateGroup, ValidationError};
+///
+/// struct UserDto {
+///     id: Option<u64>,
+/// }
+///
+/// impl ValidateGroup for UserDto {
+///     fn validate_group(&self, group: &str) -> Result<(), ValidationError> {
+///         match (group, self.id) {
+///             ("create", Some(_)) => Err(ValidationError::single("id", "forbidden", "Must not be set")),
+///             ("update", None) => Err(ValidationError::single("id", "required", "Is required")),
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let dto = UserDto { id: None };
+/// assert!(dto.validate_group("create").is_ok());
+/// assert!(dto.validate_group("update").is_err());
+/// ```
+///
+/// With the `derive` feature, `#[derive(Validate)]` implements this trait for every
+/// type without a validation context, and `validate()` validates [`DEFAULT_GROUP`].
+/// Nested fields are validated with the same group when their type implements
+/// `ValidateGroup`, and with `validate()` otherwise.
+pub trait ValidateGroup {
+    #[allow(clippy::result_large_err)]
+    fn validate_group(&self, group: &str) -> Result<(), ValidationError>;
+}
+
+/// Validates a nested value for a group, used by the derive macro.
+///
+/// `(&&NestedGroup(value)).validate_nested(group)` resolves to
+/// [`ValidateGroup::validate_group`] when the value's type implements it and falls
+/// back to [`Validate::validate`] otherwise, so hand-written `Validate` impls keep
+/// working as nested fields of grouped types.
+#[doc(hidden)]
+pub struct NestedGroup<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ViaValidateGroup {
+    #[allow(clippy::result_large_err)]
+    fn validate_nested(&self, group: &str) -> Result<(), ValidationError>;
+}
+
+impl<T: ValidateGroup + ?Sized> ViaValidateGroup for &NestedGroup<'_, T> {
+    fn validate_nested(&self, group: &str) -> Result<(), ValidationError> {
+        self.0.validate_group(group)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaValidate {
+    #[allow(clippy::result_large_err)]
+    fn validate_nested(&self, group: &str) -> Result<(), ValidationError>;
+}
+
+impl<T: Validate + ?Sized> ViaValidate for NestedGroup<'_, T> {
+    fn validate_nested(&self, _group: &str) -> Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +162,53 @@ mod tests {
         assert_eq!(err.violations.len(), 1);
         assert_eq!(err.violations[0].code, "negative");
     }
+
+    struct Limits {
+        max: i32,
+    }
+
+    impl ValidateWith<Limits> for TestStruct {
+        fn validate_with(&self, ctx: &Limits) -> Result<(), ValidationError> {
+            if self.value > ctx.max {
+                Err(ValidationError::single(
+                    "value",
+                    "above_maximum",
+                    "Value exceeds tenant limit",
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_with_uses_context() {
+        let test = TestStruct { value: 10 };
+        assert!(test.validate_with(&Limits { max: 20 }).is_ok());
+
+        let err = test.validate_with(&Limits { max: 5 }).unwrap_err();
+        assert_eq!(err.violations[0].code, "above_maximum");
+    }
+
+    impl ValidateGroup for TestStruct {
+        fn validate_group(&self, group: &str) -> Result<(), ValidationError> {
+            let max = if group == "admin" { 1000 } else { 100 };
+            if self.value > max {
+                Err(ValidationError::single(
+                    "value",
+                    "above_maximum",
+                    "Value too large",
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_group_selects_rules() {
+        let test = TestStruct { value: 500 };
+        assert!(test.validate_group("admin").is_ok());
+        assert!(test.validate_group(DEFAULT_GROUP).is_err());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::Path;
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Violation {
@@ -9,15 +10,19 @@ pub struct Violation {
     pub meta: Meta,
 }
 
+/// Machine-readable details attached to a violation, e.g. `{"min": "3"}`.
+///
+/// Keys are kept in sorted order, so iteration and serialized output are
+/// deterministic.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Meta {
-    fields: HashMap<&'static str, String>,
+    fields: BTreeMap<&'static str, String>,
 }
 
 impl Meta {
     pub fn new() -> Self {
         Self {
-            fields: HashMap::new(),
+            fields: BTreeMap::new(),
         }
     }
 
@@ -161,6 +166,17 @@ mod tests {
         assert!(entries.contains(&("actual", "150")));
     }
 
+    #[test]
+    fn test_meta_iter_is_sorted_by_key() {
+        let mut meta = Meta::new();
+        meta.insert("min", 1);
+        meta.insert("actual", 150);
+        meta.insert("max", 100);
+
+        let keys: Vec<_> = meta.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["actual", "max", "min"]);
+    }
+
     #[test]
     fn test_meta_iter_all_values_present() {
         let mut meta = Meta::new();